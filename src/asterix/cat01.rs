@@ -0,0 +1,79 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record01::Record01;
+
+data_block!(
+    /// CAT01 message
+    Cat01Message, Record01, Cat001, 1, insert_record01, remove_record01
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat01::target_report_descriptor_field::{RadarDetection, ReportType};
+    use bytes::Bytes;
+
+    // Plot with I001/010, 020, 040, 070, 141 and track with I001/010, 020, 161, 040, 042, 200, 170
+    const PLOT_TRACK: &[u8] = &[
+        0x01, 0x00, 0x24, 0xf2, 0x19, 0xc9, 0x30, 0x40, 0x40, 0x40, 0x00, 0x02, 0x00, 0x96, 0x20,
+        0xfd, 0x04, 0x19, 0xc9, 0xb0, 0x0d, 0xeb, 0x40, 0x40, 0x40, 0x00, 0xff, 0xa0, 0x40, 0x00,
+        0x00, 0x80, 0x40, 0x00, 0x61, 0x80,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(PLOT_TRACK);
+
+        let mut message = decode(&bytes).unwrap();
+
+        // Track record
+        let record = message.remove_record01().unwrap();
+
+        assert_eq!(record.get_report_type(), ReportType::Track);
+        assert_eq!(record.track_number.unwrap().get_track_number(), 3563);
+        assert_eq!(record.measured_position.unwrap().get_rho(), 128.5);
+        assert_eq!(record.calculated_position.unwrap().get_x(), -1.5);
+        assert_eq!(record.calculated_position.unwrap().get_y(), 256.0);
+        assert_eq!(record.track_velocity.unwrap().get_heading(), 90.0);
+        assert!(record.track_status.clone().unwrap().get_manoeuvre());
+        assert!(record.track_status.unwrap().get_end_of_track());
+
+        // Plot record
+        let record = message.remove_record01().unwrap();
+
+        assert_eq!(record.get_report_type(), ReportType::Plot);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.target_report_descriptor.unwrap().get_detection(),
+            RadarDetection::SsrPsr
+        );
+        assert_eq!(record.measured_position.unwrap().get_theta(), 90.0);
+        assert_eq!(record.mode_3a_code.unwrap().get_code(), 0o1000);
+        assert_eq!(record.truncated_time.unwrap().get_time(), 300.25);
+        assert_eq!(record.track_number, None);
+        assert_eq!(message.remove_record01(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(PLOT_TRACK);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_radar_plot_characteristics_overrun() {
+        // Plot with I001/130 Radar Plot Characteristics, the FX bit announces
+        // a second octet which is missing
+        let array: &'static [u8] = &[0x01, 0x00, 0x08, 0xc4, 0x19, 0x0c, 0x00, 0x81];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+}
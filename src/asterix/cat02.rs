@@ -0,0 +1,70 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record02::Record02;
+
+data_block!(
+    /// CAT02 message
+    Cat02Message, Record02, Cat002, 2, insert_record02, remove_record02
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat02::message_type_field::ServiceMessage;
+    use crate::uap::cat02::plot_count_field::PlotCountType;
+    use bytes::Bytes;
+
+    // North marker with I002/010, 000, 030, 041, 070, 090
+    const NORTH_MARKER: &[u8] = &[
+        0x02, 0x00, 0x14, 0xd9, 0xa0, 0x19, 0xc9, 0x01, 0x18, 0x1c, 0xc0, 0x02, 0x00, 0x02, 0x07,
+        0xe8, 0x8c, 0x05, 0xc0, 0x2e,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(NORTH_MARKER);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record02().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_message_type(),
+            ServiceMessage::NorthMarker
+        );
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(record.antenna_rotation.unwrap().get_period(), 4.0);
+        assert_eq!(
+            record.plot_count.unwrap().get_counts(),
+            vec![
+                (false, PlotCountType::SolePrimary, 1000),
+                (true, PlotCountType::Combined, 5)
+            ]
+        );
+        assert_eq!(record.collimation_error.unwrap().get_range_error(), -0.5);
+        assert_eq!(record.sector_number, None);
+        assert_eq!(message.remove_record02(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(NORTH_MARKER);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_plot_count_overrun() {
+        // I002/070 Plot Count Values announces two counters, the second is missing
+        let array: &'static [u8] = &[0x02, 0x00, 0x0a, 0x81, 0x80, 0x19, 0x0c, 0x02, 0x08, 0x2a];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record04::Record04;
+
+data_block!(
+    /// CAT04 message
+    Cat04Message, Record04, Cat004, 4, insert_record04, remove_record04
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat04::message_type_field::AlertType;
+    use bytes::Bytes;
+
+    // STCA alert with I004/010, 000, 020, 040, 045, 030
+    const STCA_ALERT: &[u8] = &[
+        0x04, 0x00, 0x10, 0xdd, 0x80, 0x19, 0xc9, 0x07, 0x18, 0x1c, 0xc0, 0x10, 0x92, 0x0a, 0x04,
+        0xd2,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(STCA_ALERT);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record04().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_alert_type(),
+            AlertType::ShortTermConflictAlert
+        );
+        assert_eq!(record.time_of_message.unwrap().get_time(), 12345.5);
+        assert_eq!(record.alert_identifier.unwrap().get_identifier(), 4242);
+        assert_eq!(record.alert_status.unwrap().get_status(), 5);
+        assert_eq!(record.track_number_1.unwrap().get_track_number(), 1234);
+        assert_eq!(record.track_number_2, None);
+        assert_eq!(message.remove_record04(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(STCA_ALERT);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_sdps_identifier_overrun() {
+        // I004/015 SDPS Identifier announces two SDPS, the second is missing
+        let array: &'static [u8] = &[0x04, 0x00, 0x09, 0xa0, 0x19, 0x0c, 0x02, 0x19, 0x0c];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(6)));
+    }
+}
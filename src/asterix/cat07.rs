@@ -0,0 +1,71 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record07::Record07;
+
+data_block!(
+    /// CAT07 message
+    Cat07Message, Record07, Cat007, 7, insert_record07, remove_record07
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat07::message_type_field::InterrogationMessage;
+    use bytes::Bytes;
+
+    // Interrogation request with I007/010, 000, 140, 220, 161, 020, 030
+    const INTERROGATION_REQUEST: &[u8] = &[
+        0x07, 0x00, 0x14, 0xfb, 0x80, 0x19, 0xc9, 0x01, 0x18, 0x1c, 0xc0, 0x3c, 0x66, 0x0c, 0x0d,
+        0xeb, 0xa5, 0x02, 0x40, 0x60,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(INTERROGATION_REQUEST);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record07().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_message_type(),
+            InterrogationMessage::Request
+        );
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(record.aircraft_address.unwrap().get_address(), 0x3c660c);
+        assert_eq!(record.track_number.unwrap().get_track_number(), 3563);
+        assert_eq!(
+            record.interrogation_descriptor.unwrap().get_uplink_format(),
+            20
+        );
+        assert_eq!(record.interrogation_descriptor.unwrap().get_priority(), 5);
+        assert_eq!(
+            record.interrogation_request.unwrap().get_registers(),
+            vec![(4, 0), (6, 0)]
+        );
+        assert_eq!(record.interrogation_status, None);
+        assert_eq!(message.remove_record07(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(INTERROGATION_REQUEST);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_interrogation_request_overrun() {
+        // I007/030 Interrogation Request announces two BDS registers, the second is missing
+        let array: &'static [u8] = &[0x07, 0x00, 0x09, 0x81, 0x80, 0x19, 0x0c, 0x02, 0x40];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+
+use crate::asterix::data_record::data_block;
+use crate::asterix::record08::Record08;
+use crate::category::CatError;
+use crate::uap::cat08::contour_identifier_field::ContourPosition;
+use crate::uap::cat08::processing_status_field::ProcessingStatus;
+
+data_block!(
+    /// CAT08 message
+    Cat08Message, Record08, Cat008, 8, insert_record08, remove_record08
+);
+
+/// Closed weather contour of one intensity level
+#[derive(Debug, PartialEq, Clone)]
+pub struct WeatherContour {
+    /// Contour serial number
+    pub serial_number: u8,
+    /// Contour points in NM, the last point equals the first point
+    pub points: Vec<(f64, f64)>,
+}
+
+/*
+ * Assemble contour records into closed weather contours per intensity level.
+ * A contour spans from its first to its last record, the scaling factor of
+ * the latest processing status applies to the contour points.
+ */
+pub fn assemble_contours<'a, I>(records: I) -> Result<BTreeMap<u8, Vec<WeatherContour>>, CatError>
+where
+    I: IntoIterator<Item = &'a Record08>,
+{
+    let mut contours: BTreeMap<u8, Vec<WeatherContour>> = BTreeMap::new();
+    let mut resolution = ProcessingStatus::default().get_resolution();
+    let mut open: Option<(u8, WeatherContour)> = None;
+
+    for record in records {
+        if let Some(processing_status) = &record.processing_status {
+            resolution = processing_status.get_resolution();
+        }
+        let (Some(identifier), Some(points)) = (record.contour_identifier, &record.contour_points)
+        else {
+            continue;
+        };
+        let intensity = identifier.get_intensity();
+        let serial_number = identifier.get_serial_number();
+        let position = identifier.get_position();
+
+        if matches!(
+            position,
+            ContourPosition::First | ContourPosition::FirstAndLast
+        ) {
+            // Previous contour has no last record
+            if open.is_some() {
+                return Err(CatError::ValueInvalid);
+            }
+            let contour = WeatherContour {
+                serial_number,
+                points: Vec::new(),
+            };
+            open = Some((intensity, contour));
+        }
+
+        // Continued contour must match the open contour
+        let (open_intensity, contour) = open.as_mut().ok_or(CatError::ValueInvalid)?;
+        if *open_intensity != intensity || contour.serial_number != serial_number {
+            return Err(CatError::ValueInvalid);
+        }
+        contour.points.extend(points.get_points(resolution));
+
+        if matches!(
+            position,
+            ContourPosition::Last | ContourPosition::FirstAndLast
+        ) {
+            let (intensity, mut contour) = open.take().ok_or(CatError::ValueInvalid)?;
+
+            // Close polygon
+            if let Some(first) = contour.points.first().copied() {
+                if contour.points.last() != Some(&first) {
+                    contour.points.push(first);
+                }
+            }
+            contours.entry(intensity).or_default().push(contour);
+        }
+    }
+
+    // Last contour has no last record
+    if open.is_some() {
+        return Err(CatError::ValueInvalid);
+    }
+    Ok(contours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat08::contour_identifier_field::ContourIdentifier;
+    use crate::uap::cat08::contour_points_field::ContourPoints;
+    use crate::uap::cat08::message_type_field::WeatherMessage;
+    use bytes::Bytes;
+
+    // Weather picture with I008/010, 000, 020, 034 and I008/010, 000, 090, 100
+    const WEATHER_PICTURE: &[u8] = &[
+        0x08, 0x00, 0x18, 0xe8, 0x19, 0xc9, 0x01, 0x54, 0x01, 0x14, 0x19, 0x40, 0x00, 0xc1, 0xc0,
+        0x19, 0xc9, 0xfe, 0x18, 0x1c, 0xc0, 0xf0, 0x00, 0x00,
+    ];
+
+    /*
+     * Create contour record.
+     */
+    fn contour_record(
+        intensity: u8,
+        serial_number: u8,
+        position: ContourPosition,
+        points: &[(f64, f64)],
+    ) -> Record08 {
+        let mut contour_identifier = ContourIdentifier::default();
+        contour_identifier.set_intensity(intensity).unwrap();
+        contour_identifier.set_serial_number(serial_number);
+        contour_identifier.set_position(position);
+
+        let mut contour_points = ContourPoints::default();
+        for (x, y) in points {
+            contour_points.insert_point(*x, *y, 0.25).unwrap();
+        }
+        let mut record = Record08::default();
+        record.contour_identifier = Some(contour_identifier);
+        record.contour_points = Some(contour_points);
+        record
+    }
+
+    #[test]
+    fn check_assemble_contours() {
+        // Scaling factor of 4 gives a resolution of 0.25 NM
+        let mut processing_status = ProcessingStatus::default();
+        processing_status.set_scaling_factor(4).unwrap();
+        let mut start_of_picture = Record08::default();
+        start_of_picture.processing_status = Some(processing_status);
+
+        // Contour of intensity 2 in two records, contour of intensity 5 in one record
+        let records = vec![
+            start_of_picture,
+            contour_record(2, 1, ContourPosition::First, &[(0.0, 0.0), (1.0, 0.0)]),
+            contour_record(2, 1, ContourPosition::Last, &[(1.0, 1.0)]),
+            contour_record(
+                5,
+                2,
+                ContourPosition::FirstAndLast,
+                &[(0.5, 0.5), (0.75, 0.5)],
+            ),
+        ];
+
+        let contours = assemble_contours(&records).unwrap();
+
+        assert_eq!(contours.len(), 2);
+        assert_eq!(
+            contours[&2],
+            vec![WeatherContour {
+                serial_number: 1,
+                points: vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)],
+            }]
+        );
+        assert_eq!(
+            contours[&5][0].points,
+            vec![(0.5, 0.5), (0.75, 0.5), (0.5, 0.5)]
+        );
+
+        // Contour without first record
+        let records = vec![contour_record(2, 1, ContourPosition::Last, &[(1.0, 1.0)])];
+
+        assert_eq!(assemble_contours(&records), Err(CatError::ValueInvalid));
+
+        // Contour without last record
+        let records = vec![contour_record(2, 1, ContourPosition::First, &[(1.0, 1.0)])];
+
+        assert_eq!(assemble_contours(&records), Err(CatError::ValueInvalid));
+    }
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(WEATHER_PICTURE);
+
+        let message = decode(&bytes).unwrap();
+        let records = message.get_records();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(records[0].data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            records[0].message_type.unwrap().get_message_type(),
+            WeatherMessage::PolarVector
+        );
+        let vector_qualifier = records[0].vector_qualifier.as_ref().unwrap();
+        assert_eq!(vector_qualifier.get_intensity(), 5);
+        assert_eq!(vector_qualifier.get_direction(), 2);
+        assert_eq!(
+            records[0].polar_vectors.as_ref().unwrap().get_vectors(0.5),
+            vec![(10.0, 12.5, 90.0)]
+        );
+        assert_eq!(
+            records[1].message_type.unwrap().get_message_type(),
+            WeatherMessage::StartOfPicture
+        );
+        assert_eq!(records[1].time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(
+            records[1]
+                .processing_status
+                .as_ref()
+                .unwrap()
+                .get_scaling_factor(),
+            -2
+        );
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(WEATHER_PICTURE);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_contour_points_overrun() {
+        // I008/050 Contour Points announces three points, the third is missing
+        let array: &'static [u8] = &[
+            0x08, 0x00, 0x0b, 0x82, 0x19, 0x0c, 0x03, 0x10, 0x20, 0x11, 0x21,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(6)));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record10::Record10;
+
+data_block!(
+    /// CAT10 message
+    Cat10Message, Record10, Cat010, 10, insert_record10, remove_record10
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat10::message_type_field::SurfaceMessage;
+    use crate::uap::cat10::target_report_descriptor_field::{SurfaceDetection, TargetType};
+    use crate::uap::cat10::track_status_field::MovementType;
+    use bytes::Bytes;
+
+    // Multilateration target report with I010/010, 000, 020, 140, 042, 161, 170, 220, 500
+    const TARGET_REPORT: &[u8] = &[
+        0x0a, 0x00, 0x1f, 0xf3, 0x35, 0x01, 0x80, 0x19, 0xc9, 0x01, 0x25, 0x04, 0x18, 0x1c, 0xc0,
+        0xff, 0x9c, 0x07, 0xd0, 0x0d, 0xeb, 0x21, 0xa9, 0x80, 0x3c, 0x66, 0x0c, 0x0a, 0x28, 0xff,
+        0xfc,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record10().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_message_type(),
+            SurfaceMessage::TargetReport
+        );
+        let target_report_descriptor = record.target_report_descriptor.unwrap();
+        assert_eq!(
+            target_report_descriptor.get_typ(),
+            SurfaceDetection::ModeSMultilateration
+        );
+        assert_eq!(
+            target_report_descriptor.get_target_type(),
+            TargetType::GroundVehicle
+        );
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(record.cartesian_position.unwrap().get_x(), -100);
+        assert_eq!(record.cartesian_position.unwrap().get_y(), 2000);
+        assert_eq!(record.track_number.unwrap().get_track_number(), 3563);
+        assert_eq!(
+            record.track_status.unwrap().get_movement(),
+            MovementType::Landing
+        );
+        assert_eq!(record.target_address.unwrap().get_address(), 0x3c660c);
+        assert_eq!(record.position_deviation.unwrap().get_sigma_x(), 2.5);
+        assert_eq!(record.position_deviation.unwrap().get_covariance(), -1.0);
+        assert_eq!(record.wgs_position, None);
+        assert_eq!(message.remove_record10(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_presence_overrun() {
+        // I010/280 Presence announces two position differences, the second is missing
+        let array: &'static [u8] = &[
+            0x0a, 0x00, 0x0c, 0x81, 0x01, 0x01, 0x40, 0x19, 0x0c, 0x02, 0x05, 0x0a,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(9)));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record11::Record11;
+
+data_block!(
+    /// CAT11 message
+    Cat11Message, Record11, Cat011, 11, insert_record11, remove_record11
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat11::message_type_field::TrackMessage;
+    use crate::uap::cat11::track_status_field::{AltitudeSource, TrackFlag};
+    use bytes::Bytes;
+
+    // Target report with I011/010, 000, 140, 161, 170
+    const TARGET_REPORT: &[u8] = &[
+        0x0b, 0x00, 0x0e, 0xd1, 0x0c, 0x19, 0xc9, 0x01, 0x18, 0x1c, 0xc0, 0x04, 0xd2, 0x5c,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record11().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_message_type(),
+            TrackMessage::TargetReport
+        );
+        assert_eq!(record.time_of_track.unwrap().get_time(), 12345.5);
+        assert_eq!(record.track_number.unwrap().get_track_number(), 1234);
+        let track_status = record.track_status.unwrap();
+        assert!(track_status.get_flag(TrackFlag::GroundBitSet));
+        assert_eq!(
+            track_status.get_altitude_source(),
+            AltitudeSource::Multilateration
+        );
+        assert_eq!(record.phase_of_flight, None);
+        assert_eq!(message.remove_record11(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_aircraft_derived_data_overrun() {
+        // I011/380 Mode-S / ADS-B Related Data announces the three byte
+        // aircraft address, two bytes are missing
+        let array: &'static [u8] = &[0x0b, 0x00, 0x09, 0x81, 0x10, 0x19, 0x0c, 0x40, 0x12];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+}
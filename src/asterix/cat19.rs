@@ -0,0 +1,79 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record19::Record19;
+
+data_block!(
+    /// CAT19 message
+    Cat19Message, Record19, Cat019, 19, insert_record19, remove_record19
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat10::system_status_field::OperationalStatus;
+    use crate::uap::cat19::message_type_field::StatusMessage;
+    use bytes::Bytes;
+
+    // Periodic status with I019/010, 000, 140, 550, 551, 552, 600, 610, 620
+    const PERIODIC_STATUS: &[u8] = &[
+        0x13, 0x00, 0x1d, 0xfd, 0xe0, 0x19, 0xc9, 0x02, 0x18, 0x1c, 0xc0, 0x00, 0xc0, 0x02, 0x01,
+        0x4c, 0x07, 0x20, 0x10, 0x00, 0x00, 0x00, 0xe0, 0x00, 0x00, 0x00, 0xff, 0xcd, 0xfd,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(PERIODIC_STATUS);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record19().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_message_type(),
+            StatusMessage::PeriodicStatus
+        );
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(
+            record.system_status.unwrap().get_operational(),
+            OperationalStatus::Operational
+        );
+        assert_eq!(
+            record.tracking_processor_status.unwrap().get_processor(1),
+            Ok((true, true))
+        );
+        let remote_station_status = record.remote_station_status.unwrap();
+        let stations = remote_station_status.get_stations();
+        assert_eq!(stations.len(), 2);
+        assert_eq!(stations[0].get_id(), 1);
+        assert!(stations[0].get_online());
+        assert!(stations[1].get_transmitter_1030());
+        assert_eq!(record.reference_position.unwrap().get_latitude(), 45.0);
+        assert_eq!(record.reference_position.unwrap().get_longitude(), -90.0);
+        assert_eq!(record.reference_height.unwrap().get_height(), -12.75);
+        assert_eq!(record.undulation.unwrap().get_undulation(), -3);
+        assert_eq!(record.reference_transponder_status, None);
+        assert_eq!(message.remove_record19(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(PERIODIC_STATUS);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_remote_station_status_overrun() {
+        // I019/552 Remote Sensor Detailed Status announces two stations, the second is missing
+        let array: &'static [u8] = &[0x13, 0x00, 0x09, 0x84, 0x19, 0x0c, 0x02, 0x01, 0x78];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(6)));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record20::Record20;
+
+data_block!(
+    /// CAT20 message
+    Cat20Message, Record20, Cat020, 20, insert_record20, remove_record20
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat20::reserved_expansion_field::ReservedExpansion;
+    use crate::uap::cat20::target_report_descriptor_field::MultilaterationDetection;
+    use crate::uap::cat20::track_status_field::ClimbingMode;
+    use bytes::Bytes;
+
+    // Multilateration target report with I020/010, 020, 140, 042, 161, 170, 220, RE
+    const TARGET_REPORT: &[u8] = &[
+        0x14, 0x00, 0x23, 0xef, 0x09, 0x01, 0x04, 0x19, 0xc9, 0x41, 0x10, 0x18, 0x1c, 0xc0, 0xff,
+        0xff, 0x38, 0x00, 0x0f, 0xa0, 0x0d, 0xeb, 0x12, 0x3c, 0x66, 0x0c, 0x09, 0x80, 0x40, 0x00,
+        0x1e, 0x00, 0x28, 0xff, 0xf7,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record20().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        let target_report_descriptor = record.target_report_descriptor.unwrap();
+        assert!(target_report_descriptor.get_detection(MultilaterationDetection::ModeS));
+        assert!(target_report_descriptor.get_ground());
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(record.cartesian_position.unwrap().get_x(), -100.0);
+        assert_eq!(record.cartesian_position.unwrap().get_y(), 2000.0);
+        assert_eq!(record.track_number.unwrap().get_track_number(), 3563);
+        let track_status = record.track_status.unwrap();
+        assert_eq!(track_status.get_climbing_mode(), ClimbingMode::Descending);
+        assert!(track_status.get_smoothed());
+        assert_eq!(record.target_address.unwrap().get_address(), 0x3c660c);
+        assert_eq!(record.wgs_position, None);
+
+        let content: ReservedExpansion = record.reserved_expansion.unwrap().get_content().unwrap();
+        let position_accuracy = content.get_position_accuracy().unwrap();
+        assert_eq!(
+            position_accuracy.get_cartesian_deviation(),
+            Some((7.5, 10.0, -2.25))
+        );
+        assert_eq!(message.remove_record20(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_contributing_devices_overrun() {
+        // I020/400 Contributing Devices announces two octets, the second is missing
+        let array: &'static [u8] = &[0x14, 0x00, 0x0a, 0x81, 0x01, 0x04, 0x19, 0x0c, 0x02, 0xff];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(8)));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record21::Record21;
+
+data_block!(
+    /// CAT21 message
+    Cat21Message, Record21, Cat021, 21, insert_record21, remove_record21
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat21::emitter_category_field::Emitter;
+    use crate::uap::cat21::target_report_descriptor_field::AltitudeCapability;
+    use bytes::Bytes;
+
+    // ADS-B target report with I021/010, 040, 161, 015, 071, 130, 080, 073, 140, 090, 145, 155, 160, 170, 020
+    const TARGET_REPORT: &[u8] = &[
+        0x15, 0x00, 0x30, 0xfd, 0x19, 0x63, 0x29, 0xc0, 0x19, 0xc9, 0x08, 0x0d, 0xeb, 0x05, 0x18,
+        0x1c, 0xc0, 0x20, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x3c, 0x66, 0x0c, 0x18, 0x1c, 0xc0, 0x15,
+        0xe0, 0x51, 0x72, 0x05, 0x28, 0x7f, 0x60, 0x08, 0x00, 0x80, 0x00, 0x10, 0xc2, 0x31, 0xcb,
+        0x38, 0x20, 0x05,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record21().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record
+                .target_report_descriptor
+                .clone()
+                .unwrap()
+                .get_altitude_capability(),
+            AltitudeCapability::Resolution100Ft
+        );
+        assert_eq!(record.track_number.unwrap().get_track_number(), 3563);
+        assert_eq!(record.time_position.unwrap().get_time(), 12345.5);
+        assert_eq!(record.position.unwrap().get_latitude(), 45.0);
+        assert_eq!(record.position.unwrap().get_longitude(), -90.0);
+        assert_eq!(record.target_address.unwrap().get_address(), 0x3c660c);
+        assert_eq!(record.geometric_height.unwrap().get_altitude(), 35000.0);
+        assert_eq!(record.quality_indicators.clone().unwrap().get_nac_p(), 9);
+        assert_eq!(record.flight_level.unwrap().get_flight_level(), 330.0);
+        assert_eq!(record.barometric_vertical_rate.unwrap().get_rate(), -1000.0);
+        assert_eq!(record.ground_vector.unwrap().get_track_angle(), 180.0);
+        assert_eq!(
+            record.target_identification.unwrap().get_identification(),
+            "DLH123"
+        );
+        assert_eq!(
+            record.emitter_category.unwrap().get_category(),
+            Emitter::Heavy
+        );
+        assert_eq!(record.high_resolution_position, None);
+        assert_eq!(message.remove_record21(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_met_information_overrun() {
+        // I021/220 Met Information announces wind speed and temperature,
+        // the temperature misses its second byte
+        let array: &'static [u8] = &[
+            0x15, 0x00, 0x0e, 0x81, 0x01, 0x01, 0x01, 0x20, 0x19, 0x0c, 0xa0, 0x00, 0x1e, 0x00,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(10)));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record23::Record23;
+
+data_block!(
+    /// CAT23 message
+    Cat23Message, Record23, Cat023, 23, insert_record23, remove_record23
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat23::report_type_field::GroundStationReport;
+    use crate::uap::cat23::service_configuration_field::ServiceClass;
+    use crate::uap::cat23::service_status_field::ServiceState;
+    use crate::uap::cat23::service_type_field::GroundStationService;
+    use bytes::Bytes;
+
+    // Service status report with I023/010, 000, 015, 070, 101, 110
+    const SERVICE_STATUS: &[u8] = &[
+        0x17, 0x00, 0x10, 0xf5, 0x80, 0x19, 0xc9, 0x02, 0x32, 0x18, 0x1c, 0xc0, 0x05, 0x21, 0x3c,
+        0x08,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(SERVICE_STATUS);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record23().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.report_type.unwrap().get_report_type(),
+            GroundStationReport::ServiceStatus
+        );
+        assert_eq!(record.service_type.unwrap().get_service_id(), 3);
+        assert_eq!(
+            record.service_type.unwrap().get_service_type(),
+            GroundStationService::AdsBExtendedSquitter
+        );
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        let service_configuration = record.service_configuration.unwrap();
+        assert_eq!(service_configuration.get_report_period(), 2.5);
+        assert_eq!(
+            service_configuration.get_service_class(),
+            ServiceClass::NonRadarAirspace
+        );
+        assert_eq!(service_configuration.get_status_period(), Some(30));
+        assert_eq!(
+            record.service_status.unwrap().get_status(),
+            ServiceState::Normal
+        );
+        assert_eq!(record.ground_station_status, None);
+        assert_eq!(message.remove_record23(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(SERVICE_STATUS);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_service_statistics_overrun() {
+        // I023/120 Service Statistics announces two counters, the second is missing
+        let array: &'static [u8] = &[
+            0x17, 0x00, 0x0e, 0x81, 0x40, 0x19, 0x0c, 0x02, 0x00, 0x80, 0x00, 0x00, 0x00, 0x2a,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+}
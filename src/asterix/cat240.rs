@@ -0,0 +1,287 @@
+use miniz_oxide::deflate::{compress_to_vec_zlib, CompressionLevel};
+use miniz_oxide::inflate::decompress_to_vec_zlib_with_limit;
+
+use crate::asterix::data_record::data_block;
+use crate::asterix::record240::Record240;
+use crate::category::CatError;
+use crate::uap::cat240::video_block_field::{VideoBlockHigh, VideoBlockLow, VideoBlockMedium};
+use crate::uap::cat240::video_counters_field::VideoCounters;
+use crate::uap::cat240::video_resolution_field::{CellResolution, VideoResolution};
+
+data_block!(
+    /// CAT240 message
+    Cat240Message, Record240, Cat240, 240, insert_record240, remove_record240
+);
+
+/*
+ * Decode video cells of a video record.
+ * Compressed video blocks are inflated up to the size of the counted cells.
+ * The counters limit the valid octets and cells, otherwise all cells of the
+ * video block are returned.
+ */
+pub fn decode_video_cells(record: &Record240) -> Result<Vec<u32>, CatError> {
+    let resolution = record.video_resolution.ok_or(CatError::ValueInvalid)?;
+    let bits = resolution.get_cell_bits()? as usize;
+
+    let data = record
+        .video_block_low
+        .as_ref()
+        .map(|block| block.get_data())
+        .or(record
+            .video_block_medium
+            .as_ref()
+            .map(|block| block.get_data()))
+        .or(record
+            .video_block_high
+            .as_ref()
+            .map(|block| block.get_data()))
+        .ok_or(CatError::ValueInvalid)?;
+
+    let data = match record.video_counters {
+        Some(counters) => data
+            .get(..counters.get_octets() as usize)
+            .ok_or(CatError::ValueInvalid)?,
+        None => data,
+    };
+    let octets = if resolution.get_compression() {
+        // Compressed video blocks need the counters to limit the inflated size
+        let counters = record.video_counters.ok_or(CatError::ValueInvalid)?;
+        let limit = (counters.get_cells() as usize * bits).div_ceil(8);
+
+        decompress_to_vec_zlib_with_limit(data, limit).map_err(|_| CatError::ValueInvalid)?
+    } else {
+        data.to_vec()
+    };
+    let count = match record.video_counters {
+        Some(counters) => counters.get_cells() as usize,
+        None => octets.len() * 8 / bits,
+    };
+    if count * bits > octets.len() * 8 {
+        return Err(CatError::ValueInvalid);
+    }
+
+    // Cells start with the most significant bit
+    let cells = (0..count)
+        .map(|index| {
+            let bit = index * bits;
+
+            if bits < 8 {
+                let shift = 8 - bits - bit % 8;
+                (octets[bit / 8] as u32 >> shift) & ((1 << bits) - 1)
+            } else {
+                octets[bit / 8..(bit + bits) / 8]
+                    .iter()
+                    .fold(0, |value, octet| (value << 8) | *octet as u32)
+            }
+        })
+        .collect();
+    Ok(cells)
+}
+
+/*
+ * Encode video cells into the smallest video block of a video record.
+ * Sets resolution and counters, compression with zlib when requested.
+ */
+pub fn encode_video_cells(
+    record: &mut Record240,
+    cells: &[u32],
+    resolution: CellResolution,
+    compression: bool,
+) -> Result<(), CatError> {
+    let mut video_resolution = VideoResolution::default();
+    video_resolution.set_compression(compression);
+    video_resolution.set_resolution(resolution);
+    let bits = video_resolution.get_cell_bits()? as usize;
+
+    if bits < 32 && cells.iter().any(|cell| *cell >> bits != 0) {
+        return Err(CatError::ValueInvalid);
+    }
+    let mut octets = vec![0u8; (cells.len() * bits).div_ceil(8)];
+
+    for (index, cell) in cells.iter().enumerate() {
+        let bit = index * bits;
+
+        if bits < 8 {
+            octets[bit / 8] |= (*cell << (8 - bits - bit % 8)) as u8;
+        } else {
+            let bytes = cell.to_be_bytes();
+            octets[bit / 8..(bit + bits) / 8].copy_from_slice(&bytes[4 - bits / 8..]);
+        }
+    }
+    if compression {
+        octets = compress_to_vec_zlib(&octets, CompressionLevel::DefaultLevel as u8);
+    }
+
+    let mut video_counters = VideoCounters::default();
+    video_counters.set_octets(u16::try_from(octets.len()).map_err(|_| CatError::ValueInvalid)?);
+    video_counters.set_cells(cells.len() as u32)?;
+
+    record.video_block_low = None;
+    record.video_block_medium = None;
+    record.video_block_high = None;
+
+    if octets.len() <= VideoBlockLow::DATA_MAX {
+        let mut video_block = VideoBlockLow::default();
+        video_block.set_data(&octets)?;
+        record.video_block_low = Some(video_block);
+    } else if octets.len() <= VideoBlockMedium::DATA_MAX {
+        let mut video_block = VideoBlockMedium::default();
+        video_block.set_data(&octets)?;
+        record.video_block_medium = Some(video_block);
+    } else {
+        let mut video_block = VideoBlockHigh::default();
+        video_block.set_data(&octets)?;
+        record.video_block_high = Some(video_block);
+    }
+    record.video_resolution = Some(video_resolution);
+    record.video_counters = Some(video_counters);
+    Ok(())
+}
+
+/*
+ * Get duration of one video cell in seconds.
+ */
+pub fn get_cell_duration(record: &Record240) -> Option<f64> {
+    match (record.video_header_nano, record.video_header_femto) {
+        (Some(header), _) => Some(header.get_cell_duration() as f64 * 1e-9),
+        (None, Some(header)) => Some(header.get_cell_duration() as f64 * 1e-15),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat240::message_type_field::VideoMessage;
+    use bytes::Bytes;
+
+    // Video message with I240/010, 000, 020, 040, 048, 049, 050, 140
+    const VIDEO: &[u8] = &[
+        0xf0, 0x00, 0x2b, 0xeb, 0xc8, 0x19, 0xc9, 0x02, 0x00, 0x00, 0x00, 0x01, 0x40, 0x00, 0x40,
+        0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xa0, 0x00, 0x04, 0x00, 0x05, 0x00, 0x00,
+        0x05, 0x02, 0x00, 0x40, 0x80, 0xc0, 0xff, 0x00, 0x00, 0x00, 0x18, 0x1c, 0xc0,
+    ];
+
+    #[test]
+    fn check_video_cells() {
+        let resolutions = [
+            (CellResolution::Monobit, 1),
+            (CellResolution::Low, 2),
+            (CellResolution::Medium, 4),
+            (CellResolution::High, 8),
+            (CellResolution::VeryHigh, 16),
+            (CellResolution::UltraHigh, 32),
+        ];
+
+        for (resolution, bits) in resolutions {
+            let cells: Vec<u32> = (0..300u64)
+                .map(|index| ((index * 2654435761) % (1u64 << bits)) as u32)
+                .collect();
+
+            for compression in [false, true] {
+                let mut record = Record240::default();
+                encode_video_cells(&mut record, &cells, resolution, compression).unwrap();
+
+                assert_eq!(decode_video_cells(&record).unwrap(), cells);
+            }
+        }
+
+        // Large radial needs high data volume block
+        let mut record = Record240::default();
+        encode_video_cells(&mut record, &[0xff; 20000], CellResolution::High, false).unwrap();
+
+        assert_eq!(record.video_block_low, None);
+        assert_eq!(record.video_block_medium, None);
+        assert_eq!(
+            record.video_block_high.as_ref().unwrap().get_data().len(),
+            20224
+        );
+        assert_eq!(
+            encode_video_cells(&mut record, &[4], CellResolution::Low, false),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            decode_video_cells(&Record240::default()),
+            Err(CatError::ValueInvalid)
+        );
+    }
+
+    #[test]
+    fn check_video_cells_compressed() {
+        let cells = vec![0x42; 1000];
+
+        let mut record = Record240::default();
+        encode_video_cells(&mut record, &cells, CellResolution::High, true).unwrap();
+
+        // Repeated cells are compressed into a low data volume block
+        assert!(record.video_block_low.is_some());
+        assert_eq!(decode_video_cells(&record).unwrap(), cells);
+
+        // Inflated video block must not exceed the counted cells
+        let mut video_counters = record.video_counters.unwrap();
+        video_counters.set_cells(10).unwrap();
+        record.video_counters = Some(video_counters);
+
+        assert_eq!(decode_video_cells(&record), Err(CatError::ValueInvalid));
+
+        // Compressed video block without counters
+        record.video_counters = None;
+
+        assert_eq!(decode_video_cells(&record), Err(CatError::ValueInvalid));
+    }
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(VIDEO);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record240().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_message_type(),
+            VideoMessage::Video
+        );
+        assert_eq!(record.record_header.unwrap().get_index(), 1);
+        let video_header = record.video_header_nano.unwrap();
+        assert_eq!(video_header.get_start_azimuth(), 90.0);
+        assert_eq!(video_header.get_end_azimuth(), 91.0546875);
+        assert_eq!(video_header.get_start_range(), 0);
+        assert_eq!(get_cell_duration(&record), Some(416e-9));
+        assert_eq!(
+            record.video_resolution.unwrap().get_resolution(),
+            CellResolution::High
+        );
+        assert_eq!(
+            decode_video_cells(&record).unwrap(),
+            vec![0x00, 0x40, 0x80, 0xc0, 0xff]
+        );
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(record.video_header_femto, None);
+        assert_eq!(message.remove_record240(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(VIDEO);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_video_block_overrun() {
+        // I240/050 Video Block Low Data Volume announces two blocks, the second is missing
+        let array: &'static [u8] = &[
+            0xf0, 0x00, 0x0c, 0x81, 0x40, 0x19, 0x0c, 0x02, 0x00, 0x00, 0x08, 0x10,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+}
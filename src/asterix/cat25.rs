@@ -0,0 +1,83 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record25::Record25;
+
+data_block!(
+    /// CAT25 message
+    Cat25Message, Record25, Cat025, 25, insert_record25, remove_record25
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat25::component_status_field::{Component, ComponentState};
+    use crate::uap::cat25::report_type_field::SystemReport;
+    use crate::uap::cat25::system_status_field::ReleaseStatus;
+    use bytes::Bytes;
+
+    // Component status report with I025/010, 000, 015, 070, 100, 120
+    const COMPONENT_STATUS: &[u8] = &[
+        0x19, 0x00, 0x14, 0xd7, 0x40, 0x19, 0xc9, 0x04, 0x03, 0x18, 0x1c, 0xc0, 0x00, 0x02, 0x00,
+        0x07, 0x00, 0x00, 0x08, 0x05,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(COMPONENT_STATUS);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record25().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        let report_type = record.report_type.unwrap();
+        assert_eq!(report_type.get_report_type(), SystemReport::ComponentStatus);
+        assert!(!report_type.get_event_driven());
+        assert_eq!(record.service_identification.unwrap().get_service(), 3);
+        assert_eq!(record.time_of_day.unwrap().get_time(), 12345.5);
+        assert_eq!(
+            record.system_status.unwrap().get_release_status(),
+            ReleaseStatus::Operational
+        );
+        assert_eq!(
+            record.component_status.unwrap().get_components(),
+            vec![
+                Component {
+                    identification: 7,
+                    error_code: 0,
+                    state: ComponentState::Running,
+                },
+                Component {
+                    identification: 8,
+                    error_code: 1,
+                    state: ComponentState::Failed,
+                },
+            ]
+        );
+        assert_eq!(record.service_designator, None);
+        assert_eq!(message.remove_record25(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(COMPONENT_STATUS);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_component_status_overrun() {
+        // I025/120 Component Status announces two components, the second is missing
+        let array: &'static [u8] = &[
+            0x19, 0x00, 0x0b, 0x81, 0x40, 0x19, 0x0c, 0x02, 0x00, 0x01, 0x01,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+}
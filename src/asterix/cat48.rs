@@ -0,0 +1,76 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record48::Record48;
+
+data_block!(
+    /// CAT48 message
+    Cat48Message, Record48, Cat048, 48, insert_record48, remove_record48
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat48::target_report_descriptor_field::DetectionType;
+    use bytes::Bytes;
+
+    // Target report with I048/010, 140, 020, 040, 070, 090, 130, 220, 240, 250, 161, 200, 170, 230
+    const TARGET_REPORT: &[u8] = &[
+        0x30, 0x00, 0x33, 0xff, 0xf7, 0x02, 0x19, 0xc9, 0x35, 0x6d, 0x4d, 0xe0, 0x0a, 0x00, 0x40,
+        0x00, 0x02, 0x00, 0x05, 0x28, 0xe0, 0x0a, 0x05, 0xc6, 0x3c, 0x66, 0x0c, 0x10, 0xc2, 0x36,
+        0xd4, 0x18, 0x20, 0x01, 0xc0, 0x78, 0x00, 0x31, 0xbc, 0x00, 0x00, 0x40, 0x0d, 0xeb, 0x07,
+        0xb9, 0x58, 0x2e, 0x40, 0x20, 0xf5,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record48().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.target_report_descriptor.unwrap().get_typ(),
+            DetectionType::ModeSRollCallPsr
+        );
+        assert_eq!(record.measured_position.unwrap().get_rho(), 10.0);
+        assert_eq!(record.mode_3a_code.unwrap().get_code(), 0o1000);
+        assert_eq!(record.flight_level.unwrap().get_flight_level(), 330.0);
+        assert_eq!(record.aircraft_address.unwrap().get_address(), 0x3c660c);
+        assert_eq!(
+            record.aircraft_identification.unwrap().get_identification(),
+            "DLH65A"
+        );
+        assert_eq!(
+            record.plot_characteristics.unwrap().get_ssr_amplitude(),
+            Some(-58)
+        );
+        assert_eq!(record.mode_s_mb_data.unwrap().get_mb_data()[0].1, 4);
+        assert_eq!(record.track_number.unwrap().get_track_number(), 3563);
+        assert_eq!(record.calculated_position, None);
+        assert_eq!(message.remove_record48(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(TARGET_REPORT);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_plot_characteristics_overrun() {
+        // I048/130 Radar Plot Characteristics announces SSR run length and
+        // SSR replies, the SSR replies are missing
+        let array: &'static [u8] = &[0x30, 0x00, 0x08, 0x82, 0x19, 0x0c, 0xc0, 0x05];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(6)));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record62::Record62;
+
+data_block!(
+    /// CAT62 message
+    Cat62Message, Record62, Cat062, 62, insert_record62, remove_record62
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat62::track_status_field::TrackFlag;
+    use bytes::Bytes;
+
+    // System track with I062/010, 015, 070, 105, 185, 060, 040, 080, 136
+    const SYSTEM_TRACK: &[u8] = &[
+        0x3e, 0x00, 0x1f, 0xbb, 0x4d, 0x20, 0x19, 0xc9, 0x05, 0x18, 0x1c, 0xc0, 0x00, 0x80, 0x00,
+        0x00, 0xff, 0x00, 0x00, 0x00, 0x01, 0x90, 0xff, 0x38, 0x02, 0x00, 0x0d, 0xeb, 0x80, 0x05,
+        0x28,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(SYSTEM_TRACK);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record62().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(record.service_identification.unwrap().get_service(), 5);
+        assert_eq!(record.time_of_track.unwrap().get_time(), 12345.5);
+        assert_eq!(record.wgs_position.unwrap().get_latitude(), 45.0);
+        assert_eq!(record.wgs_position.unwrap().get_longitude(), -90.0);
+        assert_eq!(record.cartesian_velocity.unwrap().get_vx(), 100.0);
+        assert_eq!(record.cartesian_velocity.unwrap().get_vy(), -50.0);
+        assert_eq!(record.mode_3a_code.unwrap().get_code(), 0o1000);
+        assert_eq!(record.track_number.unwrap().get_track_number(), 3563);
+        assert!(record
+            .track_status
+            .clone()
+            .unwrap()
+            .get_flag(TrackFlag::Mono));
+        assert_eq!(
+            record.measured_flight_level.unwrap().get_flight_level(),
+            330.0
+        );
+        assert_eq!(record.cartesian_position, None);
+        assert_eq!(message.remove_record62(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(SYSTEM_TRACK);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_aircraft_derived_data_overrun() {
+        // I062/380 Aircraft Derived Data announces one Mode S MB data entry
+        // of 15 bytes, only two bytes follow
+        let array: &'static [u8] = &[
+            0x3e, 0x00, 0x0c, 0x81, 0x10, 0x19, 0x0c, 0x01, 0x40, 0x01, 0x11, 0x22,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(7)));
+    }
+
+    #[test]
+    fn test_decode_spare_frn() {
+        // FRN 2 is spare in the CAT062 UAP
+        let array: &'static [u8] = &[0x3e, 0x00, 0x04, 0x40];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::FieldSpecInvalid(3)));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record63::Record63;
+
+data_block!(
+    /// CAT63 message
+    Cat63Message, Record63, Cat063, 63, insert_record63, remove_record63
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat63::sensor_configuration_field::{SensorConnection, SensorPart};
+    use bytes::Bytes;
+
+    // Sensor status with I063/010, 015, 030, 050, 060, 080, 081
+    const SENSOR_STATUS: &[u8] = &[
+        0x3f, 0x00, 0x14, 0xfb, 0x80, 0x19, 0xc9, 0x03, 0x18, 0x1c, 0xc0, 0x19, 0x07, 0x60, 0xff,
+        0xce, 0x00, 0x20, 0xff, 0x00,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(SENSOR_STATUS);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record63().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(record.service_identification.unwrap().get_service(), 3);
+        assert_eq!(record.time_of_message.unwrap().get_time(), 12345.5);
+        assert_eq!(record.sensor_id.unwrap().get_source_id_sic(), 7);
+        let sensor_configuration = record.sensor_configuration.unwrap();
+        assert_eq!(
+            sensor_configuration.get_connection(),
+            SensorConnection::Degraded
+        );
+        assert!(sensor_configuration.get_nogo(SensorPart::Psr));
+        assert!((record.ssr_range_bias.unwrap().get_gain() + 0.0005).abs() < 1e-9);
+        assert_eq!(record.ssr_range_bias.unwrap().get_bias(), 0.25);
+        assert_eq!(record.ssr_azimuth_bias.unwrap().get_bias(), -1.40625);
+        assert_eq!(record.psr_range_bias, None);
+        assert_eq!(message.remove_record63(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(SENSOR_STATUS);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_sensor_configuration_overrun() {
+        // I063/060 Sensor Configuration and Status, the FX bit announces
+        // a second octet which is missing
+        let array: &'static [u8] = &[0x3f, 0x00, 0x07, 0x88, 0x19, 0x0c, 0x01];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(6)));
+    }
+}
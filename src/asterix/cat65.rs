@@ -0,0 +1,65 @@
+use crate::asterix::data_record::data_block;
+use crate::asterix::record65::Record65;
+
+data_block!(
+    /// CAT65 message
+    Cat65Message, Record65, Cat065, 65, insert_record65, remove_record65
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::CatError;
+    use crate::uap::cat65::message_type_field::SdpsMessage;
+    use crate::uap::cat65::sdps_status_field::{ProcessingSystem, SdpsOperational};
+    use bytes::Bytes;
+
+    // SDPS status with I065/010, 000, 015, 030, 020, 040
+    const SDPS_STATUS: &[u8] = &[
+        0x41, 0x00, 0x0d, 0xfc, 0x19, 0xc9, 0x01, 0x03, 0x18, 0x1c, 0xc0, 0x2a, 0x4a,
+    ];
+
+    #[test]
+    fn test_decode() {
+        let bytes = Bytes::from(SDPS_STATUS);
+
+        let mut message = decode(&bytes).unwrap();
+        let record = message.remove_record65().unwrap();
+
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sac(), 0x19);
+        assert_eq!(record.data_source_id.unwrap().get_source_id_sic(), 0xc9);
+        assert_eq!(
+            record.message_type.unwrap().get_message_type(),
+            SdpsMessage::SdpsStatus
+        );
+        assert_eq!(record.service_identification.unwrap().get_service(), 3);
+        assert_eq!(record.time_of_message.unwrap().get_time(), 12345.5);
+        assert_eq!(record.batch_number.unwrap().get_batch(), 42);
+        let sdps_status = record.sdps_status.unwrap();
+        assert_eq!(sdps_status.get_operational(), SdpsOperational::Degraded);
+        assert_eq!(sdps_status.get_processing_system(), ProcessingSystem::Sdps2);
+        assert_eq!(record.service_status_report, None);
+        assert_eq!(message.remove_record65(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let bytes = Bytes::from(SDPS_STATUS);
+
+        let message = decode(&bytes).unwrap();
+        let result = encode(&message).unwrap();
+
+        assert_eq!(result, bytes);
+    }
+
+    #[test]
+    fn test_decode_reserved_expansion_overrun() {
+        // Reserved Expansion Field announces four data bytes, two are missing
+        let array: &'static [u8] = &[0x41, 0x00, 0x0a, 0x81, 0x04, 0x19, 0x0c, 0x05, 0x01, 0x02];
+        let bytes = Bytes::from(array);
+
+        let result = decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(10)));
+    }
+}
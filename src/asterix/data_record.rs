@@ -0,0 +1,530 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::header_field::Header;
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::explicit_field::{ExplicitField, ExplicitItem, ExplicitParsers};
+use crate::uap::field_spec::FieldSpec;
+
+/// Record of a data block. Several records are possible per data block.
+pub trait DataRecord: Default + Clone {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError>;
+
+    /*
+     * Encode record to byte stream.
+     */
+    fn encode(&mut self) -> Result<Bytes, CatError>;
+
+    /*
+     * Reserved Expansion Field of the record.
+     */
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        None
+    }
+
+    /*
+     * Special Purpose Field of the record.
+     */
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        None
+    }
+}
+
+/// FSPEC FX Field Reference Number (FRN)
+pub const FSPEC_FX: u8 = 8;
+
+/*
+ * Encode header and records into data block.
+ */
+pub fn encode_data_block<R: DataRecord>(header: Header, records: &[R]) -> Result<Bytes, CatError> {
+    let mut bytes_length = 0;
+    let mut vector: Vec<Bytes> = Vec::new();
+
+    // Iterate over all records in order of insertion
+    for record in records.iter() {
+        let bytes = record.clone().encode()?;
+        bytes_length += bytes.len();
+        // Append result bytes to return bytes
+        vector.push(bytes);
+    }
+
+    // Data block length must fit into the LEN field
+    if Header::MESSAGE_LENGTH + bytes_length > u16::MAX as usize {
+        return Err(CatError::SizeInvalid(u16::MAX as usize));
+    }
+
+    // Calculate length
+    let mut header = header;
+    header.set_len((Header::MESSAGE_LENGTH + bytes_length) as u16);
+    let header = header.to_bytes();
+
+    let mut sum_bytes = BytesMut::with_capacity(Header::MESSAGE_LENGTH + bytes_length);
+    sum_bytes.put(&header[..]);
+
+    for bytes in vector {
+        sum_bytes.put(bytes);
+    }
+
+    Ok(sum_bytes.into())
+}
+
+/*
+ * Decode data block into header and records.
+ * Explicit length data items are passed to the registered parsers.
+ */
+pub fn decode_data_block<R: DataRecord>(
+    bytes: &Bytes,
+    category: u8,
+    parsers: &mut ExplicitParsers,
+) -> Result<(Header, Vec<R>), CatError> {
+    // Header length is 3 bytes, contains category and data block length
+    if bytes.len() < Header::MESSAGE_LENGTH {
+        return Err(CatError::SizeInvalid(0));
+    }
+    let array: &[u8] = bytes;
+    let header_array = Header::array_of_byte_message(&array[0..Header::MESSAGE_LENGTH]);
+
+    // New message
+    let mut header = Header::default();
+
+    // Convert byte stream to struct
+    header.from_bytes(&header_array);
+    let length = header.get_len() as usize;
+
+    // Check for correct data block length
+    if length != bytes.len() || length < Header::MESSAGE_LENGTH {
+        return Err(CatError::SizeInvalid(0));
+    }
+    if header.get_cat() != category {
+        return Err(CatError::CategoryInvalid);
+    }
+
+    let mut offset = Header::MESSAGE_LENGTH;
+    let mut records = Vec::new();
+
+    // Several records can be in one data block, the FSPEC defines each record length
+    while offset < length {
+        let record_bytes = bytes.slice(offset..length);
+        let mut record = R::default();
+
+        match record.decode(&record_bytes) {
+            Ok(record_length) => {
+                parse_explicit_items(&record, category, parsers);
+                offset += record_length;
+                records.push(record);
+            }
+            Err(CatError::SizeInvalid(record_offset)) => {
+                // Report offset relative to the data block
+                return Err(CatError::SizeInvalid(offset + record_offset));
+            }
+            Err(CatError::FieldSpecInvalid(record_offset)) => {
+                // Report offset of the record in the data block
+                return Err(CatError::FieldSpecInvalid(offset + record_offset));
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+    }
+
+    Ok((header, records))
+}
+
+/*
+ * Parse Reserved Expansion Field and Special Purpose Field of a record.
+ * Site specific content never fails the decode, the record keeps the raw bytes.
+ */
+fn parse_explicit_items<R: DataRecord>(record: &R, category: u8, parsers: &mut ExplicitParsers) {
+    if let Some(reserved_expansion) = record.reserved_expansion() {
+        parsers.parse(
+            category,
+            ExplicitItem::ReservedExpansion,
+            reserved_expansion,
+        );
+    }
+    if let Some(special_purpose) = record.special_purpose() {
+        parsers.parse(category, ExplicitItem::SpecialPurpose, special_purpose);
+    }
+}
+
+/*
+ * Define the message of a category with header and records, together with
+ * the encode and decode functions of its data block.
+ */
+macro_rules! data_block {
+    (
+        $(#[$meta:meta])*
+        $message:ident, $record:ident, $variant:ident, $category:literal,
+        $insert:ident, $remove:ident
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Clone)]
+        pub struct $message {
+            /// Header contains category and data block length
+            header: $crate::asterix::header_field::Header,
+            /// Several records are possible per message.
+            record: Vec<$record>,
+        }
+
+        impl $message {
+            /*
+             * Insert record into vector.
+             */
+            pub fn $insert(&mut self, record: $record) {
+                self.record.push(record);
+            }
+
+            /*
+             * Remove last record from vector.
+             */
+            pub fn $remove(&mut self) -> Option<$record> {
+                self.record.pop()
+            }
+
+            /*
+             * Get records in order of insertion.
+             */
+            pub fn get_records(&self) -> &[$record] {
+                &self.record
+            }
+
+            pub fn get_header(&self) -> $crate::asterix::header_field::Header {
+                self.header
+            }
+
+            pub fn set_header(&mut self, header: $crate::asterix::header_field::Header) {
+                self.header = header;
+            }
+
+            /*
+             * Category.
+             */
+            pub const CATEGORY: u8 = $category;
+        }
+
+        impl Default for $message {
+            fn default() -> Self {
+                let mut message = Self {
+                    header: $crate::asterix::header_field::Header::default(),
+                    record: Vec::new(),
+                };
+                message.header.set_cat($message::CATEGORY);
+
+                message
+            }
+        }
+
+        impl TryFrom<$crate::category::Category> for $message {
+            type Error = ();
+
+            fn try_from(value: $crate::category::Category) -> Result<Self, Self::Error> {
+                match value {
+                    $crate::category::Category::$variant(cat) => Ok(cat),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        /*
+         * Encode message into data block.
+         */
+        pub fn encode(message: &$message) -> Result<::bytes::Bytes, $crate::category::CatError> {
+            $crate::asterix::data_record::encode_data_block(message.header, &message.record)
+        }
+
+        /*
+         * Decode message from data block.
+         */
+        pub fn decode(bytes: &::bytes::Bytes) -> Result<$message, $crate::category::CatError> {
+            decode_with(
+                bytes,
+                &mut $crate::uap::explicit_field::ExplicitParsers::default(),
+            )
+        }
+
+        /*
+         * Decode message from data block, site specific content is passed to
+         * the registered parsers.
+         */
+        pub fn decode_with(
+            bytes: &::bytes::Bytes,
+            parsers: &mut $crate::uap::explicit_field::ExplicitParsers,
+        ) -> Result<$message, $crate::category::CatError> {
+            let (header, record) = $crate::asterix::data_record::decode_data_block(
+                bytes,
+                $message::CATEGORY,
+                parsers,
+            )?;
+
+            Ok($message { header, record })
+        }
+    };
+}
+
+pub(crate) use data_block;
+
+/*
+ * Decode all field spec.
+ */
+pub fn decode_field_spec(bytes: &Bytes) -> Result<Vec<FieldSpec>, CatError> {
+    let array: &[u8] = bytes;
+    let mut vec = Vec::new();
+
+    loop {
+        let begin_index = vec.len();
+        let end_index = begin_index + FieldSpec::MESSAGE_LENGTH;
+
+        // FSPEC must not run over the end of the byte stream
+        if end_index > array.len() {
+            return Err(CatError::SizeInvalid(begin_index));
+        }
+        let field_spec_array = FieldSpec::array_of_byte_message(&array[begin_index..end_index]);
+
+        // New message
+        let mut field_spec = FieldSpec::default();
+
+        // Convert byte stream to struct
+        field_spec.from_bytes(&field_spec_array);
+        let fx = field_spec.get_fspec_bit(FSPEC_FX);
+
+        // Store field in vector (moves it into vector)
+        vec.push(field_spec);
+
+        // Check field extension
+        if !fx {
+            break;
+        }
+    }
+    Ok(vec)
+}
+
+/*
+ * Check that the FSPEC only sets FRN defined by the UAP.
+ * The last defined FRN and the spare FRN are given by the record.
+ */
+pub fn check_field_spec(
+    field_spec_vector: &[FieldSpec],
+    last: u8,
+    spares: &[u8],
+) -> Result<(), CatError> {
+    for (index, field_spec) in field_spec_vector.iter().enumerate() {
+        for bit in 1..FSPEC_FX {
+            let frn = index * 7 + bit as usize;
+
+            if field_spec.get_fspec_bit(bit)
+                && (frn > last as usize || spares.iter().any(|&spare| spare as usize == frn))
+            {
+                return Err(CatError::FieldSpecInvalid(0));
+            }
+        }
+    }
+    Ok(())
+}
+
+/*
+ * Check if the FSPEC bit of a Field Reference Number (FRN) is set.
+ */
+pub fn is_fspec_bit_set(field_spec_vector: &[FieldSpec], frn: u8) -> bool {
+    // Each FSPEC octet holds seven FRN and the FX bit
+    let index = (frn as usize - 1) / 7;
+    let bit = (frn - 1) % 7 + 1;
+
+    match field_spec_vector.get(index) {
+        Some(field_spec) => field_spec.get_fspec_bit(bit),
+        None => false,
+    }
+}
+
+/*
+ * Set the FSPEC bit of a Field Reference Number (FRN).
+ * Adds FSPEC with FX bit when the data item is in a later FSPEC.
+ */
+pub fn set_fspec_bit(field_spec_vector: &mut Vec<FieldSpec>, frn: u8) {
+    // Each FSPEC octet holds seven FRN and the FX bit
+    let index = (frn as usize - 1) / 7;
+    let bit = (frn - 1) % 7 + 1;
+
+    while field_spec_vector.len() <= index {
+        if let Some(last) = field_spec_vector.last_mut() {
+            last.set_fspec_bit(FSPEC_FX);
+        }
+        field_spec_vector.push(FieldSpec::default());
+    }
+    field_spec_vector[index].set_fspec_bit(bit);
+}
+
+/*
+ * Join FSPEC and data items into record byte stream.
+ * Record has at least one FSPEC.
+ */
+pub fn encode_record(field_spec_vector: &mut Vec<FieldSpec>, items: BytesMut) -> Bytes {
+    if field_spec_vector.is_empty() {
+        field_spec_vector.push(FieldSpec::default());
+    }
+
+    let mut sum_bytes = BytesMut::with_capacity(field_spec_vector.len() + items.len());
+    for field_spec in field_spec_vector.iter() {
+        sum_bytes.put(&field_spec.to_bytes()[..]);
+    }
+    sum_bytes.put(items);
+
+    sum_bytes.into()
+}
+
+/*
+ * Decode data item at offset and move offset behind the data item.
+ */
+pub fn decode_item<T: DataItem>(array: &[u8], offset: &mut usize) -> Result<T, CatError> {
+    let slice = array.get(*offset..).unwrap_or_default();
+
+    match T::decode(slice) {
+        Ok((item, length)) => {
+            *offset += length;
+            Ok(item)
+        }
+        Err(CatError::SizeInvalid(item_offset)) => {
+            Err(CatError::SizeInvalid(*offset + item_offset))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asterix::cat34::{self, Cat34Message};
+    use crate::asterix::record34::Record34;
+    use crate::category::Category;
+    use crate::uap::time_of_day_field::TimeOfDay;
+
+    #[test]
+    fn check_data_block_header() {
+        let mut message = Cat34Message::default();
+
+        assert_eq!(message.get_header().get_cat(), Cat34Message::CATEGORY);
+
+        let mut header = Header::default();
+        header.set_cat(26);
+        header.set_len(42);
+
+        message.set_header(header);
+
+        assert_eq!(message.get_header().get_cat(), 26);
+        assert_eq!(message.get_header().get_len(), 42);
+    }
+
+    #[test]
+    fn check_data_block_records() {
+        let mut message = Cat34Message::default();
+
+        let mut time_of_day = TimeOfDay::default();
+        time_of_day.set_time(12345.5);
+
+        let mut record = Record34::default();
+        record.time_of_day = Some(time_of_day);
+
+        message.insert_record34(Record34::default());
+        message.insert_record34(record.clone());
+
+        assert_eq!(message.get_records().len(), 2);
+        assert_eq!(message.get_records()[1], record);
+
+        // Records keep their order in the data block
+        let bytes = cat34::encode(&message).unwrap();
+
+        assert_eq!(
+            &bytes[..],
+            &[0x22, 0x00, 0x08, 0x00, 0x20, 0x18, 0x1c, 0xc0]
+        );
+
+        let decoded = cat34::decode(&bytes).unwrap();
+
+        assert_eq!(decoded.get_records()[0].time_of_day, None);
+        assert_eq!(decoded.get_records()[1].time_of_day, Some(time_of_day));
+
+        assert_eq!(message.remove_record34(), Some(record));
+        assert_eq!(message.remove_record34(), Some(Record34::default()));
+        assert_eq!(message.remove_record34(), None);
+    }
+
+    #[test]
+    fn check_data_block_try_from() {
+        let cat_enum = Category::Cat034(Cat34Message::default());
+        let cat: Cat34Message = cat_enum.try_into().unwrap();
+
+        assert_eq!(cat, Cat34Message::default());
+
+        let cat_enum = Category::Unknown(Bytes::new());
+        let result: Result<Cat34Message, ()> = cat_enum.try_into();
+
+        assert_eq!(result, Err(()));
+    }
+
+    #[test]
+    fn check_field_spec_frn() {
+        let mut field_spec = FieldSpec::default();
+        field_spec.set_fspec_bit(2);
+        let field_spec_vector = vec![field_spec];
+
+        assert_eq!(check_field_spec(&field_spec_vector, 7, &[]), Ok(()));
+        assert_eq!(
+            check_field_spec(&field_spec_vector, 1, &[]),
+            Err(CatError::FieldSpecInvalid(0))
+        );
+        assert_eq!(
+            check_field_spec(&field_spec_vector, 7, &[2]),
+            Err(CatError::FieldSpecInvalid(0))
+        );
+    }
+
+    #[test]
+    fn check_data_block_undefined_frn() {
+        // Second record sets FRN 15, which is not defined in the CAT034 UAP
+        let array: &'static [u8] = &[0x22, 0x00, 0x09, 0x80, 0x19, 0x0c, 0x01, 0x01, 0x80];
+        let bytes = Bytes::from(array);
+
+        let result = cat34::decode(&bytes);
+
+        assert_eq!(result, Err(CatError::FieldSpecInvalid(6)));
+    }
+
+    #[test]
+    fn check_data_block_record_overrun() {
+        // Sector Crossing record misses the sector number
+        let array: &'static [u8] = &[0x22, 0x00, 0x0a, 0xf0, 0x7b, 0x2a, 0x02, 0x4e, 0x51, 0x7c];
+        let bytes = Bytes::from(array);
+
+        let result = cat34::decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(10)));
+
+        // Data block is longer than the byte stream
+        let array: &'static [u8] = &[0x22, 0x00, 0x0b, 0xf0, 0x7b, 0x2a, 0x02, 0x4e, 0x51, 0x7c];
+
+        let result = cat34::decode(&Bytes::from(array));
+
+        assert_eq!(result, Err(CatError::SizeInvalid(0)));
+    }
+
+    #[test]
+    fn check_fspec_bits() {
+        let mut field_spec_vector = Vec::new();
+
+        set_fspec_bit(&mut field_spec_vector, 1);
+        set_fspec_bit(&mut field_spec_vector, 15);
+
+        assert_eq!(field_spec_vector.len(), 3);
+        assert_eq!(field_spec_vector[0].get_fspec(), 0x81);
+        assert_eq!(field_spec_vector[1].get_fspec(), 0x01);
+        assert_eq!(field_spec_vector[2].get_fspec(), 0x80);
+
+        assert!(is_fspec_bit_set(&field_spec_vector, 1));
+        assert!(is_fspec_bit_set(&field_spec_vector, 15));
+        assert!(!is_fspec_bit_set(&field_spec_vector, 8));
+        assert!(!is_fspec_bit_set(&field_spec_vector, 22));
+    }
+}
@@ -5,7 +5,7 @@ use std::mem;
 //
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
 // The attributes in structs have Network Byte Order in Big Endian
-#[repr(packed(1))]
+#[repr(C, packed(1))]
 pub struct Header {
     cat: u8,  // 1 byte
     len: u16, // 2 bytes
@@ -56,7 +56,7 @@ impl Header {
      * Get category
      */
     pub fn get_cat(&self) -> u8 {
-        return u8::from_be(self.cat);
+        u8::from_be(self.cat)
     }
 
     /*
@@ -70,7 +70,7 @@ impl Header {
      * Get length
      */
     pub fn get_len(&self) -> u16 {
-        return u16::from_be(self.len);
+        u16::from_be(self.len)
     }
 
     /*
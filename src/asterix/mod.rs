@@ -1,3 +1,38 @@
+pub mod cat01;
+pub mod cat02;
+pub mod cat04;
+pub mod cat07;
+pub mod cat08;
+pub mod cat10;
+pub mod cat11;
+pub mod cat19;
+pub mod cat20;
+pub mod cat21;
+pub mod cat23;
+pub mod cat240;
+pub mod cat25;
 pub mod cat34;
+pub mod cat48;
+pub mod cat62;
+pub mod cat63;
+pub mod cat65;
+pub mod data_record;
 pub mod header_field;
+pub mod record01;
+pub mod record02;
+pub mod record04;
+pub mod record07;
+pub mod record08;
+pub mod record10;
+pub mod record11;
+pub mod record19;
+pub mod record20;
+pub mod record21;
+pub mod record23;
+pub mod record240;
+pub mod record25;
 pub mod record34;
+pub mod record48;
+pub mod record62;
+pub mod record63;
+pub mod record65;
@@ -0,0 +1,746 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat01::cartesian_position_field::CartesianPosition;
+use crate::uap::cat01::doppler_speed_field::DopplerSpeed;
+use crate::uap::cat01::polar_position_field::PolarPosition;
+use crate::uap::cat01::received_power_field::ReceivedPower;
+use crate::uap::cat01::target_report_descriptor_field::{ReportType, TargetReportDescriptor};
+use crate::uap::cat01::track_status_field::TrackStatus;
+use crate::uap::cat01::truncated_time_field::TruncatedTime;
+use crate::uap::cat01::x_pulse_field::XPulse;
+use crate::uap::cat48::code_confidence_field::CodeConfidence;
+use crate::uap::cat48::mode_c_code_field::ModeCCode;
+use crate::uap::cat48::polar_velocity_field::PolarVelocity;
+use crate::uap::cat48::warning_error_field::WarningError;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::extended_field::ExtendedField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::flight_level_field::FlightLevel;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::track_number_field::TrackNumber;
+
+/// Record of CAT01 message. Several records are possible per message.
+/// The TYP bit of I001/020 selects the plot or track UAP of the record.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record01 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I001/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I001/020 Target Report Descriptor
+    pub target_report_descriptor: Option<TargetReportDescriptor>,
+    /// I001/161 Track/Plot Number
+    pub track_number: Option<TrackNumber>,
+    /// I001/040 Measured Position in Polar Co-ordinates
+    pub measured_position: Option<PolarPosition>,
+    /// I001/042 Calculated Position in Cartesian Co-ordinates
+    pub calculated_position: Option<CartesianPosition>,
+    /// I001/200 Calculated Track Velocity in Polar Co-ordinates
+    pub track_velocity: Option<PolarVelocity>,
+    /// I001/070 Mode-3/A Code in Octal Representation
+    pub mode_3a_code: Option<Mode3ACode>,
+    /// I001/090 Mode-C Code in Binary Representation
+    pub flight_level: Option<FlightLevel>,
+    /// I001/141 Truncated Time of Day
+    pub truncated_time: Option<TruncatedTime>,
+    /// I001/130 Radar Plot Characteristics
+    pub plot_characteristics: Option<ExtendedField>,
+    /// I001/131 Received Power
+    pub received_power: Option<ReceivedPower>,
+    /// I001/120 Measured Radial Doppler Speed
+    pub doppler_speed: Option<DopplerSpeed>,
+    /// I001/170 Track Status
+    pub track_status: Option<TrackStatus>,
+    /// I001/210 Track Quality
+    pub track_quality: Option<ExtendedField>,
+    /// I001/050 Mode-2 Code in Octal Representation
+    pub mode_2_code: Option<Mode3ACode>,
+    /// I001/080 Mode-3/A Code Confidence Indicator
+    pub mode_3a_confidence: Option<CodeConfidence>,
+    /// I001/100 Mode-C Code and Code Confidence Indicator
+    pub mode_c_code: Option<ModeCCode>,
+    /// I001/060 Mode-2 Code Confidence Indicator
+    pub mode_2_confidence: Option<CodeConfidence>,
+    /// I001/030 Warning/Error Conditions
+    pub warning_error: Option<WarningError>,
+    /// I001/150 Presence of X-Pulse
+    pub x_pulse: Option<XPulse>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record01 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Random field sequencing is not supported
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001RFS as u8) {
+            return Err(CatError::ValueInvalid);
+        }
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I001/010 and I001/020 have the same FRN in the plot and track UAP
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_010 as u8) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_020 as u8) {
+            self.target_report_descriptor = Some(decode_item(array, &mut offset)?);
+        }
+
+        // Report type selects the UAP of the remaining data items
+        match self.get_report_type() {
+            ReportType::Plot => self.decode_plot(array, &mut offset)?,
+            ReportType::Track => self.decode_track(array, &mut offset)?,
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Decode data items of the plot UAP.
+     */
+    fn decode_plot(&mut self, array: &[u8], offset: &mut usize) -> Result<(), CatError> {
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(
+            &self.field_spec_vector,
+            Cat01PlotFspec::I001RFS as u8,
+            &[16, 17, 18, 19],
+        )?;
+
+        // I001/040
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_040 as u8) {
+            self.measured_position = Some(decode_item(array, offset)?);
+        }
+        // I001/070
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_070 as u8) {
+            self.mode_3a_code = Some(decode_item(array, offset)?);
+        }
+        // I001/090
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_090 as u8) {
+            self.flight_level = Some(decode_item(array, offset)?);
+        }
+        // I001/130
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_130 as u8) {
+            self.plot_characteristics = Some(decode_item(array, offset)?);
+        }
+        // I001/141
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_141 as u8) {
+            self.truncated_time = Some(decode_item(array, offset)?);
+        }
+        // I001/050
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_050 as u8) {
+            self.mode_2_code = Some(decode_item(array, offset)?);
+        }
+        // I001/120
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_120 as u8) {
+            self.doppler_speed = Some(decode_item(array, offset)?);
+        }
+        // I001/131
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_131 as u8) {
+            self.received_power = Some(decode_item(array, offset)?);
+        }
+        // I001/080
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_080 as u8) {
+            self.mode_3a_confidence = Some(decode_item(array, offset)?);
+        }
+        // I001/100
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_100 as u8) {
+            self.mode_c_code = Some(decode_item(array, offset)?);
+        }
+        // I001/060
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_060 as u8) {
+            self.mode_2_confidence = Some(decode_item(array, offset)?);
+        }
+        // I001/030
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_030 as u8) {
+            self.warning_error = Some(decode_item(array, offset)?);
+        }
+        // I001/150
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001_150 as u8) {
+            self.x_pulse = Some(decode_item(array, offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat01PlotFspec::I001SP as u8) {
+            self.special_purpose = Some(decode_item(array, offset)?);
+        }
+
+        Ok(())
+    }
+
+    /*
+     * Decode data items of the track UAP.
+     */
+    fn decode_track(&mut self, array: &[u8], offset: &mut usize) -> Result<(), CatError> {
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(
+            &self.field_spec_vector,
+            Cat01TrackFspec::I001_150 as u8,
+            &[],
+        )?;
+
+        // I001/161
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_161 as u8) {
+            self.track_number = Some(decode_item(array, offset)?);
+        }
+        // I001/040
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_040 as u8) {
+            self.measured_position = Some(decode_item(array, offset)?);
+        }
+        // I001/042
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_042 as u8) {
+            self.calculated_position = Some(decode_item(array, offset)?);
+        }
+        // I001/200
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_200 as u8) {
+            self.track_velocity = Some(decode_item(array, offset)?);
+        }
+        // I001/070
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_070 as u8) {
+            self.mode_3a_code = Some(decode_item(array, offset)?);
+        }
+        // I001/090
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_090 as u8) {
+            self.flight_level = Some(decode_item(array, offset)?);
+        }
+        // I001/141
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_141 as u8) {
+            self.truncated_time = Some(decode_item(array, offset)?);
+        }
+        // I001/130
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_130 as u8) {
+            self.plot_characteristics = Some(decode_item(array, offset)?);
+        }
+        // I001/131
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_131 as u8) {
+            self.received_power = Some(decode_item(array, offset)?);
+        }
+        // I001/120
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_120 as u8) {
+            self.doppler_speed = Some(decode_item(array, offset)?);
+        }
+        // I001/170
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_170 as u8) {
+            self.track_status = Some(decode_item(array, offset)?);
+        }
+        // I001/210
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_210 as u8) {
+            self.track_quality = Some(decode_item(array, offset)?);
+        }
+        // I001/050
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_050 as u8) {
+            self.mode_2_code = Some(decode_item(array, offset)?);
+        }
+        // I001/080
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_080 as u8) {
+            self.mode_3a_confidence = Some(decode_item(array, offset)?);
+        }
+        // I001/100
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_100 as u8) {
+            self.mode_c_code = Some(decode_item(array, offset)?);
+        }
+        // I001/060
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_060 as u8) {
+            self.mode_2_confidence = Some(decode_item(array, offset)?);
+        }
+        // I001/030
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_030 as u8) {
+            self.warning_error = Some(decode_item(array, offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001SP as u8) {
+            self.special_purpose = Some(decode_item(array, offset)?);
+        }
+        // I001/150
+        if self.is_fspec_bit_set(Cat01TrackFspec::I001_150 as u8) {
+            self.x_pulse = Some(decode_item(array, offset)?);
+        }
+
+        Ok(())
+    }
+
+    /*
+     * Get report type of the record, a record without I001/020 is a plot.
+     */
+    pub fn get_report_type(&self) -> ReportType {
+        match &self.target_report_descriptor {
+            Some(target_report_descriptor) => target_report_descriptor.get_report_type(),
+            None => ReportType::Plot,
+        }
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: u8) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in order of the UAP selected by the report type.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I001/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_010 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/020
+        if let Some(target_report_descriptor) = &self.target_report_descriptor {
+            let bytes = target_report_descriptor.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_020 as u8);
+            vector.put(&bytes[..]);
+        }
+
+        match self.get_report_type() {
+            ReportType::Plot => self.encode_plot(&mut vector)?,
+            ReportType::Track => self.encode_track(&mut vector),
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Encode data items of the plot UAP.
+     */
+    fn encode_plot(&mut self, vector: &mut BytesMut) -> Result<(), CatError> {
+        // Track data items are not part of the plot UAP
+        if self.track_number.is_some()
+            || self.calculated_position.is_some()
+            || self.track_velocity.is_some()
+            || self.track_status.is_some()
+            || self.track_quality.is_some()
+        {
+            return Err(CatError::ValueInvalid);
+        }
+
+        // I001/040
+        if let Some(measured_position) = &self.measured_position {
+            let bytes = measured_position.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_040 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/070
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_070 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/090
+        if let Some(flight_level) = &self.flight_level {
+            let bytes = flight_level.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_090 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/130
+        if let Some(plot_characteristics) = &self.plot_characteristics {
+            let bytes = plot_characteristics.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_130 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/141
+        if let Some(truncated_time) = &self.truncated_time {
+            let bytes = truncated_time.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_141 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/050
+        if let Some(mode_2_code) = &self.mode_2_code {
+            let bytes = mode_2_code.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_050 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/120
+        if let Some(doppler_speed) = &self.doppler_speed {
+            let bytes = doppler_speed.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_120 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/131
+        if let Some(received_power) = &self.received_power {
+            let bytes = received_power.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_131 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/080
+        if let Some(mode_3a_confidence) = &self.mode_3a_confidence {
+            let bytes = mode_3a_confidence.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_080 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/100
+        if let Some(mode_c_code) = &self.mode_c_code {
+            let bytes = mode_c_code.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_100 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/060
+        if let Some(mode_2_confidence) = &self.mode_2_confidence {
+            let bytes = mode_2_confidence.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_060 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/030
+        if let Some(warning_error) = &self.warning_error {
+            let bytes = warning_error.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_030 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/150
+        if let Some(x_pulse) = &self.x_pulse {
+            let bytes = x_pulse.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001_150 as u8);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat01PlotFspec::I001SP as u8);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(())
+    }
+
+    /*
+     * Encode data items of the track UAP.
+     */
+    fn encode_track(&mut self, vector: &mut BytesMut) {
+        // I001/161
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_161 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/040
+        if let Some(measured_position) = &self.measured_position {
+            let bytes = measured_position.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_040 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/042
+        if let Some(calculated_position) = &self.calculated_position {
+            let bytes = calculated_position.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_042 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/200
+        if let Some(track_velocity) = &self.track_velocity {
+            let bytes = track_velocity.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_200 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/070
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_070 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/090
+        if let Some(flight_level) = &self.flight_level {
+            let bytes = flight_level.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_090 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/141
+        if let Some(truncated_time) = &self.truncated_time {
+            let bytes = truncated_time.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_141 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/130
+        if let Some(plot_characteristics) = &self.plot_characteristics {
+            let bytes = plot_characteristics.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_130 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/131
+        if let Some(received_power) = &self.received_power {
+            let bytes = received_power.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_131 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/120
+        if let Some(doppler_speed) = &self.doppler_speed {
+            let bytes = doppler_speed.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_120 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/170
+        if let Some(track_status) = &self.track_status {
+            let bytes = track_status.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_170 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/210
+        if let Some(track_quality) = &self.track_quality {
+            let bytes = track_quality.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_210 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/050
+        if let Some(mode_2_code) = &self.mode_2_code {
+            let bytes = mode_2_code.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_050 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/080
+        if let Some(mode_3a_confidence) = &self.mode_3a_confidence {
+            let bytes = mode_3a_confidence.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_080 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/100
+        if let Some(mode_c_code) = &self.mode_c_code {
+            let bytes = mode_c_code.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_100 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/060
+        if let Some(mode_2_confidence) = &self.mode_2_confidence {
+            let bytes = mode_2_confidence.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_060 as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/030
+        if let Some(warning_error) = &self.warning_error {
+            let bytes = warning_error.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_030 as u8);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001SP as u8);
+            vector.put(&bytes[..]);
+        }
+        // I001/150
+        if let Some(x_pulse) = &self.x_pulse {
+            let bytes = x_pulse.encode();
+            self.set_fspec_bit(Cat01TrackFspec::I001_150 as u8);
+            vector.put(&bytes[..]);
+        }
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: u8) {
+        set_fspec_bit(&mut self.field_spec_vector, frn);
+    }
+}
+
+impl DataRecord for Record01 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record01::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record01::encode(self)
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT01 Plot User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat01PlotFspec {
+    I001_010 = 1,
+    I001_020,
+    I001_040,
+    I001_070,
+    I001_090,
+    I001_130,
+    I001_141,
+    I001_050,
+    I001_120,
+    I001_131,
+    I001_080,
+    I001_100,
+    I001_060,
+    I001_030,
+    I001_150,
+    I001SP = 20,
+    I001RFS,
+}
+
+/// CAT01 Track User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat01TrackFspec {
+    I001_010 = 1,
+    I001_020,
+    I001_161,
+    I001_040,
+    I001_042,
+    I001_200,
+    I001_070,
+    I001_090,
+    I001_141,
+    I001_130,
+    I001_131,
+    I001_120,
+    I001_170,
+    I001_210,
+    I001_050,
+    I001_080,
+    I001_100,
+    I001_060,
+    I001_030,
+    I001SP,
+    I001RFS,
+    I001_150,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat01::target_report_descriptor_field::RadarDetection;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create plot target report descriptor
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_detection(RadarDetection::SsrPsr);
+
+        // Create measured position and truncated time
+        let mut measured_position = PolarPosition::default();
+        measured_position.set_rho(128.5).unwrap();
+        measured_position.set_theta(90.0).unwrap();
+        let mut truncated_time = TruncatedTime::default();
+        truncated_time.set_time(300.25).unwrap();
+
+        // Create record
+        let mut record = Record01 {
+            data_source_id: Some(data_source),
+            target_report_descriptor: Some(target_report_descriptor),
+            measured_position: Some(measured_position),
+            truncated_time: Some(truncated_time),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(bytes[0], 0xe2);
+
+        // New record
+        let mut object = Record01::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(object.get_report_type(), ReportType::Plot);
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_track() {
+        // Create track target report descriptor
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_report_type(ReportType::Track);
+
+        // Create track number and status
+        let mut track_number = TrackNumber::default();
+        track_number.set_track_number(3563);
+        let mut track_status = TrackStatus::default();
+        track_status.set_manoeuvre(true);
+
+        // Create record
+        let mut record = Record01 {
+            target_report_descriptor: Some(target_report_descriptor),
+            track_number: Some(track_number),
+            track_status: Some(track_status),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0x61, 0x04]);
+
+        // New record
+        let mut object = Record01::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(object.get_report_type(), ReportType::Track);
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_uap() {
+        // Plot UAP has no track number
+        let mut track_number = TrackNumber::default();
+        track_number.set_track_number(1);
+
+        let mut record = Record01 {
+            track_number: Some(track_number),
+            ..Default::default()
+        };
+
+        assert_eq!(record.encode(), Err(CatError::ValueInvalid));
+
+        // Random field sequencing is not supported
+        let bytes = Bytes::from_static(&[0x01, 0x01, 0x02]);
+
+        assert_eq!(
+            Record01::default().decode(&bytes),
+            Err(CatError::ValueInvalid)
+        );
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Special Purpose Field is in the third FSPEC of both UAP
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record01 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x04, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record01::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
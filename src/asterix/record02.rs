@@ -0,0 +1,321 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::antenna_rotation_field::AntennaRotation;
+use crate::uap::cat02::dynamic_window_field::DynamicWindow;
+use crate::uap::cat02::message_type_field::ServiceMessageType;
+use crate::uap::cat02::plot_count_field::PlotCount;
+use crate::uap::cat48::warning_error_field::WarningError;
+use crate::uap::collimation_error_field::CollimationError;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::extended_field::ExtendedField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::sector_number_field::SectorNumber;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT02 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record02 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I002/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I002/000 Message Type
+    pub message_type: Option<ServiceMessageType>,
+    /// I002/020 Sector Number
+    pub sector_number: Option<SectorNumber>,
+    /// I002/030 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I002/041 Antenna Rotation Period
+    pub antenna_rotation: Option<AntennaRotation>,
+    /// I002/050 Station Configuration Status
+    pub station_configuration_status: Option<ExtendedField>,
+    /// I002/060 Station Processing Mode
+    pub station_processing_mode: Option<ExtendedField>,
+    /// I002/070 Plot Count Values
+    pub plot_count: Option<PlotCount>,
+    /// I002/100 Dynamic Window - Type 1
+    pub dynamic_window: Option<DynamicWindow>,
+    /// I002/090 Collimation Error
+    pub collimation_error: Option<CollimationError>,
+    /// I002/080 Warning/Error Conditions
+    pub warning_error: Option<WarningError>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record02 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat02Fspec::I002SP as u8, &[12])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I002/010
+        if self.is_fspec_bit_set(Cat02Fspec::I002_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/000
+        if self.is_fspec_bit_set(Cat02Fspec::I002_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/020
+        if self.is_fspec_bit_set(Cat02Fspec::I002_020) {
+            self.sector_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/030
+        if self.is_fspec_bit_set(Cat02Fspec::I002_030) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/041
+        if self.is_fspec_bit_set(Cat02Fspec::I002_041) {
+            self.antenna_rotation = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/050
+        if self.is_fspec_bit_set(Cat02Fspec::I002_050) {
+            self.station_configuration_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/060
+        if self.is_fspec_bit_set(Cat02Fspec::I002_060) {
+            self.station_processing_mode = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/070
+        if self.is_fspec_bit_set(Cat02Fspec::I002_070) {
+            self.plot_count = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/100
+        if self.is_fspec_bit_set(Cat02Fspec::I002_100) {
+            self.dynamic_window = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/090
+        if self.is_fspec_bit_set(Cat02Fspec::I002_090) {
+            self.collimation_error = Some(decode_item(array, &mut offset)?);
+        }
+        // I002/080
+        if self.is_fspec_bit_set(Cat02Fspec::I002_080) {
+            self.warning_error = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat02Fspec::I002SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat02Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I002/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_010);
+            vector.put(&bytes[..]);
+        }
+        // I002/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_000);
+            vector.put(&bytes[..]);
+        }
+        // I002/020
+        if let Some(sector_number) = &self.sector_number {
+            let bytes = sector_number.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_020);
+            vector.put(&bytes[..]);
+        }
+        // I002/030
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_030);
+            vector.put(&bytes[..]);
+        }
+        // I002/041
+        if let Some(antenna_rotation) = &self.antenna_rotation {
+            let bytes = antenna_rotation.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_041);
+            vector.put(&bytes[..]);
+        }
+        // I002/050
+        if let Some(station_configuration_status) = &self.station_configuration_status {
+            let bytes = station_configuration_status.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_050);
+            vector.put(&bytes[..]);
+        }
+        // I002/060
+        if let Some(station_processing_mode) = &self.station_processing_mode {
+            let bytes = station_processing_mode.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_060);
+            vector.put(&bytes[..]);
+        }
+        // I002/070
+        if let Some(plot_count) = &self.plot_count {
+            let bytes = plot_count.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_070);
+            vector.put(&bytes[..]);
+        }
+        // I002/100
+        if let Some(dynamic_window) = &self.dynamic_window {
+            let bytes = dynamic_window.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_100);
+            vector.put(&bytes[..]);
+        }
+        // I002/090
+        if let Some(collimation_error) = &self.collimation_error {
+            let bytes = collimation_error.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_090);
+            vector.put(&bytes[..]);
+        }
+        // I002/080
+        if let Some(warning_error) = &self.warning_error {
+            let bytes = warning_error.encode();
+            self.set_fspec_bit(Cat02Fspec::I002_080);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat02Fspec::I002SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat02Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record02 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record02::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record02::encode(self)
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT02 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat02Fspec {
+    I002_010 = 1,
+    I002_000,
+    I002_020,
+    I002_030,
+    I002_041,
+    I002_050,
+    I002_060,
+    I002_070,
+    I002_100,
+    I002_090,
+    I002_080,
+    I002SP = 13,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat02::message_type_field::ServiceMessage;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create message type
+        let mut message_type = ServiceMessageType::default();
+        message_type.set_message_type(ServiceMessage::SectorCrossing);
+
+        // Create sector number and antenna rotation period
+        let mut sector_number = SectorNumber::default();
+        sector_number.set_sector(90.0);
+        let mut antenna_rotation = AntennaRotation::default();
+        antenna_rotation.set_period(4.0);
+
+        // Create record
+        let mut record = Record02 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            sector_number: Some(sector_number),
+            antenna_rotation: Some(antenna_rotation),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0xe8, 0x1a, 0x2a, 0x02, 0x40, 0x02, 0x00]);
+
+        // New record
+        let mut object = Record02::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record02 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x04, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record02::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
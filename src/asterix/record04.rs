@@ -0,0 +1,433 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat04::aircraft_characteristics_field::AircraftCharacteristics;
+use crate::uap::cat04::alert_identifier_field::AlertIdentifier;
+use crate::uap::cat04::alert_status_field::AlertStatus;
+use crate::uap::cat04::area_definitions_field::AreaDefinitions;
+use crate::uap::cat04::conflict_characteristics_field::ConflictCharacteristics;
+use crate::uap::cat04::conflict_timing_field::ConflictTiming;
+use crate::uap::cat04::longitudinal_deviation_field::LongitudinalDeviation;
+use crate::uap::cat04::message_type_field::AlertMessageType;
+use crate::uap::cat04::safety_net_status_field::SafetyNetStatus;
+use crate::uap::cat04::sdps_identifier_field::SdpsIdentifier;
+use crate::uap::cat04::sector_control_field::SectorControlIdentification;
+use crate::uap::cat04::transversal_deviation_field::TransversalDeviation;
+use crate::uap::cat04::vertical_deviation_field::VerticalDeviation;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+
+/// Record of CAT04 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record04 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I004/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I004/000 Message Type
+    pub message_type: Option<AlertMessageType>,
+    /// I004/015 SDPS Identifier
+    pub sdps_identifier: Option<SdpsIdentifier>,
+    /// I004/020 Time of Message
+    pub time_of_message: Option<TimeOfDay>,
+    /// I004/040 Alert Identifier
+    pub alert_identifier: Option<AlertIdentifier>,
+    /// I004/045 Alert Status
+    pub alert_status: Option<AlertStatus>,
+    /// I004/060 Safety Net Function and System Status
+    pub safety_net_status: Option<SafetyNetStatus>,
+    /// I004/030 Track Number 1
+    pub track_number_1: Option<TrackNumber>,
+    /// I004/170 Aircraft Identification and Characteristics 1
+    pub aircraft_characteristics_1: Option<AircraftCharacteristics>,
+    /// I004/120 Conflict Characteristics
+    pub conflict_characteristics: Option<ConflictCharacteristics>,
+    /// I004/070 Conflict Timing and Separation
+    pub conflict_timing: Option<ConflictTiming>,
+    /// I004/076 Vertical Deviation
+    pub vertical_deviation: Option<VerticalDeviation>,
+    /// I004/074 Longitudinal Deviation
+    pub longitudinal_deviation: Option<LongitudinalDeviation>,
+    /// I004/075 Transversal Distance Deviation
+    pub transversal_deviation: Option<TransversalDeviation>,
+    /// I004/100 Area Definitions
+    pub area_definitions: Option<AreaDefinitions>,
+    /// I004/035 Track Number 2
+    pub track_number_2: Option<TrackNumber>,
+    /// I004/171 Aircraft Identification and Characteristics 2
+    pub aircraft_characteristics_2: Option<AircraftCharacteristics>,
+    /// I004/110 FDPS Sector Control Identification
+    pub sector_control: Option<SectorControlIdentification>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record04 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat04Fspec::I004SP as u8, &[19])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I004/010
+        if self.is_fspec_bit_set(Cat04Fspec::I004_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/000
+        if self.is_fspec_bit_set(Cat04Fspec::I004_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/015
+        if self.is_fspec_bit_set(Cat04Fspec::I004_015) {
+            self.sdps_identifier = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/020
+        if self.is_fspec_bit_set(Cat04Fspec::I004_020) {
+            self.time_of_message = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/040
+        if self.is_fspec_bit_set(Cat04Fspec::I004_040) {
+            self.alert_identifier = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/045
+        if self.is_fspec_bit_set(Cat04Fspec::I004_045) {
+            self.alert_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/060
+        if self.is_fspec_bit_set(Cat04Fspec::I004_060) {
+            self.safety_net_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/030
+        if self.is_fspec_bit_set(Cat04Fspec::I004_030) {
+            self.track_number_1 = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/170
+        if self.is_fspec_bit_set(Cat04Fspec::I004_170) {
+            self.aircraft_characteristics_1 = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/120
+        if self.is_fspec_bit_set(Cat04Fspec::I004_120) {
+            self.conflict_characteristics = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/070
+        if self.is_fspec_bit_set(Cat04Fspec::I004_070) {
+            self.conflict_timing = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/076
+        if self.is_fspec_bit_set(Cat04Fspec::I004_076) {
+            self.vertical_deviation = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/074
+        if self.is_fspec_bit_set(Cat04Fspec::I004_074) {
+            self.longitudinal_deviation = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/075
+        if self.is_fspec_bit_set(Cat04Fspec::I004_075) {
+            self.transversal_deviation = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/100
+        if self.is_fspec_bit_set(Cat04Fspec::I004_100) {
+            self.area_definitions = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/035
+        if self.is_fspec_bit_set(Cat04Fspec::I004_035) {
+            self.track_number_2 = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/171
+        if self.is_fspec_bit_set(Cat04Fspec::I004_171) {
+            self.aircraft_characteristics_2 = Some(decode_item(array, &mut offset)?);
+        }
+        // I004/110
+        if self.is_fspec_bit_set(Cat04Fspec::I004_110) {
+            self.sector_control = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat04Fspec::I004RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat04Fspec::I004SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat04Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I004/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_010);
+            vector.put(&bytes[..]);
+        }
+        // I004/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_000);
+            vector.put(&bytes[..]);
+        }
+        // I004/015
+        if let Some(sdps_identifier) = &self.sdps_identifier {
+            let bytes = sdps_identifier.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_015);
+            vector.put(&bytes[..]);
+        }
+        // I004/020
+        if let Some(time_of_message) = &self.time_of_message {
+            let bytes = time_of_message.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_020);
+            vector.put(&bytes[..]);
+        }
+        // I004/040
+        if let Some(alert_identifier) = &self.alert_identifier {
+            let bytes = alert_identifier.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_040);
+            vector.put(&bytes[..]);
+        }
+        // I004/045
+        if let Some(alert_status) = &self.alert_status {
+            let bytes = alert_status.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_045);
+            vector.put(&bytes[..]);
+        }
+        // I004/060
+        if let Some(safety_net_status) = &self.safety_net_status {
+            let bytes = safety_net_status.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_060);
+            vector.put(&bytes[..]);
+        }
+        // I004/030
+        if let Some(track_number_1) = &self.track_number_1 {
+            let bytes = track_number_1.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_030);
+            vector.put(&bytes[..]);
+        }
+        // I004/170
+        if let Some(aircraft_characteristics_1) = &self.aircraft_characteristics_1 {
+            let bytes = aircraft_characteristics_1.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_170);
+            vector.put(&bytes[..]);
+        }
+        // I004/120
+        if let Some(conflict_characteristics) = &self.conflict_characteristics {
+            let bytes = conflict_characteristics.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_120);
+            vector.put(&bytes[..]);
+        }
+        // I004/070
+        if let Some(conflict_timing) = &self.conflict_timing {
+            let bytes = conflict_timing.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_070);
+            vector.put(&bytes[..]);
+        }
+        // I004/076
+        if let Some(vertical_deviation) = &self.vertical_deviation {
+            let bytes = vertical_deviation.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_076);
+            vector.put(&bytes[..]);
+        }
+        // I004/074
+        if let Some(longitudinal_deviation) = &self.longitudinal_deviation {
+            let bytes = longitudinal_deviation.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_074);
+            vector.put(&bytes[..]);
+        }
+        // I004/075
+        if let Some(transversal_deviation) = &self.transversal_deviation {
+            let bytes = transversal_deviation.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_075);
+            vector.put(&bytes[..]);
+        }
+        // I004/100
+        if let Some(area_definitions) = &self.area_definitions {
+            let bytes = area_definitions.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_100);
+            vector.put(&bytes[..]);
+        }
+        // I004/035
+        if let Some(track_number_2) = &self.track_number_2 {
+            let bytes = track_number_2.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_035);
+            vector.put(&bytes[..]);
+        }
+        // I004/171
+        if let Some(aircraft_characteristics_2) = &self.aircraft_characteristics_2 {
+            let bytes = aircraft_characteristics_2.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_171);
+            vector.put(&bytes[..]);
+        }
+        // I004/110
+        if let Some(sector_control) = &self.sector_control {
+            let bytes = sector_control.encode();
+            self.set_fspec_bit(Cat04Fspec::I004_110);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat04Fspec::I004RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat04Fspec::I004SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat04Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record04 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record04::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record04::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT04 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat04Fspec {
+    I004_010 = 1,
+    I004_000,
+    I004_015,
+    I004_020,
+    I004_040,
+    I004_045,
+    I004_060,
+    I004_030,
+    I004_170,
+    I004_120,
+    I004_070,
+    I004_076,
+    I004_074,
+    I004_075,
+    I004_100,
+    I004_035,
+    I004_171,
+    I004_110,
+    I004RE = 20,
+    I004SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat04::message_type_field::AlertType;
+
+    #[test]
+    fn check_record() {
+        // Create data source and message type
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+        let mut message_type = AlertMessageType::default();
+        message_type.set_alert_type(AlertType::ShortTermConflictAlert);
+
+        // Create track number and conflict timing
+        let mut track_number = TrackNumber::default();
+        track_number.set_track_number(1234);
+        let mut conflict_timing = ConflictTiming::default();
+        conflict_timing.set_minimum_horizontal(1500.0).unwrap();
+
+        // Create record
+        let mut record = Record04 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            track_number_1: Some(track_number),
+            conflict_timing: Some(conflict_timing),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xc1, 0x90]);
+
+        // New record
+        let mut object = Record04::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the third FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record04 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record04::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
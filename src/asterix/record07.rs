@@ -0,0 +1,372 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::aircraft_address_field::AircraftAddress;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::cat07::interrogation_descriptor_field::InterrogationDescriptor;
+use crate::uap::cat07::interrogation_request_field::InterrogationRequest;
+use crate::uap::cat07::interrogation_status_field::InterrogationStatus;
+use crate::uap::cat07::message_type_field::InterrogationMessageType;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::flight_level_field::FlightLevel;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::mode_s_mb_data_field::ModeSMbData;
+use crate::uap::polar_position_field::PolarPosition;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+
+/// Record of CAT07 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record07 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I007/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I007/000 Message Type
+    pub message_type: Option<InterrogationMessageType>,
+    /// I007/140 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I007/220 Aircraft Address
+    pub aircraft_address: Option<AircraftAddress>,
+    /// I007/161 Track Number
+    pub track_number: Option<TrackNumber>,
+    /// I007/040 Predicted Position in Polar Co-ordinates
+    pub predicted_position: Option<PolarPosition>,
+    /// I007/020 Interrogation Descriptor
+    pub interrogation_descriptor: Option<InterrogationDescriptor>,
+    /// I007/030 Interrogation Request
+    pub interrogation_request: Option<InterrogationRequest>,
+    /// I007/050 Interrogation Status
+    pub interrogation_status: Option<InterrogationStatus>,
+    /// I007/070 Mode-3/A Code in Octal Representation
+    pub mode_3a_code: Option<Mode3ACode>,
+    /// I007/090 Flight Level in Binary Representation
+    pub flight_level: Option<FlightLevel>,
+    /// I007/240 Aircraft Identification
+    pub aircraft_identification: Option<AircraftIdentification>,
+    /// I007/250 Mode S MB Data
+    pub mode_s_mb_data: Option<ModeSMbData>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record07 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat07Fspec::I007SP as u8, &[14])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I007/010
+        if self.is_fspec_bit_set(Cat07Fspec::I007_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/000
+        if self.is_fspec_bit_set(Cat07Fspec::I007_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/140
+        if self.is_fspec_bit_set(Cat07Fspec::I007_140) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/220
+        if self.is_fspec_bit_set(Cat07Fspec::I007_220) {
+            self.aircraft_address = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/161
+        if self.is_fspec_bit_set(Cat07Fspec::I007_161) {
+            self.track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/040
+        if self.is_fspec_bit_set(Cat07Fspec::I007_040) {
+            self.predicted_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/020
+        if self.is_fspec_bit_set(Cat07Fspec::I007_020) {
+            self.interrogation_descriptor = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/030
+        if self.is_fspec_bit_set(Cat07Fspec::I007_030) {
+            self.interrogation_request = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/050
+        if self.is_fspec_bit_set(Cat07Fspec::I007_050) {
+            self.interrogation_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/070
+        if self.is_fspec_bit_set(Cat07Fspec::I007_070) {
+            self.mode_3a_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/090
+        if self.is_fspec_bit_set(Cat07Fspec::I007_090) {
+            self.flight_level = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/240
+        if self.is_fspec_bit_set(Cat07Fspec::I007_240) {
+            self.aircraft_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I007/250
+        if self.is_fspec_bit_set(Cat07Fspec::I007_250) {
+            self.mode_s_mb_data = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat07Fspec::I007RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat07Fspec::I007SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat07Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I007/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_010);
+            vector.put(&bytes[..]);
+        }
+        // I007/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_000);
+            vector.put(&bytes[..]);
+        }
+        // I007/140
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_140);
+            vector.put(&bytes[..]);
+        }
+        // I007/220
+        if let Some(aircraft_address) = &self.aircraft_address {
+            let bytes = aircraft_address.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_220);
+            vector.put(&bytes[..]);
+        }
+        // I007/161
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_161);
+            vector.put(&bytes[..]);
+        }
+        // I007/040
+        if let Some(predicted_position) = &self.predicted_position {
+            let bytes = predicted_position.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_040);
+            vector.put(&bytes[..]);
+        }
+        // I007/020
+        if let Some(interrogation_descriptor) = &self.interrogation_descriptor {
+            let bytes = interrogation_descriptor.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_020);
+            vector.put(&bytes[..]);
+        }
+        // I007/030
+        if let Some(interrogation_request) = &self.interrogation_request {
+            let bytes = interrogation_request.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_030);
+            vector.put(&bytes[..]);
+        }
+        // I007/050
+        if let Some(interrogation_status) = &self.interrogation_status {
+            let bytes = interrogation_status.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_050);
+            vector.put(&bytes[..]);
+        }
+        // I007/070
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_070);
+            vector.put(&bytes[..]);
+        }
+        // I007/090
+        if let Some(flight_level) = &self.flight_level {
+            let bytes = flight_level.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_090);
+            vector.put(&bytes[..]);
+        }
+        // I007/240
+        if let Some(aircraft_identification) = &self.aircraft_identification {
+            let bytes = aircraft_identification.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_240);
+            vector.put(&bytes[..]);
+        }
+        // I007/250
+        if let Some(mode_s_mb_data) = &self.mode_s_mb_data {
+            let bytes = mode_s_mb_data.encode();
+            self.set_fspec_bit(Cat07Fspec::I007_250);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat07Fspec::I007RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat07Fspec::I007SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat07Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record07 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record07::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record07::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT07 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat07Fspec {
+    I007_010 = 1,
+    I007_000,
+    I007_140,
+    I007_220,
+    I007_161,
+    I007_040,
+    I007_020,
+    I007_030,
+    I007_050,
+    I007_070,
+    I007_090,
+    I007_240,
+    I007_250,
+    I007RE = 15,
+    I007SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat07::message_type_field::InterrogationMessage;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create message type
+        let mut message_type = InterrogationMessageType::default();
+        message_type.set_message_type(InterrogationMessage::Request);
+
+        // Create aircraft address
+        let mut aircraft_address = AircraftAddress::default();
+        aircraft_address.set_address(0x3c660c).unwrap();
+
+        // Create interrogation descriptor and request
+        let mut interrogation_descriptor = InterrogationDescriptor::default();
+        interrogation_descriptor.set_uplink_format(4).unwrap();
+        let mut interrogation_request = InterrogationRequest::default();
+        interrogation_request.insert_register(4, 0).unwrap();
+
+        // Create record
+        let mut record = Record07 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            aircraft_address: Some(aircraft_address),
+            interrogation_descriptor: Some(interrogation_descriptor),
+            interrogation_request: Some(interrogation_request),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xd3, 0x80]);
+
+        // New record
+        let mut object = Record07::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the third FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record07 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x40, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record07::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
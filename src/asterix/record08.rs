@@ -0,0 +1,355 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat08::cartesian_vectors_field::CartesianVectors;
+use crate::uap::cat08::contour_identifier_field::ContourIdentifier;
+use crate::uap::cat08::contour_points_field::ContourPoints;
+use crate::uap::cat08::message_type_field::WeatherMessageType;
+use crate::uap::cat08::polar_vectors_field::PolarVectors;
+use crate::uap::cat08::processing_status_field::ProcessingStatus;
+use crate::uap::cat08::total_items_field::TotalItems;
+use crate::uap::cat08::vector_qualifier_field::VectorQualifier;
+use crate::uap::cat08::weather_vectors_field::WeatherVectors;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::extended_field::ExtendedField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT08 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record08 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I008/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I008/000 Message Type
+    pub message_type: Option<WeatherMessageType>,
+    /// I008/020 Vector Qualifier
+    pub vector_qualifier: Option<VectorQualifier>,
+    /// I008/036 Sequence of Cartesian Vectors in SPF Notation
+    pub cartesian_vectors: Option<CartesianVectors>,
+    /// I008/034 Sequence of Polar Vectors in SPF Notation
+    pub polar_vectors: Option<PolarVectors>,
+    /// I008/040 Contour Identifier
+    pub contour_identifier: Option<ContourIdentifier>,
+    /// I008/050 Sequence of Contour Points in SPF Notation
+    pub contour_points: Option<ContourPoints>,
+    /// I008/090 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I008/100 Processing Status
+    pub processing_status: Option<ProcessingStatus>,
+    /// I008/110 Station Configuration Status
+    pub station_configuration: Option<ExtendedField>,
+    /// I008/120 Total Number of Items Constituting One Weather Picture
+    pub total_items: Option<TotalItems>,
+    /// I008/038 Sequence of Weather Vectors in SPF Notation
+    pub weather_vectors: Option<WeatherVectors>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record08 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Random field sequencing is not supported
+        if self.is_fspec_bit_set(Cat08Fspec::I008RFS) {
+            return Err(CatError::ValueInvalid);
+        }
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat08Fspec::I008RFS as u8, &[])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I008/010
+        if self.is_fspec_bit_set(Cat08Fspec::I008_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/000
+        if self.is_fspec_bit_set(Cat08Fspec::I008_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/020
+        if self.is_fspec_bit_set(Cat08Fspec::I008_020) {
+            self.vector_qualifier = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/036
+        if self.is_fspec_bit_set(Cat08Fspec::I008_036) {
+            self.cartesian_vectors = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/034
+        if self.is_fspec_bit_set(Cat08Fspec::I008_034) {
+            self.polar_vectors = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/040
+        if self.is_fspec_bit_set(Cat08Fspec::I008_040) {
+            self.contour_identifier = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/050
+        if self.is_fspec_bit_set(Cat08Fspec::I008_050) {
+            self.contour_points = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/090
+        if self.is_fspec_bit_set(Cat08Fspec::I008_090) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/100
+        if self.is_fspec_bit_set(Cat08Fspec::I008_100) {
+            self.processing_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/110
+        if self.is_fspec_bit_set(Cat08Fspec::I008_110) {
+            self.station_configuration = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/120
+        if self.is_fspec_bit_set(Cat08Fspec::I008_120) {
+            self.total_items = Some(decode_item(array, &mut offset)?);
+        }
+        // I008/038
+        if self.is_fspec_bit_set(Cat08Fspec::I008_038) {
+            self.weather_vectors = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat08Fspec::I008SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat08Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I008/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_010);
+            vector.put(&bytes[..]);
+        }
+        // I008/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_000);
+            vector.put(&bytes[..]);
+        }
+        // I008/020
+        if let Some(vector_qualifier) = &self.vector_qualifier {
+            let bytes = vector_qualifier.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_020);
+            vector.put(&bytes[..]);
+        }
+        // I008/036
+        if let Some(cartesian_vectors) = &self.cartesian_vectors {
+            let bytes = cartesian_vectors.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_036);
+            vector.put(&bytes[..]);
+        }
+        // I008/034
+        if let Some(polar_vectors) = &self.polar_vectors {
+            let bytes = polar_vectors.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_034);
+            vector.put(&bytes[..]);
+        }
+        // I008/040
+        if let Some(contour_identifier) = &self.contour_identifier {
+            let bytes = contour_identifier.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_040);
+            vector.put(&bytes[..]);
+        }
+        // I008/050
+        if let Some(contour_points) = &self.contour_points {
+            let bytes = contour_points.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_050);
+            vector.put(&bytes[..]);
+        }
+        // I008/090
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_090);
+            vector.put(&bytes[..]);
+        }
+        // I008/100
+        if let Some(processing_status) = &self.processing_status {
+            let bytes = processing_status.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_100);
+            vector.put(&bytes[..]);
+        }
+        // I008/110
+        if let Some(station_configuration) = &self.station_configuration {
+            let bytes = station_configuration.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_110);
+            vector.put(&bytes[..]);
+        }
+        // I008/120
+        if let Some(total_items) = &self.total_items {
+            let bytes = total_items.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_120);
+            vector.put(&bytes[..]);
+        }
+        // I008/038
+        if let Some(weather_vectors) = &self.weather_vectors {
+            let bytes = weather_vectors.encode();
+            self.set_fspec_bit(Cat08Fspec::I008_038);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat08Fspec::I008SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat08Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record08 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record08::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record08::encode(self)
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT08 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat08Fspec {
+    I008_010 = 1,
+    I008_000,
+    I008_020,
+    I008_036,
+    I008_034,
+    I008_040,
+    I008_050,
+    I008_090,
+    I008_100,
+    I008_110,
+    I008_120,
+    I008_038,
+    I008SP,
+    I008RFS,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat08::contour_identifier_field::ContourPosition;
+    use crate::uap::cat08::message_type_field::WeatherMessage;
+
+    #[test]
+    fn check_record() {
+        // Create data source and message type
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+        let mut message_type = WeatherMessageType::default();
+        message_type.set_message_type(WeatherMessage::ContourRecord);
+
+        // Create contour
+        let mut contour_identifier = ContourIdentifier::default();
+        contour_identifier.set_intensity(2).unwrap();
+        contour_identifier.set_position(ContourPosition::FirstAndLast);
+        let mut contour_points = ContourPoints::default();
+        contour_points.insert_point(1.0, 1.0, 0.25).unwrap();
+        contour_points.insert_point(2.0, 1.0, 0.25).unwrap();
+        contour_points.insert_point(2.0, 2.0, 0.25).unwrap();
+
+        // Create record
+        let mut record = Record08 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            contour_identifier: Some(contour_identifier),
+            contour_points: Some(contour_points),
+            processing_status: Some(ProcessingStatus::default()),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xc7, 0x40]);
+
+        // New record
+        let mut object = Record08::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record08 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x04, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record08::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+
+    #[test]
+    fn check_record_random_field_sequencing() {
+        // Random field sequencing is not supported
+        let bytes = Bytes::from_static(&[0x01, 0x02]);
+
+        let result = Record08::default().decode(&bytes);
+
+        assert_eq!(result, Err(CatError::ValueInvalid));
+    }
+}
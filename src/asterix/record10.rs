@@ -0,0 +1,544 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::aircraft_address_field::AircraftAddress;
+use crate::uap::cat10::amplitude_field::PrimaryAmplitude;
+use crate::uap::cat10::cartesian_position_field::CartesianPosition;
+use crate::uap::cat10::message_type_field::SurfaceMessageType;
+use crate::uap::cat10::polar_position_field::PolarPosition;
+use crate::uap::cat10::position_deviation_field::PositionDeviation;
+use crate::uap::cat10::preprogrammed_message_field::PreprogrammedMessage;
+use crate::uap::cat10::presence_field::Presence;
+use crate::uap::cat10::system_status_field::SystemStatus;
+use crate::uap::cat10::target_report_descriptor_field::TargetReportDescriptor;
+use crate::uap::cat10::track_status_field::TrackStatus;
+use crate::uap::cat10::wgs_position_field::WgsPosition;
+use crate::uap::cat48::polar_velocity_field::PolarVelocity;
+use crate::uap::cat62::altitude_field::GeometricAltitude;
+use crate::uap::cat62::cartesian_acceleration_field::CartesianAcceleration;
+use crate::uap::cat62::cartesian_velocity_field::CartesianVelocity;
+use crate::uap::cat62::target_size_field::TargetSize;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::flight_level_field::FlightLevel;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::mode_s_mb_data_field::ModeSMbData;
+use crate::uap::target_identification_field::TargetIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+use crate::uap::vehicle_fleet_field::VehicleFleetIdentification;
+
+/// Record of CAT10 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record10 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I010/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I010/000 Message Type
+    pub message_type: Option<SurfaceMessageType>,
+    /// I010/020 Target Report Descriptor
+    pub target_report_descriptor: Option<TargetReportDescriptor>,
+    /// I010/140 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I010/041 Position in WGS-84 Co-ordinates
+    pub wgs_position: Option<WgsPosition>,
+    /// I010/040 Measured Position in Polar Co-ordinates
+    pub measured_position: Option<PolarPosition>,
+    /// I010/042 Position in Cartesian Co-ordinates
+    pub cartesian_position: Option<CartesianPosition>,
+    /// I010/200 Calculated Track Velocity in Polar Co-ordinates
+    pub track_velocity: Option<PolarVelocity>,
+    /// I010/202 Calculated Track Velocity in Cartesian Co-ordinates
+    pub cartesian_velocity: Option<CartesianVelocity>,
+    /// I010/161 Track Number
+    pub track_number: Option<TrackNumber>,
+    /// I010/170 Track Status
+    pub track_status: Option<TrackStatus>,
+    /// I010/060 Mode-3/A Code in Octal Representation
+    pub mode_3a_code: Option<Mode3ACode>,
+    /// I010/220 Target Address
+    pub target_address: Option<AircraftAddress>,
+    /// I010/245 Target Identification
+    pub target_identification: Option<TargetIdentification>,
+    /// I010/250 Mode S MB Data
+    pub mode_s_mb_data: Option<ModeSMbData>,
+    /// I010/300 Vehicle Fleet Identification
+    pub vehicle_fleet: Option<VehicleFleetIdentification>,
+    /// I010/090 Flight Level in Binary Representation
+    pub flight_level: Option<FlightLevel>,
+    /// I010/091 Measured Height
+    pub measured_height: Option<GeometricAltitude>,
+    /// I010/270 Target Size and Orientation
+    pub target_size: Option<TargetSize>,
+    /// I010/550 System Status
+    pub system_status: Option<SystemStatus>,
+    /// I010/310 Pre-programmed Message
+    pub preprogrammed_message: Option<PreprogrammedMessage>,
+    /// I010/500 Standard Deviation of Position
+    pub position_deviation: Option<PositionDeviation>,
+    /// I010/280 Presence
+    pub presence: Option<Presence>,
+    /// I010/131 Amplitude of Primary Plot
+    pub primary_amplitude: Option<PrimaryAmplitude>,
+    /// I010/210 Calculated Acceleration
+    pub cartesian_acceleration: Option<CartesianAcceleration>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+}
+
+impl Record10 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat10Fspec::I010RE as u8, &[26])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I010/010
+        if self.is_fspec_bit_set(Cat10Fspec::I010_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/000
+        if self.is_fspec_bit_set(Cat10Fspec::I010_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/020
+        if self.is_fspec_bit_set(Cat10Fspec::I010_020) {
+            self.target_report_descriptor = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/140
+        if self.is_fspec_bit_set(Cat10Fspec::I010_140) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/041
+        if self.is_fspec_bit_set(Cat10Fspec::I010_041) {
+            self.wgs_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/040
+        if self.is_fspec_bit_set(Cat10Fspec::I010_040) {
+            self.measured_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/042
+        if self.is_fspec_bit_set(Cat10Fspec::I010_042) {
+            self.cartesian_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/200
+        if self.is_fspec_bit_set(Cat10Fspec::I010_200) {
+            self.track_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/202
+        if self.is_fspec_bit_set(Cat10Fspec::I010_202) {
+            self.cartesian_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/161
+        if self.is_fspec_bit_set(Cat10Fspec::I010_161) {
+            self.track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/170
+        if self.is_fspec_bit_set(Cat10Fspec::I010_170) {
+            self.track_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/060
+        if self.is_fspec_bit_set(Cat10Fspec::I010_060) {
+            self.mode_3a_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/220
+        if self.is_fspec_bit_set(Cat10Fspec::I010_220) {
+            self.target_address = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/245
+        if self.is_fspec_bit_set(Cat10Fspec::I010_245) {
+            self.target_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/250
+        if self.is_fspec_bit_set(Cat10Fspec::I010_250) {
+            self.mode_s_mb_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/300
+        if self.is_fspec_bit_set(Cat10Fspec::I010_300) {
+            self.vehicle_fleet = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/090
+        if self.is_fspec_bit_set(Cat10Fspec::I010_090) {
+            self.flight_level = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/091
+        if self.is_fspec_bit_set(Cat10Fspec::I010_091) {
+            self.measured_height = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/270
+        if self.is_fspec_bit_set(Cat10Fspec::I010_270) {
+            self.target_size = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/550
+        if self.is_fspec_bit_set(Cat10Fspec::I010_550) {
+            self.system_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/310
+        if self.is_fspec_bit_set(Cat10Fspec::I010_310) {
+            self.preprogrammed_message = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/500
+        if self.is_fspec_bit_set(Cat10Fspec::I010_500) {
+            self.position_deviation = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/280
+        if self.is_fspec_bit_set(Cat10Fspec::I010_280) {
+            self.presence = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/131
+        if self.is_fspec_bit_set(Cat10Fspec::I010_131) {
+            self.primary_amplitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I010/210
+        if self.is_fspec_bit_set(Cat10Fspec::I010_210) {
+            self.cartesian_acceleration = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat10Fspec::I010SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat10Fspec::I010RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat10Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I010/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_010);
+            vector.put(&bytes[..]);
+        }
+        // I010/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_000);
+            vector.put(&bytes[..]);
+        }
+        // I010/020
+        if let Some(target_report_descriptor) = &self.target_report_descriptor {
+            let bytes = target_report_descriptor.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_020);
+            vector.put(&bytes[..]);
+        }
+        // I010/140
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_140);
+            vector.put(&bytes[..]);
+        }
+        // I010/041
+        if let Some(wgs_position) = &self.wgs_position {
+            let bytes = wgs_position.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_041);
+            vector.put(&bytes[..]);
+        }
+        // I010/040
+        if let Some(measured_position) = &self.measured_position {
+            let bytes = measured_position.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_040);
+            vector.put(&bytes[..]);
+        }
+        // I010/042
+        if let Some(cartesian_position) = &self.cartesian_position {
+            let bytes = cartesian_position.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_042);
+            vector.put(&bytes[..]);
+        }
+        // I010/200
+        if let Some(track_velocity) = &self.track_velocity {
+            let bytes = track_velocity.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_200);
+            vector.put(&bytes[..]);
+        }
+        // I010/202
+        if let Some(cartesian_velocity) = &self.cartesian_velocity {
+            let bytes = cartesian_velocity.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_202);
+            vector.put(&bytes[..]);
+        }
+        // I010/161
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_161);
+            vector.put(&bytes[..]);
+        }
+        // I010/170
+        if let Some(track_status) = &self.track_status {
+            let bytes = track_status.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_170);
+            vector.put(&bytes[..]);
+        }
+        // I010/060
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_060);
+            vector.put(&bytes[..]);
+        }
+        // I010/220
+        if let Some(target_address) = &self.target_address {
+            let bytes = target_address.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_220);
+            vector.put(&bytes[..]);
+        }
+        // I010/245
+        if let Some(target_identification) = &self.target_identification {
+            let bytes = target_identification.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_245);
+            vector.put(&bytes[..]);
+        }
+        // I010/250
+        if let Some(mode_s_mb_data) = &self.mode_s_mb_data {
+            let bytes = mode_s_mb_data.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_250);
+            vector.put(&bytes[..]);
+        }
+        // I010/300
+        if let Some(vehicle_fleet) = &self.vehicle_fleet {
+            let bytes = vehicle_fleet.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_300);
+            vector.put(&bytes[..]);
+        }
+        // I010/090
+        if let Some(flight_level) = &self.flight_level {
+            let bytes = flight_level.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_090);
+            vector.put(&bytes[..]);
+        }
+        // I010/091
+        if let Some(measured_height) = &self.measured_height {
+            let bytes = measured_height.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_091);
+            vector.put(&bytes[..]);
+        }
+        // I010/270
+        if let Some(target_size) = &self.target_size {
+            let bytes = target_size.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_270);
+            vector.put(&bytes[..]);
+        }
+        // I010/550
+        if let Some(system_status) = &self.system_status {
+            let bytes = system_status.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_550);
+            vector.put(&bytes[..]);
+        }
+        // I010/310
+        if let Some(preprogrammed_message) = &self.preprogrammed_message {
+            let bytes = preprogrammed_message.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_310);
+            vector.put(&bytes[..]);
+        }
+        // I010/500
+        if let Some(position_deviation) = &self.position_deviation {
+            let bytes = position_deviation.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_500);
+            vector.put(&bytes[..]);
+        }
+        // I010/280
+        if let Some(presence) = &self.presence {
+            let bytes = presence.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_280);
+            vector.put(&bytes[..]);
+        }
+        // I010/131
+        if let Some(primary_amplitude) = &self.primary_amplitude {
+            let bytes = primary_amplitude.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_131);
+            vector.put(&bytes[..]);
+        }
+        // I010/210
+        if let Some(cartesian_acceleration) = &self.cartesian_acceleration {
+            let bytes = cartesian_acceleration.encode();
+            self.set_fspec_bit(Cat10Fspec::I010_210);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat10Fspec::I010SP);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat10Fspec::I010RE);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat10Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record10 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record10::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record10::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT10 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat10Fspec {
+    I010_010 = 1,
+    I010_000,
+    I010_020,
+    I010_140,
+    I010_041,
+    I010_040,
+    I010_042,
+    I010_200,
+    I010_202,
+    I010_161,
+    I010_170,
+    I010_060,
+    I010_220,
+    I010_245,
+    I010_250,
+    I010_300,
+    I010_090,
+    I010_091,
+    I010_270,
+    I010_550,
+    I010_310,
+    I010_500,
+    I010_280,
+    I010_131,
+    I010_210,
+    I010SP = 27,
+    I010RE,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat10::message_type_field::SurfaceMessage;
+    use crate::uap::cat10::target_report_descriptor_field::SurfaceDetection;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create message type and target report descriptor
+        let mut message_type = SurfaceMessageType::default();
+        message_type.set_message_type(SurfaceMessage::TargetReport);
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_typ(SurfaceDetection::AdsB);
+
+        // Create position
+        let mut wgs_position = WgsPosition::default();
+        wgs_position.set_lat_lon(50.0379, 8.5622).unwrap();
+
+        // Create target address and identification
+        let mut target_address = AircraftAddress::default();
+        target_address.set_address(0x3c660c).unwrap();
+        let mut target_identification = TargetIdentification::default();
+        target_identification.set_identification("DLH123").unwrap();
+
+        // Create record
+        let mut record = Record10 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            target_report_descriptor: Some(target_report_descriptor),
+            wgs_position: Some(wgs_position),
+            target_address: Some(target_address),
+            target_identification: Some(target_identification),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xe9, 0x06]);
+
+        // New record
+        let mut object = Record10::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the fourth FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record10 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x01, 0x04, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record10::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
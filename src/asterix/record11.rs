@@ -0,0 +1,565 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat10::cartesian_position_field::CartesianPosition;
+use crate::uap::cat10::preprogrammed_message_field::PreprogrammedMessage;
+use crate::uap::cat10::wgs_position_field::WgsPosition;
+use crate::uap::cat11::aircraft_derived_data_field::AircraftDerivedData;
+use crate::uap::cat11::alert_messages_field::AlertMessages;
+use crate::uap::cat11::estimated_accuracies_field::EstimatedAccuracies;
+use crate::uap::cat11::holdbar_status_field::HoldbarStatus;
+use crate::uap::cat11::message_type_field::TrackMessageType;
+use crate::uap::cat11::phase_of_flight_field::PhaseOfFlight;
+use crate::uap::cat11::track_status_field::TrackStatus;
+use crate::uap::cat11::tracks_in_alert_field::TracksInAlert;
+use crate::uap::cat11::update_ages_field::SystemTrackUpdateAges;
+use crate::uap::cat62::altitude_field::{
+    BarometricAltitude, GeometricAltitude, MeasuredFlightLevel, RateOfClimb,
+};
+use crate::uap::cat62::cartesian_acceleration_field::CartesianAcceleration;
+use crate::uap::cat62::cartesian_velocity_field::CartesianVelocity;
+use crate::uap::cat62::flight_plan_data_field::FlightPlanRelatedData;
+use crate::uap::cat62::target_size_field::TargetSize;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::service_identification_field::ServiceIdentification;
+use crate::uap::target_identification_field::TargetIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+use crate::uap::vehicle_fleet_field::VehicleFleetIdentification;
+
+/// Record of CAT11 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record11 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I011/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I011/000 Message Type
+    pub message_type: Option<TrackMessageType>,
+    /// I011/015 Service Identification
+    pub service_identification: Option<ServiceIdentification>,
+    /// I011/140 Time of Track Information
+    pub time_of_track: Option<TimeOfDay>,
+    /// I011/041 Position in WGS-84 Co-ordinates
+    pub wgs_position: Option<WgsPosition>,
+    /// I011/042 Calculated Position in Cartesian Co-ordinates
+    pub cartesian_position: Option<CartesianPosition>,
+    /// I011/202 Calculated Track Velocity in Cartesian Co-ordinates
+    pub cartesian_velocity: Option<CartesianVelocity>,
+    /// I011/210 Calculated Acceleration
+    pub cartesian_acceleration: Option<CartesianAcceleration>,
+    /// I011/060 Mode-3/A Code in Octal Representation
+    pub mode_3a_code: Option<Mode3ACode>,
+    /// I011/245 Target Identification
+    pub target_identification: Option<TargetIdentification>,
+    /// I011/380 Mode-S / ADS-B Related Data
+    pub aircraft_derived_data: Option<AircraftDerivedData>,
+    /// I011/161 Track Number
+    pub track_number: Option<TrackNumber>,
+    /// I011/170 Track Status
+    pub track_status: Option<TrackStatus>,
+    /// I011/290 System Track Update Ages
+    pub update_ages: Option<SystemTrackUpdateAges>,
+    /// I011/430 Phase of Flight
+    pub phase_of_flight: Option<PhaseOfFlight>,
+    /// I011/090 Measured Flight Level
+    pub measured_flight_level: Option<MeasuredFlightLevel>,
+    /// I011/093 Calculated Track Barometric Altitude
+    pub barometric_altitude: Option<BarometricAltitude>,
+    /// I011/092 Calculated Track Geometric Altitude
+    pub geometric_altitude: Option<GeometricAltitude>,
+    /// I011/215 Calculated Rate of Climb/Descent
+    pub rate_of_climb: Option<RateOfClimb>,
+    /// I011/270 Target Size and Orientation
+    pub target_size: Option<TargetSize>,
+    /// I011/390 Flight Plan Related Data
+    pub flight_plan_data: Option<FlightPlanRelatedData>,
+    /// I011/300 Vehicle Fleet Identification
+    pub vehicle_fleet: Option<VehicleFleetIdentification>,
+    /// I011/310 Pre-programmed Message
+    pub preprogrammed_message: Option<PreprogrammedMessage>,
+    /// I011/500 Estimated Accuracies
+    pub estimated_accuracies: Option<EstimatedAccuracies>,
+    /// I011/600 Alert Messages
+    pub alert_messages: Option<AlertMessages>,
+    /// I011/605 Tracks in Alert
+    pub tracks_in_alert: Option<TracksInAlert>,
+    /// I011/610 Holdbar Status
+    pub holdbar_status: Option<HoldbarStatus>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+}
+
+impl Record11 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat11Fspec::I011RE as u8, &[])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I011/010
+        if self.is_fspec_bit_set(Cat11Fspec::I011_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/000
+        if self.is_fspec_bit_set(Cat11Fspec::I011_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/015
+        if self.is_fspec_bit_set(Cat11Fspec::I011_015) {
+            self.service_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/140
+        if self.is_fspec_bit_set(Cat11Fspec::I011_140) {
+            self.time_of_track = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/041
+        if self.is_fspec_bit_set(Cat11Fspec::I011_041) {
+            self.wgs_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/042
+        if self.is_fspec_bit_set(Cat11Fspec::I011_042) {
+            self.cartesian_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/202
+        if self.is_fspec_bit_set(Cat11Fspec::I011_202) {
+            self.cartesian_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/210
+        if self.is_fspec_bit_set(Cat11Fspec::I011_210) {
+            self.cartesian_acceleration = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/060
+        if self.is_fspec_bit_set(Cat11Fspec::I011_060) {
+            self.mode_3a_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/245
+        if self.is_fspec_bit_set(Cat11Fspec::I011_245) {
+            self.target_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/380
+        if self.is_fspec_bit_set(Cat11Fspec::I011_380) {
+            self.aircraft_derived_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/161
+        if self.is_fspec_bit_set(Cat11Fspec::I011_161) {
+            self.track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/170
+        if self.is_fspec_bit_set(Cat11Fspec::I011_170) {
+            self.track_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/290
+        if self.is_fspec_bit_set(Cat11Fspec::I011_290) {
+            self.update_ages = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/430
+        if self.is_fspec_bit_set(Cat11Fspec::I011_430) {
+            self.phase_of_flight = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/090
+        if self.is_fspec_bit_set(Cat11Fspec::I011_090) {
+            self.measured_flight_level = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/093
+        if self.is_fspec_bit_set(Cat11Fspec::I011_093) {
+            self.barometric_altitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/092
+        if self.is_fspec_bit_set(Cat11Fspec::I011_092) {
+            self.geometric_altitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/215
+        if self.is_fspec_bit_set(Cat11Fspec::I011_215) {
+            self.rate_of_climb = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/270
+        if self.is_fspec_bit_set(Cat11Fspec::I011_270) {
+            self.target_size = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/390
+        if self.is_fspec_bit_set(Cat11Fspec::I011_390) {
+            self.flight_plan_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/300
+        if self.is_fspec_bit_set(Cat11Fspec::I011_300) {
+            self.vehicle_fleet = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/310
+        if self.is_fspec_bit_set(Cat11Fspec::I011_310) {
+            self.preprogrammed_message = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/500
+        if self.is_fspec_bit_set(Cat11Fspec::I011_500) {
+            self.estimated_accuracies = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/600
+        if self.is_fspec_bit_set(Cat11Fspec::I011_600) {
+            self.alert_messages = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/605
+        if self.is_fspec_bit_set(Cat11Fspec::I011_605) {
+            self.tracks_in_alert = Some(decode_item(array, &mut offset)?);
+        }
+        // I011/610
+        if self.is_fspec_bit_set(Cat11Fspec::I011_610) {
+            self.holdbar_status = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat11Fspec::I011SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat11Fspec::I011RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat11Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I011/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_010);
+            vector.put(&bytes[..]);
+        }
+        // I011/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_000);
+            vector.put(&bytes[..]);
+        }
+        // I011/015
+        if let Some(service_identification) = &self.service_identification {
+            let bytes = service_identification.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_015);
+            vector.put(&bytes[..]);
+        }
+        // I011/140
+        if let Some(time_of_track) = &self.time_of_track {
+            let bytes = time_of_track.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_140);
+            vector.put(&bytes[..]);
+        }
+        // I011/041
+        if let Some(wgs_position) = &self.wgs_position {
+            let bytes = wgs_position.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_041);
+            vector.put(&bytes[..]);
+        }
+        // I011/042
+        if let Some(cartesian_position) = &self.cartesian_position {
+            let bytes = cartesian_position.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_042);
+            vector.put(&bytes[..]);
+        }
+        // I011/202
+        if let Some(cartesian_velocity) = &self.cartesian_velocity {
+            let bytes = cartesian_velocity.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_202);
+            vector.put(&bytes[..]);
+        }
+        // I011/210
+        if let Some(cartesian_acceleration) = &self.cartesian_acceleration {
+            let bytes = cartesian_acceleration.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_210);
+            vector.put(&bytes[..]);
+        }
+        // I011/060
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_060);
+            vector.put(&bytes[..]);
+        }
+        // I011/245
+        if let Some(target_identification) = &self.target_identification {
+            let bytes = target_identification.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_245);
+            vector.put(&bytes[..]);
+        }
+        // I011/380
+        if let Some(aircraft_derived_data) = &self.aircraft_derived_data {
+            let bytes = aircraft_derived_data.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_380);
+            vector.put(&bytes[..]);
+        }
+        // I011/161
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_161);
+            vector.put(&bytes[..]);
+        }
+        // I011/170
+        if let Some(track_status) = &self.track_status {
+            let bytes = track_status.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_170);
+            vector.put(&bytes[..]);
+        }
+        // I011/290
+        if let Some(update_ages) = &self.update_ages {
+            let bytes = update_ages.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_290);
+            vector.put(&bytes[..]);
+        }
+        // I011/430
+        if let Some(phase_of_flight) = &self.phase_of_flight {
+            let bytes = phase_of_flight.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_430);
+            vector.put(&bytes[..]);
+        }
+        // I011/090
+        if let Some(measured_flight_level) = &self.measured_flight_level {
+            let bytes = measured_flight_level.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_090);
+            vector.put(&bytes[..]);
+        }
+        // I011/093
+        if let Some(barometric_altitude) = &self.barometric_altitude {
+            let bytes = barometric_altitude.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_093);
+            vector.put(&bytes[..]);
+        }
+        // I011/092
+        if let Some(geometric_altitude) = &self.geometric_altitude {
+            let bytes = geometric_altitude.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_092);
+            vector.put(&bytes[..]);
+        }
+        // I011/215
+        if let Some(rate_of_climb) = &self.rate_of_climb {
+            let bytes = rate_of_climb.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_215);
+            vector.put(&bytes[..]);
+        }
+        // I011/270
+        if let Some(target_size) = &self.target_size {
+            let bytes = target_size.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_270);
+            vector.put(&bytes[..]);
+        }
+        // I011/390
+        if let Some(flight_plan_data) = &self.flight_plan_data {
+            let bytes = flight_plan_data.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_390);
+            vector.put(&bytes[..]);
+        }
+        // I011/300
+        if let Some(vehicle_fleet) = &self.vehicle_fleet {
+            let bytes = vehicle_fleet.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_300);
+            vector.put(&bytes[..]);
+        }
+        // I011/310
+        if let Some(preprogrammed_message) = &self.preprogrammed_message {
+            let bytes = preprogrammed_message.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_310);
+            vector.put(&bytes[..]);
+        }
+        // I011/500
+        if let Some(estimated_accuracies) = &self.estimated_accuracies {
+            let bytes = estimated_accuracies.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_500);
+            vector.put(&bytes[..]);
+        }
+        // I011/600
+        if let Some(alert_messages) = &self.alert_messages {
+            let bytes = alert_messages.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_600);
+            vector.put(&bytes[..]);
+        }
+        // I011/605
+        if let Some(tracks_in_alert) = &self.tracks_in_alert {
+            let bytes = tracks_in_alert.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_605);
+            vector.put(&bytes[..]);
+        }
+        // I011/610
+        if let Some(holdbar_status) = &self.holdbar_status {
+            let bytes = holdbar_status.encode();
+            self.set_fspec_bit(Cat11Fspec::I011_610);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat11Fspec::I011SP);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat11Fspec::I011RE);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat11Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record11 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record11::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record11::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT11 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat11Fspec {
+    I011_010 = 1,
+    I011_000,
+    I011_015,
+    I011_140,
+    I011_041,
+    I011_042,
+    I011_202,
+    I011_210,
+    I011_060,
+    I011_245,
+    I011_380,
+    I011_161,
+    I011_170,
+    I011_290,
+    I011_430,
+    I011_090,
+    I011_093,
+    I011_092,
+    I011_215,
+    I011_270,
+    I011_390,
+    I011_300,
+    I011_310,
+    I011_500,
+    I011_600,
+    I011_605,
+    I011_610,
+    I011SP,
+    I011RE,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat11::message_type_field::TrackMessage;
+    use crate::uap::cat11::phase_of_flight_field::FlightPhase;
+    use crate::uap::cat11::track_status_field::TrackFlag;
+
+    #[test]
+    fn check_record() {
+        // Create data source and message type
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+        let mut message_type = TrackMessageType::default();
+        message_type.set_message_type(TrackMessage::TargetReport);
+
+        // Create track number, track status and phase of flight
+        let mut track_number = TrackNumber::default();
+        track_number.set_track_number(1234);
+        let mut track_status = TrackStatus::default();
+        track_status.set_flag(TrackFlag::GroundBitSet, true);
+        let mut phase_of_flight = PhaseOfFlight::default();
+        phase_of_flight.set_phase(FlightPhase::TaxiingForArrival);
+
+        // Create record
+        let mut record = Record11 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            track_number: Some(track_number),
+            track_status: Some(track_status),
+            phase_of_flight: Some(phase_of_flight),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..3], &[0xc1, 0x0d, 0x80]);
+
+        // New record
+        let mut object = Record11::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the fourth FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record11 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record11::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
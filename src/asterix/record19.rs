@@ -0,0 +1,330 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat19::message_type_field::StatusMessageType;
+use crate::uap::cat19::reference_height_field::ReferenceHeight;
+use crate::uap::cat19::reference_transponder_status_field::ReferenceTransponderStatus;
+use crate::uap::cat19::remote_station_status_field::RemoteStationStatus;
+use crate::uap::cat19::system_status_field::SystemStatus;
+use crate::uap::cat19::tracking_processor_status_field::TrackingProcessorStatus;
+use crate::uap::cat19::undulation_field::Undulation;
+use crate::uap::cat21::position_field::HighResolutionPosition;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT19 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record19 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I019/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I019/000 Message Type
+    pub message_type: Option<StatusMessageType>,
+    /// I019/140 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I019/550 System Status
+    pub system_status: Option<SystemStatus>,
+    /// I019/551 Tracking Processor Detailed Status
+    pub tracking_processor_status: Option<TrackingProcessorStatus>,
+    /// I019/552 Remote Sensor Detailed Status
+    pub remote_station_status: Option<RemoteStationStatus>,
+    /// I019/553 Reference Transponder Detailed Status
+    pub reference_transponder_status: Option<ReferenceTransponderStatus>,
+    /// I019/600 Position of the MLT System Reference Point
+    pub reference_position: Option<HighResolutionPosition>,
+    /// I019/610 Height of the MLT System Reference Point
+    pub reference_height: Option<ReferenceHeight>,
+    /// I019/620 WGS-84 Undulation
+    pub undulation: Option<Undulation>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record19 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat19Fspec::I019SP as u8, &[11, 12])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I019/010
+        if self.is_fspec_bit_set(Cat19Fspec::I019_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/000
+        if self.is_fspec_bit_set(Cat19Fspec::I019_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/140
+        if self.is_fspec_bit_set(Cat19Fspec::I019_140) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/550
+        if self.is_fspec_bit_set(Cat19Fspec::I019_550) {
+            self.system_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/551
+        if self.is_fspec_bit_set(Cat19Fspec::I019_551) {
+            self.tracking_processor_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/552
+        if self.is_fspec_bit_set(Cat19Fspec::I019_552) {
+            self.remote_station_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/553
+        if self.is_fspec_bit_set(Cat19Fspec::I019_553) {
+            self.reference_transponder_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/600
+        if self.is_fspec_bit_set(Cat19Fspec::I019_600) {
+            self.reference_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/610
+        if self.is_fspec_bit_set(Cat19Fspec::I019_610) {
+            self.reference_height = Some(decode_item(array, &mut offset)?);
+        }
+        // I019/620
+        if self.is_fspec_bit_set(Cat19Fspec::I019_620) {
+            self.undulation = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat19Fspec::I019RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat19Fspec::I019SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat19Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I019/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_010);
+            vector.put(&bytes[..]);
+        }
+        // I019/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_000);
+            vector.put(&bytes[..]);
+        }
+        // I019/140
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_140);
+            vector.put(&bytes[..]);
+        }
+        // I019/550
+        if let Some(system_status) = &self.system_status {
+            let bytes = system_status.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_550);
+            vector.put(&bytes[..]);
+        }
+        // I019/551
+        if let Some(tracking_processor_status) = &self.tracking_processor_status {
+            let bytes = tracking_processor_status.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_551);
+            vector.put(&bytes[..]);
+        }
+        // I019/552
+        if let Some(remote_station_status) = &self.remote_station_status {
+            let bytes = remote_station_status.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_552);
+            vector.put(&bytes[..]);
+        }
+        // I019/553
+        if let Some(reference_transponder_status) = &self.reference_transponder_status {
+            let bytes = reference_transponder_status.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_553);
+            vector.put(&bytes[..]);
+        }
+        // I019/600
+        if let Some(reference_position) = &self.reference_position {
+            let bytes = reference_position.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_600);
+            vector.put(&bytes[..]);
+        }
+        // I019/610
+        if let Some(reference_height) = &self.reference_height {
+            let bytes = reference_height.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_610);
+            vector.put(&bytes[..]);
+        }
+        // I019/620
+        if let Some(undulation) = &self.undulation {
+            let bytes = undulation.encode();
+            self.set_fspec_bit(Cat19Fspec::I019_620);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat19Fspec::I019RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat19Fspec::I019SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat19Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record19 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record19::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record19::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT19 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat19Fspec {
+    I019_010 = 1,
+    I019_000,
+    I019_140,
+    I019_550,
+    I019_551,
+    I019_552,
+    I019_553,
+    I019_600,
+    I019_610,
+    I019_620,
+    I019RE = 13,
+    I019SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat19::message_type_field::StatusMessage;
+    use crate::uap::cat19::remote_station_status_field::RemoteStation;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create message type and system status
+        let mut message_type = StatusMessageType::default();
+        message_type.set_message_type(StatusMessage::PeriodicStatus);
+        let mut time_of_day = TimeOfDay::default();
+        time_of_day.set_time(12345.5);
+        let system_status = SystemStatus::default();
+
+        // Create remote station status
+        let mut station = RemoteStation::new(1);
+        station.set_good(true);
+        let mut remote_station_status = RemoteStationStatus::default();
+        remote_station_status.insert_station(station).unwrap();
+
+        // Create record
+        let mut record = Record19 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            time_of_day: Some(time_of_day),
+            system_status: Some(system_status),
+            remote_station_status: Some(remote_station_status),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(bytes[0], 0xf4);
+
+        // New record
+        let mut object = Record19::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record19 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record19::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
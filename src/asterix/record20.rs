@@ -0,0 +1,571 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::acas_resolution_field::AcasResolution;
+use crate::uap::aircraft_address_field::AircraftAddress;
+use crate::uap::cat10::preprogrammed_message_field::PreprogrammedMessage;
+use crate::uap::cat20::contributing_devices_field::ContributingDevices;
+use crate::uap::cat20::position_accuracy_field::PositionAccuracy;
+use crate::uap::cat20::target_report_descriptor_field::TargetReportDescriptor;
+use crate::uap::cat20::track_status_field::TrackStatus;
+use crate::uap::cat48::communications_capability_field::CommunicationsCapability;
+use crate::uap::cat48::mode_1_code_field::Mode1Code;
+use crate::uap::cat48::mode_c_code_field::ModeCCode;
+use crate::uap::cat48::warning_error_field::WarningError;
+use crate::uap::cat62::altitude_field::GeometricAltitude;
+use crate::uap::cat62::cartesian_acceleration_field::CartesianAcceleration;
+use crate::uap::cat62::cartesian_position_field::CartesianPosition;
+use crate::uap::cat62::cartesian_velocity_field::CartesianVelocity;
+use crate::uap::cat62::wgs_position_field::WgsPosition;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::flight_level_field::FlightLevel;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::mode_s_mb_data_field::ModeSMbData;
+use crate::uap::target_identification_field::TargetIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+use crate::uap::vehicle_fleet_field::VehicleFleetIdentification;
+
+/// Record of CAT20 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record20 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I020/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I020/020 Target Report Descriptor
+    pub target_report_descriptor: Option<TargetReportDescriptor>,
+    /// I020/140 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I020/041 Position in WGS-84 Co-ordinates
+    pub wgs_position: Option<WgsPosition>,
+    /// I020/042 Position in Cartesian Co-ordinates
+    pub cartesian_position: Option<CartesianPosition>,
+    /// I020/161 Track Number
+    pub track_number: Option<TrackNumber>,
+    /// I020/170 Track Status
+    pub track_status: Option<TrackStatus>,
+    /// I020/070 Mode-3/A Code in Octal Representation
+    pub mode_3a_code: Option<Mode3ACode>,
+    /// I020/202 Calculated Track Velocity in Cartesian Co-ordinates
+    pub cartesian_velocity: Option<CartesianVelocity>,
+    /// I020/090 Flight Level in Binary Representation
+    pub flight_level: Option<FlightLevel>,
+    /// I020/100 Mode-C Code
+    pub mode_c_code: Option<ModeCCode>,
+    /// I020/220 Target Address
+    pub target_address: Option<AircraftAddress>,
+    /// I020/245 Target Identification
+    pub target_identification: Option<TargetIdentification>,
+    /// I020/110 Measured Height (Local Cartesian Co-ordinates)
+    pub measured_height: Option<GeometricAltitude>,
+    /// I020/105 Geometric Height (WGS-84)
+    pub geometric_height: Option<GeometricAltitude>,
+    /// I020/210 Calculated Acceleration
+    pub cartesian_acceleration: Option<CartesianAcceleration>,
+    /// I020/300 Vehicle Fleet Identification
+    pub vehicle_fleet: Option<VehicleFleetIdentification>,
+    /// I020/310 Pre-programmed Message
+    pub preprogrammed_message: Option<PreprogrammedMessage>,
+    /// I020/500 Position Accuracy
+    pub position_accuracy: Option<PositionAccuracy>,
+    /// I020/400 Contributing Devices
+    pub contributing_devices: Option<ContributingDevices>,
+    /// I020/250 Mode S MB Data
+    pub mode_s_mb_data: Option<ModeSMbData>,
+    /// I020/230 Comms/ACAS Capability and Flight Status
+    pub communications_capability: Option<CommunicationsCapability>,
+    /// I020/260 ACAS Resolution Advisory Report
+    pub acas_resolution: Option<AcasResolution>,
+    /// I020/030 Warning/Error Conditions
+    pub warning_error: Option<WarningError>,
+    /// I020/055 Mode-1 Code in Octal Representation
+    pub mode_1_code: Option<Mode1Code>,
+    /// I020/050 Mode-2 Code in Octal Representation
+    pub mode_2_code: Option<Mode3ACode>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record20 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat20Fspec::I020SP as u8, &[])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I020/010
+        if self.is_fspec_bit_set(Cat20Fspec::I020_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/020
+        if self.is_fspec_bit_set(Cat20Fspec::I020_020) {
+            self.target_report_descriptor = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/140
+        if self.is_fspec_bit_set(Cat20Fspec::I020_140) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/041
+        if self.is_fspec_bit_set(Cat20Fspec::I020_041) {
+            self.wgs_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/042
+        if self.is_fspec_bit_set(Cat20Fspec::I020_042) {
+            self.cartesian_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/161
+        if self.is_fspec_bit_set(Cat20Fspec::I020_161) {
+            self.track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/170
+        if self.is_fspec_bit_set(Cat20Fspec::I020_170) {
+            self.track_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/070
+        if self.is_fspec_bit_set(Cat20Fspec::I020_070) {
+            self.mode_3a_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/202
+        if self.is_fspec_bit_set(Cat20Fspec::I020_202) {
+            self.cartesian_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/090
+        if self.is_fspec_bit_set(Cat20Fspec::I020_090) {
+            self.flight_level = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/100
+        if self.is_fspec_bit_set(Cat20Fspec::I020_100) {
+            self.mode_c_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/220
+        if self.is_fspec_bit_set(Cat20Fspec::I020_220) {
+            self.target_address = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/245
+        if self.is_fspec_bit_set(Cat20Fspec::I020_245) {
+            self.target_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/110
+        if self.is_fspec_bit_set(Cat20Fspec::I020_110) {
+            self.measured_height = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/105
+        if self.is_fspec_bit_set(Cat20Fspec::I020_105) {
+            self.geometric_height = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/210
+        if self.is_fspec_bit_set(Cat20Fspec::I020_210) {
+            self.cartesian_acceleration = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/300
+        if self.is_fspec_bit_set(Cat20Fspec::I020_300) {
+            self.vehicle_fleet = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/310
+        if self.is_fspec_bit_set(Cat20Fspec::I020_310) {
+            self.preprogrammed_message = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/500
+        if self.is_fspec_bit_set(Cat20Fspec::I020_500) {
+            self.position_accuracy = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/400
+        if self.is_fspec_bit_set(Cat20Fspec::I020_400) {
+            self.contributing_devices = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/250
+        if self.is_fspec_bit_set(Cat20Fspec::I020_250) {
+            self.mode_s_mb_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/230
+        if self.is_fspec_bit_set(Cat20Fspec::I020_230) {
+            self.communications_capability = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/260
+        if self.is_fspec_bit_set(Cat20Fspec::I020_260) {
+            self.acas_resolution = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/030
+        if self.is_fspec_bit_set(Cat20Fspec::I020_030) {
+            self.warning_error = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/055
+        if self.is_fspec_bit_set(Cat20Fspec::I020_055) {
+            self.mode_1_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I020/050
+        if self.is_fspec_bit_set(Cat20Fspec::I020_050) {
+            self.mode_2_code = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat20Fspec::I020RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat20Fspec::I020SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat20Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I020/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_010);
+            vector.put(&bytes[..]);
+        }
+        // I020/020
+        if let Some(target_report_descriptor) = &self.target_report_descriptor {
+            let bytes = target_report_descriptor.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_020);
+            vector.put(&bytes[..]);
+        }
+        // I020/140
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_140);
+            vector.put(&bytes[..]);
+        }
+        // I020/041
+        if let Some(wgs_position) = &self.wgs_position {
+            let bytes = wgs_position.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_041);
+            vector.put(&bytes[..]);
+        }
+        // I020/042
+        if let Some(cartesian_position) = &self.cartesian_position {
+            let bytes = cartesian_position.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_042);
+            vector.put(&bytes[..]);
+        }
+        // I020/161
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_161);
+            vector.put(&bytes[..]);
+        }
+        // I020/170
+        if let Some(track_status) = &self.track_status {
+            let bytes = track_status.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_170);
+            vector.put(&bytes[..]);
+        }
+        // I020/070
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_070);
+            vector.put(&bytes[..]);
+        }
+        // I020/202
+        if let Some(cartesian_velocity) = &self.cartesian_velocity {
+            let bytes = cartesian_velocity.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_202);
+            vector.put(&bytes[..]);
+        }
+        // I020/090
+        if let Some(flight_level) = &self.flight_level {
+            let bytes = flight_level.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_090);
+            vector.put(&bytes[..]);
+        }
+        // I020/100
+        if let Some(mode_c_code) = &self.mode_c_code {
+            let bytes = mode_c_code.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_100);
+            vector.put(&bytes[..]);
+        }
+        // I020/220
+        if let Some(target_address) = &self.target_address {
+            let bytes = target_address.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_220);
+            vector.put(&bytes[..]);
+        }
+        // I020/245
+        if let Some(target_identification) = &self.target_identification {
+            let bytes = target_identification.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_245);
+            vector.put(&bytes[..]);
+        }
+        // I020/110
+        if let Some(measured_height) = &self.measured_height {
+            let bytes = measured_height.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_110);
+            vector.put(&bytes[..]);
+        }
+        // I020/105
+        if let Some(geometric_height) = &self.geometric_height {
+            let bytes = geometric_height.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_105);
+            vector.put(&bytes[..]);
+        }
+        // I020/210
+        if let Some(cartesian_acceleration) = &self.cartesian_acceleration {
+            let bytes = cartesian_acceleration.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_210);
+            vector.put(&bytes[..]);
+        }
+        // I020/300
+        if let Some(vehicle_fleet) = &self.vehicle_fleet {
+            let bytes = vehicle_fleet.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_300);
+            vector.put(&bytes[..]);
+        }
+        // I020/310
+        if let Some(preprogrammed_message) = &self.preprogrammed_message {
+            let bytes = preprogrammed_message.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_310);
+            vector.put(&bytes[..]);
+        }
+        // I020/500
+        if let Some(position_accuracy) = &self.position_accuracy {
+            let bytes = position_accuracy.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_500);
+            vector.put(&bytes[..]);
+        }
+        // I020/400
+        if let Some(contributing_devices) = &self.contributing_devices {
+            let bytes = contributing_devices.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_400);
+            vector.put(&bytes[..]);
+        }
+        // I020/250
+        if let Some(mode_s_mb_data) = &self.mode_s_mb_data {
+            let bytes = mode_s_mb_data.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_250);
+            vector.put(&bytes[..]);
+        }
+        // I020/230
+        if let Some(communications_capability) = &self.communications_capability {
+            let bytes = communications_capability.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_230);
+            vector.put(&bytes[..]);
+        }
+        // I020/260
+        if let Some(acas_resolution) = &self.acas_resolution {
+            let bytes = acas_resolution.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_260);
+            vector.put(&bytes[..]);
+        }
+        // I020/030
+        if let Some(warning_error) = &self.warning_error {
+            let bytes = warning_error.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_030);
+            vector.put(&bytes[..]);
+        }
+        // I020/055
+        if let Some(mode_1_code) = &self.mode_1_code {
+            let bytes = mode_1_code.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_055);
+            vector.put(&bytes[..]);
+        }
+        // I020/050
+        if let Some(mode_2_code) = &self.mode_2_code {
+            let bytes = mode_2_code.encode();
+            self.set_fspec_bit(Cat20Fspec::I020_050);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat20Fspec::I020RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat20Fspec::I020SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat20Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record20 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record20::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record20::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT20 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat20Fspec {
+    I020_010 = 1,
+    I020_020,
+    I020_140,
+    I020_041,
+    I020_042,
+    I020_161,
+    I020_170,
+    I020_070,
+    I020_202,
+    I020_090,
+    I020_100,
+    I020_220,
+    I020_245,
+    I020_110,
+    I020_105,
+    I020_210,
+    I020_300,
+    I020_310,
+    I020_500,
+    I020_400,
+    I020_250,
+    I020_230,
+    I020_260,
+    I020_030,
+    I020_055,
+    I020_050,
+    I020RE,
+    I020SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat20::reserved_expansion_field::ReservedExpansion;
+    use crate::uap::cat20::target_report_descriptor_field::MultilaterationDetection;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create target report descriptor
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_detection(MultilaterationDetection::ModeS, true);
+
+        // Create position
+        let mut wgs_position = WgsPosition::default();
+        wgs_position.set_lat_lon(50.0379, 8.5622).unwrap();
+
+        // Create target address
+        let mut target_address = AircraftAddress::default();
+        target_address.set_address(0x3c660c).unwrap();
+
+        // Create reserved expansion with position accuracy
+        let mut position_accuracy = PositionAccuracy::default();
+        position_accuracy
+            .set_cartesian_deviation(7.5, 10.0, 0.0)
+            .unwrap();
+        let mut reserved_expansion = ReservedExpansion::default();
+        reserved_expansion.set_position_accuracy(&position_accuracy);
+        let mut explicit_field = ExplicitField::default();
+        explicit_field.set_content(&reserved_expansion).unwrap();
+
+        // Create record
+        let mut record = Record20 {
+            data_source_id: Some(data_source),
+            target_report_descriptor: Some(target_report_descriptor),
+            wgs_position: Some(wgs_position),
+            target_address: Some(target_address),
+            reserved_expansion: Some(explicit_field),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..4], &[0xd1, 0x09, 0x01, 0x04]);
+
+        // New record
+        let mut object = Record20::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+
+        let content: ReservedExpansion = object.reserved_expansion.unwrap().get_content().unwrap();
+
+        assert_eq!(
+            content
+                .get_position_accuracy()
+                .unwrap()
+                .get_cartesian_deviation(),
+            Some((7.5, 10.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the fourth FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record20 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record20::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
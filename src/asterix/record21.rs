@@ -0,0 +1,792 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::acas_resolution_field::AcasResolution;
+use crate::uap::aircraft_address_field::AircraftAddress;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::cat21::air_speed_field::{AirSpeed, TrueAirspeed};
+use crate::uap::cat21::data_ages_field::DataAges;
+use crate::uap::cat21::emitter_category_field::EmitterCategory;
+use crate::uap::cat21::ground_vector_field::GroundVector;
+use crate::uap::cat21::high_precision_time_field::HighPrecisionTime;
+use crate::uap::cat21::magnetic_heading_field::MagneticHeading;
+use crate::uap::cat21::message_amplitude_field::MessageAmplitude;
+use crate::uap::cat21::met_information_field::MetInformation;
+use crate::uap::cat21::mops_version_field::MopsVersion;
+use crate::uap::cat21::operational_status_field::OperationalStatus;
+use crate::uap::cat21::position_field::{HighResolutionPosition, WgsPosition};
+use crate::uap::cat21::quality_indicators_field::QualityIndicators;
+use crate::uap::cat21::receiver_id_field::ReceiverId;
+use crate::uap::cat21::roll_angle_field::RollAngle;
+use crate::uap::cat21::selected_altitude_field::{FinalStateSelectedAltitude, SelectedAltitude};
+use crate::uap::cat21::service_management_field::ServiceManagement;
+use crate::uap::cat21::surface_capabilities_field::SurfaceCapabilities;
+use crate::uap::cat21::target_report_descriptor_field::TargetReportDescriptor;
+use crate::uap::cat21::target_status_field::TargetStatus;
+use crate::uap::cat21::track_angle_rate_field::TrackAngleRate;
+use crate::uap::cat21::trajectory_intent_field::TrajectoryIntent;
+use crate::uap::cat21::vertical_rate_field::VerticalRate;
+use crate::uap::cat62::altitude_field::{GeometricAltitude, MeasuredFlightLevel};
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::mode_s_mb_data_field::ModeSMbData;
+use crate::uap::service_identification_field::ServiceIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+
+/// Record of CAT21 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record21 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I021/010 Data Source Identification
+    pub data_source_id: Option<DataSource>,
+    /// I021/040 Target Report Descriptor
+    pub target_report_descriptor: Option<TargetReportDescriptor>,
+    /// I021/161 Track Number
+    pub track_number: Option<TrackNumber>,
+    /// I021/015 Service Identification
+    pub service_identification: Option<ServiceIdentification>,
+    /// I021/071 Time of Applicability for Position
+    pub time_position: Option<TimeOfDay>,
+    /// I021/130 Position in WGS-84 Co-ordinates
+    pub position: Option<WgsPosition>,
+    /// I021/131 Position in WGS-84 Co-ordinates, High Res.
+    pub high_resolution_position: Option<HighResolutionPosition>,
+    /// I021/072 Time of Applicability for Velocity
+    pub time_velocity: Option<TimeOfDay>,
+    /// I021/150 Air Speed
+    pub air_speed: Option<AirSpeed>,
+    /// I021/151 True Airspeed
+    pub true_airspeed: Option<TrueAirspeed>,
+    /// I021/080 Target Address
+    pub target_address: Option<AircraftAddress>,
+    /// I021/073 Time of Message Reception for Position
+    pub time_reception_position: Option<TimeOfDay>,
+    /// I021/074 Time of Message Reception of Position-High Precision
+    pub time_reception_position_high_precision: Option<HighPrecisionTime>,
+    /// I021/075 Time of Message Reception for Velocity
+    pub time_reception_velocity: Option<TimeOfDay>,
+    /// I021/076 Time of Message Reception of Velocity-High Precision
+    pub time_reception_velocity_high_precision: Option<HighPrecisionTime>,
+    /// I021/140 Geometric Height
+    pub geometric_height: Option<GeometricAltitude>,
+    /// I021/090 Quality Indicators
+    pub quality_indicators: Option<QualityIndicators>,
+    /// I021/210 MOPS Version
+    pub mops_version: Option<MopsVersion>,
+    /// I021/070 Mode 3/A Code in Octal Representation
+    pub mode_3a_code: Option<Mode3ACode>,
+    /// I021/230 Roll Angle
+    pub roll_angle: Option<RollAngle>,
+    /// I021/145 Flight Level
+    pub flight_level: Option<MeasuredFlightLevel>,
+    /// I021/152 Magnetic Heading
+    pub magnetic_heading: Option<MagneticHeading>,
+    /// I021/200 Target Status
+    pub target_status: Option<TargetStatus>,
+    /// I021/155 Barometric Vertical Rate
+    pub barometric_vertical_rate: Option<VerticalRate>,
+    /// I021/157 Geometric Vertical Rate
+    pub geometric_vertical_rate: Option<VerticalRate>,
+    /// I021/160 Airborne Ground Vector
+    pub ground_vector: Option<GroundVector>,
+    /// I021/165 Track Angle Rate
+    pub track_angle_rate: Option<TrackAngleRate>,
+    /// I021/077 Time of ASTERIX Report Transmission
+    pub time_report_transmission: Option<TimeOfDay>,
+    /// I021/170 Target Identification
+    pub target_identification: Option<AircraftIdentification>,
+    /// I021/020 Emitter Category
+    pub emitter_category: Option<EmitterCategory>,
+    /// I021/220 Met Information
+    pub met_information: Option<MetInformation>,
+    /// I021/146 Selected Altitude
+    pub selected_altitude: Option<SelectedAltitude>,
+    /// I021/148 Final State Selected Altitude
+    pub final_state_selected_altitude: Option<FinalStateSelectedAltitude>,
+    /// I021/110 Trajectory Intent
+    pub trajectory_intent: Option<TrajectoryIntent>,
+    /// I021/016 Service Management
+    pub service_management: Option<ServiceManagement>,
+    /// I021/008 Aircraft Operational Status
+    pub operational_status: Option<OperationalStatus>,
+    /// I021/271 Surface Capabilities and Characteristics
+    pub surface_capabilities: Option<SurfaceCapabilities>,
+    /// I021/132 Message Amplitude
+    pub message_amplitude: Option<MessageAmplitude>,
+    /// I021/250 Mode S MB Data
+    pub mode_s_mb_data: Option<ModeSMbData>,
+    /// I021/260 ACAS Resolution Advisory Report
+    pub acas_resolution: Option<AcasResolution>,
+    /// I021/400 Receiver ID
+    pub receiver_id: Option<ReceiverId>,
+    /// I021/295 Data Ages
+    pub data_ages: Option<DataAges>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record21 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(
+            &self.field_spec_vector,
+            Cat21Fspec::I021SP as u8,
+            &[43, 44, 45, 46, 47],
+        )?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I021/010
+        if self.is_fspec_bit_set(Cat21Fspec::I021_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/040
+        if self.is_fspec_bit_set(Cat21Fspec::I021_040) {
+            self.target_report_descriptor = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/161
+        if self.is_fspec_bit_set(Cat21Fspec::I021_161) {
+            self.track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/015
+        if self.is_fspec_bit_set(Cat21Fspec::I021_015) {
+            self.service_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/071
+        if self.is_fspec_bit_set(Cat21Fspec::I021_071) {
+            self.time_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/130
+        if self.is_fspec_bit_set(Cat21Fspec::I021_130) {
+            self.position = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/131
+        if self.is_fspec_bit_set(Cat21Fspec::I021_131) {
+            self.high_resolution_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/072
+        if self.is_fspec_bit_set(Cat21Fspec::I021_072) {
+            self.time_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/150
+        if self.is_fspec_bit_set(Cat21Fspec::I021_150) {
+            self.air_speed = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/151
+        if self.is_fspec_bit_set(Cat21Fspec::I021_151) {
+            self.true_airspeed = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/080
+        if self.is_fspec_bit_set(Cat21Fspec::I021_080) {
+            self.target_address = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/073
+        if self.is_fspec_bit_set(Cat21Fspec::I021_073) {
+            self.time_reception_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/074
+        if self.is_fspec_bit_set(Cat21Fspec::I021_074) {
+            self.time_reception_position_high_precision = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/075
+        if self.is_fspec_bit_set(Cat21Fspec::I021_075) {
+            self.time_reception_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/076
+        if self.is_fspec_bit_set(Cat21Fspec::I021_076) {
+            self.time_reception_velocity_high_precision = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/140
+        if self.is_fspec_bit_set(Cat21Fspec::I021_140) {
+            self.geometric_height = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/090
+        if self.is_fspec_bit_set(Cat21Fspec::I021_090) {
+            self.quality_indicators = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/210
+        if self.is_fspec_bit_set(Cat21Fspec::I021_210) {
+            self.mops_version = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/070
+        if self.is_fspec_bit_set(Cat21Fspec::I021_070) {
+            self.mode_3a_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/230
+        if self.is_fspec_bit_set(Cat21Fspec::I021_230) {
+            self.roll_angle = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/145
+        if self.is_fspec_bit_set(Cat21Fspec::I021_145) {
+            self.flight_level = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/152
+        if self.is_fspec_bit_set(Cat21Fspec::I021_152) {
+            self.magnetic_heading = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/200
+        if self.is_fspec_bit_set(Cat21Fspec::I021_200) {
+            self.target_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/155
+        if self.is_fspec_bit_set(Cat21Fspec::I021_155) {
+            self.barometric_vertical_rate = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/157
+        if self.is_fspec_bit_set(Cat21Fspec::I021_157) {
+            self.geometric_vertical_rate = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/160
+        if self.is_fspec_bit_set(Cat21Fspec::I021_160) {
+            self.ground_vector = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/165
+        if self.is_fspec_bit_set(Cat21Fspec::I021_165) {
+            self.track_angle_rate = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/077
+        if self.is_fspec_bit_set(Cat21Fspec::I021_077) {
+            self.time_report_transmission = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/170
+        if self.is_fspec_bit_set(Cat21Fspec::I021_170) {
+            self.target_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/020
+        if self.is_fspec_bit_set(Cat21Fspec::I021_020) {
+            self.emitter_category = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/220
+        if self.is_fspec_bit_set(Cat21Fspec::I021_220) {
+            self.met_information = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/146
+        if self.is_fspec_bit_set(Cat21Fspec::I021_146) {
+            self.selected_altitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/148
+        if self.is_fspec_bit_set(Cat21Fspec::I021_148) {
+            self.final_state_selected_altitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/110
+        if self.is_fspec_bit_set(Cat21Fspec::I021_110) {
+            self.trajectory_intent = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/016
+        if self.is_fspec_bit_set(Cat21Fspec::I021_016) {
+            self.service_management = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/008
+        if self.is_fspec_bit_set(Cat21Fspec::I021_008) {
+            self.operational_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/271
+        if self.is_fspec_bit_set(Cat21Fspec::I021_271) {
+            self.surface_capabilities = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/132
+        if self.is_fspec_bit_set(Cat21Fspec::I021_132) {
+            self.message_amplitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/250
+        if self.is_fspec_bit_set(Cat21Fspec::I021_250) {
+            self.mode_s_mb_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/260
+        if self.is_fspec_bit_set(Cat21Fspec::I021_260) {
+            self.acas_resolution = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/400
+        if self.is_fspec_bit_set(Cat21Fspec::I021_400) {
+            self.receiver_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I021/295
+        if self.is_fspec_bit_set(Cat21Fspec::I021_295) {
+            self.data_ages = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat21Fspec::I021RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat21Fspec::I021SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat21Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I021/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_010);
+            vector.put(&bytes[..]);
+        }
+        // I021/040
+        if let Some(target_report_descriptor) = &self.target_report_descriptor {
+            let bytes = target_report_descriptor.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_040);
+            vector.put(&bytes[..]);
+        }
+        // I021/161
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_161);
+            vector.put(&bytes[..]);
+        }
+        // I021/015
+        if let Some(service_identification) = &self.service_identification {
+            let bytes = service_identification.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_015);
+            vector.put(&bytes[..]);
+        }
+        // I021/071
+        if let Some(time_position) = &self.time_position {
+            let bytes = time_position.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_071);
+            vector.put(&bytes[..]);
+        }
+        // I021/130
+        if let Some(position) = &self.position {
+            let bytes = position.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_130);
+            vector.put(&bytes[..]);
+        }
+        // I021/131
+        if let Some(high_resolution_position) = &self.high_resolution_position {
+            let bytes = high_resolution_position.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_131);
+            vector.put(&bytes[..]);
+        }
+        // I021/072
+        if let Some(time_velocity) = &self.time_velocity {
+            let bytes = time_velocity.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_072);
+            vector.put(&bytes[..]);
+        }
+        // I021/150
+        if let Some(air_speed) = &self.air_speed {
+            let bytes = air_speed.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_150);
+            vector.put(&bytes[..]);
+        }
+        // I021/151
+        if let Some(true_airspeed) = &self.true_airspeed {
+            let bytes = true_airspeed.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_151);
+            vector.put(&bytes[..]);
+        }
+        // I021/080
+        if let Some(target_address) = &self.target_address {
+            let bytes = target_address.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_080);
+            vector.put(&bytes[..]);
+        }
+        // I021/073
+        if let Some(time_reception_position) = &self.time_reception_position {
+            let bytes = time_reception_position.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_073);
+            vector.put(&bytes[..]);
+        }
+        // I021/074
+        if let Some(time_reception_position_high_precision) =
+            &self.time_reception_position_high_precision
+        {
+            let bytes = time_reception_position_high_precision.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_074);
+            vector.put(&bytes[..]);
+        }
+        // I021/075
+        if let Some(time_reception_velocity) = &self.time_reception_velocity {
+            let bytes = time_reception_velocity.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_075);
+            vector.put(&bytes[..]);
+        }
+        // I021/076
+        if let Some(time_reception_velocity_high_precision) =
+            &self.time_reception_velocity_high_precision
+        {
+            let bytes = time_reception_velocity_high_precision.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_076);
+            vector.put(&bytes[..]);
+        }
+        // I021/140
+        if let Some(geometric_height) = &self.geometric_height {
+            let bytes = geometric_height.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_140);
+            vector.put(&bytes[..]);
+        }
+        // I021/090
+        if let Some(quality_indicators) = &self.quality_indicators {
+            let bytes = quality_indicators.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_090);
+            vector.put(&bytes[..]);
+        }
+        // I021/210
+        if let Some(mops_version) = &self.mops_version {
+            let bytes = mops_version.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_210);
+            vector.put(&bytes[..]);
+        }
+        // I021/070
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_070);
+            vector.put(&bytes[..]);
+        }
+        // I021/230
+        if let Some(roll_angle) = &self.roll_angle {
+            let bytes = roll_angle.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_230);
+            vector.put(&bytes[..]);
+        }
+        // I021/145
+        if let Some(flight_level) = &self.flight_level {
+            let bytes = flight_level.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_145);
+            vector.put(&bytes[..]);
+        }
+        // I021/152
+        if let Some(magnetic_heading) = &self.magnetic_heading {
+            let bytes = magnetic_heading.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_152);
+            vector.put(&bytes[..]);
+        }
+        // I021/200
+        if let Some(target_status) = &self.target_status {
+            let bytes = target_status.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_200);
+            vector.put(&bytes[..]);
+        }
+        // I021/155
+        if let Some(barometric_vertical_rate) = &self.barometric_vertical_rate {
+            let bytes = barometric_vertical_rate.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_155);
+            vector.put(&bytes[..]);
+        }
+        // I021/157
+        if let Some(geometric_vertical_rate) = &self.geometric_vertical_rate {
+            let bytes = geometric_vertical_rate.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_157);
+            vector.put(&bytes[..]);
+        }
+        // I021/160
+        if let Some(ground_vector) = &self.ground_vector {
+            let bytes = ground_vector.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_160);
+            vector.put(&bytes[..]);
+        }
+        // I021/165
+        if let Some(track_angle_rate) = &self.track_angle_rate {
+            let bytes = track_angle_rate.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_165);
+            vector.put(&bytes[..]);
+        }
+        // I021/077
+        if let Some(time_report_transmission) = &self.time_report_transmission {
+            let bytes = time_report_transmission.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_077);
+            vector.put(&bytes[..]);
+        }
+        // I021/170
+        if let Some(target_identification) = &self.target_identification {
+            let bytes = target_identification.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_170);
+            vector.put(&bytes[..]);
+        }
+        // I021/020
+        if let Some(emitter_category) = &self.emitter_category {
+            let bytes = emitter_category.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_020);
+            vector.put(&bytes[..]);
+        }
+        // I021/220
+        if let Some(met_information) = &self.met_information {
+            let bytes = met_information.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_220);
+            vector.put(&bytes[..]);
+        }
+        // I021/146
+        if let Some(selected_altitude) = &self.selected_altitude {
+            let bytes = selected_altitude.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_146);
+            vector.put(&bytes[..]);
+        }
+        // I021/148
+        if let Some(final_state_selected_altitude) = &self.final_state_selected_altitude {
+            let bytes = final_state_selected_altitude.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_148);
+            vector.put(&bytes[..]);
+        }
+        // I021/110
+        if let Some(trajectory_intent) = &self.trajectory_intent {
+            let bytes = trajectory_intent.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_110);
+            vector.put(&bytes[..]);
+        }
+        // I021/016
+        if let Some(service_management) = &self.service_management {
+            let bytes = service_management.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_016);
+            vector.put(&bytes[..]);
+        }
+        // I021/008
+        if let Some(operational_status) = &self.operational_status {
+            let bytes = operational_status.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_008);
+            vector.put(&bytes[..]);
+        }
+        // I021/271
+        if let Some(surface_capabilities) = &self.surface_capabilities {
+            let bytes = surface_capabilities.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_271);
+            vector.put(&bytes[..]);
+        }
+        // I021/132
+        if let Some(message_amplitude) = &self.message_amplitude {
+            let bytes = message_amplitude.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_132);
+            vector.put(&bytes[..]);
+        }
+        // I021/250
+        if let Some(mode_s_mb_data) = &self.mode_s_mb_data {
+            let bytes = mode_s_mb_data.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_250);
+            vector.put(&bytes[..]);
+        }
+        // I021/260
+        if let Some(acas_resolution) = &self.acas_resolution {
+            let bytes = acas_resolution.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_260);
+            vector.put(&bytes[..]);
+        }
+        // I021/400
+        if let Some(receiver_id) = &self.receiver_id {
+            let bytes = receiver_id.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_400);
+            vector.put(&bytes[..]);
+        }
+        // I021/295
+        if let Some(data_ages) = &self.data_ages {
+            let bytes = data_ages.encode();
+            self.set_fspec_bit(Cat21Fspec::I021_295);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat21Fspec::I021RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat21Fspec::I021SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat21Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record21 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record21::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record21::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT21 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat21Fspec {
+    I021_010 = 1,
+    I021_040,
+    I021_161,
+    I021_015,
+    I021_071,
+    I021_130,
+    I021_131,
+    I021_072,
+    I021_150,
+    I021_151,
+    I021_080,
+    I021_073,
+    I021_074,
+    I021_075,
+    I021_076,
+    I021_140,
+    I021_090,
+    I021_210,
+    I021_070,
+    I021_230,
+    I021_145,
+    I021_152,
+    I021_200,
+    I021_155,
+    I021_157,
+    I021_160,
+    I021_165,
+    I021_077,
+    I021_170,
+    I021_020,
+    I021_220,
+    I021_146,
+    I021_148,
+    I021_110,
+    I021_016,
+    I021_008,
+    I021_271,
+    I021_132,
+    I021_250,
+    I021_260,
+    I021_400,
+    I021_295,
+    I021RE = 48,
+    I021SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat21::target_report_descriptor_field::AddressType;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create target report descriptor
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_address_type(AddressType::IcaoAddress);
+        target_report_descriptor.set_ground(true);
+
+        // Create high resolution position
+        let mut high_resolution_position = HighResolutionPosition::default();
+        high_resolution_position
+            .set_lat_lon(50.0379, 8.5622)
+            .unwrap();
+
+        // Create target address and identification
+        let mut target_address = AircraftAddress::default();
+        target_address.set_address(0x3c660c).unwrap();
+        let mut target_identification = AircraftIdentification::default();
+        target_identification.set_identification("DLH123").unwrap();
+
+        // Create data ages
+        let mut data_ages = DataAges::default();
+        data_ages
+            .set_age(
+                crate::uap::cat21::data_ages_field::DataAge::FlightLevel,
+                0.5,
+            )
+            .unwrap();
+
+        // Create record
+        let mut record = Record21 {
+            data_source_id: Some(data_source),
+            target_report_descriptor: Some(target_report_descriptor),
+            high_resolution_position: Some(high_resolution_position),
+            target_address: Some(target_address),
+            target_identification: Some(target_identification),
+            data_ages: Some(data_ages),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..6], &[0xc3, 0x11, 0x01, 0x01, 0x81, 0x02]);
+
+        // New record
+        let mut object = Record21::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the seventh FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record21 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(
+            &bytes[..],
+            &[0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x02, 0x01]
+        );
+
+        // Empty record has one FSPEC
+        let bytes = Record21::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
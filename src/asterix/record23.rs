@@ -0,0 +1,316 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat23::ground_station_status_field::GroundStationStatus;
+use crate::uap::cat23::operational_range_field::OperationalRange;
+use crate::uap::cat23::report_type_field::ReportType;
+use crate::uap::cat23::service_configuration_field::ServiceConfiguration;
+use crate::uap::cat23::service_statistics_field::ServiceStatistics;
+use crate::uap::cat23::service_status_field::ServiceStatus;
+use crate::uap::cat23::service_type_field::ServiceType;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT23 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record23 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I023/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I023/000 Report Type
+    pub report_type: Option<ReportType>,
+    /// I023/015 Service Type and Identification
+    pub service_type: Option<ServiceType>,
+    /// I023/070 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I023/100 Ground Station Status
+    pub ground_station_status: Option<GroundStationStatus>,
+    /// I023/101 Service Configuration
+    pub service_configuration: Option<ServiceConfiguration>,
+    /// I023/200 Operational Range
+    pub operational_range: Option<OperationalRange>,
+    /// I023/110 Service Status
+    pub service_status: Option<ServiceStatus>,
+    /// I023/120 Service Statistics
+    pub service_statistics: Option<ServiceStatistics>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record23 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(
+            &self.field_spec_vector,
+            Cat23Fspec::I023SP as u8,
+            &[10, 11, 12],
+        )?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I023/010
+        if self.is_fspec_bit_set(Cat23Fspec::I023_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/000
+        if self.is_fspec_bit_set(Cat23Fspec::I023_000) {
+            self.report_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/015
+        if self.is_fspec_bit_set(Cat23Fspec::I023_015) {
+            self.service_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/070
+        if self.is_fspec_bit_set(Cat23Fspec::I023_070) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/100
+        if self.is_fspec_bit_set(Cat23Fspec::I023_100) {
+            self.ground_station_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/101
+        if self.is_fspec_bit_set(Cat23Fspec::I023_101) {
+            self.service_configuration = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/200
+        if self.is_fspec_bit_set(Cat23Fspec::I023_200) {
+            self.operational_range = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/110
+        if self.is_fspec_bit_set(Cat23Fspec::I023_110) {
+            self.service_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I023/120
+        if self.is_fspec_bit_set(Cat23Fspec::I023_120) {
+            self.service_statistics = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat23Fspec::I023RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat23Fspec::I023SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat23Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I023/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_010);
+            vector.put(&bytes[..]);
+        }
+        // I023/000
+        if let Some(report_type) = &self.report_type {
+            let bytes = report_type.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_000);
+            vector.put(&bytes[..]);
+        }
+        // I023/015
+        if let Some(service_type) = &self.service_type {
+            let bytes = service_type.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_015);
+            vector.put(&bytes[..]);
+        }
+        // I023/070
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_070);
+            vector.put(&bytes[..]);
+        }
+        // I023/100
+        if let Some(ground_station_status) = &self.ground_station_status {
+            let bytes = ground_station_status.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_100);
+            vector.put(&bytes[..]);
+        }
+        // I023/101
+        if let Some(service_configuration) = &self.service_configuration {
+            let bytes = service_configuration.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_101);
+            vector.put(&bytes[..]);
+        }
+        // I023/200
+        if let Some(operational_range) = &self.operational_range {
+            let bytes = operational_range.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_200);
+            vector.put(&bytes[..]);
+        }
+        // I023/110
+        if let Some(service_status) = &self.service_status {
+            let bytes = service_status.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_110);
+            vector.put(&bytes[..]);
+        }
+        // I023/120
+        if let Some(service_statistics) = &self.service_statistics {
+            let bytes = service_statistics.encode();
+            self.set_fspec_bit(Cat23Fspec::I023_120);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat23Fspec::I023RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat23Fspec::I023SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat23Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record23 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record23::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record23::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT23 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat23Fspec {
+    I023_010 = 1,
+    I023_000,
+    I023_015,
+    I023_070,
+    I023_100,
+    I023_101,
+    I023_200,
+    I023_110,
+    I023_120,
+    I023RE = 13,
+    I023SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat23::report_type_field::GroundStationReport;
+    use crate::uap::cat23::service_status_field::ServiceState;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create report type and time of day
+        let mut report_type = ReportType::default();
+        report_type.set_report_type(GroundStationReport::ServiceStatus);
+        let mut time_of_day = TimeOfDay::default();
+        time_of_day.set_time(12345.5);
+
+        // Create service status
+        let mut service_status = ServiceStatus::default();
+        service_status.set_status(ServiceState::Normal);
+
+        // Create record
+        let mut record = Record23 {
+            data_source_id: Some(data_source),
+            report_type: Some(report_type),
+            time_of_day: Some(time_of_day),
+            service_status: Some(service_status),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xd1, 0x80]);
+
+        // New record
+        let mut object = Record23::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record23 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record23::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
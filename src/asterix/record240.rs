@@ -0,0 +1,360 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat240::message_type_field::VideoMessageType;
+use crate::uap::cat240::record_header_field::VideoRecordHeader;
+use crate::uap::cat240::video_block_field::{VideoBlockHigh, VideoBlockLow, VideoBlockMedium};
+use crate::uap::cat240::video_counters_field::VideoCounters;
+use crate::uap::cat240::video_header_field::VideoHeader;
+use crate::uap::cat240::video_resolution_field::VideoResolution;
+use crate::uap::cat240::video_summary_field::VideoSummary;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT240 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record240 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I240/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I240/000 Message Type
+    pub message_type: Option<VideoMessageType>,
+    /// I240/020 Video Record Header
+    pub record_header: Option<VideoRecordHeader>,
+    /// I240/030 Video Summary
+    pub video_summary: Option<VideoSummary>,
+    /// I240/040 Video Header Nano
+    pub video_header_nano: Option<VideoHeader>,
+    /// I240/041 Video Header Femto
+    pub video_header_femto: Option<VideoHeader>,
+    /// I240/048 Video Cells Resolution & Data Compression Indicator
+    pub video_resolution: Option<VideoResolution>,
+    /// I240/049 Video Octets & Video Cells Counters
+    pub video_counters: Option<VideoCounters>,
+    /// I240/050 Video Block Low Data Volume
+    pub video_block_low: Option<VideoBlockLow>,
+    /// I240/051 Video Block Medium Data Volume
+    pub video_block_medium: Option<VideoBlockMedium>,
+    /// I240/052 Video Block High Data Volume
+    pub video_block_high: Option<VideoBlockHigh>,
+    /// I240/140 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record240 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat240Fspec::I240SP as u8, &[])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I240/010
+        if self.is_fspec_bit_set(Cat240Fspec::I240_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/000
+        if self.is_fspec_bit_set(Cat240Fspec::I240_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/020
+        if self.is_fspec_bit_set(Cat240Fspec::I240_020) {
+            self.record_header = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/030
+        if self.is_fspec_bit_set(Cat240Fspec::I240_030) {
+            self.video_summary = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/040
+        if self.is_fspec_bit_set(Cat240Fspec::I240_040) {
+            self.video_header_nano = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/041
+        if self.is_fspec_bit_set(Cat240Fspec::I240_041) {
+            self.video_header_femto = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/048
+        if self.is_fspec_bit_set(Cat240Fspec::I240_048) {
+            self.video_resolution = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/049
+        if self.is_fspec_bit_set(Cat240Fspec::I240_049) {
+            self.video_counters = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/050
+        if self.is_fspec_bit_set(Cat240Fspec::I240_050) {
+            self.video_block_low = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/051
+        if self.is_fspec_bit_set(Cat240Fspec::I240_051) {
+            self.video_block_medium = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/052
+        if self.is_fspec_bit_set(Cat240Fspec::I240_052) {
+            self.video_block_high = Some(decode_item(array, &mut offset)?);
+        }
+        // I240/140
+        if self.is_fspec_bit_set(Cat240Fspec::I240_140) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat240Fspec::I240RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat240Fspec::I240SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat240Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I240/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_010);
+            vector.put(&bytes[..]);
+        }
+        // I240/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_000);
+            vector.put(&bytes[..]);
+        }
+        // I240/020
+        if let Some(record_header) = &self.record_header {
+            let bytes = record_header.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_020);
+            vector.put(&bytes[..]);
+        }
+        // I240/030
+        if let Some(video_summary) = &self.video_summary {
+            let bytes = video_summary.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_030);
+            vector.put(&bytes[..]);
+        }
+        // I240/040
+        if let Some(video_header_nano) = &self.video_header_nano {
+            let bytes = video_header_nano.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_040);
+            vector.put(&bytes[..]);
+        }
+        // I240/041
+        if let Some(video_header_femto) = &self.video_header_femto {
+            let bytes = video_header_femto.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_041);
+            vector.put(&bytes[..]);
+        }
+        // I240/048
+        if let Some(video_resolution) = &self.video_resolution {
+            let bytes = video_resolution.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_048);
+            vector.put(&bytes[..]);
+        }
+        // I240/049
+        if let Some(video_counters) = &self.video_counters {
+            let bytes = video_counters.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_049);
+            vector.put(&bytes[..]);
+        }
+        // I240/050
+        if let Some(video_block_low) = &self.video_block_low {
+            let bytes = video_block_low.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_050);
+            vector.put(&bytes[..]);
+        }
+        // I240/051
+        if let Some(video_block_medium) = &self.video_block_medium {
+            let bytes = video_block_medium.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_051);
+            vector.put(&bytes[..]);
+        }
+        // I240/052
+        if let Some(video_block_high) = &self.video_block_high {
+            let bytes = video_block_high.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_052);
+            vector.put(&bytes[..]);
+        }
+        // I240/140
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat240Fspec::I240_140);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat240Fspec::I240RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat240Fspec::I240SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat240Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record240 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record240::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record240::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT240 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat240Fspec {
+    I240_010 = 1,
+    I240_000,
+    I240_020,
+    I240_030,
+    I240_040,
+    I240_041,
+    I240_048,
+    I240_049,
+    I240_050,
+    I240_051,
+    I240_052,
+    I240_140,
+    I240RE,
+    I240SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat240::message_type_field::VideoMessage;
+    use crate::uap::cat240::video_resolution_field::CellResolution;
+
+    #[test]
+    fn check_record() {
+        // Create data source and message type
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+        let mut message_type = VideoMessageType::default();
+        message_type.set_message_type(VideoMessage::Video);
+
+        // Create video header and resolution
+        let mut video_header = VideoHeader::default();
+        video_header.set_start_azimuth(45.0).unwrap();
+        video_header.set_end_azimuth(46.0).unwrap();
+        video_header.set_cell_duration(416);
+        let mut video_resolution = VideoResolution::default();
+        video_resolution.set_resolution(CellResolution::High);
+
+        // Create counters and video block
+        let mut video_counters = VideoCounters::default();
+        video_counters.set_octets(3);
+        video_counters.set_cells(3).unwrap();
+        let mut video_block = VideoBlockLow::default();
+        video_block.set_data(&[0x00, 0x80, 0xff]).unwrap();
+
+        // Create record
+        let mut record = Record240 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            video_header_nano: Some(video_header),
+            video_resolution: Some(video_resolution),
+            video_counters: Some(video_counters),
+            video_block_low: Some(video_block),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xcb, 0xc0]);
+
+        // New record
+        let mut object = Record240::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record240 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record240::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
@@ -0,0 +1,332 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat23::service_statistics_field::ServiceStatistics;
+use crate::uap::cat25::component_status_field::ComponentStatus;
+use crate::uap::cat25::error_codes_field::ErrorCodes;
+use crate::uap::cat25::message_identification_field::MessageIdentification;
+use crate::uap::cat25::report_type_field::ReportType;
+use crate::uap::cat25::service_designator_field::ServiceDesignator;
+use crate::uap::cat25::system_status_field::SystemStatus;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::service_identification_field::ServiceIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT25 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record25 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I025/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I025/000 Report Type
+    pub report_type: Option<ReportType>,
+    /// I025/200 Message Identification
+    pub message_identification: Option<MessageIdentification>,
+    /// I025/015 Service Identification
+    pub service_identification: Option<ServiceIdentification>,
+    /// I025/020 Service Designator
+    pub service_designator: Option<ServiceDesignator>,
+    /// I025/070 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I025/100 System and Service Status
+    pub system_status: Option<SystemStatus>,
+    /// I025/105 System and Service Error Codes
+    pub error_codes: Option<ErrorCodes>,
+    /// I025/120 Component Status
+    pub component_status: Option<ComponentStatus>,
+    /// I025/140 Service Statistics
+    pub service_statistics: Option<ServiceStatistics>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record25 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat25Fspec::I025SP as u8, &[11, 12])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I025/010
+        if self.is_fspec_bit_set(Cat25Fspec::I025_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/000
+        if self.is_fspec_bit_set(Cat25Fspec::I025_000) {
+            self.report_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/200
+        if self.is_fspec_bit_set(Cat25Fspec::I025_200) {
+            self.message_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/015
+        if self.is_fspec_bit_set(Cat25Fspec::I025_015) {
+            self.service_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/020
+        if self.is_fspec_bit_set(Cat25Fspec::I025_020) {
+            self.service_designator = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/070
+        if self.is_fspec_bit_set(Cat25Fspec::I025_070) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/100
+        if self.is_fspec_bit_set(Cat25Fspec::I025_100) {
+            self.system_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/105
+        if self.is_fspec_bit_set(Cat25Fspec::I025_105) {
+            self.error_codes = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/120
+        if self.is_fspec_bit_set(Cat25Fspec::I025_120) {
+            self.component_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I025/140
+        if self.is_fspec_bit_set(Cat25Fspec::I025_140) {
+            self.service_statistics = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat25Fspec::I025RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat25Fspec::I025SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat25Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I025/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_010);
+            vector.put(&bytes[..]);
+        }
+        // I025/000
+        if let Some(report_type) = &self.report_type {
+            let bytes = report_type.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_000);
+            vector.put(&bytes[..]);
+        }
+        // I025/200
+        if let Some(message_identification) = &self.message_identification {
+            let bytes = message_identification.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_200);
+            vector.put(&bytes[..]);
+        }
+        // I025/015
+        if let Some(service_identification) = &self.service_identification {
+            let bytes = service_identification.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_015);
+            vector.put(&bytes[..]);
+        }
+        // I025/020
+        if let Some(service_designator) = &self.service_designator {
+            let bytes = service_designator.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_020);
+            vector.put(&bytes[..]);
+        }
+        // I025/070
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_070);
+            vector.put(&bytes[..]);
+        }
+        // I025/100
+        if let Some(system_status) = &self.system_status {
+            let bytes = system_status.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_100);
+            vector.put(&bytes[..]);
+        }
+        // I025/105
+        if let Some(error_codes) = &self.error_codes {
+            let bytes = error_codes.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_105);
+            vector.put(&bytes[..]);
+        }
+        // I025/120
+        if let Some(component_status) = &self.component_status {
+            let bytes = component_status.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_120);
+            vector.put(&bytes[..]);
+        }
+        // I025/140
+        if let Some(service_statistics) = &self.service_statistics {
+            let bytes = service_statistics.encode();
+            self.set_fspec_bit(Cat25Fspec::I025_140);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat25Fspec::I025RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat25Fspec::I025SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat25Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record25 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record25::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record25::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT25 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat25Fspec {
+    I025_010 = 1,
+    I025_000,
+    I025_200,
+    I025_015,
+    I025_020,
+    I025_070,
+    I025_100,
+    I025_105,
+    I025_120,
+    I025_140,
+    I025RE = 13,
+    I025SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat25::component_status_field::{Component, ComponentState};
+    use crate::uap::cat25::report_type_field::SystemReport;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create report type and time of day
+        let mut report_type = ReportType::default();
+        report_type.set_report_type(SystemReport::ComponentStatus);
+        let mut time_of_day = TimeOfDay::default();
+        time_of_day.set_time(12345.5);
+
+        // Create component status
+        let mut component_status = ComponentStatus::default();
+        component_status
+            .insert_component(Component {
+                identification: 7,
+                error_code: 0,
+                state: ComponentState::Running,
+            })
+            .unwrap();
+
+        // Create record
+        let mut record = Record25 {
+            data_source_id: Some(data_source),
+            report_type: Some(report_type),
+            time_of_day: Some(time_of_day),
+            component_status: Some(component_status),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xc5, 0x40]);
+
+        // New record
+        let mut object = Record25::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record25 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record25::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
@@ -1,16 +1,21 @@
-use bytes::{BufMut, BytesMut};
-use std::io::Read;
-use bytes::Bytes;
-use deku::DekuContainerWrite;
-use crate::asterix::header_field::Header;
+use bytes::{BufMut, Bytes, BytesMut};
 
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
 use crate::category::CatError;
 
 // Search for crates in subdirectory uap
 use crate::uap::antenna_rotation_field::AntennaRotation;
+use crate::uap::collimation_error_field::CollimationError;
+use crate::uap::data_filter_field::DataFilter;
+use crate::uap::data_item::DataItem;
 use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
 use crate::uap::field_spec::FieldSpec;
 use crate::uap::generic_polar_window_field::GenericPolarWindow;
+use crate::uap::message_count_field::MessageCount;
 use crate::uap::message_type_field::MessageType;
 use crate::uap::position_source_field::PositionSource;
 use crate::uap::sector_number_field::SectorNumber;
@@ -37,148 +42,232 @@ pub struct Record34 {
     pub system_configuration_status: Option<SystemConfigurationStatus>,
     /// I034/060
     pub system_processing_mode: Option<SystemProcessingMode>,
+    /// I034/070
+    pub message_count: Option<MessageCount>,
     /// I034/100
     pub generic_polar_window: Option<GenericPolarWindow>,
+    /// I034/110
+    pub data_filter: Option<DataFilter>,
     /// I034/120
     pub position_source: Option<PositionSource>,
+    /// I034/090
+    pub collimation_error: Option<CollimationError>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
 }
 
 impl Record34 {
     /*
      * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
      */
     pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
-        // Data record has at least two FSPEC
-        if bytes.len() > 2 {
-            // Order is important, because some fields have variable length
-            self.field_spec_vector = decode_field_spec(bytes);
-        }
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
 
-        // How many bytes are in FSPEC
-        let len = self.field_spec_vector.len();
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat34Fspec::I034SP as u8, &[])?;
 
-        if bytes.len() > len {
-            let record_bytes = bytes.slice(len..);
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
 
+        // I034/010
+        if self.is_fspec_bit_set(Cat34Fspec::I034_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/000
+        if self.is_fspec_bit_set(Cat34Fspec::I034_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/030
+        if self.is_fspec_bit_set(Cat34Fspec::I034_030) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/020
+        if self.is_fspec_bit_set(Cat34Fspec::I034_020) {
+            self.sector_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/041
+        if self.is_fspec_bit_set(Cat34Fspec::I034_041) {
+            self.antenna_rotation = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/050
+        if self.is_fspec_bit_set(Cat34Fspec::I034_050) {
+            self.system_configuration_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/060
+        if self.is_fspec_bit_set(Cat34Fspec::I034_060) {
+            self.system_processing_mode = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/070
+        if self.is_fspec_bit_set(Cat34Fspec::I034_070) {
+            self.message_count = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/100
+        if self.is_fspec_bit_set(Cat34Fspec::I034_100) {
+            self.generic_polar_window = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/110
+        if self.is_fspec_bit_set(Cat34Fspec::I034_110) {
+            self.data_filter = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/120
+        if self.is_fspec_bit_set(Cat34Fspec::I034_120) {
+            self.position_source = Some(decode_item(array, &mut offset)?);
+        }
+        // I034/090
+        if self.is_fspec_bit_set(Cat34Fspec::I034_090) {
+            self.collimation_error = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat34Fspec::I034RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
         }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat34Fspec::I034SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
 
-        let length = calculate_record_length();
-        Ok(length)
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat34Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
     }
 
     /*
      * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
      */
     pub fn encode(&mut self) -> Result<Bytes, CatError> {
         // New fspec field
-        let mut field_spec1 = FieldSpec::default();
-        let mut field_spec2 = FieldSpec::default();
+        self.field_spec_vector.clear();
 
-        let mut bytes_length = 0;
-        let mut vector:Vec<BytesMut> = Vec::new();
+        let mut vector = BytesMut::new();
 
-        // First FSPEC
-        if self.data_source_id.is_some() {
-            field_spec1.set_fspec_bit(Cat34Fspec::I034_010 as u8);
-            let bytes = self.data_source_id.unwrap().to_bytes().unwrap();
+        // I034/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_010);
+            vector.put(&bytes[..]);
         }
-        if self.message_type.is_some() {
-            field_spec1.set_fspec_bit(Cat34Fspec::I034_000 as u8);
-            let bytes = self.message_type.unwrap().to_bytes();
+        // I034/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_000);
+            vector.put(&bytes[..]);
         }
-        if self.time_of_day.is_some() {
-            field_spec1.set_fspec_bit(Cat34Fspec::I034_030 as u8);
-            let bytes = self.time_of_day.unwrap().to_bytes();
+        // I034/030
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_030);
+            vector.put(&bytes[..]);
         }
-        if self.sector_number.is_some() {
-            field_spec1.set_fspec_bit(Cat34Fspec::I034_020 as u8);
-            let bytes = self.sector_number.unwrap().to_bytes();
+        // I034/020
+        if let Some(sector_number) = &self.sector_number {
+            let bytes = sector_number.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_020);
+            vector.put(&bytes[..]);
         }
-        if self.antenna_rotation.is_some() {
-            field_spec1.set_fspec_bit(Cat34Fspec::I034_041 as u8);
-            let vector = self.antenna_rotation.unwrap().to_bytes().unwrap();
-            let array: &[u8] = &vector;
+        // I034/041
+        if let Some(antenna_rotation) = &self.antenna_rotation {
+            let bytes = antenna_rotation.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_041);
+            vector.put(&bytes[..]);
         }
-        if self.system_configuration_status.is_some() {
-            field_spec1.set_fspec_bit(Cat34Fspec::I034_050 as u8);
-            let bytes = self.system_configuration_status.unwrap().to_bytes();
+        // I034/050
+        if let Some(system_configuration_status) = &self.system_configuration_status {
+            let bytes = system_configuration_status.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_050);
+            vector.put(&bytes[..]);
         }
-        if self.system_processing_mode.is_some() {
-            field_spec1.set_fspec_bit(Cat34Fspec::I034_060 as u8);
-            let bytes = self.system_processing_mode.unwrap().to_bytes();
+        // I034/060
+        if let Some(system_processing_mode) = &self.system_processing_mode {
+            let bytes = system_processing_mode.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_060);
+            vector.put(&bytes[..]);
         }
-        // Second FSPEC
-        if self.generic_polar_window.is_some() {
-            field_spec1.set_fspec_bit(FSPEC_FX);
-            field_spec2.set_fspec_bit(Cat34Fspec::I034_100 as u8 - FSPEC_FX);
-            let bytes = self.generic_polar_window.unwrap().to_bytes();
+        // I034/070
+        if let Some(message_count) = &self.message_count {
+            let bytes = message_count.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_070);
+            vector.put(&bytes[..]);
         }
-        if self.position_source.is_some() {
-            field_spec1.set_fspec_bit(FSPEC_FX);
-            field_spec2.set_fspec_bit(Cat34Fspec::I034_120 as u8 - FSPEC_FX);
-            let bytes = self.position_source.unwrap().to_bytes();
+        // I034/100
+        if let Some(generic_polar_window) = &self.generic_polar_window {
+            let bytes = generic_polar_window.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_100);
+            vector.put(&bytes[..]);
         }
-        // CAT34 has up to two FSPEC
-        self.field_spec_vector.push(field_spec1);
-        self.field_spec_vector.push(field_spec2);
-
-        let fspec1_bytes = field_spec1.to_bytes();
-        let fspec2_bytes = field_spec2.to_bytes();
-
-        let mut sum_bytes = BytesMut::with_capacity(self.field_spec_vector.len() + bytes_length);
-        sum_bytes.put(&fspec1_bytes[..]);
-        sum_bytes.put(&fspec2_bytes[..]);
-
-        for bytes in vector {
-            sum_bytes.put(bytes);
+        // I034/110
+        if let Some(data_filter) = &self.data_filter {
+            let bytes = data_filter.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_110);
+            vector.put(&bytes[..]);
+        }
+        // I034/120
+        if let Some(position_source) = &self.position_source {
+            let bytes = position_source.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_120);
+            vector.put(&bytes[..]);
+        }
+        // I034/090
+        if let Some(collimation_error) = &self.collimation_error {
+            let bytes = collimation_error.encode();
+            self.set_fspec_bit(Cat34Fspec::I034_090);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat34Fspec::I034RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat34Fspec::I034SP);
+            vector.put(&bytes[..]);
         }
 
-        Ok(sum_bytes.into())
+        Ok(encode_record(&mut self.field_spec_vector, vector))
     }
-}
 
-/*
- * Calculate record byte length.
- * Checks for valid optional attributes.
- */
-fn calculate_record_length() -> usize {
-    1u32 as usize
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat34Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
 }
 
-/*
- * Decode all field spec.
- */
-fn decode_field_spec(bytes: &Bytes) -> Vec<FieldSpec> {
-    let array: &[u8] = bytes;
-    let mut vec = Vec::new();
-
-    loop {
-        let begin_index = vec.len();
-        let end_index = begin_index + FieldSpec::MESSAGE_LENGTH;
-
-        let field_spec_array = FieldSpec::array_of_byte_message(&array[begin_index..end_index]);
-
-        // New message
-        let mut field_spec = FieldSpec::default();
+impl DataRecord for Record34 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record34::decode(self, bytes)
+    }
 
-        // Convert byte stream to struct
-        field_spec.from_bytes(&field_spec_array);
-        let fx = field_spec.get_fspec_bit(FSPEC_FX);
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record34::encode(self)
+    }
 
-        // Store field in vector (moves it into vector)
-        vec.push(field_spec);
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
 
-        // Check field extension
-        if !fx {
-            break;
-        }
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
     }
-    vec
 }
 
 /// CAT34 Standard User Application Profile (UAP)
 /// FSPEC Field Reference Number (FRN)
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum Cat34Fspec {
     I034_010 = 1,
     I034_000,
@@ -196,13 +285,16 @@ pub enum Cat34Fspec {
     I034SP,
 }
 
-/// FSPEC FX Field Reference Number (FRN)
-pub const FSPEC_FX: u8 = 8;
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::uap::data_filter_field::DataFilterEnum;
+    use crate::uap::message_count_field::MessageCountType;
     use crate::uap::message_type_field::MessageTypeEnum;
+    use crate::uap::system_configuration_field::{
+        ChannelSelection, Subfield as ConfigurationSubfield,
+    };
+    use crate::uap::system_processing_field::Subfield as ProcessingSubfield;
 
     #[test]
     fn check_record() {
@@ -228,7 +320,7 @@ mod tests {
         sector.set_sector(0.0);
 
         // Create record
-        let record = Record34 {
+        let mut record = Record34 {
             field_spec_vector: vec![field_spec],
             message_type: Some(message_type),
             data_source_id: Some(data_source),
@@ -237,18 +329,220 @@ mod tests {
             antenna_rotation: None,
             system_configuration_status: None,
             system_processing_mode: None,
+            message_count: None,
             generic_polar_window: None,
+            data_filter: None,
             position_source: None,
+            collimation_error: None,
+            reserved_expansion: None,
+            special_purpose: None,
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        // New record
+        let mut object = Record34::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+        assert_eq!(record.field_spec_vector.first().unwrap().get_fspec(), 0xf0);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only I034/120 is in the second FSPEC
+        let mut position_source = PositionSource::default();
+        position_source.set_height(555);
+
+        let mut record = Record34 {
+            position_source: Some(position_source),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(bytes.len(), 2 + PositionSource::MESSAGE_LENGTH);
+        assert_eq!(bytes[0], 0x01);
+        assert_eq!(bytes[1], 0x10);
+
+        // Empty record has one FSPEC
+        let bytes = Record34::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+
+    #[test]
+    fn check_record_antenna_rotation() {
+        // Create antenna rotation period
+        let mut antenna_rotation = AntennaRotation::default();
+        antenna_rotation.set_period(2.0);
+
+        let mut record = Record34 {
+            antenna_rotation: Some(antenna_rotation),
+            ..Default::default()
         };
 
-        // Create default record
-        let _record2 = Record34::default();
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x08, 0x01, 0x00]);
+
+        // New record
+        let mut object = Record34::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(object.antenna_rotation.unwrap().get_period(), 2.0);
+    }
 
-        let _field = record.field_spec_vector.get(0).unwrap();
+    #[test]
+    fn check_record_message_count() {
+        // Create message count
+        let mut message_count = MessageCount::default();
+        message_count
+            .insert_count(MessageCountType::SingleRollCall, 42)
+            .unwrap();
+        message_count
+            .insert_count(MessageCountType::FilterWeather, 7)
+            .unwrap();
+
+        let mut record = Record34 {
+            message_count: Some(message_count),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x80, 0x02, 0x28, 0x2a, 0x40, 0x07]);
+
+        // New record
+        let mut object = Record34::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_filter_collimation() {
+        // Create data filter
+        let mut data_filter = DataFilter::default();
+        data_filter.set_data_filter(DataFilterEnum::JammingStrobe);
+
+        // Create collimation error
+        let mut collimation_error = CollimationError::default();
+        collimation_error.set_range_error(0.25).unwrap();
+        collimation_error.set_azimuth_error(-0.5).unwrap();
+
+        let mut record = Record34 {
+            data_filter: Some(data_filter),
+            collimation_error: Some(collimation_error),
+            ..Default::default()
+        };
 
         // Convert struct to byte stream
-        //let array = record.encode(record);
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x28, 0x02, 0x20, 0xe9]);
+
+        // New record
+        let mut object = Record34::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+
+        // Undefined data filter type
+        let array: &'static [u8] = &[0x01, 0x20, 0x0a];
+
+        let result = Record34::default().decode(&Bytes::from(array));
+
+        assert_eq!(result, Err(CatError::ValueInvalid));
+    }
+
+    #[test]
+    fn check_record_explicit() {
+        // North Marker with Special Purpose Field
+        let array: &'static [u8] = &[0xc1, 0x02, 0x7b, 0x2a, 0x01, 0x03, 0xca, 0xfe];
+        let bytes = Bytes::from(array);
+
+        let mut record = Record34::default();
+        let length = record.decode(&bytes).unwrap();
 
-        assert_eq!(true, true);
+        assert_eq!(length, array.len());
+        assert_eq!(record.reserved_expansion, None);
+        assert_eq!(
+            record.special_purpose.as_ref().unwrap().get_data(),
+            &[0xca, 0xfe]
+        );
+        assert_eq!(record.encode().unwrap(), bytes);
+
+        // Reserved Expansion Field is longer than the record
+        let array: &'static [u8] = &[0x01, 0x04, 0x05, 0x00];
+
+        let result = Record34::default().decode(&Bytes::from(array));
+
+        assert_eq!(result, Err(CatError::SizeInvalid(4)));
+
+        // Length octet of the Reserved Expansion Field is zero
+        let array: &'static [u8] = &[0x01, 0x04, 0x00];
+
+        let result = Record34::default().decode(&Bytes::from(array));
+
+        assert_eq!(result, Err(CatError::ValueInvalid));
+    }
+
+    #[test]
+    fn check_record_polar_window() {
+        // Geographical Filtering with polar window
+        let array: &'static [u8] = &[
+            0x41, 0x40, 0x03, 0x00, 0x80, 0x7b, 0x80, 0x20, 0x00, 0xc0, 0x00,
+        ];
+        let bytes = Bytes::from(array);
+
+        let mut record = Record34::default();
+        let length = record.decode(&bytes).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(record.generic_polar_window.unwrap().get_rho(), (0.5, 123.5));
+        assert_eq!(
+            record.generic_polar_window.unwrap().get_theta(),
+            (45.0, 270.0)
+        );
+        assert_eq!(record.encode().unwrap(), bytes);
+    }
+
+    #[test]
+    fn check_record_compound() {
+        // I034/050 with COM and SSR, I034/060 with COM
+        let array: &'static [u8] = &[
+            0xf6, 0x19, 0x0e, 0x02, 0x3a, 0x69, 0x2b, 0x40, 0x88, 0x40, 0x40, 0x80, 0x00,
+        ];
+        let bytes = Bytes::from(array);
+
+        let mut record = Record34::default();
+        let length = record.decode(&bytes).unwrap();
+
+        assert_eq!(length, array.len());
+        let system_configuration = record.system_configuration_status.unwrap();
+        assert_eq!(
+            system_configuration.get_ssr_channel(),
+            ChannelSelection::ChannelB
+        );
+        assert!(!system_configuration.get_configuration_option(ConfigurationSubfield::Mds));
+        let system_processing = record.system_processing_mode.unwrap();
+        assert!(system_processing.get_processing_option(ProcessingSubfield::Com));
+        assert_eq!(system_processing.get_com_redrdp(), 0);
+        assert_eq!(record.encode().unwrap(), bytes);
     }
 }
@@ -0,0 +1,606 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::acas_resolution_field::AcasResolution;
+use crate::uap::aircraft_address_field::AircraftAddress;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::cat48::cartesian_position_field::CartesianPosition;
+use crate::uap::cat48::code_confidence_field::{CodeConfidence, Mode1CodeConfidence};
+use crate::uap::cat48::communications_capability_field::CommunicationsCapability;
+use crate::uap::cat48::height_3d_field::Height3D;
+use crate::uap::cat48::mode_1_code_field::Mode1Code;
+use crate::uap::cat48::mode_c_code_field::ModeCCode;
+use crate::uap::cat48::plot_characteristics_field::PlotCharacteristics;
+use crate::uap::cat48::polar_velocity_field::PolarVelocity;
+use crate::uap::cat48::radial_doppler_speed_field::RadialDopplerSpeed;
+use crate::uap::cat48::target_report_descriptor_field::TargetReportDescriptor;
+use crate::uap::cat48::track_quality_field::TrackQuality;
+use crate::uap::cat48::track_status_field::TrackStatus;
+use crate::uap::cat48::warning_error_field::WarningError;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::flight_level_field::FlightLevel;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::mode_s_mb_data_field::ModeSMbData;
+use crate::uap::polar_position_field::PolarPosition;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+
+/// Record of CAT48 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record48 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I048/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I048/140 Time of Day
+    pub time_of_day: Option<TimeOfDay>,
+    /// I048/020 Target Report Descriptor
+    pub target_report_descriptor: Option<TargetReportDescriptor>,
+    /// I048/040 Measured Position in Polar Co-ordinates
+    pub measured_position: Option<PolarPosition>,
+    /// I048/070 Mode-3/A Code in Octal Representation
+    pub mode_3a_code: Option<Mode3ACode>,
+    /// I048/090 Flight Level in Binary Representation
+    pub flight_level: Option<FlightLevel>,
+    /// I048/130 Radar Plot Characteristics
+    pub plot_characteristics: Option<PlotCharacteristics>,
+    /// I048/220 Aircraft Address
+    pub aircraft_address: Option<AircraftAddress>,
+    /// I048/240 Aircraft Identification
+    pub aircraft_identification: Option<AircraftIdentification>,
+    /// I048/250 Mode S MB Data
+    pub mode_s_mb_data: Option<ModeSMbData>,
+    /// I048/161 Track Number
+    pub track_number: Option<TrackNumber>,
+    /// I048/042 Calculated Position in Cartesian Co-ordinates
+    pub calculated_position: Option<CartesianPosition>,
+    /// I048/200 Calculated Track Velocity in Polar Co-ordinates
+    pub track_velocity: Option<PolarVelocity>,
+    /// I048/170 Track Status
+    pub track_status: Option<TrackStatus>,
+    /// I048/210 Track Quality
+    pub track_quality: Option<TrackQuality>,
+    /// I048/030 Warning/Error Conditions and Target Classification
+    pub warning_error: Option<WarningError>,
+    /// I048/080 Mode-3/A Code Confidence Indicator
+    pub mode_3a_confidence: Option<CodeConfidence>,
+    /// I048/100 Mode-C Code and Code Confidence Indicator
+    pub mode_c_code: Option<ModeCCode>,
+    /// I048/110 Height Measured by a 3D Radar
+    pub height_3d: Option<Height3D>,
+    /// I048/120 Radial Doppler Speed
+    pub radial_doppler_speed: Option<RadialDopplerSpeed>,
+    /// I048/230 Communications/ACAS Capability and Flight Status
+    pub communications_capability: Option<CommunicationsCapability>,
+    /// I048/260 ACAS Resolution Advisory Report
+    pub acas_resolution: Option<AcasResolution>,
+    /// I048/055 Mode-1 Code in Octal Representation
+    pub mode_1_code: Option<Mode1Code>,
+    /// I048/050 Mode-2 Code in Octal Representation
+    pub mode_2_code: Option<Mode3ACode>,
+    /// I048/065 Mode-1 Code Confidence Indicator
+    pub mode_1_confidence: Option<Mode1CodeConfidence>,
+    /// I048/060 Mode-2 Code Confidence Indicator
+    pub mode_2_confidence: Option<CodeConfidence>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+}
+
+impl Record48 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat48Fspec::I048RE as u8, &[])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I048/010
+        if self.is_fspec_bit_set(Cat48Fspec::I048_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/140
+        if self.is_fspec_bit_set(Cat48Fspec::I048_140) {
+            self.time_of_day = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/020
+        if self.is_fspec_bit_set(Cat48Fspec::I048_020) {
+            self.target_report_descriptor = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/040
+        if self.is_fspec_bit_set(Cat48Fspec::I048_040) {
+            self.measured_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/070
+        if self.is_fspec_bit_set(Cat48Fspec::I048_070) {
+            self.mode_3a_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/090
+        if self.is_fspec_bit_set(Cat48Fspec::I048_090) {
+            self.flight_level = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/130
+        if self.is_fspec_bit_set(Cat48Fspec::I048_130) {
+            self.plot_characteristics = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/220
+        if self.is_fspec_bit_set(Cat48Fspec::I048_220) {
+            self.aircraft_address = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/240
+        if self.is_fspec_bit_set(Cat48Fspec::I048_240) {
+            self.aircraft_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/250
+        if self.is_fspec_bit_set(Cat48Fspec::I048_250) {
+            self.mode_s_mb_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/161
+        if self.is_fspec_bit_set(Cat48Fspec::I048_161) {
+            self.track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/042
+        if self.is_fspec_bit_set(Cat48Fspec::I048_042) {
+            self.calculated_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/200
+        if self.is_fspec_bit_set(Cat48Fspec::I048_200) {
+            self.track_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/170
+        if self.is_fspec_bit_set(Cat48Fspec::I048_170) {
+            self.track_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/210
+        if self.is_fspec_bit_set(Cat48Fspec::I048_210) {
+            self.track_quality = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/030
+        if self.is_fspec_bit_set(Cat48Fspec::I048_030) {
+            self.warning_error = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/080
+        if self.is_fspec_bit_set(Cat48Fspec::I048_080) {
+            self.mode_3a_confidence = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/100
+        if self.is_fspec_bit_set(Cat48Fspec::I048_100) {
+            self.mode_c_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/110
+        if self.is_fspec_bit_set(Cat48Fspec::I048_110) {
+            self.height_3d = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/120
+        if self.is_fspec_bit_set(Cat48Fspec::I048_120) {
+            self.radial_doppler_speed = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/230
+        if self.is_fspec_bit_set(Cat48Fspec::I048_230) {
+            self.communications_capability = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/260
+        if self.is_fspec_bit_set(Cat48Fspec::I048_260) {
+            self.acas_resolution = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/055
+        if self.is_fspec_bit_set(Cat48Fspec::I048_055) {
+            self.mode_1_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/050
+        if self.is_fspec_bit_set(Cat48Fspec::I048_050) {
+            self.mode_2_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/065
+        if self.is_fspec_bit_set(Cat48Fspec::I048_065) {
+            self.mode_1_confidence = Some(decode_item(array, &mut offset)?);
+        }
+        // I048/060
+        if self.is_fspec_bit_set(Cat48Fspec::I048_060) {
+            self.mode_2_confidence = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat48Fspec::I048SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat48Fspec::I048RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat48Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I048/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_010);
+            vector.put(&bytes[..]);
+        }
+        // I048/140
+        if let Some(time_of_day) = &self.time_of_day {
+            let bytes = time_of_day.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_140);
+            vector.put(&bytes[..]);
+        }
+        // I048/020
+        if let Some(target_report_descriptor) = &self.target_report_descriptor {
+            let bytes = target_report_descriptor.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_020);
+            vector.put(&bytes[..]);
+        }
+        // I048/040
+        if let Some(measured_position) = &self.measured_position {
+            let bytes = measured_position.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_040);
+            vector.put(&bytes[..]);
+        }
+        // I048/070
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_070);
+            vector.put(&bytes[..]);
+        }
+        // I048/090
+        if let Some(flight_level) = &self.flight_level {
+            let bytes = flight_level.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_090);
+            vector.put(&bytes[..]);
+        }
+        // I048/130
+        if let Some(plot_characteristics) = &self.plot_characteristics {
+            let bytes = plot_characteristics.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_130);
+            vector.put(&bytes[..]);
+        }
+        // I048/220
+        if let Some(aircraft_address) = &self.aircraft_address {
+            let bytes = aircraft_address.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_220);
+            vector.put(&bytes[..]);
+        }
+        // I048/240
+        if let Some(aircraft_identification) = &self.aircraft_identification {
+            let bytes = aircraft_identification.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_240);
+            vector.put(&bytes[..]);
+        }
+        // I048/250
+        if let Some(mode_s_mb_data) = &self.mode_s_mb_data {
+            let bytes = mode_s_mb_data.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_250);
+            vector.put(&bytes[..]);
+        }
+        // I048/161
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_161);
+            vector.put(&bytes[..]);
+        }
+        // I048/042
+        if let Some(calculated_position) = &self.calculated_position {
+            let bytes = calculated_position.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_042);
+            vector.put(&bytes[..]);
+        }
+        // I048/200
+        if let Some(track_velocity) = &self.track_velocity {
+            let bytes = track_velocity.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_200);
+            vector.put(&bytes[..]);
+        }
+        // I048/170
+        if let Some(track_status) = &self.track_status {
+            let bytes = track_status.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_170);
+            vector.put(&bytes[..]);
+        }
+        // I048/210
+        if let Some(track_quality) = &self.track_quality {
+            let bytes = track_quality.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_210);
+            vector.put(&bytes[..]);
+        }
+        // I048/030
+        if let Some(warning_error) = &self.warning_error {
+            let bytes = warning_error.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_030);
+            vector.put(&bytes[..]);
+        }
+        // I048/080
+        if let Some(mode_3a_confidence) = &self.mode_3a_confidence {
+            let bytes = mode_3a_confidence.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_080);
+            vector.put(&bytes[..]);
+        }
+        // I048/100
+        if let Some(mode_c_code) = &self.mode_c_code {
+            let bytes = mode_c_code.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_100);
+            vector.put(&bytes[..]);
+        }
+        // I048/110
+        if let Some(height_3d) = &self.height_3d {
+            let bytes = height_3d.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_110);
+            vector.put(&bytes[..]);
+        }
+        // I048/120
+        if let Some(radial_doppler_speed) = &self.radial_doppler_speed {
+            let bytes = radial_doppler_speed.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_120);
+            vector.put(&bytes[..]);
+        }
+        // I048/230
+        if let Some(communications_capability) = &self.communications_capability {
+            let bytes = communications_capability.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_230);
+            vector.put(&bytes[..]);
+        }
+        // I048/260
+        if let Some(acas_resolution) = &self.acas_resolution {
+            let bytes = acas_resolution.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_260);
+            vector.put(&bytes[..]);
+        }
+        // I048/055
+        if let Some(mode_1_code) = &self.mode_1_code {
+            let bytes = mode_1_code.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_055);
+            vector.put(&bytes[..]);
+        }
+        // I048/050
+        if let Some(mode_2_code) = &self.mode_2_code {
+            let bytes = mode_2_code.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_050);
+            vector.put(&bytes[..]);
+        }
+        // I048/065
+        if let Some(mode_1_confidence) = &self.mode_1_confidence {
+            let bytes = mode_1_confidence.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_065);
+            vector.put(&bytes[..]);
+        }
+        // I048/060
+        if let Some(mode_2_confidence) = &self.mode_2_confidence {
+            let bytes = mode_2_confidence.encode();
+            self.set_fspec_bit(Cat48Fspec::I048_060);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat48Fspec::I048SP);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat48Fspec::I048RE);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat48Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record48 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record48::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record48::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT48 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat48Fspec {
+    I048_010 = 1,
+    I048_140,
+    I048_020,
+    I048_040,
+    I048_070,
+    I048_090,
+    I048_130,
+    I048_220,
+    I048_240,
+    I048_250,
+    I048_161,
+    I048_042,
+    I048_200,
+    I048_170,
+    I048_210,
+    I048_030,
+    I048_080,
+    I048_100,
+    I048_110,
+    I048_120,
+    I048_230,
+    I048_260,
+    I048_055,
+    I048_050,
+    I048_065,
+    I048_060,
+    I048SP,
+    I048RE,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat48::target_report_descriptor_field::DetectionType;
+    use crate::uap::cat48::track_status_field::TrackSensor;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create time of day
+        let mut time_day = TimeOfDay::default();
+        time_day.set_time(12345.5);
+
+        // Create target report descriptor
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_typ(DetectionType::SsrPsr);
+
+        // Create measured position
+        let mut measured_position = PolarPosition::default();
+        measured_position.set_rho(42.5).unwrap();
+        measured_position.set_theta(180.0).unwrap();
+
+        // Create Mode-3/A code
+        let mut mode_3a_code = Mode3ACode::default();
+        mode_3a_code.set_code(0o7000).unwrap();
+
+        // Create flight level
+        let mut flight_level = FlightLevel::default();
+        flight_level.set_flight_level(120.0).unwrap();
+
+        // Create track number and status
+        let mut track_number = TrackNumber::default();
+        track_number.set_track_number(815);
+        let mut track_status = TrackStatus::default();
+        track_status.set_sensor(TrackSensor::Combined);
+        track_status.set_ghost(true);
+
+        // Create record
+        let mut record = Record48 {
+            data_source_id: Some(data_source),
+            time_of_day: Some(time_day),
+            target_report_descriptor: Some(target_report_descriptor),
+            measured_position: Some(measured_position),
+            mode_3a_code: Some(mode_3a_code),
+            flight_level: Some(flight_level),
+            track_number: Some(track_number),
+            track_status: Some(track_status),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0xfd, 0x12]);
+
+        // New record
+        let mut object = Record48::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Reserved Expansion Field is in the fourth FSPEC
+        let mut reserved_expansion = ExplicitField::default();
+        reserved_expansion.set_data(&[0x01]).unwrap();
+
+        let mut record = Record48 {
+            reserved_expansion: Some(reserved_expansion),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record48::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+
+    #[test]
+    fn check_record_compound() {
+        // Create radar plot characteristics
+        let mut plot_characteristics = PlotCharacteristics::default();
+        plot_characteristics.set_psr_amplitude(-70);
+
+        // Create radial doppler speed
+        let mut radial_doppler_speed = RadialDopplerSpeed::default();
+        radial_doppler_speed.insert_raw_speed(10, 20, 30).unwrap();
+        radial_doppler_speed.insert_raw_speed(40, 50, 60).unwrap();
+
+        let mut record = Record48 {
+            plot_characteristics: Some(plot_characteristics),
+            radial_doppler_speed: Some(radial_doppler_speed),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(bytes.len(), 3 + 2 + 2 + 12);
+
+        // New record
+        let mut object = Record48::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+
+        // Radial doppler speed is longer than the record, offset of the data item
+        let result = Record48::default().decode(&bytes.slice(0..bytes.len() - 1));
+
+        assert_eq!(result, Err(CatError::SizeInvalid(5)));
+    }
+}
@@ -0,0 +1,599 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat62::aircraft_derived_data_field::AircraftDerivedData;
+use crate::uap::cat62::altitude_field::{
+    BarometricAltitude, GeometricAltitude, MeasuredFlightLevel, RateOfClimb,
+};
+use crate::uap::cat62::cartesian_acceleration_field::CartesianAcceleration;
+use crate::uap::cat62::cartesian_position_field::CartesianPosition;
+use crate::uap::cat62::cartesian_velocity_field::CartesianVelocity;
+use crate::uap::cat62::composed_track_number_field::ComposedTrackNumber;
+use crate::uap::cat62::data_ages_field::TrackDataAges;
+use crate::uap::cat62::estimated_accuracies_field::EstimatedAccuracies;
+use crate::uap::cat62::flight_plan_data_field::FlightPlanRelatedData;
+use crate::uap::cat62::measured_information_field::MeasuredInformation;
+use crate::uap::cat62::mode_5_data_field::Mode5Data;
+use crate::uap::cat62::mode_of_movement_field::ModeOfMovement;
+use crate::uap::cat62::target_size_field::TargetSize;
+use crate::uap::cat62::track_mode_3a_code_field::TrackMode3ACode;
+use crate::uap::cat62::track_status_field::TrackStatus;
+use crate::uap::cat62::update_ages_field::SystemTrackUpdateAges;
+use crate::uap::cat62::wgs_position_field::WgsPosition;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::service_identification_field::ServiceIdentification;
+use crate::uap::target_identification_field::TargetIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+use crate::uap::track_number_field::TrackNumber;
+use crate::uap::vehicle_fleet_field::VehicleFleetIdentification;
+
+/// Record of CAT62 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record62 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I062/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I062/015 Service Identification
+    pub service_identification: Option<ServiceIdentification>,
+    /// I062/070 Time Of Track Information
+    pub time_of_track: Option<TimeOfDay>,
+    /// I062/105 Calculated Track Position (WGS-84)
+    pub wgs_position: Option<WgsPosition>,
+    /// I062/100 Calculated Track Position (Cartesian)
+    pub cartesian_position: Option<CartesianPosition>,
+    /// I062/185 Calculated Track Velocity (Cartesian)
+    pub cartesian_velocity: Option<CartesianVelocity>,
+    /// I062/210 Calculated Acceleration (Cartesian)
+    pub cartesian_acceleration: Option<CartesianAcceleration>,
+    /// I062/060 Track Mode 3/A Code
+    pub mode_3a_code: Option<TrackMode3ACode>,
+    /// I062/245 Target Identification
+    pub target_identification: Option<TargetIdentification>,
+    /// I062/380 Aircraft Derived Data
+    pub aircraft_derived_data: Option<AircraftDerivedData>,
+    /// I062/040 Track Number
+    pub track_number: Option<TrackNumber>,
+    /// I062/080 Track Status
+    pub track_status: Option<TrackStatus>,
+    /// I062/290 System Track Update Ages
+    pub update_ages: Option<SystemTrackUpdateAges>,
+    /// I062/200 Mode of Movement
+    pub mode_of_movement: Option<ModeOfMovement>,
+    /// I062/295 Track Data Ages
+    pub data_ages: Option<TrackDataAges>,
+    /// I062/136 Measured Flight Level
+    pub measured_flight_level: Option<MeasuredFlightLevel>,
+    /// I062/130 Calculated Track Geometric Altitude
+    pub geometric_altitude: Option<GeometricAltitude>,
+    /// I062/135 Calculated Track Barometric Altitude
+    pub barometric_altitude: Option<BarometricAltitude>,
+    /// I062/220 Calculated Rate of Climb/Descent
+    pub rate_of_climb: Option<RateOfClimb>,
+    /// I062/390 Flight Plan Related Data
+    pub flight_plan_data: Option<FlightPlanRelatedData>,
+    /// I062/270 Target Size and Orientation
+    pub target_size: Option<TargetSize>,
+    /// I062/300 Vehicle Fleet Identification
+    pub vehicle_fleet: Option<VehicleFleetIdentification>,
+    /// I062/110 Mode 5 Data reports and Extended Mode 1 Code
+    pub mode_5_data: Option<Mode5Data>,
+    /// I062/120 Track Mode 2 Code
+    pub mode_2_code: Option<Mode3ACode>,
+    /// I062/510 Composed Track Number
+    pub composed_track_number: Option<ComposedTrackNumber>,
+    /// I062/500 Estimated Accuracies
+    pub estimated_accuracies: Option<EstimatedAccuracies>,
+    /// I062/340 Measured Information
+    pub measured_information: Option<MeasuredInformation>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record62 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(
+            &self.field_spec_vector,
+            Cat62Fspec::I062SP as u8,
+            &[2, 29, 30, 31, 32, 33],
+        )?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I062/010
+        if self.is_fspec_bit_set(Cat62Fspec::I062_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/015
+        if self.is_fspec_bit_set(Cat62Fspec::I062_015) {
+            self.service_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/070
+        if self.is_fspec_bit_set(Cat62Fspec::I062_070) {
+            self.time_of_track = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/105
+        if self.is_fspec_bit_set(Cat62Fspec::I062_105) {
+            self.wgs_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/100
+        if self.is_fspec_bit_set(Cat62Fspec::I062_100) {
+            self.cartesian_position = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/185
+        if self.is_fspec_bit_set(Cat62Fspec::I062_185) {
+            self.cartesian_velocity = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/210
+        if self.is_fspec_bit_set(Cat62Fspec::I062_210) {
+            self.cartesian_acceleration = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/060
+        if self.is_fspec_bit_set(Cat62Fspec::I062_060) {
+            self.mode_3a_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/245
+        if self.is_fspec_bit_set(Cat62Fspec::I062_245) {
+            self.target_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/380
+        if self.is_fspec_bit_set(Cat62Fspec::I062_380) {
+            self.aircraft_derived_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/040
+        if self.is_fspec_bit_set(Cat62Fspec::I062_040) {
+            self.track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/080
+        if self.is_fspec_bit_set(Cat62Fspec::I062_080) {
+            self.track_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/290
+        if self.is_fspec_bit_set(Cat62Fspec::I062_290) {
+            self.update_ages = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/200
+        if self.is_fspec_bit_set(Cat62Fspec::I062_200) {
+            self.mode_of_movement = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/295
+        if self.is_fspec_bit_set(Cat62Fspec::I062_295) {
+            self.data_ages = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/136
+        if self.is_fspec_bit_set(Cat62Fspec::I062_136) {
+            self.measured_flight_level = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/130
+        if self.is_fspec_bit_set(Cat62Fspec::I062_130) {
+            self.geometric_altitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/135
+        if self.is_fspec_bit_set(Cat62Fspec::I062_135) {
+            self.barometric_altitude = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/220
+        if self.is_fspec_bit_set(Cat62Fspec::I062_220) {
+            self.rate_of_climb = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/390
+        if self.is_fspec_bit_set(Cat62Fspec::I062_390) {
+            self.flight_plan_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/270
+        if self.is_fspec_bit_set(Cat62Fspec::I062_270) {
+            self.target_size = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/300
+        if self.is_fspec_bit_set(Cat62Fspec::I062_300) {
+            self.vehicle_fleet = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/110
+        if self.is_fspec_bit_set(Cat62Fspec::I062_110) {
+            self.mode_5_data = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/120
+        if self.is_fspec_bit_set(Cat62Fspec::I062_120) {
+            self.mode_2_code = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/510
+        if self.is_fspec_bit_set(Cat62Fspec::I062_510) {
+            self.composed_track_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/500
+        if self.is_fspec_bit_set(Cat62Fspec::I062_500) {
+            self.estimated_accuracies = Some(decode_item(array, &mut offset)?);
+        }
+        // I062/340
+        if self.is_fspec_bit_set(Cat62Fspec::I062_340) {
+            self.measured_information = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat62Fspec::I062RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat62Fspec::I062SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat62Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I062/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_010);
+            vector.put(&bytes[..]);
+        }
+        // I062/015
+        if let Some(service_identification) = &self.service_identification {
+            let bytes = service_identification.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_015);
+            vector.put(&bytes[..]);
+        }
+        // I062/070
+        if let Some(time_of_track) = &self.time_of_track {
+            let bytes = time_of_track.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_070);
+            vector.put(&bytes[..]);
+        }
+        // I062/105
+        if let Some(wgs_position) = &self.wgs_position {
+            let bytes = wgs_position.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_105);
+            vector.put(&bytes[..]);
+        }
+        // I062/100
+        if let Some(cartesian_position) = &self.cartesian_position {
+            let bytes = cartesian_position.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_100);
+            vector.put(&bytes[..]);
+        }
+        // I062/185
+        if let Some(cartesian_velocity) = &self.cartesian_velocity {
+            let bytes = cartesian_velocity.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_185);
+            vector.put(&bytes[..]);
+        }
+        // I062/210
+        if let Some(cartesian_acceleration) = &self.cartesian_acceleration {
+            let bytes = cartesian_acceleration.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_210);
+            vector.put(&bytes[..]);
+        }
+        // I062/060
+        if let Some(mode_3a_code) = &self.mode_3a_code {
+            let bytes = mode_3a_code.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_060);
+            vector.put(&bytes[..]);
+        }
+        // I062/245
+        if let Some(target_identification) = &self.target_identification {
+            let bytes = target_identification.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_245);
+            vector.put(&bytes[..]);
+        }
+        // I062/380
+        if let Some(aircraft_derived_data) = &self.aircraft_derived_data {
+            let bytes = aircraft_derived_data.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_380);
+            vector.put(&bytes[..]);
+        }
+        // I062/040
+        if let Some(track_number) = &self.track_number {
+            let bytes = track_number.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_040);
+            vector.put(&bytes[..]);
+        }
+        // I062/080
+        if let Some(track_status) = &self.track_status {
+            let bytes = track_status.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_080);
+            vector.put(&bytes[..]);
+        }
+        // I062/290
+        if let Some(update_ages) = &self.update_ages {
+            let bytes = update_ages.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_290);
+            vector.put(&bytes[..]);
+        }
+        // I062/200
+        if let Some(mode_of_movement) = &self.mode_of_movement {
+            let bytes = mode_of_movement.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_200);
+            vector.put(&bytes[..]);
+        }
+        // I062/295
+        if let Some(data_ages) = &self.data_ages {
+            let bytes = data_ages.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_295);
+            vector.put(&bytes[..]);
+        }
+        // I062/136
+        if let Some(measured_flight_level) = &self.measured_flight_level {
+            let bytes = measured_flight_level.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_136);
+            vector.put(&bytes[..]);
+        }
+        // I062/130
+        if let Some(geometric_altitude) = &self.geometric_altitude {
+            let bytes = geometric_altitude.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_130);
+            vector.put(&bytes[..]);
+        }
+        // I062/135
+        if let Some(barometric_altitude) = &self.barometric_altitude {
+            let bytes = barometric_altitude.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_135);
+            vector.put(&bytes[..]);
+        }
+        // I062/220
+        if let Some(rate_of_climb) = &self.rate_of_climb {
+            let bytes = rate_of_climb.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_220);
+            vector.put(&bytes[..]);
+        }
+        // I062/390
+        if let Some(flight_plan_data) = &self.flight_plan_data {
+            let bytes = flight_plan_data.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_390);
+            vector.put(&bytes[..]);
+        }
+        // I062/270
+        if let Some(target_size) = &self.target_size {
+            let bytes = target_size.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_270);
+            vector.put(&bytes[..]);
+        }
+        // I062/300
+        if let Some(vehicle_fleet) = &self.vehicle_fleet {
+            let bytes = vehicle_fleet.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_300);
+            vector.put(&bytes[..]);
+        }
+        // I062/110
+        if let Some(mode_5_data) = &self.mode_5_data {
+            let bytes = mode_5_data.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_110);
+            vector.put(&bytes[..]);
+        }
+        // I062/120
+        if let Some(mode_2_code) = &self.mode_2_code {
+            let bytes = mode_2_code.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_120);
+            vector.put(&bytes[..]);
+        }
+        // I062/510
+        if let Some(composed_track_number) = &self.composed_track_number {
+            let bytes = composed_track_number.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_510);
+            vector.put(&bytes[..]);
+        }
+        // I062/500
+        if let Some(estimated_accuracies) = &self.estimated_accuracies {
+            let bytes = estimated_accuracies.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_500);
+            vector.put(&bytes[..]);
+        }
+        // I062/340
+        if let Some(measured_information) = &self.measured_information {
+            let bytes = measured_information.encode();
+            self.set_fspec_bit(Cat62Fspec::I062_340);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat62Fspec::I062RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat62Fspec::I062SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat62Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record62 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record62::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record62::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT62 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat62Fspec {
+    I062_010 = 1,
+    I062_015 = 3,
+    I062_070,
+    I062_105,
+    I062_100,
+    I062_185,
+    I062_210,
+    I062_060,
+    I062_245,
+    I062_380,
+    I062_040,
+    I062_080,
+    I062_290,
+    I062_200,
+    I062_295,
+    I062_136,
+    I062_130,
+    I062_135,
+    I062_220,
+    I062_390,
+    I062_270,
+    I062_300,
+    I062_110,
+    I062_120,
+    I062_510,
+    I062_500,
+    I062_340,
+    I062RE = 34,
+    I062SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::aircraft_address_field::AircraftAddress;
+    use crate::uap::cat62::track_status_field::TrackFlag;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create time of track information
+        let mut time_of_track = TimeOfDay::default();
+        time_of_track.set_time(12345.5);
+
+        // Create calculated track positions and velocity
+        let mut wgs_position = WgsPosition::default();
+        wgs_position.set_lat_lon(50.0379, 8.5622).unwrap();
+        let mut cartesian_position = CartesianPosition::default();
+        cartesian_position.set_position(-1500.0, 25000.5).unwrap();
+        let mut cartesian_velocity = CartesianVelocity::default();
+        cartesian_velocity.set_velocity(120.25, -80.5).unwrap();
+
+        // Create aircraft derived data
+        let mut aircraft_address = AircraftAddress::default();
+        aircraft_address.set_address(0x3c660c).unwrap();
+        let mut aircraft_derived_data = AircraftDerivedData::default();
+        aircraft_derived_data.set_address(aircraft_address);
+
+        // Create track number and status
+        let mut track_number = TrackNumber::default();
+        track_number.set_track_number(815);
+        let mut track_status = TrackStatus::default();
+        track_status.set_flag(TrackFlag::Mono, true);
+        track_status.set_flag(TrackFlag::Coasted, true);
+
+        // Create record
+        let mut record = Record62 {
+            data_source_id: Some(data_source),
+            time_of_track: Some(time_of_track),
+            wgs_position: Some(wgs_position),
+            cartesian_position: Some(cartesian_position),
+            cartesian_velocity: Some(cartesian_velocity),
+            aircraft_derived_data: Some(aircraft_derived_data),
+            track_number: Some(track_number),
+            track_status: Some(track_status),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0x9f, 0x1c]);
+
+        // New record
+        let mut object = Record62::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the fifth FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record62 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x01, 0x01, 0x01, 0x02, 0x02, 0x01]);
+
+        // New record
+        let mut object = Record62::default();
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_overrun() {
+        // Track number announced but missing
+        let array: &'static [u8] = &[0x01, 0x08, 0x03];
+        let bytes = Bytes::from(array);
+
+        let result = Record62::default().decode(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(2)));
+    }
+}
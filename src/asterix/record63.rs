@@ -0,0 +1,333 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat63::angular_bias_field::AngularBias;
+use crate::uap::cat63::range_bias_field::RangeBias;
+use crate::uap::cat63::sensor_configuration_field::SensorConfiguration;
+use crate::uap::cat63::time_stamping_bias_field::TimeStampingBias;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::service_identification_field::ServiceIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT63 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record63 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I063/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I063/015 Service Identification
+    pub service_identification: Option<ServiceIdentification>,
+    /// I063/030 Time of Message
+    pub time_of_message: Option<TimeOfDay>,
+    /// I063/050 Sensor Identifier
+    pub sensor_id: Option<DataSource>,
+    /// I063/060 Sensor Configuration and Status
+    pub sensor_configuration: Option<SensorConfiguration>,
+    /// I063/070 Time Stamping Bias
+    pub time_stamping_bias: Option<TimeStampingBias>,
+    /// I063/080 SSR/Mode S Range Gain and Bias
+    pub ssr_range_bias: Option<RangeBias>,
+    /// I063/081 SSR/Mode S Azimuth Bias
+    pub ssr_azimuth_bias: Option<AngularBias>,
+    /// I063/090 PSR Range Gain and Bias
+    pub psr_range_bias: Option<RangeBias>,
+    /// I063/091 PSR Azimuth Bias
+    pub psr_azimuth_bias: Option<AngularBias>,
+    /// I063/092 PSR Elevation Bias
+    pub psr_elevation_bias: Option<AngularBias>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record63 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(&self.field_spec_vector, Cat63Fspec::I063SP as u8, &[12])?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I063/010
+        if self.is_fspec_bit_set(Cat63Fspec::I063_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/015
+        if self.is_fspec_bit_set(Cat63Fspec::I063_015) {
+            self.service_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/030
+        if self.is_fspec_bit_set(Cat63Fspec::I063_030) {
+            self.time_of_message = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/050
+        if self.is_fspec_bit_set(Cat63Fspec::I063_050) {
+            self.sensor_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/060
+        if self.is_fspec_bit_set(Cat63Fspec::I063_060) {
+            self.sensor_configuration = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/070
+        if self.is_fspec_bit_set(Cat63Fspec::I063_070) {
+            self.time_stamping_bias = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/080
+        if self.is_fspec_bit_set(Cat63Fspec::I063_080) {
+            self.ssr_range_bias = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/081
+        if self.is_fspec_bit_set(Cat63Fspec::I063_081) {
+            self.ssr_azimuth_bias = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/090
+        if self.is_fspec_bit_set(Cat63Fspec::I063_090) {
+            self.psr_range_bias = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/091
+        if self.is_fspec_bit_set(Cat63Fspec::I063_091) {
+            self.psr_azimuth_bias = Some(decode_item(array, &mut offset)?);
+        }
+        // I063/092
+        if self.is_fspec_bit_set(Cat63Fspec::I063_092) {
+            self.psr_elevation_bias = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat63Fspec::I063RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat63Fspec::I063SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat63Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I063/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_010);
+            vector.put(&bytes[..]);
+        }
+        // I063/015
+        if let Some(service_identification) = &self.service_identification {
+            let bytes = service_identification.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_015);
+            vector.put(&bytes[..]);
+        }
+        // I063/030
+        if let Some(time_of_message) = &self.time_of_message {
+            let bytes = time_of_message.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_030);
+            vector.put(&bytes[..]);
+        }
+        // I063/050
+        if let Some(sensor_id) = &self.sensor_id {
+            let bytes = sensor_id.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_050);
+            vector.put(&bytes[..]);
+        }
+        // I063/060
+        if let Some(sensor_configuration) = &self.sensor_configuration {
+            let bytes = sensor_configuration.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_060);
+            vector.put(&bytes[..]);
+        }
+        // I063/070
+        if let Some(time_stamping_bias) = &self.time_stamping_bias {
+            let bytes = time_stamping_bias.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_070);
+            vector.put(&bytes[..]);
+        }
+        // I063/080
+        if let Some(ssr_range_bias) = &self.ssr_range_bias {
+            let bytes = ssr_range_bias.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_080);
+            vector.put(&bytes[..]);
+        }
+        // I063/081
+        if let Some(ssr_azimuth_bias) = &self.ssr_azimuth_bias {
+            let bytes = ssr_azimuth_bias.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_081);
+            vector.put(&bytes[..]);
+        }
+        // I063/090
+        if let Some(psr_range_bias) = &self.psr_range_bias {
+            let bytes = psr_range_bias.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_090);
+            vector.put(&bytes[..]);
+        }
+        // I063/091
+        if let Some(psr_azimuth_bias) = &self.psr_azimuth_bias {
+            let bytes = psr_azimuth_bias.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_091);
+            vector.put(&bytes[..]);
+        }
+        // I063/092
+        if let Some(psr_elevation_bias) = &self.psr_elevation_bias {
+            let bytes = psr_elevation_bias.encode();
+            self.set_fspec_bit(Cat63Fspec::I063_092);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat63Fspec::I063RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat63Fspec::I063SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat63Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record63 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record63::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record63::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT63 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat63Fspec {
+    I063_010 = 1,
+    I063_015,
+    I063_030,
+    I063_050,
+    I063_060,
+    I063_070,
+    I063_080,
+    I063_081,
+    I063_090,
+    I063_091,
+    I063_092,
+    I063RE = 13,
+    I063SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_record() {
+        // Create data source and sensor identifier
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+        let mut sensor_id = DataSource::default();
+        sensor_id.set_source_id_sic(7);
+        sensor_id.set_source_id_sac(26);
+
+        // Create SSR biases
+        let mut ssr_range_bias = RangeBias::default();
+        ssr_range_bias.set_bias(0.25).unwrap();
+        let mut ssr_azimuth_bias = AngularBias::default();
+        ssr_azimuth_bias.set_bias(-1.40625).unwrap();
+
+        // Create record
+        let mut record = Record63 {
+            data_source_id: Some(data_source),
+            sensor_id: Some(sensor_id),
+            ssr_range_bias: Some(ssr_range_bias),
+            ssr_azimuth_bias: Some(ssr_azimuth_bias),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[0..2], &[0x93, 0x80]);
+
+        // New record
+        let mut object = Record63::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record63 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record63::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
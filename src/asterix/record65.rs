@@ -0,0 +1,285 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::asterix::data_record::{
+    check_field_spec, decode_field_spec, decode_item, encode_record, is_fspec_bit_set,
+    set_fspec_bit, DataRecord,
+};
+use crate::category::CatError;
+
+// Search for crates in subdirectory uap
+use crate::uap::cat65::batch_number_field::BatchNumber;
+use crate::uap::cat65::message_type_field::SdpsMessageType;
+use crate::uap::cat65::sdps_status_field::SdpsStatus;
+use crate::uap::cat65::service_status_report_field::ServiceStatusReport;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+use crate::uap::explicit_field::ExplicitField;
+use crate::uap::field_spec::FieldSpec;
+use crate::uap::service_identification_field::ServiceIdentification;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+/// Record of CAT65 message. Several records are possible per message.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Record65 {
+    /// Several field spec are possible for one record.
+    field_spec_vector: Vec<FieldSpec>,
+    /// I065/010 Data Source Identifier
+    pub data_source_id: Option<DataSource>,
+    /// I065/000 Message Type
+    pub message_type: Option<SdpsMessageType>,
+    /// I065/015 Service Identification
+    pub service_identification: Option<ServiceIdentification>,
+    /// I065/030 Time of Message
+    pub time_of_message: Option<TimeOfDay>,
+    /// I065/020 Batch Number
+    pub batch_number: Option<BatchNumber>,
+    /// I065/040 SDPS Configuration and Status
+    pub sdps_status: Option<SdpsStatus>,
+    /// I065/050 Service Status Report
+    pub service_status_report: Option<ServiceStatusReport>,
+    /// Reserved Expansion Field
+    pub reserved_expansion: Option<ExplicitField>,
+    /// Special Purpose Field
+    pub special_purpose: Option<ExplicitField>,
+}
+
+impl Record65 {
+    /*
+     * Decode byte stream to record.
+     * Returns the number of bytes consumed by the record.
+     */
+    pub fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        // Order is important, because some fields have variable length
+        self.field_spec_vector = decode_field_spec(bytes)?;
+
+        // Spare and undefined FRN of the UAP are not allowed
+        check_field_spec(
+            &self.field_spec_vector,
+            Cat65Fspec::I065SP as u8,
+            &[8, 9, 10, 11, 12],
+        )?;
+
+        // Data items start after the FSPEC
+        let array: &[u8] = bytes;
+        let mut offset = self.field_spec_vector.len();
+
+        // I065/010
+        if self.is_fspec_bit_set(Cat65Fspec::I065_010) {
+            self.data_source_id = Some(decode_item(array, &mut offset)?);
+        }
+        // I065/000
+        if self.is_fspec_bit_set(Cat65Fspec::I065_000) {
+            self.message_type = Some(decode_item(array, &mut offset)?);
+        }
+        // I065/015
+        if self.is_fspec_bit_set(Cat65Fspec::I065_015) {
+            self.service_identification = Some(decode_item(array, &mut offset)?);
+        }
+        // I065/030
+        if self.is_fspec_bit_set(Cat65Fspec::I065_030) {
+            self.time_of_message = Some(decode_item(array, &mut offset)?);
+        }
+        // I065/020
+        if self.is_fspec_bit_set(Cat65Fspec::I065_020) {
+            self.batch_number = Some(decode_item(array, &mut offset)?);
+        }
+        // I065/040
+        if self.is_fspec_bit_set(Cat65Fspec::I065_040) {
+            self.sdps_status = Some(decode_item(array, &mut offset)?);
+        }
+        // I065/050
+        if self.is_fspec_bit_set(Cat65Fspec::I065_050) {
+            self.service_status_report = Some(decode_item(array, &mut offset)?);
+        }
+        // Reserved Expansion Field
+        if self.is_fspec_bit_set(Cat65Fspec::I065RE) {
+            self.reserved_expansion = Some(decode_item(array, &mut offset)?);
+        }
+        // Special Purpose Field
+        if self.is_fspec_bit_set(Cat65Fspec::I065SP) {
+            self.special_purpose = Some(decode_item(array, &mut offset)?);
+        }
+
+        Ok(offset)
+    }
+
+    /*
+     * Check if the FSPEC bit of a data item is set.
+     */
+    fn is_fspec_bit_set(&self, frn: Cat65Fspec) -> bool {
+        is_fspec_bit_set(&self.field_spec_vector, frn as u8)
+    }
+
+    /*
+     * Encode record to byte stream.
+     * Data items are written in UAP order, only the needed FSPEC are written.
+     */
+    pub fn encode(&mut self) -> Result<Bytes, CatError> {
+        // New fspec field
+        self.field_spec_vector.clear();
+
+        let mut vector = BytesMut::new();
+
+        // I065/010
+        if let Some(data_source_id) = &self.data_source_id {
+            let bytes = data_source_id.encode();
+            self.set_fspec_bit(Cat65Fspec::I065_010);
+            vector.put(&bytes[..]);
+        }
+        // I065/000
+        if let Some(message_type) = &self.message_type {
+            let bytes = message_type.encode();
+            self.set_fspec_bit(Cat65Fspec::I065_000);
+            vector.put(&bytes[..]);
+        }
+        // I065/015
+        if let Some(service_identification) = &self.service_identification {
+            let bytes = service_identification.encode();
+            self.set_fspec_bit(Cat65Fspec::I065_015);
+            vector.put(&bytes[..]);
+        }
+        // I065/030
+        if let Some(time_of_message) = &self.time_of_message {
+            let bytes = time_of_message.encode();
+            self.set_fspec_bit(Cat65Fspec::I065_030);
+            vector.put(&bytes[..]);
+        }
+        // I065/020
+        if let Some(batch_number) = &self.batch_number {
+            let bytes = batch_number.encode();
+            self.set_fspec_bit(Cat65Fspec::I065_020);
+            vector.put(&bytes[..]);
+        }
+        // I065/040
+        if let Some(sdps_status) = &self.sdps_status {
+            let bytes = sdps_status.encode();
+            self.set_fspec_bit(Cat65Fspec::I065_040);
+            vector.put(&bytes[..]);
+        }
+        // I065/050
+        if let Some(service_status_report) = &self.service_status_report {
+            let bytes = service_status_report.encode();
+            self.set_fspec_bit(Cat65Fspec::I065_050);
+            vector.put(&bytes[..]);
+        }
+        // Reserved Expansion Field
+        if let Some(reserved_expansion) = &self.reserved_expansion {
+            let bytes = reserved_expansion.encode();
+            self.set_fspec_bit(Cat65Fspec::I065RE);
+            vector.put(&bytes[..]);
+        }
+        // Special Purpose Field
+        if let Some(special_purpose) = &self.special_purpose {
+            let bytes = special_purpose.encode();
+            self.set_fspec_bit(Cat65Fspec::I065SP);
+            vector.put(&bytes[..]);
+        }
+
+        Ok(encode_record(&mut self.field_spec_vector, vector))
+    }
+
+    /*
+     * Set the FSPEC bit of a data item.
+     */
+    fn set_fspec_bit(&mut self, frn: Cat65Fspec) {
+        set_fspec_bit(&mut self.field_spec_vector, frn as u8);
+    }
+}
+
+impl DataRecord for Record65 {
+    fn decode(&mut self, bytes: &Bytes) -> Result<usize, CatError> {
+        Record65::decode(self, bytes)
+    }
+
+    fn encode(&mut self) -> Result<Bytes, CatError> {
+        Record65::encode(self)
+    }
+
+    fn reserved_expansion(&self) -> Option<&ExplicitField> {
+        self.reserved_expansion.as_ref()
+    }
+
+    fn special_purpose(&self) -> Option<&ExplicitField> {
+        self.special_purpose.as_ref()
+    }
+}
+
+/// CAT65 Standard User Application Profile (UAP)
+/// FSPEC Field Reference Number (FRN)
+#[derive(Debug, Clone, Copy)]
+pub enum Cat65Fspec {
+    I065_010 = 1,
+    I065_000,
+    I065_015,
+    I065_030,
+    I065_020,
+    I065_040,
+    I065_050,
+    I065RE = 13,
+    I065SP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::cat65::message_type_field::SdpsMessage;
+
+    #[test]
+    fn check_record() {
+        // Create data source
+        let mut data_source = DataSource::default();
+        data_source.set_source_id_sic(42);
+        data_source.set_source_id_sac(26);
+
+        // Create end of batch
+        let mut message_type = SdpsMessageType::default();
+        message_type.set_message_type(SdpsMessage::EndOfBatch);
+        let mut time_of_message = TimeOfDay::default();
+        time_of_message.set_time(12345.5);
+        let mut batch_number = BatchNumber::default();
+        batch_number.set_batch(42);
+
+        // Create record
+        let mut record = Record65 {
+            data_source_id: Some(data_source),
+            message_type: Some(message_type),
+            time_of_message: Some(time_of_message),
+            batch_number: Some(batch_number),
+            ..Default::default()
+        };
+
+        // Convert struct to byte stream
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(bytes[0], 0xd8);
+
+        // New record
+        let mut object = Record65::default();
+
+        // Convert byte stream to struct
+        let length = object.decode(&bytes).unwrap();
+
+        assert_eq!(length, bytes.len());
+        assert_eq!(record, object);
+    }
+
+    #[test]
+    fn check_record_field_spec() {
+        // Only Special Purpose Field is in the second FSPEC
+        let mut special_purpose = ExplicitField::default();
+        special_purpose.set_data(&[0x01]).unwrap();
+
+        let mut record = Record65 {
+            special_purpose: Some(special_purpose),
+            ..Default::default()
+        };
+        let bytes = record.encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x01, 0x02, 0x02, 0x01]);
+
+        // Empty record has one FSPEC
+        let bytes = Record65::default().encode().unwrap();
+
+        assert_eq!(&bytes[..], &[0x00]);
+    }
+}
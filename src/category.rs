@@ -1,21 +1,73 @@
+use bytes::Bytes;
+
+use crate::asterix::cat01::Cat01Message;
+use crate::asterix::cat02::Cat02Message;
+use crate::asterix::cat04::Cat04Message;
+use crate::asterix::cat07::Cat07Message;
+use crate::asterix::cat08::Cat08Message;
+use crate::asterix::cat10::Cat10Message;
+use crate::asterix::cat11::Cat11Message;
+use crate::asterix::cat19::Cat19Message;
+use crate::asterix::cat20::Cat20Message;
+use crate::asterix::cat21::Cat21Message;
+use crate::asterix::cat23::Cat23Message;
+use crate::asterix::cat240::Cat240Message;
+use crate::asterix::cat25::Cat25Message;
 use crate::asterix::cat34::Cat34Message;
+use crate::asterix::cat48::Cat48Message;
+use crate::asterix::cat62::Cat62Message;
+use crate::asterix::cat63::Cat63Message;
+use crate::asterix::cat65::Cat65Message;
 
 /// ASTERIX category
 #[derive(Debug, PartialEq, Clone)]
 pub enum Category {
-    Cat007,               // Directed Interrogation Messages
-    Cat034(Cat34Message), // Transmission of Monoradar Service Messages
-    Cat048,               // Monoradar Target Reports
-    Cat062,               // System Track Data
+    Cat001(Cat01Message),  // Monoradar Target Reports (legacy)
+    Cat002(Cat02Message),  // Monoradar Service Messages (legacy)
+    Cat004(Cat04Message),  // Safety Net Messages
+    Cat007(Cat07Message),  // Directed Interrogation Messages
+    Cat008(Cat08Message),  // Monoradar Derived Weather Information
+    Cat010(Cat10Message),  // Monosensor Surface Movement Data
+    Cat011(Cat11Message),  // A-SMGCS Surface Movement Tracks
+    Cat019(Cat19Message),  // Multilateration System Status Messages
+    Cat020(Cat20Message),  // Multilateration Target Reports
+    Cat021(Cat21Message),  // ADS-B Target Reports
+    Cat023(Cat23Message),  // CNS/ATM Ground Station Service Messages
+    Cat025(Cat25Message),  // CNS/ATM Ground System Status Reports
+    Cat034(Cat34Message),  // Transmission of Monoradar Service Messages
+    Cat048(Cat48Message),  // Monoradar Target Reports
+    Cat062(Cat62Message),  // System Track Data
+    Cat063(Cat63Message),  // Sensor Status Messages
+    Cat065(Cat65Message),  // SDPS Service Status Messages
+    Cat240(Cat240Message), // Radar Video Transmission
+    Unknown(Bytes),        // Data block of unsupported category
 }
 
 impl std::fmt::Display for Category {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Category::Cat007 => write!(f, "Cat007"),
+            Category::Cat001(_message) => write!(f, "Cat001"),
+            Category::Cat002(_message) => write!(f, "Cat002"),
+            Category::Cat004(_message) => write!(f, "Cat004"),
+            Category::Cat007(_message) => write!(f, "Cat007"),
+            Category::Cat008(_message) => write!(f, "Cat008"),
+            Category::Cat010(_message) => write!(f, "Cat010"),
+            Category::Cat011(_message) => write!(f, "Cat011"),
+            Category::Cat019(_message) => write!(f, "Cat019"),
+            Category::Cat020(_message) => write!(f, "Cat020"),
+            Category::Cat021(_message) => write!(f, "Cat021"),
+            Category::Cat023(_message) => write!(f, "Cat023"),
+            Category::Cat025(_message) => write!(f, "Cat025"),
             Category::Cat034(_message) => write!(f, "Cat034"),
-            Category::Cat048 => write!(f, "Cat048"),
-            Category::Cat062 => write!(f, "Cat062"),
+            Category::Cat048(_message) => write!(f, "Cat048"),
+            Category::Cat062(_message) => write!(f, "Cat062"),
+            Category::Cat063(_message) => write!(f, "Cat063"),
+            Category::Cat065(_message) => write!(f, "Cat065"),
+            Category::Cat240(_message) => write!(f, "Cat240"),
+            Category::Unknown(bytes) => match bytes.first() {
+                Some(cat) => write!(f, "Unknown({})", cat),
+                None => write!(f, "Unknown"),
+            },
         }
     }
 }
@@ -24,26 +76,50 @@ impl std::fmt::Display for Category {
 #[derive(Debug, Clone, PartialEq)]
 pub enum CatError {
     CategoryInvalid,
-    SizeInvalid,
+    /// Byte offset where the data block or record overruns its length
+    SizeInvalid(usize),
+    /// Value out of range of a data item
+    ValueInvalid,
+    /// Byte offset of a record whose FSPEC sets a spare or undefined FRN
+    FieldSpecInvalid(usize),
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_000Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_010Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_020Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_030Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_041Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_050Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_060Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_070Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_090Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_100Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_110Invalid,
+    #[deprecated(note = "data items report SizeInvalid or ValueInvalid")]
     I034_120Invalid,
 }
 
+#[allow(deprecated)]
 impl std::fmt::Display for CatError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             CatError::CategoryInvalid => write!(f, "Error: Category invalid"),
-            CatError::SizeInvalid => write!(f, "Error: Data block size invalid"),
+            CatError::SizeInvalid(offset) => {
+                write!(f, "Error: Data block size invalid at offset {}", offset)
+            }
+            CatError::ValueInvalid => write!(f, "Error: Data item value invalid"),
+            CatError::FieldSpecInvalid(offset) => {
+                write!(f, "Error: Field specification invalid at offset {}", offset)
+            }
             CatError::I034_000Invalid => write!(f, "Error: I034_000 Message Type invalid"),
             CatError::I034_010Invalid => write!(f, "Error: I034_010 Data Source Identifier invalid"),
             CatError::I034_020Invalid => write!(f, "Error: I034_020 Sector Number invalid"),
@@ -66,15 +142,32 @@ mod tests {
 
     #[test]
     fn check_category() {
-        let cat_007 = Category::Cat007;
-        let cat_048 = Category::Cat048;
-        let cat_062 = Category::Cat062;
+        let cat_001 = Category::Cat001(Cat01Message::default());
+        let cat_002 = Category::Cat002(Cat02Message::default());
+        let cat_004 = Category::Cat004(Cat04Message::default());
+        let cat_007 = Category::Cat007(Cat07Message::default());
+        let cat_008 = Category::Cat008(Cat08Message::default());
+        let cat_010 = Category::Cat010(Cat10Message::default());
+        let cat_011 = Category::Cat011(Cat11Message::default());
+        let cat_019 = Category::Cat019(Cat19Message::default());
+        let cat_020 = Category::Cat020(Cat20Message::default());
+        let cat_021 = Category::Cat021(Cat21Message::default());
+        let cat_023 = Category::Cat023(Cat23Message::default());
+        let cat_025 = Category::Cat025(Cat25Message::default());
+        let cat_048 = Category::Cat048(Cat48Message::default());
+        let cat_062 = Category::Cat062(Cat62Message::default());
+        let cat_063 = Category::Cat063(Cat63Message::default());
+        let cat_065 = Category::Cat065(Cat65Message::default());
+        let cat_240 = Category::Cat240(Cat240Message::default());
 
         let message_in = Cat34Message::default();
         let cat_034 = Category::Cat034(message_in.clone());
 
-        let categories: Vec<Category> = vec![cat_007, cat_034, cat_048, cat_062];
-        let result = categories.get(1).unwrap().clone();
+        let categories: Vec<Category> = vec![
+            cat_001, cat_002, cat_004, cat_007, cat_008, cat_010, cat_011, cat_019, cat_020,
+            cat_021, cat_023, cat_025, cat_034, cat_048, cat_062, cat_063, cat_065, cat_240,
+        ];
+        let result = categories.get(12).unwrap().clone();
         let message_out: Cat34Message = result.try_into().unwrap();
 
         assert_eq!(message_in, message_out);
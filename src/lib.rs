@@ -1,45 +1,139 @@
 pub mod asterix; // Name of subdirectory
 pub mod category;
 pub mod uap; // Name of subdirectory
+pub mod video; // Name of subdirectory
 
-use crate::asterix::cat34::{Cat34Message, encode, decode};
+use crate::asterix::cat01::{self, Cat01Message};
+use crate::asterix::cat02::{self, Cat02Message};
+use crate::asterix::cat04::{self, Cat04Message};
+use crate::asterix::cat07::{self, Cat07Message};
+use crate::asterix::cat08::{self, Cat08Message};
+use crate::asterix::cat10::{self, Cat10Message};
+use crate::asterix::cat11::{self, Cat11Message};
+use crate::asterix::cat19::{self, Cat19Message};
+use crate::asterix::cat20::{self, Cat20Message};
+use crate::asterix::cat21::{self, Cat21Message};
+use crate::asterix::cat23::{self, Cat23Message};
+use crate::asterix::cat240::{self, Cat240Message};
+use crate::asterix::cat25::{self, Cat25Message};
+use crate::asterix::cat34::{self, Cat34Message};
+use crate::asterix::cat48::{self, Cat48Message};
+use crate::asterix::cat62::{self, Cat62Message};
+use crate::asterix::cat63::{self, Cat63Message};
+use crate::asterix::cat65::{self, Cat65Message};
 use crate::asterix::header_field::Header;
-use bytes::Bytes;
+use crate::uap::explicit_field::ExplicitParsers;
+use bytes::{BufMut, Bytes, BytesMut};
 use category::{CatError, Category};
 
+/// Maximum UDP payload without IP fragmentation on Ethernet
+pub const MAX_DATAGRAM_SIZE: usize = 1472;
+
 /**
  * Encode many ASTERIX categories into byte stream
  */
-pub fn encode_asterix(messages: &Vec<Category>) -> Result<Bytes, CatError> {
-    let mut sum_bytes = Bytes::default();
+pub fn encode_asterix(messages: &[Category]) -> Result<Bytes, CatError> {
+    let mut sum_bytes = BytesMut::new();
 
     for category in messages.iter() {
-        let result;
+        // Append each byte stream to one big byte stream when OK result
+        let bytes = encode_category(category)?;
+        sum_bytes.put(bytes);
+    }
+    Ok(sum_bytes.into())
+}
 
-        match category {
-            Category::Cat034(cat34) => result = encode(cat34),
-            _ => result = Err(CatError::CategoryInvalid),
+/**
+ * Encode many ASTERIX categories into datagrams of maximum size
+ * Data blocks are never split over two datagrams
+ */
+pub fn encode_asterix_datagrams(
+    messages: &[Category],
+    max_size: usize,
+) -> Result<Vec<Bytes>, CatError> {
+    let mut datagrams: Vec<Bytes> = Vec::new();
+    // Data block length is limited by the LEN field
+    let mut datagram = BytesMut::with_capacity(max_size.min(u16::MAX as usize));
+
+    for category in messages.iter() {
+        let bytes = encode_category(category)?;
+
+        // Data block alone does not fit into a datagram
+        if bytes.len() > max_size {
+            return Err(CatError::SizeInvalid(max_size));
         }
-        // Append each byte stream to one big byte stream when OK result
-        if result.is_ok() {
-            let bytes = result.unwrap();
-            // TODO Append to byte array
-            sum_bytes = bytes;
-        } else {
-            return result;
+        // Start next datagram when data block does not fit anymore
+        if datagram.len() + bytes.len() > max_size {
+            datagrams.push(datagram.split().freeze());
         }
+        datagram.put(bytes);
+    }
+    if !datagram.is_empty() {
+        datagrams.push(datagram.freeze());
+    }
+    Ok(datagrams)
+}
+
+/*
+ * Encode one ASTERIX category into data block.
+ */
+fn encode_category(category: &Category) -> Result<Bytes, CatError> {
+    match category {
+        Category::Cat001(message) => cat01::encode(message),
+        Category::Cat002(message) => cat02::encode(message),
+        Category::Cat004(message) => cat04::encode(message),
+        Category::Cat007(message) => cat07::encode(message),
+        Category::Cat008(message) => cat08::encode(message),
+        Category::Cat010(message) => cat10::encode(message),
+        Category::Cat011(message) => cat11::encode(message),
+        Category::Cat019(message) => cat19::encode(message),
+        Category::Cat020(message) => cat20::encode(message),
+        Category::Cat021(message) => cat21::encode(message),
+        Category::Cat023(message) => cat23::encode(message),
+        Category::Cat025(message) => cat25::encode(message),
+        Category::Cat034(message) => cat34::encode(message),
+        Category::Cat048(message) => cat48::encode(message),
+        Category::Cat062(message) => cat62::encode(message),
+        Category::Cat063(message) => cat63::encode(message),
+        Category::Cat065(message) => cat65::encode(message),
+        Category::Cat240(message) => cat240::encode(message),
+        Category::Unknown(bytes) => Ok(bytes.clone()),
     }
-    Ok(sum_bytes)
 }
 
 /**
  * Decode byte stream into many ASTERIX categories
+ * Data blocks of unsupported categories are returned as raw bytes
  */
 pub fn decode_asterix(bytes: &Bytes) -> Result<Vec<Category>, CatError> {
+    decode_asterix_with(bytes, &mut ExplicitParsers::default())
+}
+
+/**
+ * Decode byte stream into many ASTERIX categories
+ * Site specific content of explicit length data items is passed to the registered parsers
+ */
+pub fn decode_asterix_with(
+    bytes: &Bytes,
+    parsers: &mut ExplicitParsers,
+) -> Result<Vec<Category>, CatError> {
     // Header length is 3 bytes, contains category and data block length
-    if bytes.len() > 3 {
+    if bytes.len() < Header::MESSAGE_LENGTH {
+        return Err(CatError::SizeInvalid(0));
+    }
+
+    // Collect decoded messages
+    let mut vector: Vec<Category> = Vec::new();
+    let mut offset = 0;
+
+    // Several data blocks can follow each other in one byte stream
+    while offset < bytes.len() {
+        if offset + Header::MESSAGE_LENGTH > bytes.len() {
+            return Err(CatError::SizeInvalid(offset));
+        }
         let array: &[u8] = bytes;
-        let header_array = Header::array_of_byte_message(&array[0..Header::MESSAGE_LENGTH]);
+        let header_array =
+            Header::array_of_byte_message(&array[offset..offset + Header::MESSAGE_LENGTH]);
 
         // New message
         let mut header = Header::default();
@@ -48,30 +142,123 @@ pub fn decode_asterix(bytes: &Bytes) -> Result<Vec<Category>, CatError> {
         header.from_bytes(&header_array);
         let length = header.get_len() as usize;
 
-        // Collect decoded messages
-        let mut vector:Vec<Category> = Vec::new();
-
-        // TODO Process remaining bytes of next message
         // Check for correct data block length
-        if length <= bytes.len() {
-            if header.get_cat() == Cat34Message::CATEGORY {
-                let result = decode(bytes);
-
-                if result.is_ok() {
-                    let message = result.unwrap();
-                    let cat = Category::Cat034(message);
-                    vector.push(cat);
-                    return Ok(vector);
-                } else {
-                    let error = result.err().unwrap();
-                    return Err(error);
-                }
-            } else {
-                return Err(CatError::CategoryInvalid);
+        if length < Header::MESSAGE_LENGTH || offset + length > bytes.len() {
+            return Err(CatError::SizeInvalid(offset));
+        }
+        let block = bytes.slice(offset..offset + length);
+
+        let cat = match header.get_cat() {
+            Cat01Message::CATEGORY => {
+                let message =
+                    cat01::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat001(message)
+            }
+            Cat02Message::CATEGORY => {
+                let message =
+                    cat02::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat002(message)
+            }
+            Cat04Message::CATEGORY => {
+                let message =
+                    cat04::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat004(message)
+            }
+            Cat07Message::CATEGORY => {
+                let message =
+                    cat07::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat007(message)
             }
+            Cat08Message::CATEGORY => {
+                let message =
+                    cat08::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat008(message)
+            }
+            Cat10Message::CATEGORY => {
+                let message =
+                    cat10::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat010(message)
+            }
+            Cat11Message::CATEGORY => {
+                let message =
+                    cat11::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat011(message)
+            }
+            Cat19Message::CATEGORY => {
+                let message =
+                    cat19::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat019(message)
+            }
+            Cat20Message::CATEGORY => {
+                let message =
+                    cat20::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat020(message)
+            }
+            Cat21Message::CATEGORY => {
+                let message =
+                    cat21::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat021(message)
+            }
+            Cat23Message::CATEGORY => {
+                let message =
+                    cat23::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat023(message)
+            }
+            Cat25Message::CATEGORY => {
+                let message =
+                    cat25::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat025(message)
+            }
+            Cat34Message::CATEGORY => {
+                let message =
+                    cat34::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat034(message)
+            }
+            Cat48Message::CATEGORY => {
+                let message =
+                    cat48::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat048(message)
+            }
+            Cat62Message::CATEGORY => {
+                let message =
+                    cat62::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat062(message)
+            }
+            Cat63Message::CATEGORY => {
+                let message =
+                    cat63::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat063(message)
+            }
+            Cat65Message::CATEGORY => {
+                let message =
+                    cat65::decode_with(&block, parsers).map_err(|err| shift_offset(err, offset))?;
+                Category::Cat065(message)
+            }
+            Cat240Message::CATEGORY => {
+                let message = cat240::decode_with(&block, parsers)
+                    .map_err(|err| shift_offset(err, offset))?;
+                Category::Cat240(message)
+            }
+            _ => Category::Unknown(block),
+        };
+        vector.push(cat);
+
+        offset += length;
+    }
+    Ok(vector)
+}
+
+/*
+ * Make offset of size and field spec errors relative to the whole byte stream.
+ */
+fn shift_offset(err: CatError, offset: usize) -> CatError {
+    match err {
+        CatError::SizeInvalid(block_offset) => CatError::SizeInvalid(offset + block_offset),
+        CatError::FieldSpecInvalid(block_offset) => {
+            CatError::FieldSpecInvalid(offset + block_offset)
         }
+        _ => err,
     }
-    Err(CatError::SizeInvalid)
 }
 
 #[cfg(test)]
@@ -79,6 +266,27 @@ mod tests {
     use super::*;
     use crate::asterix::header_field::Header;
     use crate::asterix::record34::Record34;
+    use crate::uap::explicit_field::{ExplicitContent, ExplicitItem};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Site specific content with a single marker
+    struct SiteMarker {
+        marker: u16,
+    }
+
+    impl ExplicitContent for SiteMarker {
+        fn from_bytes(data: &[u8]) -> Result<Self, CatError> {
+            let array: [u8; 2] = data.try_into().map_err(|_| CatError::ValueInvalid)?;
+            Ok(SiteMarker {
+                marker: u16::from_be_bytes(array),
+            })
+        }
+
+        fn to_bytes(&self) -> Vec<u8> {
+            self.marker.to_be_bytes().to_vec()
+        }
+    }
 
     #[test]
     fn test_encode() {
@@ -97,7 +305,60 @@ mod tests {
 
         let result = encode_asterix(&messages);
 
-        assert_eq!(result.is_ok(), true);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_encode_concatenate() {
+        let mut message34 = Cat34Message::default();
+        message34.insert_record34(Record34::default());
+
+        let unknown: &'static [u8] = &[0xfa, 0x00, 0x06, 0x80, 0x7b, 0x2a];
+
+        let messages = vec![
+            Category::Cat034(message34.clone()),
+            Category::Unknown(Bytes::from(unknown)),
+            Category::Cat034(message34.clone()),
+        ];
+
+        let result = encode_asterix(&messages).unwrap();
+        let block34 = cat34::encode(&message34).unwrap();
+
+        assert_eq!(result.len(), 2 * block34.len() + unknown.len());
+        assert_eq!(result.slice(0..block34.len()), block34);
+        assert_eq!(result.slice(block34.len()..block34.len() + unknown.len()), unknown);
+
+        let decoded = decode_asterix(&result).unwrap();
+        assert_eq!(decoded.len(), 3);
+    }
+
+    #[test]
+    fn test_encode_datagrams() {
+        let unknown: &'static [u8] = &[0xfa, 0x00, 0x06, 0x80, 0x7b, 0x2a];
+        let messages = vec![Category::Unknown(Bytes::from(unknown)); 5];
+
+        // Two data blocks fit into one datagram
+        let result = encode_asterix_datagrams(&messages, 15).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].len(), 12);
+        assert_eq!(result[1].len(), 12);
+        assert_eq!(result[2].len(), 6);
+
+        // Data block larger than datagram
+        let result = encode_asterix_datagrams(&messages, 5);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(5)));
+
+        let result = encode_asterix_datagrams(&messages, MAX_DATAGRAM_SIZE).unwrap();
+
+        assert_eq!(result.len(), 1);
+
+        // No limit of the datagram size
+        let result = encode_asterix_datagrams(&messages, usize::MAX).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 30);
     }
 
     #[test]
@@ -118,6 +379,109 @@ mod tests {
 
         let result = decode_asterix(&bytes);
 
-        assert_eq!(result.is_err(), false);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_decode_blocks() {
+        // CAT034 North Marker, unsupported CAT250 and CAT034 Sector Crossing
+        let array: &'static [u8] = &[
+            0x22, 0x00, 0x17, 0xed, 0x10, 0x7b, 0x2a, 0x01, 0x4e, 0x51, 0x7b, 0x01, 0x00, 0x80,
+            0x00, 0x02, 0x2b, 0x21, 0xfe, 0x5b, 0x06, 0x99, 0x0a, 0xfa, 0x00, 0x06, 0x80, 0x7b,
+            0x2a, 0x22, 0x00, 0x0b, 0xf0, 0x7b, 0x2a, 0x02, 0x4e, 0x51, 0x7c, 0x40,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode_asterix(&bytes).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].to_string(), "Cat034");
+        assert_eq!(result[1], Category::Unknown(bytes.slice(23..29)));
+        assert_eq!(result[2].to_string(), "Cat034");
+    }
+
+    #[test]
+    fn test_decode_block_overrun() {
+        // Second data block is longer than the byte stream
+        let array: &'static [u8] = &[
+            0x22, 0x00, 0x0b, 0xf0, 0x7b, 0x2a, 0x02, 0x4e, 0x51, 0x7c, 0x40, 0x30, 0x00, 0x10,
+            0x80,
+        ];
+        let bytes = Bytes::from(array);
+
+        let result = decode_asterix(&bytes);
+
+        assert_eq!(result, Err(CatError::SizeInvalid(11)));
+    }
+
+    #[test]
+    fn test_decode_explicit_parsers() {
+        // CAT034 North Marker with Special Purpose Field
+        let array: &'static [u8] = &[
+            0x22, 0x00, 0x0b, 0xc1, 0x02, 0x7b, 0x2a, 0x01, 0x03, 0xca, 0xfe,
+        ];
+        let bytes = Bytes::from(array);
+
+        let markers = Rc::new(RefCell::new(Vec::new()));
+
+        let mut parsers = ExplicitParsers::default();
+        let handler_markers = Rc::clone(&markers);
+        parsers.register(
+            Cat34Message::CATEGORY,
+            ExplicitItem::SpecialPurpose,
+            move |content: Result<SiteMarker, CatError>| {
+                handler_markers
+                    .borrow_mut()
+                    .push(content.map(|content| content.marker));
+            },
+        );
+
+        let result = decode_asterix_with(&bytes, &mut parsers).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(*markers.borrow(), vec![Ok(0xcafe)]);
+    }
+
+    #[test]
+    fn test_decode_explicit_parsers_failed() {
+        // CAT034 North Marker with short Special Purpose Field, followed by
+        // CAT034 Sector Crossing
+        let array: &'static [u8] = &[
+            0x22, 0x00, 0x0a, 0xc1, 0x02, 0x7b, 0x2a, 0x01, 0x02, 0xca, 0x22, 0x00, 0x0b, 0xf0,
+            0x7b, 0x2a, 0x02, 0x4e, 0x51, 0x7c, 0x40,
+        ];
+        let bytes = Bytes::from(array);
+
+        let markers = Rc::new(RefCell::new(Vec::new()));
+
+        let mut parsers = ExplicitParsers::default();
+        let handler_markers = Rc::clone(&markers);
+        parsers.register(
+            Cat34Message::CATEGORY,
+            ExplicitItem::SpecialPurpose,
+            move |content: Result<SiteMarker, CatError>| {
+                handler_markers
+                    .borrow_mut()
+                    .push(content.map(|content| content.marker));
+            },
+        );
+
+        // Content does not fit the registered parser, the decode goes on
+        let result = decode_asterix_with(&bytes, &mut parsers).unwrap();
+
+        assert_eq!(*markers.borrow(), vec![Err(CatError::ValueInvalid)]);
+        assert_eq!(result.len(), 2);
+
+        let message: Cat34Message = result[0].clone().try_into().unwrap();
+
+        assert_eq!(
+            message.get_records()[0]
+                .special_purpose
+                .as_ref()
+                .unwrap()
+                .get_data(),
+            &[0xca]
+        );
+        assert_eq!(result[1].to_string(), "Cat034");
     }
 }
@@ -0,0 +1,69 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// ACAS Resolution Advisory Report
+//
+// 56 bit MB field of BDS register 3,0
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AcasResolution {
+    report: [u8; 7], // 7 bytes
+}
+
+/*
+* Implementation AcasResolution
+*/
+impl AcasResolution {
+    /*
+     * Set resolution advisory report
+     */
+    pub fn set_report(&mut self, report: [u8; 7]) {
+        self.report = report;
+    }
+
+    /*
+     * Get resolution advisory report
+     */
+    pub fn get_report(&self) -> [u8; 7] {
+        self.report
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for AcasResolution {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let report = fixed_array(array)?;
+        Ok((AcasResolution { report }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.report.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_acas_resolution() {
+        // Create message
+        let mut acas_resolution = AcasResolution::default();
+        acas_resolution.set_report([0x30, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+
+        // Convert struct to byte stream
+        let array = acas_resolution.encode();
+
+        // New message
+        let (object, length) = AcasResolution::decode(&array).unwrap();
+
+        assert_eq!(length, AcasResolution::MESSAGE_LENGTH);
+        assert_eq!(acas_resolution.get_report(), object.get_report());
+    }
+}
@@ -0,0 +1,81 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Aircraft Address, 24 bit ICAO address
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AircraftAddress {
+    address: [u8; 3], // 3 bytes
+}
+
+/*
+* Implementation AircraftAddress
+*/
+impl AircraftAddress {
+    /*
+     * Set aircraft address
+     * 0 <= address <= 0xffffff
+     */
+    pub fn set_address(&mut self, address: u32) -> Result<(), CatError> {
+        if address > 0xff_ffff {
+            return Err(CatError::ValueInvalid);
+        }
+        let address_bytes = address.to_be_bytes();
+
+        self.address[0] = address_bytes[1];
+        self.address[1] = address_bytes[2];
+        self.address[2] = address_bytes[3];
+        Ok(())
+    }
+
+    /*
+     * Get aircraft address
+     */
+    pub fn get_address(&self) -> u32 {
+        u32::from_be_bytes([0, self.address[0], self.address[1], self.address[2]])
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for AircraftAddress {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let address = fixed_array(array)?;
+        Ok((AircraftAddress { address }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.address.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_aircraft_address() {
+        // Create message
+        let mut aircraft_address = AircraftAddress::default();
+        aircraft_address.set_address(0x3c6586).unwrap();
+
+        // Convert struct to byte stream
+        let array = aircraft_address.encode();
+
+        assert_eq!(array, vec![0x3c, 0x65, 0x86]);
+
+        // New message
+        let (object, _length) = AircraftAddress::decode(&array).unwrap();
+
+        assert_eq!(object.get_address(), 0x3c6586);
+        assert_eq!(
+            aircraft_address.set_address(0x1000000),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Aircraft Identification
+//
+// Eight characters with 6 bit International Alphabet No. 5 coding
+//
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AircraftIdentification {
+    identification: [u8; 6], // 6 bytes
+}
+
+impl Default for AircraftIdentification {
+    fn default() -> Self {
+        // Eight space characters
+        let mut identification = AircraftIdentification {
+            identification: [0u8; 6],
+        };
+        identification
+            .set_identification("")
+            .expect("empty identification is valid");
+        identification
+    }
+}
+
+/*
+* Implementation AircraftIdentification
+*/
+impl AircraftIdentification {
+    /*
+     * Set identification, padded with spaces to eight characters
+     * Characters A..Z, 0..9 and space
+     */
+    pub fn set_identification(&mut self, identification: &str) -> Result<(), CatError> {
+        if identification.len() > Self::CHARACTERS {
+            return Err(CatError::ValueInvalid);
+        }
+        let mut bits: u64 = 0;
+        let mut characters = identification.chars();
+
+        for _ in 0..Self::CHARACTERS {
+            let character = characters.next().unwrap_or(' ');
+            bits = (bits << 6) | encode_character(character)? as u64;
+        }
+        self.identification
+            .copy_from_slice(&bits.to_be_bytes()[2..8]);
+        Ok(())
+    }
+
+    /*
+     * Get identification without trailing spaces
+     */
+    pub fn get_identification(&self) -> String {
+        let mut array = [0u8; 8];
+        array[2..8].copy_from_slice(&self.identification);
+        let bits = u64::from_be_bytes(array);
+
+        let identification: String = (0..Self::CHARACTERS)
+            .rev()
+            .map(|index| decode_character(((bits >> (index * 6)) & 0x3f) as u8))
+            .collect();
+        identification.trim_end().to_string()
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Number of characters.
+     */
+    const CHARACTERS: usize = 8;
+}
+
+impl DataItem for AircraftIdentification {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let identification = fixed_array(array)?;
+        Ok((
+            AircraftIdentification { identification },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.identification.to_vec()
+    }
+}
+
+/*
+ * Encode character into 6 bit IA-5 code.
+ */
+fn encode_character(character: char) -> Result<u8, CatError> {
+    match character {
+        'A'..='Z' => Ok(character as u8 - b'A' + 1),
+        ' ' => Ok(32),
+        '0'..='9' => Ok(character as u8),
+        _ => Err(CatError::ValueInvalid),
+    }
+}
+
+/*
+ * Decode character from 6 bit IA-5 code.
+ */
+fn decode_character(code: u8) -> char {
+    match code {
+        1..=26 => (b'A' + code - 1) as char,
+        48..=57 => code as char,
+        _ => ' ',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_aircraft_identification() {
+        // Create message
+        let mut aircraft_identification = AircraftIdentification::default();
+        aircraft_identification
+            .set_identification("DLH123")
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = aircraft_identification.encode();
+
+        assert_eq!(array, vec![0x10, 0xc2, 0x31, 0xcb, 0x38, 0x20]);
+
+        // New message
+        let (object, _length) = AircraftIdentification::decode(&array).unwrap();
+
+        assert_eq!(object.get_identification(), "DLH123");
+        assert_eq!(AircraftIdentification::default().get_identification(), "");
+        assert_eq!(
+            aircraft_identification.set_identification("dlh123"),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
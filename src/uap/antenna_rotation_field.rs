@@ -1,9 +1,11 @@
 use std::mem;
-use deku::prelude::*;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
 
 // Antenna Rotation Period
 //
-#[derive(Default, Debug, PartialEq, Clone, Copy, DekuRead, DekuWrite)]
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
 pub struct AntennaRotation {
     period: u16, // 2 bytes
 }
@@ -38,6 +40,18 @@ impl AntennaRotation {
     const FACTOR: f32 = 1.0 / 128.0;
 }
 
+impl DataItem for AntennaRotation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        // Period is transmitted in network byte order
+        let period = u16::from_be_bytes(fixed_array(array)?);
+        Ok((AntennaRotation { period }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.period.to_be_bytes().to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,13 +63,14 @@ mod tests {
         antenna_rotation.set_period(5.2);
 
         // Convert struct to byte stream
-        let vector = antenna_rotation.to_bytes().unwrap();
-        let array: &[u8] = &vector;
+        let array = antenna_rotation.encode();
+
+        assert_eq!(array, vec![0x02, 0x99]);
 
         // New message
-        let (_rest, object) = AntennaRotation::from_bytes((array, 0)).unwrap();
-        let vvv = array.to_vec();
+        let (object, length) = AntennaRotation::decode(&array).unwrap();
 
+        assert_eq!(length, AntennaRotation::MESSAGE_LENGTH);
         assert_eq!(antenna_rotation.get_period(), object.get_period());
     }
 }
@@ -0,0 +1,98 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Calculated Position in Cartesian Co-ordinates
+//
+// Position has a resolution of 1/64 NM in CAT001
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CartesianPosition {
+    x: i16, // 2 bytes
+    y: i16, // 2 bytes
+}
+
+/*
+* Implementation CartesianPosition
+*/
+impl CartesianPosition {
+    /*
+     * Set position in NM
+     * -512 <= x, y < 512
+     */
+    pub fn set_position(&mut self, x: f32, y: f32) -> Result<(), CatError> {
+        let x = from_signed(x as f64, Self::FACTOR as f64, 16)? as i16;
+        let y = from_signed(y as f64, Self::FACTOR as f64, 16)? as i16;
+
+        self.x = x;
+        self.y = y;
+        Ok(())
+    }
+
+    /*
+     * Get position x in NM
+     */
+    pub fn get_x(&self) -> f32 {
+        self.x as f32 * Self::FACTOR
+    }
+
+    /*
+     * Get position y in NM
+     */
+    pub fn get_y(&self) -> f32 {
+        self.y as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 64.0;
+}
+
+impl DataItem for CartesianPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let x = i16::from_be_bytes([array[0], array[1]]);
+        let y = i16::from_be_bytes([array[2], array[3]]);
+        Ok((CartesianPosition { x, y }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.x.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.y.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cartesian_position() {
+        // Create message
+        let mut cartesian_position = CartesianPosition::default();
+        cartesian_position.set_position(-1.5, 256.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = cartesian_position.encode();
+
+        assert_eq!(array, vec![0xff, 0xa0, 0x40, 0x00]);
+
+        // New message
+        let (object, _length) = CartesianPosition::decode(&array).unwrap();
+
+        assert_eq!(object.get_x(), -1.5);
+        assert_eq!(object.get_y(), 256.0);
+        assert_eq!(
+            cartesian_position.set_position(512.0, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Measured Radial Doppler Speed
+//
+// Positive speed means the target moves away from the radar
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct DopplerSpeed {
+    speed: i8, // 1 byte
+}
+
+/*
+* Implementation DopplerSpeed
+*/
+impl DopplerSpeed {
+    /*
+     * Set radial speed in NM/s
+     * -128 * 2^-14 <= speed < 128 * 2^-14
+     */
+    pub fn set_speed(&mut self, speed: f32) -> Result<(), CatError> {
+        self.speed = from_signed(speed as f64, Self::FACTOR as f64, 8)? as i8;
+        Ok(())
+    }
+
+    /*
+     * Get radial speed in NM/s
+     */
+    pub fn get_speed(&self) -> f32 {
+        self.speed as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 16384.0;
+}
+
+impl DataItem for DopplerSpeed {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let speed = i8::from_be_bytes(fixed_array(array)?);
+        Ok((DopplerSpeed { speed }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.speed.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_doppler_speed() {
+        // Create message
+        let mut doppler_speed = DopplerSpeed::default();
+        doppler_speed.set_speed(-2.0 / 16384.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = doppler_speed.encode();
+
+        assert_eq!(array, vec![0xfe]);
+
+        // New message
+        let (object, length) = DopplerSpeed::decode(&array).unwrap();
+
+        assert_eq!(length, DopplerSpeed::MESSAGE_LENGTH);
+        assert_eq!(object.get_speed(), -2.0 / 16384.0);
+        assert_eq!(
+            doppler_speed.set_speed(128.0 / 16384.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub mod cartesian_position_field;
+pub mod doppler_speed_field;
+pub mod polar_position_field;
+pub mod received_power_field;
+pub mod target_report_descriptor_field;
+pub mod track_status_field;
+pub mod truncated_time_field;
+pub mod x_pulse_field;
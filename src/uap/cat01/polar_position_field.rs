@@ -0,0 +1,106 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Measured Position in Polar Co-ordinates
+//
+// Range has a resolution of 1/128 NM in CAT001
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct PolarPosition {
+    rho: u16,   // 2 bytes
+    theta: u16, // 2 bytes
+}
+
+/*
+* Implementation PolarPosition
+*/
+impl PolarPosition {
+    /*
+     * Set range in NM
+     * 0 <= rho < 512
+     */
+    pub fn set_rho(&mut self, rho: f32) -> Result<(), CatError> {
+        self.rho = from_unsigned(rho as f64, Self::FACTOR_RHO as f64, 16)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get range in NM
+     */
+    pub fn get_rho(&self) -> f32 {
+        self.rho as f32 * Self::FACTOR_RHO
+    }
+
+    /*
+     * Set azimuth in degree
+     * 0 <= theta < 360
+     */
+    pub fn set_theta(&mut self, theta: f32) -> Result<(), CatError> {
+        self.theta = from_unsigned(theta as f64, Self::FACTOR_THETA as f64, 16)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get azimuth in degree
+     */
+    pub fn get_theta(&self) -> f32 {
+        self.theta as f32 * Self::FACTOR_THETA
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of range.
+     */
+    const FACTOR_RHO: f32 = 1.0 / 128.0;
+
+    /*
+     * Conversion factor of azimuth.
+     */
+    const FACTOR_THETA: f32 = 360.0 / 65536.0;
+}
+
+impl DataItem for PolarPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let rho = u16::from_be_bytes([array[0], array[1]]);
+        let theta = u16::from_be_bytes([array[2], array[3]]);
+        Ok((PolarPosition { rho, theta }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.rho.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.theta.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_polar_position() {
+        // Create message
+        let mut polar_position = PolarPosition::default();
+        polar_position.set_rho(128.5).unwrap();
+        polar_position.set_theta(90.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = polar_position.encode();
+
+        assert_eq!(array, vec![0x40, 0x40, 0x40, 0x00]);
+
+        // New message
+        let (object, _length) = PolarPosition::decode(&array).unwrap();
+
+        assert_eq!(object.get_rho(), 128.5);
+        assert_eq!(object.get_theta(), 90.0);
+        assert_eq!(polar_position.set_rho(512.0), Err(CatError::ValueInvalid));
+    }
+}
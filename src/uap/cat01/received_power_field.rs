@@ -0,0 +1,69 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Received Power
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ReceivedPower {
+    power: i8, // 1 byte
+}
+
+/*
+* Implementation ReceivedPower
+*/
+impl ReceivedPower {
+    /*
+     * Set received power in dBm
+     */
+    pub fn set_power(&mut self, power: i8) {
+        self.power = power;
+    }
+
+    /*
+     * Get received power in dBm
+     */
+    pub fn get_power(&self) -> i8 {
+        self.power
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ReceivedPower {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let power = i8::from_be_bytes(fixed_array(array)?);
+        Ok((ReceivedPower { power }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.power.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_received_power() {
+        // Create message
+        let mut received_power = ReceivedPower::default();
+        received_power.set_power(-75);
+
+        // Convert struct to byte stream
+        let array = received_power.encode();
+
+        assert_eq!(array, vec![0xb5]);
+
+        // New message
+        let (object, length) = ReceivedPower::decode(&array).unwrap();
+
+        assert_eq!(length, ReceivedPower::MESSAGE_LENGTH);
+        assert_eq!(object.get_power(), -75);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Target Report Descriptor
+//
+// |  8  |  7  |  6 5    |  4  |  3  |  2  | 1  | bit
+// | TYP | SIM | SSR/PSR | ANT | SPI | RAB | FX |
+// | TST | DS1 DS2 | ME  | MI  |   spare   | FX |
+//
+
+/// Type of report, selects the UAP of the record
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ReportType {
+    Plot,
+    Track,
+}
+
+/// Type of radar detection
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum RadarDetection {
+    NoDetection,
+    Psr,
+    Ssr,
+    SsrPsr,
+}
+
+/// Emergency condition of the Mode-3/A code
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum EmergencyCode {
+    Default,
+    UnlawfulInterference,
+    RadioCommunicationFailure,
+    Emergency,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TargetReportDescriptor {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TargetReportDescriptor
+*/
+impl TargetReportDescriptor {
+    /*
+     * Set report type
+     */
+    pub fn set_report_type(&mut self, report_type: ReportType) {
+        self.field.set_bits(0, 8, 1, report_type as u8);
+    }
+
+    /*
+     * Get report type
+     */
+    pub fn get_report_type(&self) -> ReportType {
+        match self.field.get_bits(0, 8, 1) {
+            0 => ReportType::Plot,
+            _ => ReportType::Track,
+        }
+    }
+
+    /*
+     * Set simulated target report
+     */
+    pub fn set_simulated(&mut self, value: bool) {
+        self.field.set_bit(0, 7, value);
+    }
+
+    /*
+     * Get simulated target report
+     */
+    pub fn get_simulated(&self) -> bool {
+        self.field.get_bit(0, 7)
+    }
+
+    /*
+     * Set type of radar detection
+     */
+    pub fn set_detection(&mut self, detection: RadarDetection) {
+        self.field.set_bits(0, 6, 2, detection as u8);
+    }
+
+    /*
+     * Get type of radar detection
+     */
+    pub fn get_detection(&self) -> RadarDetection {
+        match self.field.get_bits(0, 6, 2) {
+            0 => RadarDetection::NoDetection,
+            1 => RadarDetection::Psr,
+            2 => RadarDetection::Ssr,
+            _ => RadarDetection::SsrPsr,
+        }
+    }
+
+    /*
+     * Set report from antenna 2
+     */
+    pub fn set_antenna_2(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get report from antenna 2
+     */
+    pub fn get_antenna_2(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set special position identification
+     */
+    pub fn set_spi(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get special position identification
+     */
+    pub fn get_spi(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set report from field monitor (fixed transponder)
+     */
+    pub fn set_field_monitor(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get report from field monitor (fixed transponder)
+     */
+    pub fn get_field_monitor(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set test target report
+     */
+    pub fn set_test(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get test target report
+     */
+    pub fn get_test(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set emergency condition
+     */
+    pub fn set_emergency(&mut self, emergency: EmergencyCode) {
+        self.field.set_bits(1, 7, 2, emergency as u8);
+    }
+
+    /*
+     * Get emergency condition
+     */
+    pub fn get_emergency(&self) -> EmergencyCode {
+        match self.field.get_bits(1, 7, 2) {
+            0 => EmergencyCode::Default,
+            1 => EmergencyCode::UnlawfulInterference,
+            2 => EmergencyCode::RadioCommunicationFailure,
+            _ => EmergencyCode::Emergency,
+        }
+    }
+
+    /*
+     * Set military emergency
+     */
+    pub fn set_military_emergency(&mut self, value: bool) {
+        self.field.set_bit(1, 5, value);
+    }
+
+    /*
+     * Get military emergency
+     */
+    pub fn get_military_emergency(&self) -> bool {
+        self.field.get_bit(1, 5)
+    }
+
+    /*
+     * Set military identification
+     */
+    pub fn set_military_identification(&mut self, value: bool) {
+        self.field.set_bit(1, 4, value);
+    }
+
+    /*
+     * Get military identification
+     */
+    pub fn get_military_identification(&self) -> bool {
+        self.field.get_bit(1, 4)
+    }
+}
+
+impl DataItem for TargetReportDescriptor {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TargetReportDescriptor { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_report_descriptor() {
+        // Create message
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_report_type(ReportType::Track);
+        target_report_descriptor.set_detection(RadarDetection::SsrPsr);
+        target_report_descriptor.set_spi(true);
+        target_report_descriptor.set_emergency(EmergencyCode::Emergency);
+
+        // Convert struct to byte stream
+        let array = target_report_descriptor.encode();
+
+        assert_eq!(array, vec![0xb5, 0x60]);
+
+        // New message
+        let (object, length) = TargetReportDescriptor::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_report_type(), ReportType::Track);
+        assert_eq!(object.get_detection(), RadarDetection::SsrPsr);
+        assert!(object.get_spi());
+        assert!(!object.get_simulated());
+        assert_eq!(object.get_emergency(), EmergencyCode::Emergency);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Track Status
+//
+// |  8  |  7  |  6  |  5  |  4   |   3   |  2  | 1  | bit
+// | CON | RAD | MAN | DOU | RDPC | spare | GHO | FX |
+// | TRE |           spare                      | FX |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrackStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TrackStatus
+*/
+impl TrackStatus {
+    /*
+     * Set track in initialisation phase
+     */
+    pub fn set_initialisation(&mut self, value: bool) {
+        self.field.set_bit(0, 8, value);
+    }
+
+    /*
+     * Get track in initialisation phase
+     */
+    pub fn get_initialisation(&self) -> bool {
+        self.field.get_bit(0, 8)
+    }
+
+    /*
+     * Set SSR or combined track, otherwise primary track
+     */
+    pub fn set_ssr_track(&mut self, value: bool) {
+        self.field.set_bit(0, 7, value);
+    }
+
+    /*
+     * Get SSR or combined track, otherwise primary track
+     */
+    pub fn get_ssr_track(&self) -> bool {
+        self.field.get_bit(0, 7)
+    }
+
+    /*
+     * Set manoeuvre detected
+     */
+    pub fn set_manoeuvre(&mut self, value: bool) {
+        self.field.set_bit(0, 6, value);
+    }
+
+    /*
+     * Get manoeuvre detected
+     */
+    pub fn get_manoeuvre(&self) -> bool {
+        self.field.get_bit(0, 6)
+    }
+
+    /*
+     * Set doubtful plot to track association
+     */
+    pub fn set_doubtful(&mut self, value: bool) {
+        self.field.set_bit(0, 5, value);
+    }
+
+    /*
+     * Get doubtful plot to track association
+     */
+    pub fn get_doubtful(&self) -> bool {
+        self.field.get_bit(0, 5)
+    }
+
+    /*
+     * Set track from RDP chain 2
+     */
+    pub fn set_rdp_chain_2(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get track from RDP chain 2
+     */
+    pub fn get_rdp_chain_2(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set ghost track
+     */
+    pub fn set_ghost(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get ghost track
+     */
+    pub fn get_ghost(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set last report for the track
+     */
+    pub fn set_end_of_track(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get last report for the track
+     */
+    pub fn get_end_of_track(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+}
+
+impl DataItem for TrackStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TrackStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_status() {
+        // Create message
+        let mut track_status = TrackStatus::default();
+        track_status.set_ssr_track(true);
+        track_status.set_manoeuvre(true);
+        track_status.set_end_of_track(true);
+
+        // Convert struct to byte stream
+        let array = track_status.encode();
+
+        assert_eq!(array, vec![0x61, 0x80]);
+
+        // New message
+        let (object, length) = TrackStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert!(object.get_ssr_track());
+        assert!(object.get_manoeuvre());
+        assert!(!object.get_initialisation());
+        assert!(object.get_end_of_track());
+    }
+}
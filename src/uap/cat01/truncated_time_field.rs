@@ -0,0 +1,79 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Truncated Time of Day
+//
+// Time of day modulo 512 seconds
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TruncatedTime {
+    time: u16, // 2 bytes
+}
+
+/*
+* Implementation TruncatedTime
+*/
+impl TruncatedTime {
+    /*
+     * Set truncated time in seconds
+     * 0 <= time < 512
+     */
+    pub fn set_time(&mut self, time: f32) -> Result<(), CatError> {
+        self.time = from_unsigned(time as f64, Self::FACTOR as f64, 16)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get truncated time in seconds
+     */
+    pub fn get_time(&self) -> f32 {
+        self.time as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 128.0;
+}
+
+impl DataItem for TruncatedTime {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let time = u16::from_be_bytes(fixed_array(array)?);
+        Ok((TruncatedTime { time }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.time.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_truncated_time() {
+        // Create message
+        let mut truncated_time = TruncatedTime::default();
+        truncated_time.set_time(300.25).unwrap();
+
+        // Convert struct to byte stream
+        let array = truncated_time.encode();
+
+        assert_eq!(array, vec![0x96, 0x20]);
+
+        // New message
+        let (object, length) = TruncatedTime::decode(&array).unwrap();
+
+        assert_eq!(length, TruncatedTime::MESSAGE_LENGTH);
+        assert_eq!(object.get_time(), 300.25);
+        assert_eq!(truncated_time.set_time(512.0), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,121 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Presence of X-Pulse
+//
+// | 8  |  7  | 6  | 5 4   | 3  | 2 1   | bit
+// | XA |spare| XC | spare | X2 | spare |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct XPulse {
+    presence: u8, // 1 byte
+}
+
+/*
+* Implementation XPulse
+*/
+impl XPulse {
+    /*
+     * Set X-pulse received in Mode-3/A reply
+     */
+    pub fn set_mode_3a(&mut self, value: bool) {
+        self.set_bit(Self::XA, value);
+    }
+
+    /*
+     * Get X-pulse received in Mode-3/A reply
+     */
+    pub fn get_mode_3a(&self) -> bool {
+        self.presence & Self::XA != 0
+    }
+
+    /*
+     * Set X-pulse received in Mode-C reply
+     */
+    pub fn set_mode_c(&mut self, value: bool) {
+        self.set_bit(Self::XC, value);
+    }
+
+    /*
+     * Get X-pulse received in Mode-C reply
+     */
+    pub fn get_mode_c(&self) -> bool {
+        self.presence & Self::XC != 0
+    }
+
+    /*
+     * Set X-pulse received in Mode-2 reply
+     */
+    pub fn set_mode_2(&mut self, value: bool) {
+        self.set_bit(Self::X2, value);
+    }
+
+    /*
+     * Get X-pulse received in Mode-2 reply
+     */
+    pub fn get_mode_2(&self) -> bool {
+        self.presence & Self::X2 != 0
+    }
+
+    /*
+     * Set or clear flag of the bit mask
+     */
+    fn set_bit(&mut self, mask: u8, value: bool) {
+        if value {
+            self.presence |= mask;
+        } else {
+            self.presence &= !mask;
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Bit masks of the X-pulse flags.
+     */
+    const XA: u8 = 0x80;
+    const XC: u8 = 0x20;
+    const X2: u8 = 0x04;
+}
+
+impl DataItem for XPulse {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [presence] = fixed_array(array)?;
+        Ok((XPulse { presence }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.presence]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_x_pulse() {
+        // Create message
+        let mut x_pulse = XPulse::default();
+        x_pulse.set_mode_3a(true);
+        x_pulse.set_mode_2(true);
+
+        // Convert struct to byte stream
+        let array = x_pulse.encode();
+
+        assert_eq!(array, vec![0x84]);
+
+        // New message
+        let (object, length) = XPulse::decode(&array).unwrap();
+
+        assert_eq!(length, XPulse::MESSAGE_LENGTH);
+        assert!(object.get_mode_3a());
+        assert!(!object.get_mode_c());
+        assert!(object.get_mode_2());
+    }
+}
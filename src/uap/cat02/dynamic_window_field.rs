@@ -0,0 +1,143 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Dynamic Window - Type 1
+//
+// | 64 .. 49  | 48 .. 33 | 32 .. 17    | 16 .. 1   | bit
+// | RHO START | RHO END  | THETA START | THETA END |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct DynamicWindow {
+    rho_start: u16,   // 2 bytes
+    rho_end: u16,     // 2 bytes
+    theta_start: u16, // 2 bytes
+    theta_end: u16,   // 2 bytes
+}
+
+/*
+* Implementation DynamicWindow
+*/
+impl DynamicWindow {
+    /*
+     * Set range of the window in NM
+     * 0 <= start, end < 512
+     */
+    pub fn set_rho(&mut self, start: f32, end: f32) -> Result<(), CatError> {
+        let start = from_unsigned(start as f64, Self::FACTOR_RHO as f64, 16)? as u16;
+        let end = from_unsigned(end as f64, Self::FACTOR_RHO as f64, 16)? as u16;
+
+        self.rho_start = start;
+        self.rho_end = end;
+        Ok(())
+    }
+
+    /*
+     * Get start range in NM
+     */
+    pub fn get_rho_start(&self) -> f32 {
+        self.rho_start as f32 * Self::FACTOR_RHO
+    }
+
+    /*
+     * Get end range in NM
+     */
+    pub fn get_rho_end(&self) -> f32 {
+        self.rho_end as f32 * Self::FACTOR_RHO
+    }
+
+    /*
+     * Set azimuth of the window in degree
+     * 0 <= start, end < 360
+     */
+    pub fn set_theta(&mut self, start: f32, end: f32) -> Result<(), CatError> {
+        let start = from_unsigned(start as f64, Self::FACTOR_THETA as f64, 16)? as u16;
+        let end = from_unsigned(end as f64, Self::FACTOR_THETA as f64, 16)? as u16;
+
+        self.theta_start = start;
+        self.theta_end = end;
+        Ok(())
+    }
+
+    /*
+     * Get start azimuth in degree
+     */
+    pub fn get_theta_start(&self) -> f32 {
+        self.theta_start as f32 * Self::FACTOR_THETA
+    }
+
+    /*
+     * Get end azimuth in degree
+     */
+    pub fn get_theta_end(&self) -> f32 {
+        self.theta_end as f32 * Self::FACTOR_THETA
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of range.
+     */
+    const FACTOR_RHO: f32 = 1.0 / 128.0;
+
+    /*
+     * Conversion factor of azimuth.
+     */
+    const FACTOR_THETA: f32 = 360.0 / 65536.0;
+}
+
+impl DataItem for DynamicWindow {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 8] = fixed_array(array)?;
+        let dynamic_window = DynamicWindow {
+            rho_start: u16::from_be_bytes([array[0], array[1]]),
+            rho_end: u16::from_be_bytes([array[2], array[3]]),
+            theta_start: u16::from_be_bytes([array[4], array[5]]),
+            theta_end: u16::from_be_bytes([array[6], array[7]]),
+        };
+        Ok((dynamic_window, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.rho_start.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.rho_end.to_be_bytes());
+        vector.extend_from_slice(&self.theta_start.to_be_bytes());
+        vector.extend_from_slice(&self.theta_end.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_dynamic_window() {
+        // Create message
+        let mut dynamic_window = DynamicWindow::default();
+        dynamic_window.set_rho(2.0, 256.5).unwrap();
+        dynamic_window.set_theta(90.0, 180.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = dynamic_window.encode();
+
+        assert_eq!(array, vec![0x01, 0x00, 0x80, 0x40, 0x40, 0x00, 0x80, 0x00]);
+
+        // New message
+        let (object, length) = DynamicWindow::decode(&array).unwrap();
+
+        assert_eq!(length, DynamicWindow::MESSAGE_LENGTH);
+        assert_eq!(object.get_rho_start(), 2.0);
+        assert_eq!(object.get_rho_end(), 256.5);
+        assert_eq!(object.get_theta_start(), 90.0);
+        assert_eq!(object.get_theta_end(), 180.0);
+        assert_eq!(
+            dynamic_window.set_rho(0.0, 512.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
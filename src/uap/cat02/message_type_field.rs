@@ -0,0 +1,91 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Monoradar Service Message Type
+//
+
+/// Types of monoradar service messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ServiceMessage {
+    Unknown,
+    NorthMarker,
+    SectorCrossing,
+    SouthMarker,
+    BlindZoneFilteringStart = 8,
+    BlindZoneFilteringStop = 9,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ServiceMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation ServiceMessageType
+*/
+impl ServiceMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: ServiceMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> ServiceMessage {
+        match self.message {
+            1 => ServiceMessage::NorthMarker,
+            2 => ServiceMessage::SectorCrossing,
+            3 => ServiceMessage::SouthMarker,
+            8 => ServiceMessage::BlindZoneFilteringStart,
+            9 => ServiceMessage::BlindZoneFilteringStop,
+            _ => ServiceMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ServiceMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((ServiceMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_message_type() {
+        // Create message
+        let mut message_type = ServiceMessageType::default();
+        message_type.set_message_type(ServiceMessage::BlindZoneFilteringStop);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x09]);
+
+        // New message
+        let (object, length) = ServiceMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, ServiceMessageType::MESSAGE_LENGTH);
+        assert_eq!(
+            object.get_message_type(),
+            ServiceMessage::BlindZoneFilteringStop
+        );
+    }
+}
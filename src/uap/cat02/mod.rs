@@ -0,0 +1,3 @@
+pub mod dynamic_window_field;
+pub mod message_type_field;
+pub mod plot_count_field;
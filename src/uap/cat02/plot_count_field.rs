@@ -0,0 +1,145 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Plot Count Values
+//
+// Repetition factor followed by counters
+// | 16 | 15 .. 11 | 10 .. 1 | bit
+// | A  |  IDENT   | COUNTER |
+//
+
+/// Types of plot counters
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum PlotCountType {
+    Unknown,
+    SolePrimary,
+    SoleSsr,
+    Combined,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct PlotCount {
+    counters: Vec<u16>, // 2 bytes each
+}
+
+/*
+* Implementation PlotCount
+*/
+impl PlotCount {
+    /*
+     * Insert plot counter of antenna 1 or antenna 2
+     * 0 <= count <= 1023
+     */
+    pub fn insert_count(
+        &mut self,
+        antenna_2: bool,
+        typ: PlotCountType,
+        count: u16,
+    ) -> Result<(), CatError> {
+        if typ == PlotCountType::Unknown
+            || count > Self::COUNTER_MAX
+            || self.counters.len() >= u8::MAX as usize
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        let value = ((antenna_2 as u16) << 15) | ((typ as u16) << 10) | count;
+        self.counters.push(value);
+        Ok(())
+    }
+
+    /*
+     * Get all counters with antenna 2 flag and plot type
+     */
+    pub fn get_counts(&self) -> Vec<(bool, PlotCountType, u16)> {
+        self.counters
+            .iter()
+            .map(|counter| {
+                let typ = match (counter >> 10) & 0x1f {
+                    1 => PlotCountType::SolePrimary,
+                    2 => PlotCountType::SoleSsr,
+                    3 => PlotCountType::Combined,
+                    _ => PlotCountType::Unknown,
+                };
+                (counter & 0x8000 != 0, typ, counter & Self::COUNTER_MAX)
+            })
+            .collect()
+    }
+
+    /*
+     * Length of one counter.
+     */
+    const COUNTER_LENGTH: usize = 2;
+
+    /*
+     * Maximum counter value in 10 bits.
+     */
+    const COUNTER_MAX: u16 = 0x3ff;
+}
+
+impl DataItem for PlotCount {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::COUNTER_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let counters = array[1..length]
+            .chunks_exact(Self::COUNTER_LENGTH)
+            .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
+            .collect();
+
+        Ok((PlotCount { counters }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.counters.len() as u8];
+
+        for counter in self.counters.iter() {
+            vector.extend_from_slice(&counter.to_be_bytes());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_plot_count() {
+        // Create message
+        let mut plot_count = PlotCount::default();
+        plot_count
+            .insert_count(false, PlotCountType::SolePrimary, 1000)
+            .unwrap();
+        plot_count
+            .insert_count(true, PlotCountType::Combined, 5)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = plot_count.encode();
+
+        assert_eq!(array, vec![0x02, 0x07, 0xe8, 0x8c, 0x05]);
+
+        // New message
+        let (object, length) = PlotCount::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(
+            object.get_counts(),
+            vec![
+                (false, PlotCountType::SolePrimary, 1000),
+                (true, PlotCountType::Combined, 5)
+            ]
+        );
+        assert_eq!(
+            plot_count.insert_count(false, PlotCountType::SoleSsr, 1024),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            PlotCount::decode(&[0x02, 0x07, 0xe8]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
@@ -0,0 +1,429 @@
+use crate::category::CatError;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_signed, from_unsigned, to_signed, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Aircraft Identification and Characteristics
+//
+// Compound data item, primary subfield followed by present subfields
+// | AI | M3A | CPW | CPC | TT  | DT | AC | FX |
+// | MS | FP  | CF  |     spare ...      | FX |
+//
+// Predicted conflict position WGS-84
+// | latitude 4 bytes | longitude 4 bytes | altitude 2 bytes |
+//
+// Predicted conflict position Cartesian
+// | X 3 bytes | Y 3 bytes | Z 2 bytes |
+//
+
+/// Subfields of aircraft identification and characteristics
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    AircraftIdentifier,
+    Mode3ACode,
+    ConflictPositionWgs,
+    ConflictPositionCartesian,
+    TimeToThreshold,
+    DistanceToThreshold,
+    AircraftCharacteristics,
+    ModeSIdentifier,
+    FlightPlanNumber,
+    ClearedFlightLevel,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct AircraftCharacteristics {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation AircraftCharacteristics
+*/
+impl AircraftCharacteristics {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set aircraft identifier, up to seven characters
+     */
+    pub fn set_identifier(&mut self, identifier: &str) -> Result<(), CatError> {
+        if identifier.len() > Self::IDENTIFIER_LENGTH
+            || !identifier.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        let data = format!("{:<width$}", identifier, width = Self::IDENTIFIER_LENGTH).into_bytes();
+        self.field
+            .set_subfield(Subfield::AircraftIdentifier as usize, data);
+        Ok(())
+    }
+
+    /*
+     * Get aircraft identifier without trailing spaces
+     */
+    pub fn get_identifier(&self) -> Option<String> {
+        let value = self.get_subfield(Subfield::AircraftIdentifier)?;
+        Some(String::from_utf8_lossy(value).trim_end().to_string())
+    }
+
+    /*
+     * Set Mode 3/A code in octal representation
+     * 0 <= code <= 0o7777
+     */
+    pub fn set_mode_3a_code(&mut self, code: u16) -> Result<(), CatError> {
+        if code > 0o7777 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field
+            .set_subfield(Subfield::Mode3ACode as usize, code.to_be_bytes().to_vec());
+        Ok(())
+    }
+
+    /*
+     * Get Mode 3/A code in octal representation
+     */
+    pub fn get_mode_3a_code(&self) -> Option<u16> {
+        let value = self.get_subfield(Subfield::Mode3ACode)?;
+        Some(u16::from_be_bytes([value[0], value[1]]) & 0o7777)
+    }
+
+    /*
+     * Set predicted conflict position in degree and altitude in ft
+     * -90 <= latitude <= 90
+     * -180 <= longitude < 180
+     */
+    pub fn set_conflict_position_wgs(
+        &mut self,
+        latitude: f64,
+        longitude: f64,
+        altitude: f32,
+    ) -> Result<(), CatError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..180.0).contains(&longitude) {
+            return Err(CatError::ValueInvalid);
+        }
+        let mut data = Vec::with_capacity(10);
+        data.extend(from_signed(latitude, Self::FACTOR_WGS, 32)?.to_be_bytes());
+        data.extend(from_signed(longitude, Self::FACTOR_WGS, 32)?.to_be_bytes());
+        data.extend(
+            (from_signed(altitude as f64, Self::FACTOR_ALTITUDE as f64, 16)? as u16).to_be_bytes(),
+        );
+        self.field
+            .set_subfield(Subfield::ConflictPositionWgs as usize, data);
+        Ok(())
+    }
+
+    /*
+     * Get predicted conflict position as latitude and longitude in degree and altitude in ft
+     */
+    pub fn get_conflict_position_wgs(&self) -> Option<(f64, f64, f32)> {
+        let value = self.get_subfield(Subfield::ConflictPositionWgs)?;
+        let latitude = i32::from_be_bytes([value[0], value[1], value[2], value[3]]);
+        let longitude = i32::from_be_bytes([value[4], value[5], value[6], value[7]]);
+        let altitude = i16::from_be_bytes([value[8], value[9]]);
+
+        Some((
+            latitude as f64 * Self::FACTOR_WGS,
+            longitude as f64 * Self::FACTOR_WGS,
+            altitude as f32 * Self::FACTOR_ALTITUDE,
+        ))
+    }
+
+    /*
+     * Set predicted conflict position in m and altitude in ft
+     * -4194304 <= x, y < 4194304
+     */
+    pub fn set_conflict_position_cartesian(
+        &mut self,
+        x: f64,
+        y: f64,
+        altitude: f32,
+    ) -> Result<(), CatError> {
+        let mut data = Vec::with_capacity(8);
+        data.extend(&from_signed(x, Self::FACTOR_DISTANCE, 24)?.to_be_bytes()[1..4]);
+        data.extend(&from_signed(y, Self::FACTOR_DISTANCE, 24)?.to_be_bytes()[1..4]);
+        data.extend(
+            (from_signed(altitude as f64, Self::FACTOR_ALTITUDE as f64, 16)? as u16).to_be_bytes(),
+        );
+        self.field
+            .set_subfield(Subfield::ConflictPositionCartesian as usize, data);
+        Ok(())
+    }
+
+    /*
+     * Get predicted conflict position as x and y in m and altitude in ft
+     */
+    pub fn get_conflict_position_cartesian(&self) -> Option<(f64, f64, f32)> {
+        let value = self.get_subfield(Subfield::ConflictPositionCartesian)?;
+        let x = to_signed(u32::from_be_bytes([0, value[0], value[1], value[2]]), 24);
+        let y = to_signed(u32::from_be_bytes([0, value[3], value[4], value[5]]), 24);
+        let altitude = i16::from_be_bytes([value[6], value[7]]);
+
+        Some((
+            x as f64 * Self::FACTOR_DISTANCE,
+            y as f64 * Self::FACTOR_DISTANCE,
+            altitude as f32 * Self::FACTOR_ALTITUDE,
+        ))
+    }
+
+    /*
+     * Set time to runway threshold in s
+     * 0 <= time < 131072
+     */
+    pub fn set_time_to_threshold(&mut self, time: f64) -> Result<(), CatError> {
+        let value = from_unsigned(time, Self::FACTOR_TIME, 24)?;
+        self.field.set_subfield(
+            Subfield::TimeToThreshold as usize,
+            value.to_be_bytes()[1..4].to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get time to runway threshold in s
+     */
+    pub fn get_time_to_threshold(&self) -> Option<f64> {
+        let value = self.get_subfield(Subfield::TimeToThreshold)?;
+        Some(u32::from_be_bytes([0, value[0], value[1], value[2]]) as f64 * Self::FACTOR_TIME)
+    }
+
+    /*
+     * Set distance to runway threshold in m
+     * 0 <= distance < 32768
+     */
+    pub fn set_distance_to_threshold(&mut self, distance: f64) -> Result<(), CatError> {
+        let value = from_unsigned(distance, Self::FACTOR_DISTANCE, 16)? as u16;
+        self.field.set_subfield(
+            Subfield::DistanceToThreshold as usize,
+            value.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get distance to runway threshold in m
+     */
+    pub fn get_distance_to_threshold(&self) -> Option<f64> {
+        let value = self.get_subfield(Subfield::DistanceToThreshold)?;
+        Some(u16::from_be_bytes([value[0], value[1]]) as f64 * Self::FACTOR_DISTANCE)
+    }
+
+    /*
+     * Set aircraft characteristics, octets with FX bit
+     */
+    pub fn set_characteristics(&mut self, characteristics: &ExtendedField) {
+        self.field
+            .set_item(Subfield::AircraftCharacteristics as usize, characteristics);
+    }
+
+    /*
+     * Get aircraft characteristics when present
+     */
+    pub fn get_characteristics(&self) -> Option<ExtendedField> {
+        self.field
+            .get_item(Subfield::AircraftCharacteristics as usize)
+    }
+
+    /*
+     * Set Mode S identifier, up to eight characters A..Z, 0..9 and space
+     */
+    pub fn set_mode_s_identifier(&mut self, identifier: &str) -> Result<(), CatError> {
+        let mut mode_s_identifier = AircraftIdentification::default();
+        mode_s_identifier.set_identification(identifier)?;
+        self.field
+            .set_item(Subfield::ModeSIdentifier as usize, &mode_s_identifier);
+        Ok(())
+    }
+
+    /*
+     * Get Mode S identifier without trailing spaces
+     */
+    pub fn get_mode_s_identifier(&self) -> Option<String> {
+        self.field
+            .get_item::<AircraftIdentification>(Subfield::ModeSIdentifier as usize)
+            .map(|identifier| identifier.get_identification())
+    }
+
+    /*
+     * Set flight plan number
+     * 0 <= number < 2^27
+     */
+    pub fn set_flight_plan_number(&mut self, number: u32) -> Result<(), CatError> {
+        if number > Self::FLIGHT_PLAN_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_subfield(
+            Subfield::FlightPlanNumber as usize,
+            number.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get flight plan number
+     */
+    pub fn get_flight_plan_number(&self) -> Option<u32> {
+        let value = self.get_subfield(Subfield::FlightPlanNumber)?;
+        Some(u32::from_be_bytes([value[0], value[1], value[2], value[3]]) & Self::FLIGHT_PLAN_MAX)
+    }
+
+    /*
+     * Set cleared flight level in FL
+     * -8192 <= level < 8192
+     */
+    pub fn set_cleared_flight_level(&mut self, level: f32) -> Result<(), CatError> {
+        let value = from_signed(level as f64, Self::FACTOR_LEVEL as f64, 16)? as u16;
+        self.field.set_subfield(
+            Subfield::ClearedFlightLevel as usize,
+            value.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get cleared flight level in FL
+     */
+    pub fn get_cleared_flight_level(&self) -> Option<f32> {
+        let value = self.get_subfield(Subfield::ClearedFlightLevel)?;
+        Some(i16::from_be_bytes([value[0], value[1]]) as f32 * Self::FACTOR_LEVEL)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 10] = [
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(10),
+        SubfieldLength::Fixed(8),
+        SubfieldLength::Fixed(3),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Extended,
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(2),
+    ];
+
+    /*
+     * Number of characters of the aircraft identifier.
+     */
+    const IDENTIFIER_LENGTH: usize = 7;
+
+    /*
+     * Maximum flight plan number in 27 bits.
+     */
+    const FLIGHT_PLAN_MAX: u32 = 0x07ff_ffff;
+
+    /*
+     * Conversion factor of latitude and longitude.
+     */
+    const FACTOR_WGS: f64 = 180.0 / 33554432.0;
+
+    /*
+     * Conversion factor of altitude.
+     */
+    const FACTOR_ALTITUDE: f32 = 25.0;
+
+    /*
+     * Conversion factor of distances.
+     */
+    const FACTOR_DISTANCE: f64 = 0.5;
+
+    /*
+     * Conversion factor of time to threshold.
+     */
+    const FACTOR_TIME: f64 = 1.0 / 128.0;
+
+    /*
+     * Conversion factor of cleared flight level.
+     */
+    const FACTOR_LEVEL: f32 = 1.0 / 4.0;
+}
+
+impl DataItem for AircraftCharacteristics {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((AircraftCharacteristics { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_aircraft_characteristics() {
+        // Create message
+        let mut aircraft_characteristics = AircraftCharacteristics::default();
+        aircraft_characteristics.set_identifier("DLH4AB").unwrap();
+        aircraft_characteristics.set_mode_3a_code(0o1234).unwrap();
+        aircraft_characteristics
+            .set_conflict_position_wgs(45.0, -90.0, 35000.0)
+            .unwrap();
+        aircraft_characteristics
+            .set_conflict_position_cartesian(-1000.5, 2000.0, 10000.0)
+            .unwrap();
+        aircraft_characteristics
+            .set_mode_s_identifier("DLH4AB")
+            .unwrap();
+        aircraft_characteristics
+            .set_cleared_flight_level(350.0)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = aircraft_characteristics.encode();
+
+        assert_eq!(&array[0..2], &[0xf1, 0xa0]);
+        assert_eq!(&array[2..9], b"DLH4AB ");
+        assert_eq!(&array[9..11], &[0x02, 0x9c]);
+        assert_eq!(
+            &array[11..21],
+            &[0x00, 0x80, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x05, 0x78]
+        );
+        assert_eq!(
+            &array[21..29],
+            &[0xff, 0xf8, 0x2f, 0x00, 0x0f, 0xa0, 0x01, 0x90]
+        );
+        assert_eq!(&array[35..37], &[0x05, 0x78]);
+        assert_eq!(array.len(), 37);
+
+        // New message
+        let (object, length) = AircraftCharacteristics::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, aircraft_characteristics);
+        assert_eq!(object.get_identifier(), Some("DLH4AB".to_string()));
+        assert_eq!(object.get_mode_3a_code(), Some(0o1234));
+        assert_eq!(
+            object.get_conflict_position_wgs(),
+            Some((45.0, -90.0, 35000.0))
+        );
+        assert_eq!(
+            object.get_conflict_position_cartesian(),
+            Some((-1000.5, 2000.0, 10000.0))
+        );
+        assert_eq!(object.get_mode_s_identifier(), Some("DLH4AB".to_string()));
+        assert_eq!(object.get_cleared_flight_level(), Some(350.0));
+        assert_eq!(object.get_time_to_threshold(), None);
+        assert_eq!(
+            aircraft_characteristics.set_mode_3a_code(0o10000),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
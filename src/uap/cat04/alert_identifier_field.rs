@@ -0,0 +1,69 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Alert Identifier
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AlertIdentifier {
+    identifier: u16, // 2 bytes
+}
+
+/*
+* Implementation AlertIdentifier
+*/
+impl AlertIdentifier {
+    /*
+     * Set alert identifier
+     */
+    pub fn set_identifier(&mut self, identifier: u16) {
+        self.identifier = identifier;
+    }
+
+    /*
+     * Get alert identifier
+     */
+    pub fn get_identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for AlertIdentifier {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let identifier = u16::from_be_bytes(fixed_array(array)?);
+        Ok((AlertIdentifier { identifier }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.identifier.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_alert_identifier() {
+        // Create message
+        let mut alert_identifier = AlertIdentifier::default();
+        alert_identifier.set_identifier(4242);
+
+        // Convert struct to byte stream
+        let array = alert_identifier.encode();
+
+        assert_eq!(array, vec![0x10, 0x92]);
+
+        // New message
+        let (object, length) = AlertIdentifier::decode(&array).unwrap();
+
+        assert_eq!(length, AlertIdentifier::MESSAGE_LENGTH);
+        assert_eq!(object.get_identifier(), 4242);
+    }
+}
@@ -0,0 +1,83 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Alert Status
+//
+// | 8 7 6 5 | 4 3 2 |   1   | bit
+// |  spare  | STAT  | spare |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AlertStatus {
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation AlertStatus
+*/
+impl AlertStatus {
+    /*
+     * Set alert status, meaning depends on the safety net function
+     * 0 <= status <= 7
+     */
+    pub fn set_status(&mut self, status: u8) -> Result<(), CatError> {
+        if status > Self::STATUS_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.status = set_bits(self.status as u32, 4, 3, status as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get alert status
+     */
+    pub fn get_status(&self) -> u8 {
+        get_bits(self.status as u32, 4, 3) as u8
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Maximum status in 3 bits.
+     */
+    const STATUS_MAX: u8 = 0x07;
+}
+
+impl DataItem for AlertStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [status] = fixed_array(array)?;
+        Ok((AlertStatus { status }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.status]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_alert_status() {
+        // Create message
+        let mut alert_status = AlertStatus::default();
+        alert_status.set_status(5).unwrap();
+
+        // Convert struct to byte stream
+        let array = alert_status.encode();
+
+        assert_eq!(array, vec![0x0a]);
+
+        // New message
+        let (object, length) = AlertStatus::decode(&array).unwrap();
+
+        assert_eq!(length, AlertStatus::MESSAGE_LENGTH);
+        assert_eq!(object.get_status(), 5);
+        assert_eq!(alert_status.set_status(8), Err(CatError::ValueInvalid));
+    }
+}
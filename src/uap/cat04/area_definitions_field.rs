@@ -0,0 +1,217 @@
+use crate::category::CatError;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::DataItem;
+
+// Area Definitions
+//
+// Compound data item, primary subfield followed by present subfields
+// | AN | CAN | RT1 | RT2 | SB | G | spare | FX |
+//
+
+/// Subfields of area definitions
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    AreaName,
+    CrossingAreaName,
+    RunwayTaxiway1,
+    RunwayTaxiway2,
+    StopBar,
+    Gate,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct AreaDefinitions {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation AreaDefinitions
+*/
+impl AreaDefinitions {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set area name, up to eight characters A..Z, 0..9 and space
+     */
+    pub fn set_area_name(&mut self, name: &str) -> Result<(), CatError> {
+        let mut area_name = AircraftIdentification::default();
+        area_name.set_identification(name)?;
+        self.field.set_item(Subfield::AreaName as usize, &area_name);
+        Ok(())
+    }
+
+    /*
+     * Get area name without trailing spaces
+     */
+    pub fn get_area_name(&self) -> Option<String> {
+        self.field
+            .get_item::<AircraftIdentification>(Subfield::AreaName as usize)
+            .map(|area_name| area_name.get_identification())
+    }
+
+    /*
+     * Set name of the crossing area, up to seven characters
+     */
+    pub fn set_crossing_area(&mut self, name: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::CrossingAreaName, name)
+    }
+
+    /*
+     * Get name of the crossing area without trailing spaces
+     */
+    pub fn get_crossing_area(&self) -> Option<String> {
+        self.get_text(Subfield::CrossingAreaName)
+    }
+
+    /*
+     * Set runway or taxiway designator of the first aircraft, up to seven characters
+     */
+    pub fn set_runway_taxiway_1(&mut self, designator: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::RunwayTaxiway1, designator)
+    }
+
+    /*
+     * Get runway or taxiway designator of the first aircraft
+     */
+    pub fn get_runway_taxiway_1(&self) -> Option<String> {
+        self.get_text(Subfield::RunwayTaxiway1)
+    }
+
+    /*
+     * Set runway or taxiway designator of the second aircraft, up to seven characters
+     */
+    pub fn set_runway_taxiway_2(&mut self, designator: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::RunwayTaxiway2, designator)
+    }
+
+    /*
+     * Get runway or taxiway designator of the second aircraft
+     */
+    pub fn get_runway_taxiway_2(&self) -> Option<String> {
+        self.get_text(Subfield::RunwayTaxiway2)
+    }
+
+    /*
+     * Set stop bar designator, up to seven characters
+     */
+    pub fn set_stop_bar(&mut self, designator: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::StopBar, designator)
+    }
+
+    /*
+     * Get stop bar designator without trailing spaces
+     */
+    pub fn get_stop_bar(&self) -> Option<String> {
+        self.get_text(Subfield::StopBar)
+    }
+
+    /*
+     * Set gate designator, up to seven characters
+     */
+    pub fn set_gate(&mut self, designator: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::Gate, designator)
+    }
+
+    /*
+     * Get gate designator without trailing spaces
+     */
+    pub fn get_gate(&self) -> Option<String> {
+        self.get_text(Subfield::Gate)
+    }
+
+    /*
+     * Set ASCII text subfield, padded with spaces
+     */
+    fn set_text(&mut self, subfield: Subfield, text: &str) -> Result<(), CatError> {
+        if text.len() > Self::TEXT_LENGTH || !text.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        let data = format!("{:<width$}", text, width = Self::TEXT_LENGTH).into_bytes();
+        self.field.set_subfield(subfield as usize, data);
+        Ok(())
+    }
+
+    /*
+     * Get ASCII text subfield without trailing spaces
+     */
+    fn get_text(&self, subfield: Subfield) -> Option<String> {
+        let value = self.get_subfield(subfield)?;
+        Some(String::from_utf8_lossy(value).trim_end().to_string())
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 6] = [
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(7),
+    ];
+
+    /*
+     * Number of characters of the designators.
+     */
+    const TEXT_LENGTH: usize = 7;
+}
+
+impl DataItem for AreaDefinitions {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((AreaDefinitions { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_area_definitions() {
+        // Create message
+        let mut area_definitions = AreaDefinitions::default();
+        area_definitions.set_area_name("EDR123").unwrap();
+        area_definitions.set_runway_taxiway_1("25C").unwrap();
+
+        // Convert struct to byte stream
+        let array = area_definitions.encode();
+
+        assert_eq!(array[0], 0xa0);
+        assert_eq!(&array[7..14], b"25C    ");
+        assert_eq!(array.len(), 1 + 6 + 7);
+
+        // New message
+        let (object, length) = AreaDefinitions::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, area_definitions);
+        assert_eq!(object.get_area_name(), Some("EDR123".to_string()));
+        assert_eq!(object.get_runway_taxiway_1(), Some("25C".to_string()));
+        assert_eq!(object.get_gate(), None);
+        assert_eq!(
+            area_definitions.set_gate("GATE1234"),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Conflict Characteristics
+//
+// Compound data item, primary subfield followed by present subfields
+// | CN | CC | CP | CD | spare | FX |
+//
+// Conflict Nature
+// |  8   |  7   |  6   |   5     |    4    |    3    |   2   | 1  | bit
+// | MAS  | CAS  | FLD  |  FVD    |  Type   |  Cross  |  Div  | FX |
+// | RRC  | RTC  | MRVA | VRAMCRM | VRAMVRM | VRAMVTM | HAMHD | FX |
+//
+// Conflict Classification
+// | 8 7 6 5  | 4 3 2 |  1 | bit
+// | Table ID | Class | CS |
+//
+
+/// Subfields of conflict characteristics
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    ConflictNature,
+    ConflictClassification,
+    ConflictProbability,
+    ConflictDuration,
+}
+
+/// Indications of the conflict nature
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ConflictNature {
+    MilitaryAirspace,
+    CivilAirspace,
+    FastLateralDivergence,
+    FastVerticalDivergence,
+    MajorSeparationInfringement,
+    AircraftCrossed,
+    AircraftDiverging,
+    RunwayRunwayCrossing,
+    RunwayTaxiwayCrossing,
+    MinimumRadarVectoringAltitude,
+    ClearedRateMonitor,
+    VerticalRateMonitor,
+    VerticalTrackMonitor,
+    HeadingDeviation,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ConflictCharacteristics {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation ConflictCharacteristics
+*/
+impl ConflictCharacteristics {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set indication of the conflict nature
+     */
+    pub fn set_nature(&mut self, nature: ConflictNature, value: bool) {
+        let index = nature as usize;
+        let mut field: ExtendedField = self
+            .field
+            .get_item(Subfield::ConflictNature as usize)
+            .unwrap_or_default();
+
+        field.set_bit(index / 7, 8 - (index % 7) as u8, value);
+        self.field
+            .set_item(Subfield::ConflictNature as usize, &field);
+    }
+
+    /*
+     * Get indication of the conflict nature when present
+     */
+    pub fn get_nature(&self, nature: ConflictNature) -> Option<bool> {
+        let index = nature as usize;
+        let field: ExtendedField = self.field.get_item(Subfield::ConflictNature as usize)?;
+        Some(field.get_bit(index / 7, 8 - (index % 7) as u8))
+    }
+
+    /*
+     * Set conflict classification with identifier of the classification table,
+     * conflict properties class and high severity
+     * 0 <= table <= 15
+     * 0 <= class <= 7
+     */
+    pub fn set_classification(
+        &mut self,
+        table: u8,
+        class: u8,
+        severe: bool,
+    ) -> Result<(), CatError> {
+        if table > 0x0f || class > 0x07 {
+            return Err(CatError::ValueInvalid);
+        }
+        let value = (table << 4) | (class << 1) | severe as u8;
+        self.field
+            .set_subfield(Subfield::ConflictClassification as usize, vec![value]);
+        Ok(())
+    }
+
+    /*
+     * Get conflict classification as table identifier, class and high severity
+     */
+    pub fn get_classification(&self) -> Option<(u8, u8, bool)> {
+        let value = self.get_subfield(Subfield::ConflictClassification)?[0];
+        Some((value >> 4, (value >> 1) & 0x07, value & 0x01 != 0))
+    }
+
+    /*
+     * Set conflict probability in %
+     * 0 <= probability <= 100
+     */
+    pub fn set_probability(&mut self, probability: f32) -> Result<(), CatError> {
+        if !(0.0..=100.0).contains(&probability) {
+            return Err(CatError::ValueInvalid);
+        }
+        let value = from_unsigned(probability as f64, Self::FACTOR_PROBABILITY as f64, 8)? as u8;
+        self.field
+            .set_subfield(Subfield::ConflictProbability as usize, vec![value]);
+        Ok(())
+    }
+
+    /*
+     * Get conflict probability in %
+     */
+    pub fn get_probability(&self) -> Option<f32> {
+        let value = self.get_subfield(Subfield::ConflictProbability)?[0];
+        Some(value as f32 * Self::FACTOR_PROBABILITY)
+    }
+
+    /*
+     * Set conflict duration in s
+     * 0 <= duration < 131072
+     */
+    pub fn set_duration(&mut self, duration: f64) -> Result<(), CatError> {
+        let value = from_unsigned(duration, Self::FACTOR_DURATION, 24)?;
+        self.field.set_subfield(
+            Subfield::ConflictDuration as usize,
+            value.to_be_bytes()[1..4].to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get conflict duration in s
+     */
+    pub fn get_duration(&self) -> Option<f64> {
+        let value = self.get_subfield(Subfield::ConflictDuration)?;
+        Some(u32::from_be_bytes([0, value[0], value[1], value[2]]) as f64 * Self::FACTOR_DURATION)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 4] = [
+        SubfieldLength::Extended,
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(3),
+    ];
+
+    /*
+     * Conversion factor of conflict probability.
+     */
+    const FACTOR_PROBABILITY: f32 = 0.5;
+
+    /*
+     * Conversion factor of conflict duration.
+     */
+    const FACTOR_DURATION: f64 = 1.0 / 128.0;
+}
+
+impl DataItem for ConflictCharacteristics {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((ConflictCharacteristics { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_conflict_characteristics() {
+        // Create message
+        let mut conflict_characteristics = ConflictCharacteristics::default();
+        conflict_characteristics.set_nature(ConflictNature::AircraftCrossed, true);
+        conflict_characteristics.set_nature(ConflictNature::RunwayTaxiwayCrossing, true);
+        conflict_characteristics
+            .set_classification(2, 3, true)
+            .unwrap();
+        conflict_characteristics.set_probability(75.5).unwrap();
+        conflict_characteristics.set_duration(30.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = conflict_characteristics.encode();
+
+        assert_eq!(array, vec![0xf0, 0x05, 0x40, 0x27, 0x97, 0x00, 0x0f, 0x00]);
+
+        // New message
+        let (object, length) = ConflictCharacteristics::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, conflict_characteristics);
+        assert_eq!(
+            object.get_nature(ConflictNature::AircraftCrossed),
+            Some(true)
+        );
+        assert_eq!(
+            object.get_nature(ConflictNature::CivilAirspace),
+            Some(false)
+        );
+        assert_eq!(object.get_classification(), Some((2, 3, true)));
+        assert_eq!(object.get_probability(), Some(75.5));
+        assert_eq!(object.get_duration(), Some(30.0));
+        assert_eq!(
+            conflict_characteristics.set_probability(100.5),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
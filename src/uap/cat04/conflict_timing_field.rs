@@ -0,0 +1,264 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+
+// Conflict Timing and Separation
+//
+// Compound data item, primary subfield followed by present subfields
+// | TC | TCA | CHS | MHS | CVS | MVS | spare | FX |
+//
+
+/// Subfields of conflict timing and separation
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    TimeToConflict,
+    TimeToClosestApproach,
+    CurrentHorizontalSeparation,
+    MinimumHorizontalSeparation,
+    CurrentVerticalSeparation,
+    MinimumVerticalSeparation,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ConflictTiming {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation ConflictTiming
+*/
+impl ConflictTiming {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set time to conflict as time of day in s
+     * 0 <= time < 131072
+     */
+    pub fn set_time_to_conflict(&mut self, time: f64) -> Result<(), CatError> {
+        self.set_u24(Subfield::TimeToConflict, time, Self::FACTOR_TIME)
+    }
+
+    /*
+     * Get time to conflict as time of day in s
+     */
+    pub fn get_time_to_conflict(&self) -> Option<f64> {
+        self.get_u24(Subfield::TimeToConflict, Self::FACTOR_TIME)
+    }
+
+    /*
+     * Set time to closest approach as time of day in s
+     * 0 <= time < 131072
+     */
+    pub fn set_time_to_closest_approach(&mut self, time: f64) -> Result<(), CatError> {
+        self.set_u24(Subfield::TimeToClosestApproach, time, Self::FACTOR_TIME)
+    }
+
+    /*
+     * Get time to closest approach as time of day in s
+     */
+    pub fn get_time_to_closest_approach(&self) -> Option<f64> {
+        self.get_u24(Subfield::TimeToClosestApproach, Self::FACTOR_TIME)
+    }
+
+    /*
+     * Set current horizontal separation in m
+     * 0 <= separation < 8388608
+     */
+    pub fn set_current_horizontal(&mut self, separation: f64) -> Result<(), CatError> {
+        self.set_u24(
+            Subfield::CurrentHorizontalSeparation,
+            separation,
+            Self::FACTOR_HORIZONTAL,
+        )
+    }
+
+    /*
+     * Get current horizontal separation in m
+     */
+    pub fn get_current_horizontal(&self) -> Option<f64> {
+        self.get_u24(
+            Subfield::CurrentHorizontalSeparation,
+            Self::FACTOR_HORIZONTAL,
+        )
+    }
+
+    /*
+     * Set estimated minimum horizontal separation in m
+     * 0 <= separation < 32768
+     */
+    pub fn set_minimum_horizontal(&mut self, separation: f64) -> Result<(), CatError> {
+        self.set_u16(
+            Subfield::MinimumHorizontalSeparation,
+            separation,
+            Self::FACTOR_HORIZONTAL,
+        )
+    }
+
+    /*
+     * Get estimated minimum horizontal separation in m
+     */
+    pub fn get_minimum_horizontal(&self) -> Option<f64> {
+        self.get_u16(
+            Subfield::MinimumHorizontalSeparation,
+            Self::FACTOR_HORIZONTAL,
+        )
+    }
+
+    /*
+     * Set current vertical separation in ft
+     * 0 <= separation < 1638400
+     */
+    pub fn set_current_vertical(&mut self, separation: f64) -> Result<(), CatError> {
+        self.set_u16(
+            Subfield::CurrentVerticalSeparation,
+            separation,
+            Self::FACTOR_VERTICAL,
+        )
+    }
+
+    /*
+     * Get current vertical separation in ft
+     */
+    pub fn get_current_vertical(&self) -> Option<f64> {
+        self.get_u16(Subfield::CurrentVerticalSeparation, Self::FACTOR_VERTICAL)
+    }
+
+    /*
+     * Set estimated minimum vertical separation in ft
+     * 0 <= separation < 1638400
+     */
+    pub fn set_minimum_vertical(&mut self, separation: f64) -> Result<(), CatError> {
+        self.set_u16(
+            Subfield::MinimumVerticalSeparation,
+            separation,
+            Self::FACTOR_VERTICAL,
+        )
+    }
+
+    /*
+     * Get estimated minimum vertical separation in ft
+     */
+    pub fn get_minimum_vertical(&self) -> Option<f64> {
+        self.get_u16(Subfield::MinimumVerticalSeparation, Self::FACTOR_VERTICAL)
+    }
+
+    /*
+     * Set unsigned 24 bit subfield
+     */
+    fn set_u24(&mut self, subfield: Subfield, value: f64, factor: f64) -> Result<(), CatError> {
+        let value = from_unsigned(value, factor, 24)?;
+        self.field
+            .set_subfield(subfield as usize, value.to_be_bytes()[1..4].to_vec());
+        Ok(())
+    }
+
+    /*
+     * Get unsigned 24 bit subfield
+     */
+    fn get_u24(&self, subfield: Subfield, factor: f64) -> Option<f64> {
+        let value = self.get_subfield(subfield)?;
+        Some(u32::from_be_bytes([0, value[0], value[1], value[2]]) as f64 * factor)
+    }
+
+    /*
+     * Set unsigned 16 bit subfield
+     */
+    fn set_u16(&mut self, subfield: Subfield, value: f64, factor: f64) -> Result<(), CatError> {
+        let value = from_unsigned(value, factor, 16)? as u16;
+        self.field
+            .set_subfield(subfield as usize, value.to_be_bytes().to_vec());
+        Ok(())
+    }
+
+    /*
+     * Get unsigned 16 bit subfield
+     */
+    fn get_u16(&self, subfield: Subfield, factor: f64) -> Option<f64> {
+        let value = self.get_subfield(subfield)?;
+        Some(u16::from_be_bytes([value[0], value[1]]) as f64 * factor)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 6] = [
+        SubfieldLength::Fixed(3),
+        SubfieldLength::Fixed(3),
+        SubfieldLength::Fixed(3),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+    ];
+
+    /*
+     * Conversion factor of times.
+     */
+    const FACTOR_TIME: f64 = 1.0 / 128.0;
+
+    /*
+     * Conversion factor of horizontal separations.
+     */
+    const FACTOR_HORIZONTAL: f64 = 0.5;
+
+    /*
+     * Conversion factor of vertical separations.
+     */
+    const FACTOR_VERTICAL: f64 = 25.0;
+}
+
+impl DataItem for ConflictTiming {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((ConflictTiming { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_conflict_timing() {
+        // Create message
+        let mut conflict_timing = ConflictTiming::default();
+        conflict_timing.set_time_to_conflict(12345.5).unwrap();
+        conflict_timing.set_minimum_horizontal(1500.0).unwrap();
+        conflict_timing.set_minimum_vertical(700.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = conflict_timing.encode();
+
+        assert_eq!(array, vec![0x94, 0x18, 0x1c, 0xc0, 0x0b, 0xb8, 0x00, 0x1c]);
+
+        // New message
+        let (object, length) = ConflictTiming::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, conflict_timing);
+        assert_eq!(object.get_time_to_conflict(), Some(12345.5));
+        assert_eq!(object.get_minimum_horizontal(), Some(1500.0));
+        assert_eq!(object.get_minimum_vertical(), Some(700.0));
+        assert_eq!(object.get_current_vertical(), None);
+        assert_eq!(
+            conflict_timing.set_minimum_horizontal(40000.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
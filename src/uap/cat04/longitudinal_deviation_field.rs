@@ -0,0 +1,78 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Longitudinal Deviation
+//
+// Deviation along the cleared route, positive ahead of the planned position
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct LongitudinalDeviation {
+    deviation: i16, // 2 bytes
+}
+
+/*
+* Implementation LongitudinalDeviation
+*/
+impl LongitudinalDeviation {
+    /*
+     * Set longitudinal deviation in m
+     * -1048576 <= deviation < 1048576
+     */
+    pub fn set_deviation(&mut self, deviation: f32) -> Result<(), CatError> {
+        self.deviation = from_signed(deviation as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get longitudinal deviation in m
+     */
+    pub fn get_deviation(&self) -> f32 {
+        self.deviation as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of longitudinal deviation.
+     */
+    const FACTOR: f32 = 32.0;
+}
+
+impl DataItem for LongitudinalDeviation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let deviation = i16::from_be_bytes(fixed_array(array)?);
+        Ok((LongitudinalDeviation { deviation }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.deviation.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_longitudinal_deviation() {
+        // Create message
+        let mut longitudinal_deviation = LongitudinalDeviation::default();
+        longitudinal_deviation.set_deviation(3200.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = longitudinal_deviation.encode();
+
+        assert_eq!(array, vec![0x00, 0x64]);
+
+        // New message
+        let (object, length) = LongitudinalDeviation::decode(&array).unwrap();
+
+        assert_eq!(length, LongitudinalDeviation::MESSAGE_LENGTH);
+        assert_eq!(object.get_deviation(), 3200.0);
+    }
+}
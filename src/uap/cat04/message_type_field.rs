@@ -0,0 +1,118 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Safety Net Message Type
+//
+
+/// Types of safety net alerts
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum AlertType {
+    Unknown,
+    AliveMessage,
+    RouteAdherenceLongitudinal,
+    RouteAdherenceHeading,
+    MinimumSafeAltitudeWarning,
+    AreaProximityWarning,
+    ClearanceLevelAdherence,
+    ShortTermConflictAlert,
+    ApproachPathMonitor,
+    RunwayIncursion,
+    AcasResolutionAdvisory,
+    NearTermConflictAlert,
+    BarometricPressureSettingMonitor,
+    SpeedAdherenceMonitor,
+    OutsideControlledAirspace,
+    VerticalConflictDetection,
+    VerticalRateAdherenceMonitor,
+    ClearedHeadingAdherenceMonitor,
+    SelectedAltitudeMonitor,
+    HoldingAdherenceMonitor,
+    VerticalPathMonitor,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AlertMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation AlertMessageType
+*/
+impl AlertMessageType {
+    /*
+     * Set alert type
+     */
+    pub fn set_alert_type(&mut self, alert_type: AlertType) {
+        self.message = alert_type as u8;
+    }
+
+    /*
+     * Get alert type
+     */
+    pub fn get_alert_type(&self) -> AlertType {
+        match self.message {
+            1 => AlertType::AliveMessage,
+            2 => AlertType::RouteAdherenceLongitudinal,
+            3 => AlertType::RouteAdherenceHeading,
+            4 => AlertType::MinimumSafeAltitudeWarning,
+            5 => AlertType::AreaProximityWarning,
+            6 => AlertType::ClearanceLevelAdherence,
+            7 => AlertType::ShortTermConflictAlert,
+            8 => AlertType::ApproachPathMonitor,
+            9 => AlertType::RunwayIncursion,
+            10 => AlertType::AcasResolutionAdvisory,
+            11 => AlertType::NearTermConflictAlert,
+            12 => AlertType::BarometricPressureSettingMonitor,
+            13 => AlertType::SpeedAdherenceMonitor,
+            14 => AlertType::OutsideControlledAirspace,
+            15 => AlertType::VerticalConflictDetection,
+            16 => AlertType::VerticalRateAdherenceMonitor,
+            17 => AlertType::ClearedHeadingAdherenceMonitor,
+            18 => AlertType::SelectedAltitudeMonitor,
+            19 => AlertType::HoldingAdherenceMonitor,
+            20 => AlertType::VerticalPathMonitor,
+            _ => AlertType::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for AlertMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((AlertMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_alert_message_type() {
+        // Create message
+        let mut message_type = AlertMessageType::default();
+        message_type.set_alert_type(AlertType::ShortTermConflictAlert);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x07]);
+
+        // New message
+        let (object, length) = AlertMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, AlertMessageType::MESSAGE_LENGTH);
+        assert_eq!(object.get_alert_type(), AlertType::ShortTermConflictAlert);
+    }
+}
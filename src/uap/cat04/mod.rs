@@ -0,0 +1,13 @@
+pub mod aircraft_characteristics_field;
+pub mod alert_identifier_field;
+pub mod alert_status_field;
+pub mod area_definitions_field;
+pub mod conflict_characteristics_field;
+pub mod conflict_timing_field;
+pub mod longitudinal_deviation_field;
+pub mod message_type_field;
+pub mod safety_net_status_field;
+pub mod sdps_identifier_field;
+pub mod sector_control_field;
+pub mod transversal_deviation_field;
+pub mod vertical_deviation_field;
@@ -0,0 +1,106 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Safety Net Function and System Status
+//
+// |  8   |   7   |   6   |  5   |  4   |  3   |  2   | 1  | bit
+// | MRVA | RAMLD | RAMHD | MSAW | APW  | CLAM | STCA | FX |
+// | AFDA | RIMCA | ACASRA| NTCA |  DG  |  OF  |  OL  | FX |
+// | AIW  | PAIW  | OTCA  | CPA  |      spare         | FX |
+//
+
+/// Safety net functions and system states
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SafetyNetFunction {
+    MinimumRadarVectoringAltitude,
+    RouteAdherenceLongitudinal,
+    RouteAdherenceHeading,
+    MinimumSafeAltitudeWarning,
+    AreaProximityWarning,
+    ClearanceLevelAdherence,
+    ShortTermConflictAlert,
+    ApproachFunctionDeviationAlert,
+    RunwayIncursion,
+    AcasResolutionAdvisory,
+    NearTermConflictAlert,
+    SystemDegraded,
+    OverflowError,
+    OverloadError,
+    AirspaceInfringementWarning,
+    PreAirspaceInfringementWarning,
+    OneTrackConflictAlert,
+    ConflictPredictionAlert,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SafetyNetStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation SafetyNetStatus
+*/
+impl SafetyNetStatus {
+    /*
+     * Set status of a safety net function, true means failed or active
+     */
+    pub fn set_function(&mut self, function: SafetyNetFunction, value: bool) {
+        let (octet, bit) = Self::function_bit(function);
+        self.field.set_bit(octet, bit, value);
+    }
+
+    /*
+     * Get status of a safety net function
+     */
+    pub fn get_function(&self, function: SafetyNetFunction) -> bool {
+        let (octet, bit) = Self::function_bit(function);
+        self.field.get_bit(octet, bit)
+    }
+
+    /*
+     * Octet and bit of a safety net function.
+     */
+    fn function_bit(function: SafetyNetFunction) -> (usize, u8) {
+        let index = function as usize;
+        (index / 7, 8 - (index % 7) as u8)
+    }
+}
+
+impl DataItem for SafetyNetStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((SafetyNetStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_safety_net_status() {
+        // Create message
+        let mut safety_net_status = SafetyNetStatus::default();
+        safety_net_status.set_function(SafetyNetFunction::ShortTermConflictAlert, true);
+        safety_net_status.set_function(SafetyNetFunction::SystemDegraded, true);
+
+        // Convert struct to byte stream
+        let array = safety_net_status.encode();
+
+        assert_eq!(array, vec![0x03, 0x08]);
+
+        // New message
+        let (object, length) = SafetyNetStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert!(object.get_function(SafetyNetFunction::ShortTermConflictAlert));
+        assert!(object.get_function(SafetyNetFunction::SystemDegraded));
+        assert!(!object.get_function(SafetyNetFunction::AreaProximityWarning));
+        assert!(!object.get_function(SafetyNetFunction::ConflictPredictionAlert));
+    }
+}
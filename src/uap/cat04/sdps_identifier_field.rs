@@ -0,0 +1,94 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::data_source_field::DataSource;
+
+// SDPS Identifier
+//
+// Repetition factor followed by the SDPS which generated the alert
+// | 16 .. 9 | 8 .. 1 | bit
+// |   SAC   |  SIC   |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SdpsIdentifier {
+    sources: Vec<DataSource>, // 2 bytes each
+}
+
+/*
+* Implementation SdpsIdentifier
+*/
+impl SdpsIdentifier {
+    /*
+     * Insert SDPS identifier
+     */
+    pub fn insert_source(&mut self, source: DataSource) -> Result<(), CatError> {
+        if self.sources.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.sources.push(source);
+        Ok(())
+    }
+
+    /*
+     * Get all SDPS identifiers
+     */
+    pub fn get_sources(&self) -> &[DataSource] {
+        &self.sources
+    }
+}
+
+impl DataItem for SdpsIdentifier {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * DataSource::MESSAGE_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let sources = array[1..length]
+            .chunks_exact(DataSource::MESSAGE_LENGTH)
+            .map(|chunk| DataSource::decode(chunk).map(|(source, _)| source))
+            .collect::<Result<_, _>>()?;
+
+        Ok((SdpsIdentifier { sources }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.sources.len() as u8];
+
+        for source in self.sources.iter() {
+            vector.extend(source.encode());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_sdps_identifier() {
+        // Create message
+        let mut source = DataSource::default();
+        source.set_source_id_sac(0x19);
+        source.set_source_id_sic(0xc9);
+
+        let mut sdps_identifier = SdpsIdentifier::default();
+        sdps_identifier.insert_source(source).unwrap();
+
+        // Convert struct to byte stream
+        let array = sdps_identifier.encode();
+
+        assert_eq!(array, vec![0x01, 0x19, 0xc9]);
+
+        // New message
+        let (object, length) = SdpsIdentifier::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_sources(), &[source]);
+        assert_eq!(
+            SdpsIdentifier::decode(&[0x02, 0x19, 0xc9]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
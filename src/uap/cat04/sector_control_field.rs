@@ -0,0 +1,113 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// FDPS Sector Control Identification
+//
+// Repetition factor followed by the sectors controlling the aircraft
+// |   16 .. 9   |     8 .. 1       | bit
+// | Centre      | Control position |
+//
+
+/// Sector identified by centre and control position
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct SectorControl {
+    pub centre: u8,
+    pub position: u8,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SectorControlIdentification {
+    sectors: Vec<SectorControl>, // 2 bytes each
+}
+
+/*
+* Implementation SectorControlIdentification
+*/
+impl SectorControlIdentification {
+    /*
+     * Insert sector controlling the aircraft
+     */
+    pub fn insert_sector(&mut self, sector: SectorControl) -> Result<(), CatError> {
+        if self.sectors.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.sectors.push(sector);
+        Ok(())
+    }
+
+    /*
+     * Get all sectors controlling the aircraft
+     */
+    pub fn get_sectors(&self) -> &[SectorControl] {
+        &self.sectors
+    }
+
+    /*
+     * Length of one sector.
+     */
+    const SECTOR_LENGTH: usize = 2;
+}
+
+impl DataItem for SectorControlIdentification {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::SECTOR_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let sectors = array[1..length]
+            .chunks_exact(Self::SECTOR_LENGTH)
+            .map(|chunk| SectorControl {
+                centre: chunk[0],
+                position: chunk[1],
+            })
+            .collect();
+
+        Ok((SectorControlIdentification { sectors }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.sectors.len() as u8];
+
+        for sector in self.sectors.iter() {
+            vector.extend([sector.centre, sector.position]);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_sector_control_identification() {
+        // Create message
+        let mut sector_control = SectorControlIdentification::default();
+        sector_control
+            .insert_sector(SectorControl {
+                centre: 0x12,
+                position: 0x34,
+            })
+            .unwrap();
+        sector_control
+            .insert_sector(SectorControl {
+                centre: 0x12,
+                position: 0x35,
+            })
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = sector_control.encode();
+
+        assert_eq!(array, vec![0x02, 0x12, 0x34, 0x12, 0x35]);
+
+        // New message
+        let (object, length) = SectorControlIdentification::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, sector_control);
+        assert_eq!(object.get_sectors()[1].position, 0x35);
+    }
+}
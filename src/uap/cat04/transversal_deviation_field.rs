@@ -0,0 +1,81 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, to_signed, DataItem};
+
+// Transversal Distance Deviation
+//
+// Deviation across the cleared route, positive to the right
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TransversalDeviation {
+    deviation: [u8; 3], // 3 bytes
+}
+
+/*
+* Implementation TransversalDeviation
+*/
+impl TransversalDeviation {
+    /*
+     * Set transversal deviation in m
+     * -4194304 <= deviation < 4194304
+     */
+    pub fn set_deviation(&mut self, deviation: f64) -> Result<(), CatError> {
+        let value = from_signed(deviation, Self::FACTOR, 24)?;
+        self.deviation.copy_from_slice(&value.to_be_bytes()[1..4]);
+        Ok(())
+    }
+
+    /*
+     * Get transversal deviation in m
+     */
+    pub fn get_deviation(&self) -> f64 {
+        let value =
+            u32::from_be_bytes([0, self.deviation[0], self.deviation[1], self.deviation[2]]);
+        to_signed(value, 24) as f64 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of transversal deviation.
+     */
+    const FACTOR: f64 = 0.5;
+}
+
+impl DataItem for TransversalDeviation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let deviation = fixed_array(array)?;
+        Ok((TransversalDeviation { deviation }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.deviation.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_transversal_deviation() {
+        // Create message
+        let mut transversal_deviation = TransversalDeviation::default();
+        transversal_deviation.set_deviation(-1.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = transversal_deviation.encode();
+
+        assert_eq!(array, vec![0xff, 0xff, 0xfd]);
+
+        // New message
+        let (object, length) = TransversalDeviation::decode(&array).unwrap();
+
+        assert_eq!(length, TransversalDeviation::MESSAGE_LENGTH);
+        assert_eq!(object.get_deviation(), -1.5);
+    }
+}
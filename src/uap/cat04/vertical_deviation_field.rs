@@ -0,0 +1,78 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Vertical Deviation
+//
+// Deviation between the cleared and the current flight level
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VerticalDeviation {
+    deviation: i16, // 2 bytes
+}
+
+/*
+* Implementation VerticalDeviation
+*/
+impl VerticalDeviation {
+    /*
+     * Set vertical deviation in ft
+     * -819200 <= deviation < 819200
+     */
+    pub fn set_deviation(&mut self, deviation: f32) -> Result<(), CatError> {
+        self.deviation = from_signed(deviation as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get vertical deviation in ft
+     */
+    pub fn get_deviation(&self) -> f32 {
+        self.deviation as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of vertical deviation.
+     */
+    const FACTOR: f32 = 25.0;
+}
+
+impl DataItem for VerticalDeviation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let deviation = i16::from_be_bytes(fixed_array(array)?);
+        Ok((VerticalDeviation { deviation }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.deviation.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_vertical_deviation() {
+        // Create message
+        let mut vertical_deviation = VerticalDeviation::default();
+        vertical_deviation.set_deviation(-500.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = vertical_deviation.encode();
+
+        assert_eq!(array, vec![0xff, 0xec]);
+
+        // New message
+        let (object, length) = VerticalDeviation::decode(&array).unwrap();
+
+        assert_eq!(length, VerticalDeviation::MESSAGE_LENGTH);
+        assert_eq!(object.get_deviation(), -500.0);
+    }
+}
@@ -0,0 +1,103 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Interrogation Descriptor
+//
+// | 8 7 6 5 4 | 3 2 1 | bit
+// |    UF     |  PRI  |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct InterrogationDescriptor {
+    descriptor: u8, // 1 byte
+}
+
+/*
+* Implementation InterrogationDescriptor
+*/
+impl InterrogationDescriptor {
+    /*
+     * Set Mode S uplink format of the interrogation
+     * 0 <= uplink_format <= 31
+     */
+    pub fn set_uplink_format(&mut self, uplink_format: u8) -> Result<(), CatError> {
+        if uplink_format > 0x1f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.descriptor = set_bits(self.descriptor as u32, 8, 5, uplink_format as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get Mode S uplink format of the interrogation
+     */
+    pub fn get_uplink_format(&self) -> u8 {
+        get_bits(self.descriptor as u32, 8, 5) as u8
+    }
+
+    /*
+     * Set priority of the interrogation, 0 is the lowest priority
+     * 0 <= priority <= 7
+     */
+    pub fn set_priority(&mut self, priority: u8) -> Result<(), CatError> {
+        if priority > 0x07 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.descriptor = set_bits(self.descriptor as u32, 3, 3, priority as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get priority of the interrogation
+     */
+    pub fn get_priority(&self) -> u8 {
+        get_bits(self.descriptor as u32, 3, 3) as u8
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for InterrogationDescriptor {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [descriptor] = fixed_array(array)?;
+        Ok((InterrogationDescriptor { descriptor }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.descriptor]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_interrogation_descriptor() {
+        // Create message
+        let mut descriptor = InterrogationDescriptor::default();
+        descriptor.set_uplink_format(20).unwrap();
+        descriptor.set_priority(5).unwrap();
+
+        // Convert struct to byte stream
+        let array = descriptor.encode();
+
+        assert_eq!(array, vec![0xa5]);
+
+        // New message
+        let (object, length) = InterrogationDescriptor::decode(&array).unwrap();
+
+        assert_eq!(length, InterrogationDescriptor::MESSAGE_LENGTH);
+        assert_eq!(object.get_uplink_format(), 20);
+        assert_eq!(object.get_priority(), 5);
+        assert_eq!(
+            descriptor.set_uplink_format(32),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(descriptor.set_priority(8), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Interrogation Request
+//
+// Repetition factor followed by requested BDS registers
+// | 8 7 6 5 | 4 3 2 1 | bit
+// |  BDS1   |  BDS2   |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct InterrogationRequest {
+    registers: Vec<u8>, // 1 byte each
+}
+
+/*
+* Implementation InterrogationRequest
+*/
+impl InterrogationRequest {
+    /*
+     * Insert requested BDS register
+     * 0 <= bds1, bds2 <= 15
+     */
+    pub fn insert_register(&mut self, bds1: u8, bds2: u8) -> Result<(), CatError> {
+        if bds1 > 0x0f || bds2 > 0x0f || self.registers.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.registers.push((bds1 << 4) | bds2);
+        Ok(())
+    }
+
+    /*
+     * Get all requested BDS registers as BDS1 and BDS2
+     */
+    pub fn get_registers(&self) -> Vec<(u8, u8)> {
+        self.registers
+            .iter()
+            .map(|register| (register >> 4, register & 0x0f))
+            .collect()
+    }
+}
+
+impl DataItem for InterrogationRequest {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition;
+        let slice = array.get(1..length).ok_or(CatError::SizeInvalid(0))?;
+
+        let registers = slice.to_vec();
+        Ok((InterrogationRequest { registers }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.registers.len() as u8];
+        vector.extend_from_slice(&self.registers);
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_interrogation_request() {
+        // Create message
+        let mut request = InterrogationRequest::default();
+        request.insert_register(4, 0).unwrap();
+        request.insert_register(6, 0).unwrap();
+
+        // Convert struct to byte stream
+        let array = request.encode();
+
+        assert_eq!(array, vec![0x02, 0x40, 0x60]);
+
+        // New message
+        let (object, length) = InterrogationRequest::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_registers(), vec![(4, 0), (6, 0)]);
+        assert_eq!(request.insert_register(16, 0), Err(CatError::ValueInvalid));
+
+        // Second register is missing
+        assert_eq!(
+            InterrogationRequest::decode(&[0x02, 0x40]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
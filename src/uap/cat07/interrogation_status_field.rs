@@ -0,0 +1,92 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Interrogation Status
+//
+
+/// Result of a directed interrogation
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum InterrogationResult {
+    Unknown,
+    ReplyReceived,
+    NoReply,
+    OutOfCoverage,
+    Rejected,
+    Cancelled,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct InterrogationStatus {
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation InterrogationStatus
+*/
+impl InterrogationStatus {
+    /*
+     * Set result of the interrogation
+     */
+    pub fn set_result(&mut self, result: InterrogationResult) {
+        self.status = result as u8;
+    }
+
+    /*
+     * Get result of the interrogation
+     */
+    pub fn get_result(&self) -> InterrogationResult {
+        match self.status {
+            1 => InterrogationResult::ReplyReceived,
+            2 => InterrogationResult::NoReply,
+            3 => InterrogationResult::OutOfCoverage,
+            4 => InterrogationResult::Rejected,
+            5 => InterrogationResult::Cancelled,
+            _ => InterrogationResult::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for InterrogationStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [status] = fixed_array(array)?;
+        Ok((InterrogationStatus { status }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.status]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_interrogation_status() {
+        // Create message
+        let mut status = InterrogationStatus::default();
+        status.set_result(InterrogationResult::NoReply);
+
+        // Convert struct to byte stream
+        let array = status.encode();
+
+        assert_eq!(array, vec![0x02]);
+
+        // New message
+        let (object, length) = InterrogationStatus::decode(&array).unwrap();
+
+        assert_eq!(length, InterrogationStatus::MESSAGE_LENGTH);
+        assert_eq!(object.get_result(), InterrogationResult::NoReply);
+        assert_eq!(
+            InterrogationStatus::decode(&[0x09]).unwrap().0.get_result(),
+            InterrogationResult::Unknown
+        );
+    }
+}
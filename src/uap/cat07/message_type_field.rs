@@ -0,0 +1,89 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Directed Interrogation Message Type
+//
+
+/// Types of directed interrogation messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum InterrogationMessage {
+    Unknown,
+    Request,
+    Reply,
+    Cancellation,
+    Rejection,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct InterrogationMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation InterrogationMessageType
+*/
+impl InterrogationMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: InterrogationMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> InterrogationMessage {
+        match self.message {
+            1 => InterrogationMessage::Request,
+            2 => InterrogationMessage::Reply,
+            3 => InterrogationMessage::Cancellation,
+            4 => InterrogationMessage::Rejection,
+            _ => InterrogationMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for InterrogationMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((InterrogationMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_interrogation_message_type() {
+        // Create message
+        let mut message_type = InterrogationMessageType::default();
+        message_type.set_message_type(InterrogationMessage::Cancellation);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x03]);
+
+        // New message
+        let (object, length) = InterrogationMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, InterrogationMessageType::MESSAGE_LENGTH);
+        assert_eq!(
+            object.get_message_type(),
+            InterrogationMessage::Cancellation
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod interrogation_descriptor_field;
+pub mod interrogation_request_field;
+pub mod interrogation_status_field;
+pub mod message_type_field;
@@ -0,0 +1,120 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_signed, from_unsigned, DataItem};
+
+// Sequence of Cartesian Vectors in SPF Notation
+//
+// Repetition factor followed by Cartesian vectors
+// | 24 .. 17 | 16 .. 9 | 8 .. 1 | bit
+// |    X     |    Y    | LENGTH |
+//
+// Resolution of the coordinates is given by the scaling factor of the processing status.
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct CartesianVectors {
+    vectors: Vec<(i8, i8, u8)>, // 3 bytes each
+}
+
+/*
+* Implementation CartesianVectors
+*/
+impl CartesianVectors {
+    /*
+     * Insert vector with start point and length in NM, resolution in NM
+     * -128 * resolution <= x, y < 128 * resolution
+     * 0 <= length < 256 * resolution
+     */
+    pub fn insert_vector(
+        &mut self,
+        x: f64,
+        y: f64,
+        length: f64,
+        resolution: f64,
+    ) -> Result<(), CatError> {
+        if self.vectors.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        let x = from_signed(x, resolution, 8)? as i8;
+        let y = from_signed(y, resolution, 8)? as i8;
+        let length = from_unsigned(length, resolution, 8)? as u8;
+        self.vectors.push((x, y, length));
+        Ok(())
+    }
+
+    /*
+     * Get all vectors as start point and length in NM, resolution in NM
+     */
+    pub fn get_vectors(&self, resolution: f64) -> Vec<(f64, f64, f64)> {
+        self.vectors
+            .iter()
+            .map(|(x, y, length)| {
+                (
+                    *x as f64 * resolution,
+                    *y as f64 * resolution,
+                    *length as f64 * resolution,
+                )
+            })
+            .collect()
+    }
+
+    /*
+     * Length of one vector.
+     */
+    const VECTOR_LENGTH: usize = 3;
+}
+
+impl DataItem for CartesianVectors {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::VECTOR_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let vectors = array[1..length]
+            .chunks_exact(Self::VECTOR_LENGTH)
+            .map(|chunk| (chunk[0] as i8, chunk[1] as i8, chunk[2]))
+            .collect();
+
+        Ok((CartesianVectors { vectors }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.vectors.len() as u8];
+
+        for (x, y, length) in self.vectors.iter() {
+            vector.push(*x as u8);
+            vector.push(*y as u8);
+            vector.push(*length);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cartesian_vectors() {
+        // Create message
+        let mut cartesian_vectors = CartesianVectors::default();
+        cartesian_vectors
+            .insert_vector(-2.0, 1.5, 3.0, 0.25)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = cartesian_vectors.encode();
+
+        assert_eq!(array, vec![0x01, 0xf8, 0x06, 0x0c]);
+
+        // New message
+        let (object, length) = CartesianVectors::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_vectors(0.25), vec![(-2.0, 1.5, 3.0)]);
+        assert_eq!(
+            cartesian_vectors.insert_vector(32.0, 0.0, 0.0, 0.25),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
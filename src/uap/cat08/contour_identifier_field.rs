@@ -0,0 +1,150 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Contour Identifier
+//
+// |  16  | 15 .. 13 | 12 11 | 10 9   | 8 .. 1 | bit
+// | ORG  |   INT    | spare | FSTLST |  CSN   |
+//
+
+/// Position of the record within its contour
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ContourPosition {
+    Intermediate,
+    Last,
+    First,
+    FirstAndLast,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ContourIdentifier {
+    identifier: u16, // 2 bytes
+}
+
+/*
+* Implementation ContourIdentifier
+*/
+impl ContourIdentifier {
+    /*
+     * Set contour in system coordinates instead of local coordinates
+     */
+    pub fn set_system_coordinates(&mut self, value: bool) {
+        self.set_identifier_bits(16, 1, value as u32);
+    }
+
+    /*
+     * Get contour in system coordinates instead of local coordinates
+     */
+    pub fn get_system_coordinates(&self) -> bool {
+        get_bits(self.identifier as u32, 16, 1) == 1
+    }
+
+    /*
+     * Set intensity level
+     * 0 <= intensity <= 7
+     */
+    pub fn set_intensity(&mut self, intensity: u8) -> Result<(), CatError> {
+        if intensity > 0x07 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.set_identifier_bits(15, 3, intensity as u32);
+        Ok(())
+    }
+
+    /*
+     * Get intensity level
+     */
+    pub fn get_intensity(&self) -> u8 {
+        get_bits(self.identifier as u32, 15, 3) as u8
+    }
+
+    /*
+     * Set position of the record within its contour
+     */
+    pub fn set_position(&mut self, position: ContourPosition) {
+        self.set_identifier_bits(10, 2, position as u32);
+    }
+
+    /*
+     * Get position of the record within its contour
+     */
+    pub fn get_position(&self) -> ContourPosition {
+        match get_bits(self.identifier as u32, 10, 2) {
+            1 => ContourPosition::Last,
+            2 => ContourPosition::First,
+            3 => ContourPosition::FirstAndLast,
+            _ => ContourPosition::Intermediate,
+        }
+    }
+
+    /*
+     * Set contour serial number
+     */
+    pub fn set_serial_number(&mut self, serial_number: u8) {
+        self.set_identifier_bits(8, 8, serial_number as u32);
+    }
+
+    /*
+     * Get contour serial number
+     */
+    pub fn get_serial_number(&self) -> u8 {
+        get_bits(self.identifier as u32, 8, 8) as u8
+    }
+
+    /*
+     * Set bits of the identifier
+     */
+    fn set_identifier_bits(&mut self, bit: u8, width: u8, value: u32) {
+        self.identifier = set_bits(self.identifier as u32, bit, width, value) as u16;
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ContourIdentifier {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let identifier = u16::from_be_bytes(fixed_array(array)?);
+        Ok((ContourIdentifier { identifier }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.identifier.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_contour_identifier() {
+        // Create message
+        let mut contour_identifier = ContourIdentifier::default();
+        contour_identifier.set_intensity(3).unwrap();
+        contour_identifier.set_position(ContourPosition::First);
+        contour_identifier.set_serial_number(42);
+
+        // Convert struct to byte stream
+        let array = contour_identifier.encode();
+
+        assert_eq!(array, vec![0x32, 0x2a]);
+
+        // New message
+        let (object, length) = ContourIdentifier::decode(&array).unwrap();
+
+        assert_eq!(length, ContourIdentifier::MESSAGE_LENGTH);
+        assert!(!object.get_system_coordinates());
+        assert_eq!(object.get_intensity(), 3);
+        assert_eq!(object.get_position(), ContourPosition::First);
+        assert_eq!(object.get_serial_number(), 42);
+        assert_eq!(
+            contour_identifier.set_intensity(8),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
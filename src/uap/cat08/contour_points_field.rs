@@ -0,0 +1,104 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_signed, DataItem};
+
+// Sequence of Contour Points in SPF Notation
+//
+// Repetition factor followed by contour points
+// | 16 .. 9 | 8 .. 1 | bit
+// |   X1    |   Y1   |
+//
+// Resolution of the coordinates is given by the scaling factor of the processing status.
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ContourPoints {
+    points: Vec<(i8, i8)>, // 2 bytes each
+}
+
+/*
+* Implementation ContourPoints
+*/
+impl ContourPoints {
+    /*
+     * Insert contour point in NM, resolution in NM
+     * -128 * resolution <= x, y < 128 * resolution
+     */
+    pub fn insert_point(&mut self, x: f64, y: f64, resolution: f64) -> Result<(), CatError> {
+        if self.points.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        let x = from_signed(x, resolution, 8)? as i8;
+        let y = from_signed(y, resolution, 8)? as i8;
+        self.points.push((x, y));
+        Ok(())
+    }
+
+    /*
+     * Get all contour points in NM, resolution in NM
+     */
+    pub fn get_points(&self, resolution: f64) -> Vec<(f64, f64)> {
+        self.points
+            .iter()
+            .map(|(x, y)| (*x as f64 * resolution, *y as f64 * resolution))
+            .collect()
+    }
+
+    /*
+     * Length of one contour point.
+     */
+    const POINT_LENGTH: usize = 2;
+}
+
+impl DataItem for ContourPoints {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::POINT_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let points = array[1..length]
+            .chunks_exact(Self::POINT_LENGTH)
+            .map(|chunk| (chunk[0] as i8, chunk[1] as i8))
+            .collect();
+
+        Ok((ContourPoints { points }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.points.len() as u8];
+
+        for (x, y) in self.points.iter() {
+            vector.push(*x as u8);
+            vector.push(*y as u8);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_contour_points() {
+        // Create message
+        let mut contour_points = ContourPoints::default();
+        contour_points.insert_point(1.0, -0.5, 0.25).unwrap();
+        contour_points.insert_point(2.0, 0.75, 0.25).unwrap();
+
+        // Convert struct to byte stream
+        let array = contour_points.encode();
+
+        assert_eq!(array, vec![0x02, 0x04, 0xfe, 0x08, 0x03]);
+
+        // New message
+        let (object, length) = ContourPoints::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_points(0.25), vec![(1.0, -0.5), (2.0, 0.75)]);
+        assert_eq!(
+            ContourPoints::decode(&[0x02, 0x04, 0xfe]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
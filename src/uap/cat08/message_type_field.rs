@@ -0,0 +1,97 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Weather Message Type
+//
+
+/// Types of weather messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum WeatherMessage {
+    Unknown = 0,
+    PolarVector = 1,
+    CartesianVector = 2,
+    ContourRecord = 3,
+    CartesianStartEndVector = 4,
+    StartOfPicture = 254,
+    EndOfPicture = 255,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct WeatherMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation WeatherMessageType
+*/
+impl WeatherMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: WeatherMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> WeatherMessage {
+        match self.message {
+            1 => WeatherMessage::PolarVector,
+            2 => WeatherMessage::CartesianVector,
+            3 => WeatherMessage::ContourRecord,
+            4 => WeatherMessage::CartesianStartEndVector,
+            254 => WeatherMessage::StartOfPicture,
+            255 => WeatherMessage::EndOfPicture,
+            _ => WeatherMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for WeatherMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((WeatherMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_weather_message_type() {
+        // Create message
+        let mut message_type = WeatherMessageType::default();
+        message_type.set_message_type(WeatherMessage::StartOfPicture);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0xfe]);
+
+        // New message
+        let (object, length) = WeatherMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, WeatherMessageType::MESSAGE_LENGTH);
+        assert_eq!(object.get_message_type(), WeatherMessage::StartOfPicture);
+        assert_eq!(
+            WeatherMessageType::decode(&[0x05])
+                .unwrap()
+                .0
+                .get_message_type(),
+            WeatherMessage::Unknown
+        );
+    }
+}
@@ -0,0 +1,9 @@
+pub mod cartesian_vectors_field;
+pub mod contour_identifier_field;
+pub mod contour_points_field;
+pub mod message_type_field;
+pub mod polar_vectors_field;
+pub mod processing_status_field;
+pub mod total_items_field;
+pub mod vector_qualifier_field;
+pub mod weather_vectors_field;
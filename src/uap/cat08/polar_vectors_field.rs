@@ -0,0 +1,125 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_unsigned, DataItem};
+
+// Sequence of Polar Vectors in SPF Notation
+//
+// Repetition factor followed by polar vectors
+// | 32 .. 25    | 24 .. 17  | 16 .. 1 | bit
+// | START RANGE | END RANGE | AZIMUTH |
+//
+// Resolution of the ranges is given by the scaling factor of the processing status.
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct PolarVectors {
+    vectors: Vec<(u8, u8, u16)>, // 4 bytes each
+}
+
+/*
+* Implementation PolarVectors
+*/
+impl PolarVectors {
+    /*
+     * Insert vector with start and end range in NM and azimuth in degree,
+     * resolution of the ranges in NM
+     * 0 <= range < 256 * resolution
+     * 0 <= azimuth < 360
+     */
+    pub fn insert_vector(
+        &mut self,
+        start_range: f64,
+        end_range: f64,
+        azimuth: f64,
+        resolution: f64,
+    ) -> Result<(), CatError> {
+        if self.vectors.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        let start_range = from_unsigned(start_range, resolution, 8)? as u8;
+        let end_range = from_unsigned(end_range, resolution, 8)? as u8;
+        let azimuth = from_unsigned(azimuth, Self::FACTOR_AZIMUTH, 16)? as u16;
+        self.vectors.push((start_range, end_range, azimuth));
+        Ok(())
+    }
+
+    /*
+     * Get all vectors as start and end range in NM and azimuth in degree,
+     * resolution of the ranges in NM
+     */
+    pub fn get_vectors(&self, resolution: f64) -> Vec<(f64, f64, f64)> {
+        self.vectors
+            .iter()
+            .map(|(start_range, end_range, azimuth)| {
+                (
+                    *start_range as f64 * resolution,
+                    *end_range as f64 * resolution,
+                    *azimuth as f64 * Self::FACTOR_AZIMUTH,
+                )
+            })
+            .collect()
+    }
+
+    /*
+     * Length of one vector.
+     */
+    const VECTOR_LENGTH: usize = 4;
+
+    /*
+     * Conversion factor of azimuth.
+     */
+    const FACTOR_AZIMUTH: f64 = 360.0 / 65536.0;
+}
+
+impl DataItem for PolarVectors {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::VECTOR_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let vectors = array[1..length]
+            .chunks_exact(Self::VECTOR_LENGTH)
+            .map(|chunk| (chunk[0], chunk[1], u16::from_be_bytes([chunk[2], chunk[3]])))
+            .collect();
+
+        Ok((PolarVectors { vectors }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.vectors.len() as u8];
+
+        for (start_range, end_range, azimuth) in self.vectors.iter() {
+            vector.push(*start_range);
+            vector.push(*end_range);
+            vector.extend(azimuth.to_be_bytes());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_polar_vectors() {
+        // Create message
+        let mut polar_vectors = PolarVectors::default();
+        polar_vectors.insert_vector(10.0, 12.5, 90.0, 0.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = polar_vectors.encode();
+
+        assert_eq!(array, vec![0x01, 0x14, 0x19, 0x40, 0x00]);
+
+        // New message
+        let (object, length) = PolarVectors::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_vectors(0.5), vec![(10.0, 12.5, 90.0)]);
+        assert_eq!(
+            polar_vectors.insert_vector(128.0, 0.0, 0.0, 0.5),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
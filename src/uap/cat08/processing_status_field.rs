@@ -0,0 +1,175 @@
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, to_signed, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Processing Status
+//
+// First part of three octets followed by one octet extents
+// | 24 .. 20 | 19 .. 17 | 16 .. 2 | 1  | bit
+// |    f     |    R     |    Q    | FX |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ProcessingStatus {
+    status: [u8; 3],        // 3 bytes
+    extents: ExtendedField, // 0+ bytes
+}
+
+/*
+* Implementation ProcessingStatus
+*/
+impl ProcessingStatus {
+    /*
+     * Set scaling factor of the vector and contour coordinates
+     * -16 <= scaling <= 15
+     */
+    pub fn set_scaling_factor(&mut self, scaling: i8) -> Result<(), CatError> {
+        if !(-16..=15).contains(&scaling) {
+            return Err(CatError::ValueInvalid);
+        }
+        self.set_status_bits(24, 5, scaling as u32);
+        Ok(())
+    }
+
+    /*
+     * Get scaling factor of the vector and contour coordinates
+     */
+    pub fn get_scaling_factor(&self) -> i8 {
+        to_signed(get_bits(self.get_status(), 24, 5), 5) as i8
+    }
+
+    /*
+     * Get resolution of the vector and contour coordinates in NM
+     */
+    pub fn get_resolution(&self) -> f64 {
+        2f64.powi(self.get_scaling_factor() as i32 - 6)
+    }
+
+    /*
+     * Set current reduction stage in use
+     * 0 <= stage <= 7
+     */
+    pub fn set_reduction_stage(&mut self, stage: u8) -> Result<(), CatError> {
+        if stage > 0x07 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.set_status_bits(19, 3, stage as u32);
+        Ok(())
+    }
+
+    /*
+     * Get current reduction stage in use
+     */
+    pub fn get_reduction_stage(&self) -> u8 {
+        get_bits(self.get_status(), 19, 3) as u8
+    }
+
+    /*
+     * Set processing parameters
+     * 0 <= parameters < 2^15
+     */
+    pub fn set_parameters(&mut self, parameters: u16) -> Result<(), CatError> {
+        if parameters > 0x7fff {
+            return Err(CatError::ValueInvalid);
+        }
+        self.set_status_bits(16, 15, parameters as u32);
+        Ok(())
+    }
+
+    /*
+     * Get processing parameters
+     */
+    pub fn get_parameters(&self) -> u16 {
+        get_bits(self.get_status(), 16, 15) as u16
+    }
+
+    /*
+     * Get first part as 24 bit value
+     */
+    fn get_status(&self) -> u32 {
+        u32::from_be_bytes([0, self.status[0], self.status[1], self.status[2]])
+    }
+
+    /*
+     * Set bits of the first part
+     */
+    fn set_status_bits(&mut self, bit: u8, width: u8, value: u32) {
+        let status = set_bits(self.get_status(), bit, width, value);
+        self.status.copy_from_slice(&status.to_be_bytes()[1..4]);
+    }
+
+    /*
+     * Length of the first part.
+     */
+    const STATUS_LENGTH: usize = 3;
+
+    /*
+     * FX bit of the first part.
+     */
+    const FX: u8 = 0x01;
+}
+
+impl DataItem for ProcessingStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let status: [u8; 3] = fixed_array(array)?;
+        let mut length = Self::STATUS_LENGTH;
+        let mut extents = ExtendedField::default();
+
+        // Check field extension
+        if status[2] & Self::FX != 0 {
+            let (field, field_length) = ExtendedField::decode(&array[length..])?;
+            extents = field;
+            length += field_length;
+        }
+        Ok((ProcessingStatus { status, extents }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.status.to_vec();
+
+        if self.extents.is_empty() {
+            vector[2] &= !Self::FX;
+        } else {
+            vector[2] |= Self::FX;
+            vector.extend(self.extents.encode());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_processing_status() {
+        // Create message
+        let mut processing_status = ProcessingStatus::default();
+        processing_status.set_scaling_factor(-2).unwrap();
+        processing_status.set_reduction_stage(3).unwrap();
+        processing_status.set_parameters(0x1234).unwrap();
+
+        // Convert struct to byte stream
+        let array = processing_status.encode();
+
+        assert_eq!(array, vec![0xf3, 0x24, 0x68]);
+
+        // New message
+        let (object, length) = ProcessingStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 3);
+        assert_eq!(object.get_scaling_factor(), -2);
+        assert_eq!(object.get_resolution(), 1.0 / 256.0);
+        assert_eq!(object.get_reduction_stage(), 3);
+        assert_eq!(object.get_parameters(), 0x1234);
+
+        // First part with one extent
+        let (object, length) = ProcessingStatus::decode(&[0x00, 0x00, 0x01, 0x80]).unwrap();
+
+        assert_eq!(length, 4);
+        assert_eq!(object.encode(), vec![0x00, 0x00, 0x01, 0x80]);
+        assert_eq!(
+            processing_status.set_scaling_factor(16),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
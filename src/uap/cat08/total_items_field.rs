@@ -0,0 +1,69 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Total Number of Items Constituting One Weather Picture
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TotalItems {
+    total: u16, // 2 bytes
+}
+
+/*
+* Implementation TotalItems
+*/
+impl TotalItems {
+    /*
+     * Set total number of items of the weather picture
+     */
+    pub fn set_total(&mut self, total: u16) {
+        self.total = total;
+    }
+
+    /*
+     * Get total number of items of the weather picture
+     */
+    pub fn get_total(&self) -> u16 {
+        self.total
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TotalItems {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let total = u16::from_be_bytes(fixed_array(array)?);
+        Ok((TotalItems { total }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.total.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_total_items() {
+        // Create message
+        let mut total_items = TotalItems::default();
+        total_items.set_total(1000);
+
+        // Convert struct to byte stream
+        let array = total_items.encode();
+
+        assert_eq!(array, vec![0x03, 0xe8]);
+
+        // New message
+        let (object, length) = TotalItems::decode(&array).unwrap();
+
+        assert_eq!(length, TotalItems::MESSAGE_LENGTH);
+        assert_eq!(object.get_total(), 1000);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Vector Qualifier
+//
+// |   8   |  7 6 5  |  4 3 2  | 1  | bit
+// |  ORG  |   INT   |   DIR   | FX |
+// |     spare       | TST | ER | FX |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct VectorQualifier {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation VectorQualifier
+*/
+impl VectorQualifier {
+    /*
+     * Set vectors in system coordinates instead of local coordinates
+     */
+    pub fn set_system_coordinates(&mut self, value: bool) {
+        self.field.set_bit(0, 8, value);
+    }
+
+    /*
+     * Get vectors in system coordinates instead of local coordinates
+     */
+    pub fn get_system_coordinates(&self) -> bool {
+        self.field.get_bit(0, 8)
+    }
+
+    /*
+     * Set intensity level
+     * 0 <= intensity <= 7
+     */
+    pub fn set_intensity(&mut self, intensity: u8) -> Result<(), CatError> {
+        if intensity > Self::VALUE_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(0, 7, 3, intensity);
+        Ok(())
+    }
+
+    /*
+     * Get intensity level
+     */
+    pub fn get_intensity(&self) -> u8 {
+        self.field.get_bits(0, 7, 3)
+    }
+
+    /*
+     * Set shading orientation in steps of 22.5 degree
+     * 0 <= direction <= 7
+     */
+    pub fn set_direction(&mut self, direction: u8) -> Result<(), CatError> {
+        if direction > Self::VALUE_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(0, 4, 3, direction);
+        Ok(())
+    }
+
+    /*
+     * Get shading orientation in steps of 22.5 degree
+     */
+    pub fn get_direction(&self) -> u8 {
+        self.field.get_bits(0, 4, 3)
+    }
+
+    /*
+     * Set test vector
+     */
+    pub fn set_test(&mut self, value: bool) {
+        self.field.set_bit(1, 3, value);
+    }
+
+    /*
+     * Get test vector
+     */
+    pub fn get_test(&self) -> bool {
+        self.field.get_bit(1, 3)
+    }
+
+    /*
+     * Set error condition encountered
+     */
+    pub fn set_error(&mut self, value: bool) {
+        self.field.set_bit(1, 2, value);
+    }
+
+    /*
+     * Get error condition encountered
+     */
+    pub fn get_error(&self) -> bool {
+        self.field.get_bit(1, 2)
+    }
+
+    /*
+     * Maximum intensity level and direction in 3 bits.
+     */
+    const VALUE_MAX: u8 = 0x07;
+}
+
+impl DataItem for VectorQualifier {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((VectorQualifier { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_vector_qualifier() {
+        // Create message
+        let mut vector_qualifier = VectorQualifier::default();
+        vector_qualifier.set_intensity(5).unwrap();
+        vector_qualifier.set_direction(2).unwrap();
+        vector_qualifier.set_test(true);
+
+        // Convert struct to byte stream
+        let array = vector_qualifier.encode();
+
+        assert_eq!(array, vec![0x55, 0x04]);
+
+        // New message
+        let (object, length) = VectorQualifier::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert!(!object.get_system_coordinates());
+        assert_eq!(object.get_intensity(), 5);
+        assert_eq!(object.get_direction(), 2);
+        assert!(object.get_test());
+        assert!(!object.get_error());
+        assert_eq!(
+            vector_qualifier.set_intensity(8),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_signed, DataItem};
+
+// Sequence of Weather Vectors in SPF Notation
+//
+// Repetition factor followed by vectors with start and end point
+// | 32 .. 25 | 24 .. 17 | 16 .. 9 | 8 .. 1 | bit
+// |    X1    |    Y1    |   X2    |   Y2   |
+//
+// Resolution of the coordinates is given by the scaling factor of the processing status.
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct WeatherVectors {
+    vectors: Vec<[i8; 4]>, // 4 bytes each
+}
+
+/*
+* Implementation WeatherVectors
+*/
+impl WeatherVectors {
+    /*
+     * Insert vector with start and end point in NM, resolution in NM
+     * -128 * resolution <= coordinate < 128 * resolution
+     */
+    pub fn insert_vector(
+        &mut self,
+        start: (f64, f64),
+        end: (f64, f64),
+        resolution: f64,
+    ) -> Result<(), CatError> {
+        if self.vectors.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        let mut vector = [0i8; 4];
+
+        for (value, coordinate) in vector.iter_mut().zip([start.0, start.1, end.0, end.1]) {
+            *value = from_signed(coordinate, resolution, 8)? as i8;
+        }
+        self.vectors.push(vector);
+        Ok(())
+    }
+
+    /*
+     * Get all vectors as start and end point in NM, resolution in NM
+     */
+    pub fn get_vectors(&self, resolution: f64) -> Vec<((f64, f64), (f64, f64))> {
+        self.vectors
+            .iter()
+            .map(|vector| {
+                (
+                    (vector[0] as f64 * resolution, vector[1] as f64 * resolution),
+                    (vector[2] as f64 * resolution, vector[3] as f64 * resolution),
+                )
+            })
+            .collect()
+    }
+
+    /*
+     * Length of one vector.
+     */
+    const VECTOR_LENGTH: usize = 4;
+}
+
+impl DataItem for WeatherVectors {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::VECTOR_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let vectors = array[1..length]
+            .chunks_exact(Self::VECTOR_LENGTH)
+            .map(|chunk| {
+                [
+                    chunk[0] as i8,
+                    chunk[1] as i8,
+                    chunk[2] as i8,
+                    chunk[3] as i8,
+                ]
+            })
+            .collect();
+
+        Ok((WeatherVectors { vectors }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.vectors.len() as u8];
+
+        for coordinates in self.vectors.iter() {
+            vector.extend(coordinates.iter().map(|value| *value as u8));
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_weather_vectors() {
+        // Create message
+        let mut weather_vectors = WeatherVectors::default();
+        weather_vectors
+            .insert_vector((-1.0, 2.0), (3.0, -4.0), 0.5)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = weather_vectors.encode();
+
+        assert_eq!(array, vec![0x01, 0xfe, 0x04, 0x06, 0xf8]);
+
+        // New message
+        let (object, length) = WeatherVectors::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_vectors(0.5), vec![((-1.0, 2.0), (3.0, -4.0))]);
+        assert_eq!(
+            weather_vectors.insert_vector((64.0, 0.0), (0.0, 0.0), 0.5),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Amplitude of Primary Plot
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct PrimaryAmplitude {
+    amplitude: u8, // 1 byte
+}
+
+/*
+* Implementation PrimaryAmplitude
+*/
+impl PrimaryAmplitude {
+    /*
+     * Set amplitude, 0 is the maximum amplitude
+     */
+    pub fn set_amplitude(&mut self, amplitude: u8) {
+        self.amplitude = amplitude;
+    }
+
+    /*
+     * Get amplitude, 0 is the maximum amplitude
+     */
+    pub fn get_amplitude(&self) -> u8 {
+        self.amplitude
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for PrimaryAmplitude {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [amplitude] = fixed_array(array)?;
+        Ok((PrimaryAmplitude { amplitude }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.amplitude]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_primary_amplitude() {
+        // Create message
+        let mut primary_amplitude = PrimaryAmplitude::default();
+        primary_amplitude.set_amplitude(200);
+
+        // Convert struct to byte stream
+        let array = primary_amplitude.encode();
+
+        assert_eq!(array, vec![0xc8]);
+
+        // New message
+        let (object, length) = PrimaryAmplitude::decode(&array).unwrap();
+
+        assert_eq!(length, PrimaryAmplitude::MESSAGE_LENGTH);
+        assert_eq!(object.get_amplitude(), 200);
+    }
+}
@@ -0,0 +1,84 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Position in Cartesian Co-ordinates
+//
+// Position has a resolution of 1 m in CAT010
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CartesianPosition {
+    x: i16, // 2 bytes
+    y: i16, // 2 bytes
+}
+
+/*
+* Implementation CartesianPosition
+*/
+impl CartesianPosition {
+    /*
+     * Set position in m
+     */
+    pub fn set_position(&mut self, x: i16, y: i16) {
+        self.x = x;
+        self.y = y;
+    }
+
+    /*
+     * Get position x in m
+     */
+    pub fn get_x(&self) -> i16 {
+        self.x
+    }
+
+    /*
+     * Get position y in m
+     */
+    pub fn get_y(&self) -> i16 {
+        self.y
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for CartesianPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let x = i16::from_be_bytes([array[0], array[1]]);
+        let y = i16::from_be_bytes([array[2], array[3]]);
+        Ok((CartesianPosition { x, y }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.x.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.y.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cartesian_position() {
+        // Create message
+        let mut cartesian_position = CartesianPosition::default();
+        cartesian_position.set_position(-100, 2000);
+
+        // Convert struct to byte stream
+        let array = cartesian_position.encode();
+
+        assert_eq!(array, vec![0xff, 0x9c, 0x07, 0xd0]);
+
+        // New message
+        let (object, _length) = CartesianPosition::decode(&array).unwrap();
+
+        assert_eq!(object.get_x(), -100);
+        assert_eq!(object.get_y(), 2000);
+    }
+}
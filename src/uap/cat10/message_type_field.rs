@@ -0,0 +1,86 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Surface Movement Message Type
+//
+
+/// Types of surface movement messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SurfaceMessage {
+    Unknown,
+    TargetReport,
+    StartOfUpdateCycle,
+    PeriodicStatus,
+    EventStatus,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct SurfaceMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation SurfaceMessageType
+*/
+impl SurfaceMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: SurfaceMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> SurfaceMessage {
+        match self.message {
+            1 => SurfaceMessage::TargetReport,
+            2 => SurfaceMessage::StartOfUpdateCycle,
+            3 => SurfaceMessage::PeriodicStatus,
+            4 => SurfaceMessage::EventStatus,
+            _ => SurfaceMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for SurfaceMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((SurfaceMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_surface_message_type() {
+        // Create message
+        let mut message_type = SurfaceMessageType::default();
+        message_type.set_message_type(SurfaceMessage::PeriodicStatus);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x03]);
+
+        // New message
+        let (object, length) = SurfaceMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, SurfaceMessageType::MESSAGE_LENGTH);
+        assert_eq!(object.get_message_type(), SurfaceMessage::PeriodicStatus);
+    }
+}
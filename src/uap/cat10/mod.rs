@@ -0,0 +1,11 @@
+pub mod amplitude_field;
+pub mod cartesian_position_field;
+pub mod message_type_field;
+pub mod polar_position_field;
+pub mod position_deviation_field;
+pub mod preprogrammed_message_field;
+pub mod presence_field;
+pub mod system_status_field;
+pub mod target_report_descriptor_field;
+pub mod track_status_field;
+pub mod wgs_position_field;
@@ -0,0 +1,99 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Measured Position in Polar Co-ordinates
+//
+// Range has a resolution of 1 m in CAT010
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct PolarPosition {
+    rho: u16,   // 2 bytes
+    theta: u16, // 2 bytes
+}
+
+/*
+* Implementation PolarPosition
+*/
+impl PolarPosition {
+    /*
+     * Set range in m
+     */
+    pub fn set_rho(&mut self, rho: u16) {
+        self.rho = rho;
+    }
+
+    /*
+     * Get range in m
+     */
+    pub fn get_rho(&self) -> u16 {
+        self.rho
+    }
+
+    /*
+     * Set azimuth in degree
+     * 0 <= theta < 360
+     */
+    pub fn set_theta(&mut self, theta: f32) -> Result<(), CatError> {
+        self.theta = from_unsigned(theta as f64, Self::FACTOR_THETA as f64, 16)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get azimuth in degree
+     */
+    pub fn get_theta(&self) -> f32 {
+        self.theta as f32 * Self::FACTOR_THETA
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of azimuth.
+     */
+    const FACTOR_THETA: f32 = 360.0 / 65536.0;
+}
+
+impl DataItem for PolarPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let rho = u16::from_be_bytes([array[0], array[1]]);
+        let theta = u16::from_be_bytes([array[2], array[3]]);
+        Ok((PolarPosition { rho, theta }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.rho.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.theta.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_polar_position() {
+        // Create message
+        let mut polar_position = PolarPosition::default();
+        polar_position.set_rho(1500);
+        polar_position.set_theta(270.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = polar_position.encode();
+
+        assert_eq!(array, vec![0x05, 0xdc, 0xc0, 0x00]);
+
+        // New message
+        let (object, _length) = PolarPosition::decode(&array).unwrap();
+
+        assert_eq!(object.get_rho(), 1500);
+        assert_eq!(object.get_theta(), 270.0);
+        assert_eq!(polar_position.set_theta(360.0), Err(CatError::ValueInvalid));
+    }
+}
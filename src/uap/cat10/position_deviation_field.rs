@@ -0,0 +1,122 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, from_unsigned, DataItem};
+
+// Standard Deviation of Position
+//
+// | 32 .. 25 | 24 .. 17 | 16 .. 1 | bit
+// | SIGMA X  | SIGMA Y  | COV XY  |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct PositionDeviation {
+    sigma_x: u8,     // 1 byte
+    sigma_y: u8,     // 1 byte
+    covariance: i16, // 2 bytes
+}
+
+/*
+* Implementation PositionDeviation
+*/
+impl PositionDeviation {
+    /*
+     * Set standard deviation of x and y in m
+     * 0 <= sigma_x, sigma_y < 64
+     */
+    pub fn set_sigma(&mut self, sigma_x: f32, sigma_y: f32) -> Result<(), CatError> {
+        let sigma_x = from_unsigned(sigma_x as f64, Self::FACTOR as f64, 8)? as u8;
+        let sigma_y = from_unsigned(sigma_y as f64, Self::FACTOR as f64, 8)? as u8;
+
+        self.sigma_x = sigma_x;
+        self.sigma_y = sigma_y;
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of x in m
+     */
+    pub fn get_sigma_x(&self) -> f32 {
+        self.sigma_x as f32 * Self::FACTOR
+    }
+
+    /*
+     * Get standard deviation of y in m
+     */
+    pub fn get_sigma_y(&self) -> f32 {
+        self.sigma_y as f32 * Self::FACTOR
+    }
+
+    /*
+     * Set covariance of x and y in m²
+     * -8192 <= covariance < 8192
+     */
+    pub fn set_covariance(&mut self, covariance: f32) -> Result<(), CatError> {
+        self.covariance = from_signed(covariance as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get covariance of x and y in m²
+     */
+    pub fn get_covariance(&self) -> f32 {
+        self.covariance as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 0.25;
+}
+
+impl DataItem for PositionDeviation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let position_deviation = PositionDeviation {
+            sigma_x: array[0],
+            sigma_y: array[1],
+            covariance: i16::from_be_bytes([array[2], array[3]]),
+        };
+        Ok((position_deviation, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.sigma_x, self.sigma_y];
+        vector.extend_from_slice(&self.covariance.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_position_deviation() {
+        // Create message
+        let mut position_deviation = PositionDeviation::default();
+        position_deviation.set_sigma(2.5, 10.0).unwrap();
+        position_deviation.set_covariance(-1.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = position_deviation.encode();
+
+        assert_eq!(array, vec![0x0a, 0x28, 0xff, 0xfc]);
+
+        // New message
+        let (object, length) = PositionDeviation::decode(&array).unwrap();
+
+        assert_eq!(length, PositionDeviation::MESSAGE_LENGTH);
+        assert_eq!(object.get_sigma_x(), 2.5);
+        assert_eq!(object.get_sigma_y(), 10.0);
+        assert_eq!(object.get_covariance(), -1.0);
+        assert_eq!(
+            position_deviation.set_sigma(64.0, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Pre-programmed Message
+//
+// |  8  | 7 .. 1 | bit
+// | TRB |  MSG   |
+//
+
+/// Pre-programmed messages of a vehicle
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum VehicleMessage {
+    Unknown,
+    TowingAircraft,
+    FollowMe,
+    RunwayCheck,
+    EmergencyOperation,
+    WorkInProgress,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct PreprogrammedMessage {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation PreprogrammedMessage
+*/
+impl PreprogrammedMessage {
+    /*
+     * Set vehicle in trouble
+     */
+    pub fn set_trouble(&mut self, value: bool) {
+        if value {
+            self.message |= Self::TRB;
+        } else {
+            self.message &= !Self::TRB;
+        }
+    }
+
+    /*
+     * Get vehicle in trouble
+     */
+    pub fn get_trouble(&self) -> bool {
+        self.message & Self::TRB != 0
+    }
+
+    /*
+     * Set pre-programmed message
+     */
+    pub fn set_message(&mut self, message: VehicleMessage) {
+        self.message = (self.message & Self::TRB) | message as u8;
+    }
+
+    /*
+     * Get pre-programmed message
+     */
+    pub fn get_message(&self) -> VehicleMessage {
+        match self.message & !Self::TRB {
+            1 => VehicleMessage::TowingAircraft,
+            2 => VehicleMessage::FollowMe,
+            3 => VehicleMessage::RunwayCheck,
+            4 => VehicleMessage::EmergencyOperation,
+            5 => VehicleMessage::WorkInProgress,
+            _ => VehicleMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Bit mask of the trouble flag.
+     */
+    const TRB: u8 = 0x80;
+}
+
+impl DataItem for PreprogrammedMessage {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((PreprogrammedMessage { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_preprogrammed_message() {
+        // Create message
+        let mut preprogrammed_message = PreprogrammedMessage::default();
+        preprogrammed_message.set_message(VehicleMessage::FollowMe);
+        preprogrammed_message.set_trouble(true);
+
+        // Convert struct to byte stream
+        let array = preprogrammed_message.encode();
+
+        assert_eq!(array, vec![0x82]);
+
+        // New message
+        let (object, length) = PreprogrammedMessage::decode(&array).unwrap();
+
+        assert_eq!(length, PreprogrammedMessage::MESSAGE_LENGTH);
+        assert!(object.get_trouble());
+        assert_eq!(object.get_message(), VehicleMessage::FollowMe);
+    }
+}
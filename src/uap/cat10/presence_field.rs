@@ -0,0 +1,113 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_signed, DataItem};
+
+// Presence
+//
+// Repetition factor followed by position differences to the plot
+// | 16 .. 9 | 8 .. 1 | bit
+// |  DRHO   | DTHETA |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Presence {
+    differences: Vec<(i8, i8)>, // 2 bytes each
+}
+
+/*
+* Implementation Presence
+*/
+impl Presence {
+    /*
+     * Insert difference of range in m and azimuth in degree
+     * -128 <= drho < 128
+     * -19.2 <= dtheta < 19.2
+     */
+    pub fn insert_difference(&mut self, drho: i8, dtheta: f32) -> Result<(), CatError> {
+        if self.differences.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        let dtheta = from_signed(dtheta as f64, Self::FACTOR as f64, 8)? as i8;
+        self.differences.push((drho, dtheta));
+        Ok(())
+    }
+
+    /*
+     * Get all differences of range in m and azimuth in degree
+     */
+    pub fn get_differences(&self) -> Vec<(i8, f32)> {
+        self.differences
+            .iter()
+            .map(|(drho, dtheta)| (*drho, *dtheta as f32 * Self::FACTOR))
+            .collect()
+    }
+
+    /*
+     * Length of one difference.
+     */
+    const DIFFERENCE_LENGTH: usize = 2;
+
+    /*
+     * Conversion factor of azimuth.
+     */
+    const FACTOR: f32 = 0.15;
+}
+
+impl DataItem for Presence {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::DIFFERENCE_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let differences = array[1..length]
+            .chunks_exact(Self::DIFFERENCE_LENGTH)
+            .map(|chunk| (chunk[0] as i8, chunk[1] as i8))
+            .collect();
+
+        Ok((Presence { differences }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.differences.len() as u8];
+
+        for (drho, dtheta) in self.differences.iter() {
+            vector.push(*drho as u8);
+            vector.push(*dtheta as u8);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_presence() {
+        // Create message
+        let mut presence = Presence::default();
+        presence.insert_difference(-10, 0.3).unwrap();
+        presence.insert_difference(25, -1.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = presence.encode();
+
+        assert_eq!(array, vec![0x02, 0xf6, 0x02, 0x19, 0xf6]);
+
+        // New message
+        let (object, length) = Presence::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_differences().len(), 2);
+        assert_eq!(object.get_differences()[1].0, 25);
+        assert!((object.get_differences()[1].1 + 1.5).abs() < 1e-6);
+        assert_eq!(
+            presence.insert_difference(0, 19.2),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            Presence::decode(&[0x02, 0xf6, 0x02]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
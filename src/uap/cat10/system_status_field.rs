@@ -0,0 +1,148 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// System Status
+//
+// | 8 7  |  6  |  5  |  4  |  3  | 2 1   | bit
+// | NOGO | OVL | TSV | DIV | TTF | spare |
+//
+
+/// Operational release status of the system
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum OperationalStatus {
+    Operational,
+    Degraded,
+    NoGo,
+    Undefined,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct SystemStatus {
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation SystemStatus
+*/
+impl SystemStatus {
+    /*
+     * Set operational release status
+     */
+    pub fn set_operational(&mut self, operational: OperationalStatus) {
+        self.status = set_bits(self.status as u32, 8, 2, operational as u32) as u8;
+    }
+
+    /*
+     * Get operational release status
+     */
+    pub fn get_operational(&self) -> OperationalStatus {
+        match get_bits(self.status as u32, 8, 2) {
+            0 => OperationalStatus::Operational,
+            1 => OperationalStatus::Degraded,
+            2 => OperationalStatus::NoGo,
+            _ => OperationalStatus::Undefined,
+        }
+    }
+
+    /*
+     * Set overload
+     */
+    pub fn set_overload(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 6, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get overload
+     */
+    pub fn get_overload(&self) -> bool {
+        get_bits(self.status as u32, 6, 1) == 1
+    }
+
+    /*
+     * Set invalid time source
+     */
+    pub fn set_time_source_invalid(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 5, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get invalid time source
+     */
+    pub fn get_time_source_invalid(&self) -> bool {
+        get_bits(self.status as u32, 5, 1) == 1
+    }
+
+    /*
+     * Set diversity degraded
+     */
+    pub fn set_diversity_degraded(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 4, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get diversity degraded
+     */
+    pub fn get_diversity_degraded(&self) -> bool {
+        get_bits(self.status as u32, 4, 1) == 1
+    }
+
+    /*
+     * Set test target failure
+     */
+    pub fn set_test_target_failure(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 3, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get test target failure
+     */
+    pub fn get_test_target_failure(&self) -> bool {
+        get_bits(self.status as u32, 3, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for SystemStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [status] = fixed_array(array)?;
+        Ok((SystemStatus { status }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.status]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_system_status() {
+        // Create message
+        let mut system_status = SystemStatus::default();
+        system_status.set_operational(OperationalStatus::Degraded);
+        system_status.set_overload(true);
+        system_status.set_test_target_failure(true);
+
+        // Convert struct to byte stream
+        let array = system_status.encode();
+
+        assert_eq!(array, vec![0x64]);
+
+        // New message
+        let (object, length) = SystemStatus::decode(&array).unwrap();
+
+        assert_eq!(length, SystemStatus::MESSAGE_LENGTH);
+        assert_eq!(object.get_operational(), OperationalStatus::Degraded);
+        assert!(object.get_overload());
+        assert!(!object.get_time_source_invalid());
+        assert!(object.get_test_target_failure());
+    }
+}
@@ -0,0 +1,266 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Target Report Descriptor
+//
+// | 8 7 6 |  5  |  4  |  3  |  2  | 1  | bit
+// |  TYP  | DCR | CHN | GBS | CRT | FX |
+// |  SIM  | TST | RAB | LOP | TOT | FX |
+// |  SPI  |        spare          | FX |
+//
+
+/// Type of surface detection
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SurfaceDetection {
+    SsrMultilateration,
+    ModeSMultilateration,
+    AdsB,
+    Psr,
+    MagneticLoop,
+    HfMultilateration,
+    NotDefined,
+    Other,
+}
+
+/// Loop status of a magnetic loop detection
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum LoopStatus {
+    Undetermined,
+    LoopStart,
+    LoopFinish,
+    Unknown,
+}
+
+/// Type of target
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TargetType {
+    Undetermined,
+    Aircraft,
+    GroundVehicle,
+    Helicopter,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TargetReportDescriptor {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TargetReportDescriptor
+*/
+impl TargetReportDescriptor {
+    /*
+     * Set detection type
+     */
+    pub fn set_typ(&mut self, typ: SurfaceDetection) {
+        self.field.set_bits(0, 8, 3, typ as u8);
+    }
+
+    /*
+     * Get detection type
+     */
+    pub fn get_typ(&self) -> SurfaceDetection {
+        match self.field.get_bits(0, 8, 3) {
+            0 => SurfaceDetection::SsrMultilateration,
+            1 => SurfaceDetection::ModeSMultilateration,
+            2 => SurfaceDetection::AdsB,
+            3 => SurfaceDetection::Psr,
+            4 => SurfaceDetection::MagneticLoop,
+            5 => SurfaceDetection::HfMultilateration,
+            6 => SurfaceDetection::NotDefined,
+            _ => SurfaceDetection::Other,
+        }
+    }
+
+    /*
+     * Set differential correction applied
+     */
+    pub fn set_differential_correction(&mut self, value: bool) {
+        self.field.set_bit(0, 5, value);
+    }
+
+    /*
+     * Get differential correction applied
+     */
+    pub fn get_differential_correction(&self) -> bool {
+        self.field.get_bit(0, 5)
+    }
+
+    /*
+     * Set report from chain 2
+     */
+    pub fn set_chain_2(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get report from chain 2
+     */
+    pub fn get_chain_2(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set transponder ground bit set
+     */
+    pub fn set_ground(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get transponder ground bit set
+     */
+    pub fn get_ground(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set corrupted replies in multilateration
+     */
+    pub fn set_corrupted(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get corrupted replies in multilateration
+     */
+    pub fn get_corrupted(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set simulated target report
+     */
+    pub fn set_simulated(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get simulated target report
+     */
+    pub fn get_simulated(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set test target report
+     */
+    pub fn set_test(&mut self, value: bool) {
+        self.field.set_bit(1, 7, value);
+    }
+
+    /*
+     * Get test target report
+     */
+    pub fn get_test(&self) -> bool {
+        self.field.get_bit(1, 7)
+    }
+
+    /*
+     * Set report from field monitor (fixed transponder)
+     */
+    pub fn set_field_monitor(&mut self, value: bool) {
+        self.field.set_bit(1, 6, value);
+    }
+
+    /*
+     * Get report from field monitor (fixed transponder)
+     */
+    pub fn get_field_monitor(&self) -> bool {
+        self.field.get_bit(1, 6)
+    }
+
+    /*
+     * Set loop status
+     */
+    pub fn set_loop_status(&mut self, status: LoopStatus) {
+        self.field.set_bits(1, 5, 2, status as u8);
+    }
+
+    /*
+     * Get loop status
+     */
+    pub fn get_loop_status(&self) -> LoopStatus {
+        match self.field.get_bits(1, 5, 2) {
+            0 => LoopStatus::Undetermined,
+            1 => LoopStatus::LoopStart,
+            2 => LoopStatus::LoopFinish,
+            _ => LoopStatus::Unknown,
+        }
+    }
+
+    /*
+     * Set type of target
+     */
+    pub fn set_target_type(&mut self, target_type: TargetType) {
+        self.field.set_bits(1, 3, 2, target_type as u8);
+    }
+
+    /*
+     * Get type of target
+     */
+    pub fn get_target_type(&self) -> TargetType {
+        match self.field.get_bits(1, 3, 2) {
+            0 => TargetType::Undetermined,
+            1 => TargetType::Aircraft,
+            2 => TargetType::GroundVehicle,
+            _ => TargetType::Helicopter,
+        }
+    }
+
+    /*
+     * Set special position identification
+     */
+    pub fn set_spi(&mut self, value: bool) {
+        self.field.set_bit(2, 8, value);
+    }
+
+    /*
+     * Get special position identification
+     */
+    pub fn get_spi(&self) -> bool {
+        self.field.get_bit(2, 8)
+    }
+}
+
+impl DataItem for TargetReportDescriptor {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TargetReportDescriptor { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_report_descriptor() {
+        // Create message
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_typ(SurfaceDetection::ModeSMultilateration);
+        target_report_descriptor.set_ground(true);
+        target_report_descriptor.set_target_type(TargetType::GroundVehicle);
+
+        // Convert struct to byte stream
+        let array = target_report_descriptor.encode();
+
+        assert_eq!(array, vec![0x25, 0x04]);
+
+        // New message
+        let (object, length) = TargetReportDescriptor::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_typ(), SurfaceDetection::ModeSMultilateration);
+        assert!(object.get_ground());
+        assert!(!object.get_simulated());
+        assert_eq!(object.get_loop_status(), LoopStatus::Undetermined);
+        assert_eq!(object.get_target_type(), TargetType::GroundVehicle);
+        assert!(!object.get_spi());
+    }
+}
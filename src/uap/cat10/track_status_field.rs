@@ -0,0 +1,241 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Track Status
+//
+// |  8  |  7  | 6 5 |  4  |  3  |  2  | 1  | bit
+// | CNF | TRE | CST | MAH | TCC | STH | FX |
+// | TOM |    DOU    |    MRS    | FX |
+// | GHO |        spare          | FX |
+//
+
+/// Extrapolation of the track
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Extrapolation {
+    NoExtrapolation,
+    SensorRefreshPeriod,
+    MaskedArea,
+    AbsenceOfDetection,
+}
+
+/// Type of movement of the track
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum MovementType {
+    Unknown,
+    TakeOff,
+    Landing,
+    Other,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrackStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TrackStatus
+*/
+impl TrackStatus {
+    /*
+     * Set track in initiation phase
+     */
+    pub fn set_tentative(&mut self, value: bool) {
+        self.field.set_bit(0, 8, value);
+    }
+
+    /*
+     * Get track in initiation phase
+     */
+    pub fn get_tentative(&self) -> bool {
+        self.field.get_bit(0, 8)
+    }
+
+    /*
+     * Set last report for the track
+     */
+    pub fn set_end_of_track(&mut self, value: bool) {
+        self.field.set_bit(0, 7, value);
+    }
+
+    /*
+     * Get last report for the track
+     */
+    pub fn get_end_of_track(&self) -> bool {
+        self.field.get_bit(0, 7)
+    }
+
+    /*
+     * Set extrapolation of the track
+     */
+    pub fn set_extrapolation(&mut self, extrapolation: Extrapolation) {
+        self.field.set_bits(0, 6, 2, extrapolation as u8);
+    }
+
+    /*
+     * Get extrapolation of the track
+     */
+    pub fn get_extrapolation(&self) -> Extrapolation {
+        match self.field.get_bits(0, 6, 2) {
+            0 => Extrapolation::NoExtrapolation,
+            1 => Extrapolation::SensorRefreshPeriod,
+            2 => Extrapolation::MaskedArea,
+            _ => Extrapolation::AbsenceOfDetection,
+        }
+    }
+
+    /*
+     * Set horizontal manoeuvre
+     */
+    pub fn set_manoeuvre(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get horizontal manoeuvre
+     */
+    pub fn get_manoeuvre(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set slant range correction applied, otherwise tracking in radar plane
+     */
+    pub fn set_slant_correction(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get slant range correction applied, otherwise tracking in radar plane
+     */
+    pub fn get_slant_correction(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set smoothed position, otherwise measured position
+     */
+    pub fn set_smoothed(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get smoothed position, otherwise measured position
+     */
+    pub fn get_smoothed(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set type of movement
+     */
+    pub fn set_movement(&mut self, movement: MovementType) {
+        self.field.set_bits(1, 8, 2, movement as u8);
+    }
+
+    /*
+     * Get type of movement
+     */
+    pub fn get_movement(&self) -> MovementType {
+        match self.field.get_bits(1, 8, 2) {
+            0 => MovementType::Unknown,
+            1 => MovementType::TakeOff,
+            2 => MovementType::Landing,
+            _ => MovementType::Other,
+        }
+    }
+
+    /*
+     * Set doubt code
+     * 0 <= doubt <= 7
+     */
+    pub fn set_doubt(&mut self, doubt: u8) -> Result<(), CatError> {
+        if doubt > 0x07 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(1, 6, 3, doubt);
+        Ok(())
+    }
+
+    /*
+     * Get doubt code
+     */
+    pub fn get_doubt(&self) -> u8 {
+        self.field.get_bits(1, 6, 3)
+    }
+
+    /*
+     * Set merge or split indication
+     * 0 <= merge_split <= 3
+     */
+    pub fn set_merge_split(&mut self, merge_split: u8) -> Result<(), CatError> {
+        if merge_split > 0x03 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(1, 3, 2, merge_split);
+        Ok(())
+    }
+
+    /*
+     * Get merge or split indication
+     */
+    pub fn get_merge_split(&self) -> u8 {
+        self.field.get_bits(1, 3, 2)
+    }
+
+    /*
+     * Set ghost track
+     */
+    pub fn set_ghost(&mut self, value: bool) {
+        self.field.set_bit(2, 8, value);
+    }
+
+    /*
+     * Get ghost track
+     */
+    pub fn get_ghost(&self) -> bool {
+        self.field.get_bit(2, 8)
+    }
+}
+
+impl DataItem for TrackStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TrackStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_status() {
+        // Create message
+        let mut track_status = TrackStatus::default();
+        track_status.set_extrapolation(Extrapolation::MaskedArea);
+        track_status.set_movement(MovementType::Landing);
+        track_status.set_doubt(5).unwrap();
+        track_status.set_ghost(true);
+
+        // Convert struct to byte stream
+        let array = track_status.encode();
+
+        assert_eq!(array, vec![0x21, 0xa9, 0x80]);
+
+        // New message
+        let (object, length) = TrackStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 3);
+        assert_eq!(object.get_extrapolation(), Extrapolation::MaskedArea);
+        assert_eq!(object.get_movement(), MovementType::Landing);
+        assert_eq!(object.get_doubt(), 5);
+        assert_eq!(object.get_merge_split(), 0);
+        assert!(object.get_ghost());
+        assert_eq!(track_status.set_doubt(8), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::category::CatError;
+use crate::uap::aircraft_address_field::AircraftAddress;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::DataItem;
+use crate::uap::mode_s_mb_data_field::ModeSMbData;
+
+// Mode S / ADS-B Related Data
+//
+// Compound data item, primary subfield followed by present subfields
+// | MB  | ADR | spare | COM | spare | spare | spare | FX |
+// | ACT | EMC | spare ...                           | FX |
+//
+// Communications/ACAS Capability and Flight Status
+// | 16 15 14 | 13 12 11 | 10 9  |  8  |  7  |  6  |  5  | 4 .. 1 | bit
+// |   COM    |   STAT   | spare | SSC | ARC | AIC | B1A |  B1B   |
+//
+
+/// Subfields of Mode S / ADS-B related data
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    ModeSMbData = 0,
+    TargetAddress = 1,
+    CommunicationsCapability = 3,
+    AircraftType = 7,
+    EmitterCategory = 8,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct AircraftDerivedData {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation AircraftDerivedData
+*/
+impl AircraftDerivedData {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set Mode S MB data
+     */
+    pub fn set_mode_s_mb_data(&mut self, mode_s_mb_data: &ModeSMbData) {
+        self.field
+            .set_item(Subfield::ModeSMbData as usize, mode_s_mb_data);
+    }
+
+    /*
+     * Get Mode S MB data when present
+     */
+    pub fn get_mode_s_mb_data(&self) -> Option<ModeSMbData> {
+        self.field.get_item(Subfield::ModeSMbData as usize)
+    }
+
+    /*
+     * Set 24 bit target address
+     */
+    pub fn set_address(&mut self, address: AircraftAddress) {
+        self.field
+            .set_item(Subfield::TargetAddress as usize, &address);
+    }
+
+    /*
+     * Get 24 bit target address when present
+     */
+    pub fn get_address(&self) -> Option<AircraftAddress> {
+        self.field.get_item(Subfield::TargetAddress as usize)
+    }
+
+    /*
+     * Set communications capability and flight status of the transponder
+     * 0 <= capability, status <= 7
+     */
+    pub fn set_communications(&mut self, capability: u8, status: u8) -> Result<(), CatError> {
+        if capability > 0x07 || status > 0x07 {
+            return Err(CatError::ValueInvalid);
+        }
+        let mut value = match self.get_subfield(Subfield::CommunicationsCapability) {
+            Some(value) => [value[0], value[1]],
+            None => [0, 0],
+        };
+        value[0] = (value[0] & 0x03) | (capability << 5) | (status << 2);
+        self.field
+            .set_subfield(Subfield::CommunicationsCapability as usize, value.to_vec());
+        Ok(())
+    }
+
+    /*
+     * Get communications capability and flight status of the transponder
+     */
+    pub fn get_communications(&self) -> Option<(u8, u8)> {
+        let value = self.get_subfield(Subfield::CommunicationsCapability)?;
+        Some((value[0] >> 5, (value[0] >> 2) & 0x07))
+    }
+
+    /*
+     * Set aircraft derived type of aircraft, up to four characters
+     */
+    pub fn set_aircraft_type(&mut self, aircraft_type: &str) -> Result<(), CatError> {
+        if aircraft_type.len() > Self::TYPE_LENGTH
+            || !aircraft_type
+                .chars()
+                .all(|c| c.is_ascii_graphic() || c == ' ')
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        let data = format!("{:<width$}", aircraft_type, width = Self::TYPE_LENGTH).into_bytes();
+        self.field
+            .set_subfield(Subfield::AircraftType as usize, data);
+        Ok(())
+    }
+
+    /*
+     * Get aircraft derived type of aircraft without trailing spaces
+     */
+    pub fn get_aircraft_type(&self) -> Option<String> {
+        let value = self.get_subfield(Subfield::AircraftType)?;
+        Some(String::from_utf8_lossy(value).trim_end().to_string())
+    }
+
+    /*
+     * Set ADS-B emitter category
+     */
+    pub fn set_emitter_category(&mut self, category: u8) {
+        self.field
+            .set_subfield(Subfield::EmitterCategory as usize, vec![category]);
+    }
+
+    /*
+     * Get ADS-B emitter category when present
+     */
+    pub fn get_emitter_category(&self) -> Option<u8> {
+        self.get_subfield(Subfield::EmitterCategory)
+            .map(|value| value[0])
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 9] = [
+        SubfieldLength::Repetitive(8),
+        SubfieldLength::Fixed(3),
+        SubfieldLength::Spare,
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Spare,
+        SubfieldLength::Spare,
+        SubfieldLength::Spare,
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(1),
+    ];
+
+    /*
+     * Number of characters of the aircraft type.
+     */
+    const TYPE_LENGTH: usize = 4;
+}
+
+impl DataItem for AircraftDerivedData {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((AircraftDerivedData { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_aircraft_derived_data() {
+        // Create message
+        let mut address = AircraftAddress::default();
+        address.set_address(0x3c6586).unwrap();
+
+        let mut aircraft_derived_data = AircraftDerivedData::default();
+        aircraft_derived_data.set_address(address);
+        aircraft_derived_data.set_communications(1, 4).unwrap();
+        aircraft_derived_data.set_aircraft_type("A320").unwrap();
+        aircraft_derived_data.set_emitter_category(3);
+
+        // Convert struct to byte stream
+        let array = aircraft_derived_data.encode();
+
+        assert_eq!(
+            array,
+            vec![0x51, 0xc0, 0x3c, 0x65, 0x86, 0x30, 0x00, 0x41, 0x33, 0x32, 0x30, 0x03]
+        );
+
+        // New message
+        let (object, length) = AircraftDerivedData::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, aircraft_derived_data);
+        assert_eq!(object.get_address(), Some(address));
+        assert_eq!(object.get_communications(), Some((1, 4)));
+        assert_eq!(object.get_aircraft_type(), Some("A320".to_string()));
+        assert_eq!(object.get_emitter_category(), Some(3));
+        assert_eq!(object.get_mode_s_mb_data(), None);
+
+        // Spare subfield is not present
+        assert_eq!(
+            AircraftDerivedData::decode(&[0x20]),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Alert Messages
+//
+// |  24 | 23 22 | 21 .. 17 | 16 .. 9 | 8 .. 1 | bit
+// | ACK |  SVR  |  spare   |   AT    |   AN   |
+//
+
+/// Severity of an alert
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum AlertSeverity {
+    End,
+    Pre,
+    Severe,
+    Undefined,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AlertMessages {
+    alert: [u8; 3], // 3 bytes
+}
+
+/*
+* Implementation AlertMessages
+*/
+impl AlertMessages {
+    /*
+     * Set alert acknowledged
+     */
+    pub fn set_acknowledged(&mut self, value: bool) {
+        self.alert[0] = (self.alert[0] & !0x80) | ((value as u8) << 7);
+    }
+
+    /*
+     * Get alert acknowledged
+     */
+    pub fn get_acknowledged(&self) -> bool {
+        self.alert[0] & 0x80 != 0
+    }
+
+    /*
+     * Set alert severity
+     */
+    pub fn set_severity(&mut self, severity: AlertSeverity) {
+        self.alert[0] = (self.alert[0] & !0x60) | ((severity as u8) << 5);
+    }
+
+    /*
+     * Get alert severity
+     */
+    pub fn get_severity(&self) -> AlertSeverity {
+        match (self.alert[0] >> 5) & 0x03 {
+            0 => AlertSeverity::End,
+            1 => AlertSeverity::Pre,
+            2 => AlertSeverity::Severe,
+            _ => AlertSeverity::Undefined,
+        }
+    }
+
+    /*
+     * Set alert type
+     */
+    pub fn set_alert_type(&mut self, alert_type: u8) {
+        self.alert[1] = alert_type;
+    }
+
+    /*
+     * Get alert type
+     */
+    pub fn get_alert_type(&self) -> u8 {
+        self.alert[1]
+    }
+
+    /*
+     * Set alert number
+     */
+    pub fn set_alert_number(&mut self, number: u8) {
+        self.alert[2] = number;
+    }
+
+    /*
+     * Get alert number
+     */
+    pub fn get_alert_number(&self) -> u8 {
+        self.alert[2]
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for AlertMessages {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let alert = fixed_array(array)?;
+        Ok((AlertMessages { alert }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.alert.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_alert_messages() {
+        // Create message
+        let mut alert_messages = AlertMessages::default();
+        alert_messages.set_acknowledged(true);
+        alert_messages.set_severity(AlertSeverity::Severe);
+        alert_messages.set_alert_type(7);
+        alert_messages.set_alert_number(42);
+
+        // Convert struct to byte stream
+        let array = alert_messages.encode();
+
+        assert_eq!(array, vec![0xc0, 0x07, 0x2a]);
+
+        // New message
+        let (object, length) = AlertMessages::decode(&array).unwrap();
+
+        assert_eq!(length, AlertMessages::MESSAGE_LENGTH);
+        assert!(object.get_acknowledged());
+        assert_eq!(object.get_severity(), AlertSeverity::Severe);
+        assert_eq!(object.get_alert_type(), 7);
+        assert_eq!(object.get_alert_number(), 42);
+    }
+}
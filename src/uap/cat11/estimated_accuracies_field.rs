@@ -0,0 +1,298 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+
+// Estimated Accuracies
+//
+// Compound data item, primary subfield followed by present subfields
+// | APC | APW | ATH | AVC | ARC | AAC | spare | FX |
+//
+// APC position x and y, APW latitude and longitude, AVC velocity x and y,
+// AAC acceleration x and y
+//
+
+/// Subfields of estimated accuracies
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    CartesianPosition,
+    WgsPosition,
+    Height,
+    CartesianVelocity,
+    RateOfClimb,
+    CartesianAcceleration,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct EstimatedAccuracies {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation EstimatedAccuracies
+*/
+impl EstimatedAccuracies {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set accuracy of the Cartesian position in m
+     * 0 <= x, y < 64
+     */
+    pub fn set_cartesian_position(&mut self, x: f32, y: f32) -> Result<(), CatError> {
+        self.set_pair(
+            Subfield::CartesianPosition,
+            x as f64,
+            y as f64,
+            8,
+            Self::FACTOR_POSITION,
+        )
+    }
+
+    /*
+     * Get accuracy of the Cartesian position in m
+     */
+    pub fn get_cartesian_position(&self) -> Option<(f32, f32)> {
+        let (x, y) = self.get_pair(Subfield::CartesianPosition, Self::FACTOR_POSITION)?;
+        Some((x as f32, y as f32))
+    }
+
+    /*
+     * Set accuracy of the WGS-84 position in degree
+     * 0 <= latitude, longitude < 65536 * 180 / 2^31
+     */
+    pub fn set_wgs_position(&mut self, latitude: f64, longitude: f64) -> Result<(), CatError> {
+        self.set_pair(
+            Subfield::WgsPosition,
+            latitude,
+            longitude,
+            16,
+            Self::FACTOR_WGS,
+        )
+    }
+
+    /*
+     * Get accuracy of the WGS-84 position in degree
+     */
+    pub fn get_wgs_position(&self) -> Option<(f64, f64)> {
+        self.get_pair(Subfield::WgsPosition, Self::FACTOR_WGS)
+    }
+
+    /*
+     * Set accuracy of the height in m
+     * 0 <= height < 128
+     */
+    pub fn set_height(&mut self, height: f32) -> Result<(), CatError> {
+        let value = from_unsigned(height as f64, Self::FACTOR_HEIGHT as f64, 8)? as u8;
+        self.field
+            .set_subfield(Subfield::Height as usize, vec![value]);
+        Ok(())
+    }
+
+    /*
+     * Get accuracy of the height in m
+     */
+    pub fn get_height(&self) -> Option<f32> {
+        let value = self.get_subfield(Subfield::Height)?[0];
+        Some(value as f32 * Self::FACTOR_HEIGHT)
+    }
+
+    /*
+     * Set accuracy of the Cartesian velocity in m/s
+     * 0 <= vx, vy < 25.6
+     */
+    pub fn set_cartesian_velocity(&mut self, vx: f32, vy: f32) -> Result<(), CatError> {
+        self.set_pair(
+            Subfield::CartesianVelocity,
+            vx as f64,
+            vy as f64,
+            8,
+            Self::FACTOR_VELOCITY,
+        )
+    }
+
+    /*
+     * Get accuracy of the Cartesian velocity in m/s
+     */
+    pub fn get_cartesian_velocity(&self) -> Option<(f32, f32)> {
+        let (vx, vy) = self.get_pair(Subfield::CartesianVelocity, Self::FACTOR_VELOCITY)?;
+        Some((vx as f32, vy as f32))
+    }
+
+    /*
+     * Set accuracy of the rate of climb or descent in ft/min
+     * 0 <= rate < 1600
+     */
+    pub fn set_rate_of_climb(&mut self, rate: f32) -> Result<(), CatError> {
+        let value = from_unsigned(rate as f64, Self::FACTOR_RATE as f64, 8)? as u8;
+        self.field
+            .set_subfield(Subfield::RateOfClimb as usize, vec![value]);
+        Ok(())
+    }
+
+    /*
+     * Get accuracy of the rate of climb or descent in ft/min
+     */
+    pub fn get_rate_of_climb(&self) -> Option<f32> {
+        let value = self.get_subfield(Subfield::RateOfClimb)?[0];
+        Some(value as f32 * Self::FACTOR_RATE)
+    }
+
+    /*
+     * Set accuracy of the Cartesian acceleration in m/s^2
+     * 0 <= ax, ay < 2.56
+     */
+    pub fn set_cartesian_acceleration(&mut self, ax: f32, ay: f32) -> Result<(), CatError> {
+        self.set_pair(
+            Subfield::CartesianAcceleration,
+            ax as f64,
+            ay as f64,
+            8,
+            Self::FACTOR_ACCELERATION,
+        )
+    }
+
+    /*
+     * Get accuracy of the Cartesian acceleration in m/s^2
+     */
+    pub fn get_cartesian_acceleration(&self) -> Option<(f32, f32)> {
+        let (ax, ay) = self.get_pair(Subfield::CartesianAcceleration, Self::FACTOR_ACCELERATION)?;
+        Some((ax as f32, ay as f32))
+    }
+
+    /*
+     * Set subfield of two unsigned values of the same width
+     */
+    fn set_pair(
+        &mut self,
+        subfield: Subfield,
+        first: f64,
+        second: f64,
+        width: u8,
+        factor: f64,
+    ) -> Result<(), CatError> {
+        let bytes = (width / 8) as usize;
+        let mut data = Vec::with_capacity(2 * bytes);
+
+        for value in [first, second] {
+            let value = from_unsigned(value, factor, width)?;
+            data.extend(&value.to_be_bytes()[4 - bytes..]);
+        }
+        self.field.set_subfield(subfield as usize, data);
+        Ok(())
+    }
+
+    /*
+     * Get subfield of two unsigned values of the same width
+     */
+    fn get_pair(&self, subfield: Subfield, factor: f64) -> Option<(f64, f64)> {
+        let value = self.get_subfield(subfield)?;
+        let (first, second) = value.split_at(value.len() / 2);
+        let to_value = |array: &[u8]| {
+            array
+                .iter()
+                .fold(0_u32, |value, byte| (value << 8) | *byte as u32) as f64
+                * factor
+        };
+        Some((to_value(first), to_value(second)))
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 6] = [
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(2),
+    ];
+
+    /*
+     * Conversion factor of position accuracy.
+     */
+    const FACTOR_POSITION: f64 = 0.25;
+
+    /*
+     * Conversion factor of WGS-84 position accuracy.
+     */
+    const FACTOR_WGS: f64 = 180.0 / 2147483648.0;
+
+    /*
+     * Conversion factor of height accuracy.
+     */
+    const FACTOR_HEIGHT: f32 = 0.5;
+
+    /*
+     * Conversion factor of velocity accuracy.
+     */
+    const FACTOR_VELOCITY: f64 = 0.1;
+
+    /*
+     * Conversion factor of rate of climb or descent accuracy.
+     */
+    const FACTOR_RATE: f32 = 6.25;
+
+    /*
+     * Conversion factor of acceleration accuracy.
+     */
+    const FACTOR_ACCELERATION: f64 = 0.01;
+}
+
+impl DataItem for EstimatedAccuracies {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((EstimatedAccuracies { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_estimated_accuracies() {
+        // Create message
+        let mut estimated_accuracies = EstimatedAccuracies::default();
+        estimated_accuracies
+            .set_cartesian_position(2.5, 7.75)
+            .unwrap();
+        estimated_accuracies.set_height(3.5).unwrap();
+        estimated_accuracies.set_rate_of_climb(125.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = estimated_accuracies.encode();
+
+        assert_eq!(array, vec![0xa8, 0x0a, 0x1f, 0x07, 0x14]);
+
+        // New message
+        let (object, length) = EstimatedAccuracies::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, estimated_accuracies);
+        assert_eq!(object.get_cartesian_position(), Some((2.5, 7.75)));
+        assert_eq!(object.get_height(), Some(3.5));
+        assert_eq!(object.get_rate_of_climb(), Some(125.0));
+        assert_eq!(object.get_wgs_position(), None);
+        assert_eq!(
+            estimated_accuracies.set_height(128.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
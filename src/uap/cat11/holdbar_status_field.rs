@@ -0,0 +1,111 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Holdbar Status
+//
+// Repetition factor followed by the status of holdbar banks
+// | 16 .. 13 | 12 | 11 | .. | 1   | bit
+// |   BKN    | I1 | I2 | .. | I12 |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct HoldbarStatus {
+    banks: Vec<u16>, // 2 bytes each
+}
+
+/*
+* Implementation HoldbarStatus
+*/
+impl HoldbarStatus {
+    /*
+     * Insert bank with the indicators of holdbars 1 to 12, true means holdbar on
+     * 0 <= bank <= 15
+     */
+    pub fn insert_bank(&mut self, bank: u8, holdbars: [bool; 12]) -> Result<(), CatError> {
+        if bank > 0x0f || self.banks.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        let indicators = holdbars
+            .iter()
+            .fold(0_u16, |value, holdbar| (value << 1) | *holdbar as u16);
+        self.banks.push(((bank as u16) << 12) | indicators);
+        Ok(())
+    }
+
+    /*
+     * Get all banks with the indicators of holdbars 1 to 12
+     */
+    pub fn get_banks(&self) -> Vec<(u8, [bool; 12])> {
+        self.banks
+            .iter()
+            .map(|value| {
+                let mut holdbars = [false; 12];
+                for (index, holdbar) in holdbars.iter_mut().enumerate() {
+                    *holdbar = value & (0x0800 >> index) != 0;
+                }
+                ((value >> 12) as u8, holdbars)
+            })
+            .collect()
+    }
+
+    /*
+     * Length of one bank.
+     */
+    const BANK_LENGTH: usize = 2;
+}
+
+impl DataItem for HoldbarStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::BANK_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let banks = array[1..length]
+            .chunks_exact(Self::BANK_LENGTH)
+            .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
+            .collect();
+
+        Ok((HoldbarStatus { banks }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.banks.len() as u8];
+
+        for bank in self.banks.iter() {
+            vector.extend(bank.to_be_bytes());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_holdbar_status() {
+        // Create message
+        let mut holdbars = [false; 12];
+        holdbars[0] = true;
+        holdbars[11] = true;
+
+        let mut holdbar_status = HoldbarStatus::default();
+        holdbar_status.insert_bank(3, holdbars).unwrap();
+
+        // Convert struct to byte stream
+        let array = holdbar_status.encode();
+
+        assert_eq!(array, vec![0x01, 0x38, 0x01]);
+
+        // New message
+        let (object, length) = HoldbarStatus::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_banks(), vec![(3, holdbars)]);
+        assert_eq!(
+            holdbar_status.insert_bank(16, holdbars),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Surface Track Message Type
+//
+
+/// Types of surface track messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TrackMessage {
+    Unknown,
+    TargetReport,
+    ManualAttachment,
+    ManualDetachment,
+    FlightPlanInsertion,
+    FlightPlanSuppression,
+    FlightPlanModification,
+    HoldbarStatus,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrackMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation TrackMessageType
+*/
+impl TrackMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: TrackMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> TrackMessage {
+        match self.message {
+            1 => TrackMessage::TargetReport,
+            2 => TrackMessage::ManualAttachment,
+            3 => TrackMessage::ManualDetachment,
+            4 => TrackMessage::FlightPlanInsertion,
+            5 => TrackMessage::FlightPlanSuppression,
+            6 => TrackMessage::FlightPlanModification,
+            7 => TrackMessage::HoldbarStatus,
+            _ => TrackMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TrackMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((TrackMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_message_type() {
+        // Create message
+        let mut message_type = TrackMessageType::default();
+        message_type.set_message_type(TrackMessage::HoldbarStatus);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x07]);
+
+        // New message
+        let (object, length) = TrackMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, TrackMessageType::MESSAGE_LENGTH);
+        assert_eq!(object.get_message_type(), TrackMessage::HoldbarStatus);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod aircraft_derived_data_field;
+pub mod alert_messages_field;
+pub mod estimated_accuracies_field;
+pub mod holdbar_status_field;
+pub mod message_type_field;
+pub mod phase_of_flight_field;
+pub mod track_status_field;
+pub mod tracks_in_alert_field;
+pub mod update_ages_field;
@@ -0,0 +1,96 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Phase of Flight
+//
+
+/// Current phase of flight on the airport surface
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum FlightPhase {
+    Unknown,
+    OnStand,
+    TaxiingForDeparture,
+    TaxiingForArrival,
+    RunwayForDeparture,
+    RunwayForArrival,
+    HoldForDeparture,
+    HoldForArrival,
+    PushBack,
+    OnFinals,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct PhaseOfFlight {
+    phase: u8, // 1 byte
+}
+
+/*
+* Implementation PhaseOfFlight
+*/
+impl PhaseOfFlight {
+    /*
+     * Set phase of flight
+     */
+    pub fn set_phase(&mut self, phase: FlightPhase) {
+        self.phase = phase as u8;
+    }
+
+    /*
+     * Get phase of flight
+     */
+    pub fn get_phase(&self) -> FlightPhase {
+        match self.phase {
+            1 => FlightPhase::OnStand,
+            2 => FlightPhase::TaxiingForDeparture,
+            3 => FlightPhase::TaxiingForArrival,
+            4 => FlightPhase::RunwayForDeparture,
+            5 => FlightPhase::RunwayForArrival,
+            6 => FlightPhase::HoldForDeparture,
+            7 => FlightPhase::HoldForArrival,
+            8 => FlightPhase::PushBack,
+            9 => FlightPhase::OnFinals,
+            _ => FlightPhase::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for PhaseOfFlight {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [phase] = fixed_array(array)?;
+        Ok((PhaseOfFlight { phase }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.phase]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_phase_of_flight() {
+        // Create message
+        let mut phase_of_flight = PhaseOfFlight::default();
+        phase_of_flight.set_phase(FlightPhase::TaxiingForDeparture);
+
+        // Convert struct to byte stream
+        let array = phase_of_flight.encode();
+
+        assert_eq!(array, vec![0x02]);
+
+        // New message
+        let (object, length) = PhaseOfFlight::decode(&array).unwrap();
+
+        assert_eq!(length, PhaseOfFlight::MESSAGE_LENGTH);
+        assert_eq!(object.get_phase(), FlightPhase::TaxiingForDeparture);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Track Status
+//
+// |  8  |  7  |  6  | 5 4 3  |  2  | 1  | bit
+// | MON | GBS | MRH |  SRC   | CNF | FX |
+// | SIM | TSE | TSB | FRIFOE | ME  | MI | FX |
+// | AMA | SPI | CST | FPC | AFF | spare | FX |
+//
+// FRIFOE occupies bits 5 and 4, ME bit 3 and MI bit 2 of the second octet.
+//
+
+/// Source of calculated track altitude
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum AltitudeSource {
+    NoSource,
+    Gnss,
+    Radar3D,
+    Triangulation,
+    HeightFromCoverage,
+    SpeedLookUpTable,
+    DefaultHeight,
+    Multilateration,
+}
+
+/// Flags of the track status
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TrackFlag {
+    MonoSensor,
+    GroundBitSet,
+    GeometricAltitudeMoreReliable,
+    Tentative,
+    Simulated,
+    LastMessage,
+    FirstMessage,
+    MilitaryEmergency,
+    MilitaryIdentification,
+    AmalgamatedTrack,
+    SpecialPositionIdentification,
+    CoastedTrack,
+    FlightPlanCorrelated,
+    AdsInconsistent,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrackStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TrackStatus
+*/
+impl TrackStatus {
+    /*
+     * Set flag of the track status
+     */
+    pub fn set_flag(&mut self, flag: TrackFlag, value: bool) {
+        let (octet, bit) = Self::flag_bit(flag);
+        self.field.set_bit(octet, bit, value);
+    }
+
+    /*
+     * Get flag of the track status
+     */
+    pub fn get_flag(&self, flag: TrackFlag) -> bool {
+        let (octet, bit) = Self::flag_bit(flag);
+        self.field.get_bit(octet, bit)
+    }
+
+    /*
+     * Set source of calculated track altitude
+     */
+    pub fn set_altitude_source(&mut self, source: AltitudeSource) {
+        self.field.set_bits(0, 5, 3, source as u8);
+    }
+
+    /*
+     * Get source of calculated track altitude
+     */
+    pub fn get_altitude_source(&self) -> AltitudeSource {
+        match self.field.get_bits(0, 5, 3) {
+            1 => AltitudeSource::Gnss,
+            2 => AltitudeSource::Radar3D,
+            3 => AltitudeSource::Triangulation,
+            4 => AltitudeSource::HeightFromCoverage,
+            5 => AltitudeSource::SpeedLookUpTable,
+            6 => AltitudeSource::DefaultHeight,
+            7 => AltitudeSource::Multilateration,
+            _ => AltitudeSource::NoSource,
+        }
+    }
+
+    /*
+     * Set friend or foe indicator
+     * 0 <= indicator <= 3
+     */
+    pub fn set_friend_foe(&mut self, indicator: u8) -> Result<(), CatError> {
+        if indicator > 0x03 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(1, 5, 2, indicator);
+        Ok(())
+    }
+
+    /*
+     * Get friend or foe indicator
+     */
+    pub fn get_friend_foe(&self) -> u8 {
+        self.field.get_bits(1, 5, 2)
+    }
+
+    /*
+     * Octet and bit of a flag.
+     */
+    fn flag_bit(flag: TrackFlag) -> (usize, u8) {
+        match flag {
+            TrackFlag::MonoSensor => (0, 8),
+            TrackFlag::GroundBitSet => (0, 7),
+            TrackFlag::GeometricAltitudeMoreReliable => (0, 6),
+            TrackFlag::Tentative => (0, 2),
+            TrackFlag::Simulated => (1, 8),
+            TrackFlag::LastMessage => (1, 7),
+            TrackFlag::FirstMessage => (1, 6),
+            TrackFlag::MilitaryEmergency => (1, 3),
+            TrackFlag::MilitaryIdentification => (1, 2),
+            TrackFlag::AmalgamatedTrack => (2, 8),
+            TrackFlag::SpecialPositionIdentification => (2, 7),
+            TrackFlag::CoastedTrack => (2, 6),
+            TrackFlag::FlightPlanCorrelated => (2, 5),
+            TrackFlag::AdsInconsistent => (2, 4),
+        }
+    }
+}
+
+impl DataItem for TrackStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TrackStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_status() {
+        // Create message
+        let mut track_status = TrackStatus::default();
+        track_status.set_flag(TrackFlag::GroundBitSet, true);
+        track_status.set_altitude_source(AltitudeSource::Multilateration);
+        track_status.set_flag(TrackFlag::CoastedTrack, true);
+
+        // Convert struct to byte stream
+        let array = track_status.encode();
+
+        assert_eq!(array, vec![0x5d, 0x01, 0x20]);
+
+        // New message
+        let (object, length) = TrackStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 3);
+        assert!(object.get_flag(TrackFlag::GroundBitSet));
+        assert!(object.get_flag(TrackFlag::CoastedTrack));
+        assert!(!object.get_flag(TrackFlag::MonoSensor));
+        assert_eq!(
+            object.get_altitude_source(),
+            AltitudeSource::Multilateration
+        );
+        assert_eq!(object.get_friend_foe(), 0);
+        assert_eq!(track_status.set_friend_foe(4), Err(CatError::ValueInvalid));
+    }
+}
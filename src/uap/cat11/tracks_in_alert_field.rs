@@ -0,0 +1,101 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Tracks in Alert
+//
+// Repetition factor followed by the track numbers involved in the alert
+// | 16 .. 13 | 12 .. 1 | bit
+// |  spare   |   FTN   |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TracksInAlert {
+    tracks: Vec<u16>, // 2 bytes each
+}
+
+/*
+* Implementation TracksInAlert
+*/
+impl TracksInAlert {
+    /*
+     * Insert fusion track number
+     * 0 <= track <= 4095
+     */
+    pub fn insert_track(&mut self, track: u16) -> Result<(), CatError> {
+        if track > Self::TRACK_MAX || self.tracks.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.tracks.push(track);
+        Ok(())
+    }
+
+    /*
+     * Get all fusion track numbers
+     */
+    pub fn get_tracks(&self) -> &[u16] {
+        &self.tracks
+    }
+
+    /*
+     * Length of one track number.
+     */
+    const TRACK_LENGTH: usize = 2;
+
+    /*
+     * Maximum track number in 12 bits.
+     */
+    const TRACK_MAX: u16 = 0x0fff;
+}
+
+impl DataItem for TracksInAlert {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::TRACK_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let tracks = array[1..length]
+            .chunks_exact(Self::TRACK_LENGTH)
+            .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]) & Self::TRACK_MAX)
+            .collect();
+
+        Ok((TracksInAlert { tracks }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.tracks.len() as u8];
+
+        for track in self.tracks.iter() {
+            vector.extend(track.to_be_bytes());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_tracks_in_alert() {
+        // Create message
+        let mut tracks_in_alert = TracksInAlert::default();
+        tracks_in_alert.insert_track(1234).unwrap();
+        tracks_in_alert.insert_track(4095).unwrap();
+
+        // Convert struct to byte stream
+        let array = tracks_in_alert.encode();
+
+        assert_eq!(array, vec![0x02, 0x04, 0xd2, 0x0f, 0xff]);
+
+        // New message
+        let (object, length) = TracksInAlert::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_tracks(), &[1234, 4095]);
+        assert_eq!(
+            tracks_in_alert.insert_track(4096),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Multilateration System Status Message Type
+//
+
+/// Types of multilateration system status messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum StatusMessage {
+    Unknown,
+    StartOfUpdateCycle,
+    PeriodicStatus,
+    EventStatus,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct StatusMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation StatusMessageType
+*/
+impl StatusMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: StatusMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> StatusMessage {
+        match self.message {
+            1 => StatusMessage::StartOfUpdateCycle,
+            2 => StatusMessage::PeriodicStatus,
+            3 => StatusMessage::EventStatus,
+            _ => StatusMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for StatusMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((StatusMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_status_message_type() {
+        // Create message
+        let mut message_type = StatusMessageType::default();
+        message_type.set_message_type(StatusMessage::EventStatus);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x03]);
+
+        // New message
+        let (object, length) = StatusMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, StatusMessageType::MESSAGE_LENGTH);
+        assert_eq!(object.get_message_type(), StatusMessage::EventStatus);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod message_type_field;
+pub mod reference_height_field;
+pub mod reference_transponder_status_field;
+pub mod remote_station_status_field;
+pub mod system_status_field;
+pub mod tracking_processor_status_field;
+pub mod undulation_field;
@@ -0,0 +1,82 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Height of the MLT System Reference Point
+//
+// Height above WGS-84 ellipsoid in two's complement
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ReferenceHeight {
+    height: i16, // 2 bytes
+}
+
+/*
+* Implementation ReferenceHeight
+*/
+impl ReferenceHeight {
+    /*
+     * Set height in m
+     * -8192 <= height < 8192
+     */
+    pub fn set_height(&mut self, height: f32) -> Result<(), CatError> {
+        self.height = from_signed(height as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get height in m
+     */
+    pub fn get_height(&self) -> f32 {
+        self.height as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 0.25;
+}
+
+impl DataItem for ReferenceHeight {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let height = i16::from_be_bytes(fixed_array(array)?);
+        Ok((ReferenceHeight { height }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.height.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_reference_height() {
+        // Create message
+        let mut reference_height = ReferenceHeight::default();
+        reference_height.set_height(-12.75).unwrap();
+
+        // Convert struct to byte stream
+        let array = reference_height.encode();
+
+        assert_eq!(array, vec![0xff, 0xcd]);
+
+        // New message
+        let (object, length) = ReferenceHeight::decode(&array).unwrap();
+
+        assert_eq!(length, ReferenceHeight::MESSAGE_LENGTH);
+        assert_eq!(object.get_height(), -12.75);
+        assert_eq!(
+            reference_height.set_height(8192.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
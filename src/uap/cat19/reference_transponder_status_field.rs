@@ -0,0 +1,115 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Reference Transponder Detailed Status
+//
+// |   8   | 7 6  |   5   | 4 3  |   2   | 1  | bit
+// | spare | REF1 | spare | REF2 | spare | FX |
+// | spare | REF3 | spare | REF4 | spare | FX |
+//
+
+/// Status of a reference transponder
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TransponderStatus {
+    Undefined,
+    Warning,
+    Faulty,
+    Good,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ReferenceTransponderStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation ReferenceTransponderStatus
+*/
+impl ReferenceTransponderStatus {
+    /*
+     * Set status of a reference transponder
+     * 1 <= transponder <= 254
+     */
+    pub fn set_status(
+        &mut self,
+        transponder: u8,
+        status: TransponderStatus,
+    ) -> Result<(), CatError> {
+        let (octet, bit) = Self::transponder_bit(transponder)?;
+        self.field.set_bits(octet, bit, 2, status as u8);
+        Ok(())
+    }
+
+    /*
+     * Get status of a reference transponder
+     */
+    pub fn get_status(&self, transponder: u8) -> Result<TransponderStatus, CatError> {
+        let (octet, bit) = Self::transponder_bit(transponder)?;
+
+        match self.field.get_bits(octet, bit, 2) {
+            1 => Ok(TransponderStatus::Warning),
+            2 => Ok(TransponderStatus::Faulty),
+            3 => Ok(TransponderStatus::Good),
+            _ => Ok(TransponderStatus::Undefined),
+        }
+    }
+
+    /*
+     * Octet and highest bit of a reference transponder.
+     */
+    fn transponder_bit(transponder: u8) -> Result<(usize, u8), CatError> {
+        if transponder == 0 || transponder == u8::MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        let octet = (transponder as usize - 1) / 2;
+        let bit = if transponder % 2 == 1 { 7 } else { 4 };
+        Ok((octet, bit))
+    }
+}
+
+impl DataItem for ReferenceTransponderStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((ReferenceTransponderStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_reference_transponder_status() {
+        // Create message
+        let mut reference_transponder_status = ReferenceTransponderStatus::default();
+        reference_transponder_status
+            .set_status(1, TransponderStatus::Good)
+            .unwrap();
+        reference_transponder_status
+            .set_status(3, TransponderStatus::Faulty)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = reference_transponder_status.encode();
+
+        assert_eq!(array, vec![0x61, 0x40]);
+
+        // New message
+        let (object, length) = ReferenceTransponderStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_status(1), Ok(TransponderStatus::Good));
+        assert_eq!(object.get_status(2), Ok(TransponderStatus::Undefined));
+        assert_eq!(object.get_status(3), Ok(TransponderStatus::Faulty));
+        assert_eq!(object.get_status(9), Ok(TransponderStatus::Undefined));
+        assert_eq!(
+            reference_transponder_status.set_status(0, TransponderStatus::Good),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
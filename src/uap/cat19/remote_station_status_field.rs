@@ -0,0 +1,205 @@
+use crate::category::CatError;
+use crate::uap::data_item::{get_bits, set_bits, DataItem};
+
+// Remote Sensor Detailed Status
+//
+// Repetition factor followed by the status of each remote station
+// | 16 .. 9 |   8   |   7    |   6    |   5    |  4  |  3  | 2 1   | bit
+// | RS ID   | spare | RS1090 | TX1030 | TX1090 | RSS | RSO | spare |
+//
+
+/// Status of one remote station
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct RemoteStation {
+    id: u8,     // 1 byte
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation RemoteStation
+*/
+impl RemoteStation {
+    /*
+     * Create remote station status with its identification
+     */
+    pub fn new(id: u8) -> Self {
+        RemoteStation { id, status: 0 }
+    }
+
+    /*
+     * Get identification of the remote station
+     */
+    pub fn get_id(&self) -> u8 {
+        self.id
+    }
+
+    /*
+     * Set receiver 1090 MHz present
+     */
+    pub fn set_receiver_1090(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 7, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get receiver 1090 MHz present
+     */
+    pub fn get_receiver_1090(&self) -> bool {
+        get_bits(self.status as u32, 7, 1) == 1
+    }
+
+    /*
+     * Set transmitter 1030 MHz present
+     */
+    pub fn set_transmitter_1030(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 6, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get transmitter 1030 MHz present
+     */
+    pub fn get_transmitter_1030(&self) -> bool {
+        get_bits(self.status as u32, 6, 1) == 1
+    }
+
+    /*
+     * Set transmitter 1090 MHz present
+     */
+    pub fn set_transmitter_1090(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 5, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get transmitter 1090 MHz present
+     */
+    pub fn get_transmitter_1090(&self) -> bool {
+        get_bits(self.status as u32, 5, 1) == 1
+    }
+
+    /*
+     * Set remote station status good
+     */
+    pub fn set_good(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 4, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get remote station status good
+     */
+    pub fn get_good(&self) -> bool {
+        get_bits(self.status as u32, 4, 1) == 1
+    }
+
+    /*
+     * Set remote station online
+     */
+    pub fn set_online(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 3, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get remote station online
+     */
+    pub fn get_online(&self) -> bool {
+        get_bits(self.status as u32, 3, 1) == 1
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct RemoteStationStatus {
+    stations: Vec<RemoteStation>, // 2 bytes each
+}
+
+/*
+* Implementation RemoteStationStatus
+*/
+impl RemoteStationStatus {
+    /*
+     * Insert status of a remote station
+     */
+    pub fn insert_station(&mut self, station: RemoteStation) -> Result<(), CatError> {
+        if self.stations.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.stations.push(station);
+        Ok(())
+    }
+
+    /*
+     * Get status of all remote stations
+     */
+    pub fn get_stations(&self) -> &[RemoteStation] {
+        &self.stations
+    }
+
+    /*
+     * Length of one remote station status.
+     */
+    const STATION_LENGTH: usize = 2;
+}
+
+impl DataItem for RemoteStationStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::STATION_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let stations = array[1..length]
+            .chunks_exact(Self::STATION_LENGTH)
+            .map(|chunk| RemoteStation {
+                id: chunk[0],
+                status: chunk[1],
+            })
+            .collect();
+
+        Ok((RemoteStationStatus { stations }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.stations.len() as u8];
+
+        for station in self.stations.iter() {
+            vector.push(station.id);
+            vector.push(station.status);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_remote_station_status() {
+        // Create message
+        let mut station_1 = RemoteStation::new(1);
+        station_1.set_receiver_1090(true);
+        station_1.set_good(true);
+        station_1.set_online(true);
+        let mut station_7 = RemoteStation::new(7);
+        station_7.set_transmitter_1030(true);
+
+        let mut remote_station_status = RemoteStationStatus::default();
+        remote_station_status.insert_station(station_1).unwrap();
+        remote_station_status.insert_station(station_7).unwrap();
+
+        // Convert struct to byte stream
+        let array = remote_station_status.encode();
+
+        assert_eq!(array, vec![0x02, 0x01, 0x4c, 0x07, 0x20]);
+
+        // New message
+        let (object, length) = RemoteStationStatus::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_stations(), &[station_1, station_7]);
+        assert!(object.get_stations()[0].get_online());
+        assert!(!object.get_stations()[1].get_good());
+        assert_eq!(
+            RemoteStationStatus::decode(&[0x02, 0x01, 0x4c]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
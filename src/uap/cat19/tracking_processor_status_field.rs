@@ -0,0 +1,108 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Tracking Processor Detailed Status
+//
+// |  8   |  7   |  6   |  5   |  4   |  3   |  2   |  1   | bit
+// | EXEC | GOOD | EXEC | GOOD | EXEC | GOOD | EXEC | GOOD |
+// |  tracking processor 1  ..  tracking processor 4       |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrackingProcessorStatus {
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation TrackingProcessorStatus
+*/
+impl TrackingProcessorStatus {
+    /*
+     * Set tracking processor executing and its status good
+     * 1 <= processor <= 4
+     */
+    pub fn set_processor(
+        &mut self,
+        processor: u8,
+        executing: bool,
+        good: bool,
+    ) -> Result<(), CatError> {
+        let bit = Self::processor_bit(processor)?;
+        let value = (executing as u32) << 1 | good as u32;
+        self.status = set_bits(self.status as u32, bit, 2, value) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get tracking processor executing and its status good
+     * 1 <= processor <= 4
+     */
+    pub fn get_processor(&self, processor: u8) -> Result<(bool, bool), CatError> {
+        let bit = Self::processor_bit(processor)?;
+        let value = get_bits(self.status as u32, bit, 2);
+        Ok((value & 0x02 != 0, value & 0x01 != 0))
+    }
+
+    /*
+     * Highest bit of a tracking processor.
+     */
+    fn processor_bit(processor: u8) -> Result<u8, CatError> {
+        if !(1..=Self::PROCESSOR_MAX).contains(&processor) {
+            return Err(CatError::ValueInvalid);
+        }
+        Ok(10 - 2 * processor)
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Number of tracking processors.
+     */
+    const PROCESSOR_MAX: u8 = 4;
+}
+
+impl DataItem for TrackingProcessorStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [status] = fixed_array(array)?;
+        Ok((TrackingProcessorStatus { status }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.status]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_tracking_processor_status() {
+        // Create message
+        let mut tracking_processor_status = TrackingProcessorStatus::default();
+        tracking_processor_status
+            .set_processor(1, true, true)
+            .unwrap();
+        tracking_processor_status
+            .set_processor(3, true, false)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = tracking_processor_status.encode();
+
+        assert_eq!(array, vec![0xc8]);
+
+        // New message
+        let (object, length) = TrackingProcessorStatus::decode(&array).unwrap();
+
+        assert_eq!(length, TrackingProcessorStatus::MESSAGE_LENGTH);
+        assert_eq!(object.get_processor(1), Ok((true, true)));
+        assert_eq!(object.get_processor(2), Ok((false, false)));
+        assert_eq!(object.get_processor(3), Ok((true, false)));
+        assert_eq!(object.get_processor(5), Err(CatError::ValueInvalid));
+    }
+}
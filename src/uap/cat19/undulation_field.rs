@@ -0,0 +1,76 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// WGS-84 Undulation
+//
+// Geoid undulation at the MLT system reference point in two's complement
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct Undulation {
+    undulation: i8, // 1 byte
+}
+
+/*
+* Implementation Undulation
+*/
+impl Undulation {
+    /*
+     * Set undulation in m
+     */
+    pub fn set_undulation(&mut self, undulation: i8) {
+        self.undulation = undulation;
+    }
+
+    /*
+     * Get undulation in m
+     */
+    pub fn get_undulation(&self) -> i8 {
+        self.undulation
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for Undulation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [undulation] = fixed_array(array)?;
+        Ok((
+            Undulation {
+                undulation: undulation as i8,
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.undulation as u8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_undulation() {
+        // Create message
+        let mut undulation = Undulation::default();
+        undulation.set_undulation(-3);
+
+        // Convert struct to byte stream
+        let array = undulation.encode();
+
+        assert_eq!(array, vec![0xfd]);
+
+        // New message
+        let (object, length) = Undulation::decode(&array).unwrap();
+
+        assert_eq!(length, Undulation::MESSAGE_LENGTH);
+        assert_eq!(object.get_undulation(), -3);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Contributing Devices
+//
+// Repetition factor followed by one bit per receiver or transmitter unit
+// |   8    |   7    | .. |   1    | bit
+// | TU1RU1 | TU2RU2 | .. | TU8RU8 |
+//
+// Octet n holds the units 8n-7 to 8n
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ContributingDevices {
+    units: Vec<u8>, // 1 byte each
+}
+
+/*
+* Implementation ContributingDevices
+*/
+impl ContributingDevices {
+    /*
+     * Set unit contributed to the target report
+     * 1 <= unit <= 2040
+     */
+    pub fn set_unit(&mut self, unit: u16, value: bool) -> Result<(), CatError> {
+        if unit == 0 || unit > Self::UNIT_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        let index = (unit as usize - 1) / 8;
+        let mask = 0x80 >> ((unit - 1) % 8);
+
+        if self.units.len() <= index {
+            self.units.resize(index + 1, 0);
+        }
+        if value {
+            self.units[index] |= mask;
+        } else {
+            self.units[index] &= !mask;
+        }
+        Ok(())
+    }
+
+    /*
+     * Get unit contributed to the target report
+     */
+    pub fn get_unit(&self, unit: u16) -> bool {
+        if unit == 0 {
+            return false;
+        }
+        let index = (unit as usize - 1) / 8;
+        let mask = 0x80 >> ((unit - 1) % 8);
+
+        match self.units.get(index) {
+            Some(octet) => octet & mask != 0,
+            None => false,
+        }
+    }
+
+    /*
+     * Get all units contributed to the target report
+     */
+    pub fn get_units(&self) -> Vec<u16> {
+        (1..=(self.units.len() * 8) as u16)
+            .filter(|unit| self.get_unit(*unit))
+            .collect()
+    }
+
+    /*
+     * Highest unit number with a repetition factor of 255.
+     */
+    const UNIT_MAX: u16 = 255 * 8;
+}
+
+impl DataItem for ContributingDevices {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let units = array[1..length].to_vec();
+
+        Ok((ContributingDevices { units }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.units.len() as u8];
+        vector.extend_from_slice(&self.units);
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_contributing_devices() {
+        // Create message
+        let mut contributing_devices = ContributingDevices::default();
+        contributing_devices.set_unit(1, true).unwrap();
+        contributing_devices.set_unit(3, true).unwrap();
+        contributing_devices.set_unit(12, true).unwrap();
+
+        // Convert struct to byte stream
+        let array = contributing_devices.encode();
+
+        assert_eq!(array, vec![0x02, 0xa0, 0x10]);
+
+        // New message
+        let (object, length) = ContributingDevices::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_units(), vec![1, 3, 12]);
+        assert!(!object.get_unit(2));
+        assert_eq!(
+            contributing_devices.set_unit(0, true),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            ContributingDevices::decode(&[0x02, 0xa0]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod contributing_devices_field;
+pub mod position_accuracy_field;
+pub mod reserved_expansion_field;
+pub mod target_report_descriptor_field;
+pub mod track_status_field;
@@ -0,0 +1,252 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_signed, from_unsigned, DataItem};
+
+// Position Accuracy
+//
+// Compound data item, primary subfield followed by present subfields
+// |  8  |  7  |  6  |  5  | 4 3 2 | 1  | bit
+// | DOP | SDP | SDH | SDW | spare | FX |
+//
+// SDW is only defined in the Reserved Expansion Field
+//
+
+/// Subfields of position accuracy
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    DilutionOfPrecision,
+    CartesianDeviation,
+    HeightDeviation,
+    WgsDeviation,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct PositionAccuracy {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation PositionAccuracy
+*/
+impl PositionAccuracy {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set dilution of precision in x, y and xy
+     * 0 <= x, y, xy < 16384
+     */
+    pub fn set_dilution_of_precision(&mut self, x: f32, y: f32, xy: f32) -> Result<(), CatError> {
+        let factor = Self::FACTOR_DOP as f64;
+        let values = [
+            from_unsigned(x as f64, factor, 16)? as u16,
+            from_unsigned(y as f64, factor, 16)? as u16,
+            from_unsigned(xy as f64, factor, 16)? as u16,
+        ];
+        self.field.set_subfield(
+            Subfield::DilutionOfPrecision as usize,
+            Self::to_vector(&values),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get dilution of precision in x, y and xy
+     */
+    pub fn get_dilution_of_precision(&self) -> Option<(f32, f32, f32)> {
+        let value = self.get_subfield(Subfield::DilutionOfPrecision)?;
+        Some((
+            u16::from_be_bytes([value[0], value[1]]) as f32 * Self::FACTOR_DOP,
+            u16::from_be_bytes([value[2], value[3]]) as f32 * Self::FACTOR_DOP,
+            u16::from_be_bytes([value[4], value[5]]) as f32 * Self::FACTOR_DOP,
+        ))
+    }
+
+    /*
+     * Set standard deviation of x and y in m and covariance in m²
+     * 0 <= sigma_x, sigma_y < 16384
+     * -8192 <= covariance < 8192
+     */
+    pub fn set_cartesian_deviation(
+        &mut self,
+        sigma_x: f32,
+        sigma_y: f32,
+        covariance: f32,
+    ) -> Result<(), CatError> {
+        let factor = Self::FACTOR_POSITION as f64;
+        let values = [
+            from_unsigned(sigma_x as f64, factor, 16)? as u16,
+            from_unsigned(sigma_y as f64, factor, 16)? as u16,
+            from_signed(covariance as f64, factor, 16)? as u16,
+        ];
+        self.field.set_subfield(
+            Subfield::CartesianDeviation as usize,
+            Self::to_vector(&values),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of x and y in m and covariance in m²
+     */
+    pub fn get_cartesian_deviation(&self) -> Option<(f32, f32, f32)> {
+        let value = self.get_subfield(Subfield::CartesianDeviation)?;
+        Some((
+            u16::from_be_bytes([value[0], value[1]]) as f32 * Self::FACTOR_POSITION,
+            u16::from_be_bytes([value[2], value[3]]) as f32 * Self::FACTOR_POSITION,
+            i16::from_be_bytes([value[4], value[5]]) as f32 * Self::FACTOR_POSITION,
+        ))
+    }
+
+    /*
+     * Set standard deviation of geometric height in m
+     * 0 <= sigma < 32768
+     */
+    pub fn set_height_deviation(&mut self, sigma: f32) -> Result<(), CatError> {
+        let value = from_unsigned(sigma as f64, Self::FACTOR_HEIGHT as f64, 16)? as u16;
+        self.field.set_subfield(
+            Subfield::HeightDeviation as usize,
+            value.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of geometric height in m
+     */
+    pub fn get_height_deviation(&self) -> Option<f32> {
+        let value = self.get_subfield(Subfield::HeightDeviation)?;
+        Some(u16::from_be_bytes([value[0], value[1]]) as f32 * Self::FACTOR_HEIGHT)
+    }
+
+    /*
+     * Set standard deviation of latitude and longitude in degree and covariance in degree²
+     * 0 <= sigma_latitude, sigma_longitude < 0.35
+     * -0.17 <= covariance < 0.17
+     */
+    pub fn set_wgs_deviation(
+        &mut self,
+        sigma_latitude: f64,
+        sigma_longitude: f64,
+        covariance: f64,
+    ) -> Result<(), CatError> {
+        let values = [
+            from_unsigned(sigma_latitude, Self::FACTOR_WGS, 16)? as u16,
+            from_unsigned(sigma_longitude, Self::FACTOR_WGS, 16)? as u16,
+            from_signed(covariance, Self::FACTOR_WGS, 16)? as u16,
+        ];
+        self.field
+            .set_subfield(Subfield::WgsDeviation as usize, Self::to_vector(&values));
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of latitude and longitude in degree and covariance in degree²
+     */
+    pub fn get_wgs_deviation(&self) -> Option<(f64, f64, f64)> {
+        let value = self.get_subfield(Subfield::WgsDeviation)?;
+        Some((
+            u16::from_be_bytes([value[0], value[1]]) as f64 * Self::FACTOR_WGS,
+            u16::from_be_bytes([value[2], value[3]]) as f64 * Self::FACTOR_WGS,
+            i16::from_be_bytes([value[4], value[5]]) as f64 * Self::FACTOR_WGS,
+        ))
+    }
+
+    /*
+     * Join 16 bit values into subfield bytes.
+     */
+    fn to_vector(values: &[u16]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect()
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    pub(crate) const LAYOUT: [SubfieldLength; 4] = [
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(6),
+    ];
+
+    /*
+     * Conversion factor of dilution of precision.
+     */
+    const FACTOR_DOP: f32 = 0.25;
+
+    /*
+     * Conversion factor of cartesian position.
+     */
+    const FACTOR_POSITION: f32 = 0.25;
+
+    /*
+     * Conversion factor of geometric height.
+     */
+    const FACTOR_HEIGHT: f32 = 0.5;
+
+    /*
+     * Conversion factor of WGS-84 position.
+     */
+    const FACTOR_WGS: f64 = 180.0 / 33554432.0;
+}
+
+impl DataItem for PositionAccuracy {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((PositionAccuracy { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_position_accuracy() {
+        // Create message
+        let mut position_accuracy = PositionAccuracy::default();
+        position_accuracy
+            .set_cartesian_deviation(7.5, 10.0, -2.25)
+            .unwrap();
+        position_accuracy.set_height_deviation(20.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = position_accuracy.encode();
+
+        assert_eq!(
+            array,
+            vec![0x60, 0x00, 0x1e, 0x00, 0x28, 0xff, 0xf7, 0x00, 0x29]
+        );
+
+        // New message
+        let (object, length) = PositionAccuracy::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_cartesian_deviation(), Some((7.5, 10.0, -2.25)));
+        assert_eq!(object.get_height_deviation(), Some(20.5));
+        assert_eq!(object.get_dilution_of_precision(), None);
+        assert_eq!(
+            position_accuracy.set_height_deviation(-1.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
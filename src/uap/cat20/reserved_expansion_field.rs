@@ -0,0 +1,212 @@
+use crate::category::CatError;
+use crate::uap::cat20::position_accuracy_field::PositionAccuracy;
+use crate::uap::cat21::ground_vector_field::GroundVector;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::from_unsigned;
+use crate::uap::explicit_field::ExplicitContent;
+use crate::uap::time_of_day_field::TimeOfDay;
+
+// Reserved Expansion Field content
+//
+// Compound data item without length octet, primary subfield followed by present subfields
+// | 8  |  7  |  6  |  5  | 4  | 3 2   | 1  | bit
+// | PA | GVV | GVA | TRT | DA | spare | FX |
+//
+// Data Ages (DA) is not supported, content with DA can't be decoded
+//
+
+/// Subfields of the Reserved Expansion Field
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    PositionAccuracy,
+    GroundVelocity,
+    GroundVelocityAccuracy,
+    TransmissionTime,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ReservedExpansion {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation ReservedExpansion
+*/
+impl ReservedExpansion {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set position accuracy
+     */
+    pub fn set_position_accuracy(&mut self, accuracy: &PositionAccuracy) {
+        self.field
+            .set_item(Subfield::PositionAccuracy as usize, accuracy);
+    }
+
+    /*
+     * Get position accuracy when present
+     */
+    pub fn get_position_accuracy(&self) -> Option<PositionAccuracy> {
+        self.field.get_item(Subfield::PositionAccuracy as usize)
+    }
+
+    /*
+     * Set ground velocity vector
+     */
+    pub fn set_ground_velocity(&mut self, velocity: &GroundVector) {
+        self.field
+            .set_item(Subfield::GroundVelocity as usize, velocity);
+    }
+
+    /*
+     * Get ground velocity vector when present
+     */
+    pub fn get_ground_velocity(&self) -> Option<GroundVector> {
+        self.field.get_item(Subfield::GroundVelocity as usize)
+    }
+
+    /*
+     * Set standard deviation of ground speed in NM/s and track angle in degree
+     * 0 <= speed < 0.0156
+     * 0 <= angle < 22.5
+     */
+    pub fn set_ground_velocity_accuracy(&mut self, speed: f64, angle: f64) -> Result<(), CatError> {
+        let speed = from_unsigned(speed, Self::FACTOR_SPEED, 8)? as u8;
+        let angle = from_unsigned(angle, Self::FACTOR_ANGLE, 8)? as u8;
+        self.field.set_subfield(
+            Subfield::GroundVelocityAccuracy as usize,
+            vec![speed, angle],
+        );
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of ground speed in NM/s and track angle in degree
+     */
+    pub fn get_ground_velocity_accuracy(&self) -> Option<(f64, f64)> {
+        let value = self.get_subfield(Subfield::GroundVelocityAccuracy)?;
+        Some((
+            value[0] as f64 * Self::FACTOR_SPEED,
+            value[1] as f64 * Self::FACTOR_ANGLE,
+        ))
+    }
+
+    /*
+     * Set time of report transmission
+     */
+    pub fn set_transmission_time(&mut self, time: &TimeOfDay) {
+        self.field
+            .set_item(Subfield::TransmissionTime as usize, time);
+    }
+
+    /*
+     * Get time of report transmission when present
+     */
+    pub fn get_transmission_time(&self) -> Option<TimeOfDay> {
+        self.field.get_item(Subfield::TransmissionTime as usize)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 4] = [
+        SubfieldLength::Compound(&PositionAccuracy::LAYOUT),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(3),
+    ];
+
+    /*
+     * Conversion factor of ground speed.
+     */
+    const FACTOR_SPEED: f64 = 1.0 / 16384.0;
+
+    /*
+     * Conversion factor of track angle.
+     */
+    const FACTOR_ANGLE: f64 = 360.0 / 4096.0;
+}
+
+impl ExplicitContent for ReservedExpansion {
+    fn from_bytes(data: &[u8]) -> Result<Self, CatError> {
+        let (field, length) = CompoundField::decode(data, &Self::LAYOUT)?;
+
+        // Content must fill the data of the explicit length data item
+        if length != data.len() {
+            return Err(CatError::SizeInvalid(length));
+        }
+        Ok(ReservedExpansion { field })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uap::data_item::DataItem;
+    use crate::uap::explicit_field::ExplicitField;
+
+    #[test]
+    fn check_reserved_expansion() {
+        // Create message
+        let mut position_accuracy = PositionAccuracy::default();
+        position_accuracy.set_height_deviation(5.0).unwrap();
+
+        let mut reserved_expansion = ReservedExpansion::default();
+        reserved_expansion.set_position_accuracy(&position_accuracy);
+        reserved_expansion
+            .set_ground_velocity_accuracy(2.0 / 16384.0, 0.703125)
+            .unwrap();
+
+        let mut explicit_field = ExplicitField::default();
+        explicit_field.set_content(&reserved_expansion).unwrap();
+
+        // Convert struct to byte stream
+        let array = explicit_field.encode();
+
+        assert_eq!(array, vec![0x07, 0xa0, 0x20, 0x00, 0x0a, 0x02, 0x08]);
+
+        // New message
+        let (object, length) = ExplicitField::decode(&array).unwrap();
+
+        // Convert byte stream to struct
+        let content: ReservedExpansion = object.get_content().unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(content, reserved_expansion);
+        assert_eq!(
+            content
+                .get_position_accuracy()
+                .unwrap()
+                .get_height_deviation(),
+            Some(5.0)
+        );
+        assert_eq!(
+            content.get_ground_velocity_accuracy(),
+            Some((2.0 / 16384.0, 0.703125))
+        );
+        assert_eq!(content.get_ground_velocity(), None);
+
+        // Data ages are not supported
+        assert_eq!(
+            ReservedExpansion::from_bytes(&[0x08, 0x00]),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
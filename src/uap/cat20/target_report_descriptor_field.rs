@@ -0,0 +1,184 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Target Report Descriptor
+//
+// |  8  |  7  |  6  |  5   |  4  |  3  |  2  | 1  | bit
+// | SSR | MS  | HF  | VDL4 | UAT | DME | OT  | FX |
+// | RAB | SPI | CHN | GBS  | CRT | SIM | TST | FX |
+//
+
+/// Detection technique which contributed to the target report
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum MultilaterationDetection {
+    NonModeS,
+    ModeS,
+    HfMultilateration,
+    Vdl4,
+    Uat,
+    Dme,
+    Other,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TargetReportDescriptor {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TargetReportDescriptor
+*/
+impl TargetReportDescriptor {
+    /*
+     * Set detection technique, several techniques are possible
+     */
+    pub fn set_detection(&mut self, detection: MultilaterationDetection, value: bool) {
+        self.field.set_bit(0, 8 - detection as u8, value);
+    }
+
+    /*
+     * Get detection technique
+     */
+    pub fn get_detection(&self, detection: MultilaterationDetection) -> bool {
+        self.field.get_bit(0, 8 - detection as u8)
+    }
+
+    /*
+     * Set report from field monitor (fixed transponder)
+     */
+    pub fn set_field_monitor(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get report from field monitor (fixed transponder)
+     */
+    pub fn get_field_monitor(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set special position identification
+     */
+    pub fn set_spi(&mut self, value: bool) {
+        self.field.set_bit(1, 7, value);
+    }
+
+    /*
+     * Get special position identification
+     */
+    pub fn get_spi(&self) -> bool {
+        self.field.get_bit(1, 7)
+    }
+
+    /*
+     * Set report from chain 2
+     */
+    pub fn set_chain_2(&mut self, value: bool) {
+        self.field.set_bit(1, 6, value);
+    }
+
+    /*
+     * Get report from chain 2
+     */
+    pub fn get_chain_2(&self) -> bool {
+        self.field.get_bit(1, 6)
+    }
+
+    /*
+     * Set transponder ground bit set
+     */
+    pub fn set_ground(&mut self, value: bool) {
+        self.field.set_bit(1, 5, value);
+    }
+
+    /*
+     * Get transponder ground bit set
+     */
+    pub fn get_ground(&self) -> bool {
+        self.field.get_bit(1, 5)
+    }
+
+    /*
+     * Set corrupted replies in multilateration
+     */
+    pub fn set_corrupted(&mut self, value: bool) {
+        self.field.set_bit(1, 4, value);
+    }
+
+    /*
+     * Get corrupted replies in multilateration
+     */
+    pub fn get_corrupted(&self) -> bool {
+        self.field.get_bit(1, 4)
+    }
+
+    /*
+     * Set simulated target report
+     */
+    pub fn set_simulated(&mut self, value: bool) {
+        self.field.set_bit(1, 3, value);
+    }
+
+    /*
+     * Get simulated target report
+     */
+    pub fn get_simulated(&self) -> bool {
+        self.field.get_bit(1, 3)
+    }
+
+    /*
+     * Set test target report
+     */
+    pub fn set_test(&mut self, value: bool) {
+        self.field.set_bit(1, 2, value);
+    }
+
+    /*
+     * Get test target report
+     */
+    pub fn get_test(&self) -> bool {
+        self.field.get_bit(1, 2)
+    }
+}
+
+impl DataItem for TargetReportDescriptor {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TargetReportDescriptor { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_report_descriptor() {
+        // Create message
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_detection(MultilaterationDetection::ModeS, true);
+        target_report_descriptor.set_detection(MultilaterationDetection::Dme, true);
+        target_report_descriptor.set_ground(true);
+
+        // Convert struct to byte stream
+        let array = target_report_descriptor.encode();
+
+        assert_eq!(array, vec![0x45, 0x10]);
+
+        // New message
+        let (object, length) = TargetReportDescriptor::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert!(object.get_detection(MultilaterationDetection::ModeS));
+        assert!(object.get_detection(MultilaterationDetection::Dme));
+        assert!(!object.get_detection(MultilaterationDetection::NonModeS));
+        assert!(object.get_ground());
+        assert!(!object.get_spi());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Track Status
+//
+// |  8  |  7  |  6  | 5 4 |  3  |  2  | 1  | bit
+// | CNF | TRE | CST | CDM | MAH | STH | FX |
+// | GHO |        spare          | FX |
+//
+
+/// Vertical climbing or descending mode
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ClimbingMode {
+    Maintaining,
+    Climbing,
+    Descending,
+    Invalid,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrackStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TrackStatus
+*/
+impl TrackStatus {
+    /*
+     * Set track in initiation phase
+     */
+    pub fn set_tentative(&mut self, value: bool) {
+        self.field.set_bit(0, 8, value);
+    }
+
+    /*
+     * Get track in initiation phase
+     */
+    pub fn get_tentative(&self) -> bool {
+        self.field.get_bit(0, 8)
+    }
+
+    /*
+     * Set last report for the track
+     */
+    pub fn set_end_of_track(&mut self, value: bool) {
+        self.field.set_bit(0, 7, value);
+    }
+
+    /*
+     * Get last report for the track
+     */
+    pub fn get_end_of_track(&self) -> bool {
+        self.field.get_bit(0, 7)
+    }
+
+    /*
+     * Set extrapolated track, no detection in the update
+     */
+    pub fn set_extrapolated(&mut self, value: bool) {
+        self.field.set_bit(0, 6, value);
+    }
+
+    /*
+     * Get extrapolated track
+     */
+    pub fn get_extrapolated(&self) -> bool {
+        self.field.get_bit(0, 6)
+    }
+
+    /*
+     * Set climbing mode
+     */
+    pub fn set_climbing_mode(&mut self, mode: ClimbingMode) {
+        self.field.set_bits(0, 5, 2, mode as u8);
+    }
+
+    /*
+     * Get climbing mode
+     */
+    pub fn get_climbing_mode(&self) -> ClimbingMode {
+        match self.field.get_bits(0, 5, 2) {
+            0 => ClimbingMode::Maintaining,
+            1 => ClimbingMode::Climbing,
+            2 => ClimbingMode::Descending,
+            _ => ClimbingMode::Invalid,
+        }
+    }
+
+    /*
+     * Set horizontal manoeuvre
+     */
+    pub fn set_manoeuvre(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get horizontal manoeuvre
+     */
+    pub fn get_manoeuvre(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set smoothed position, otherwise measured position
+     */
+    pub fn set_smoothed(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get smoothed position
+     */
+    pub fn get_smoothed(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set ghost track
+     */
+    pub fn set_ghost(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get ghost track
+     */
+    pub fn get_ghost(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+}
+
+impl DataItem for TrackStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TrackStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_status() {
+        // Create message
+        let mut track_status = TrackStatus::default();
+        track_status.set_climbing_mode(ClimbingMode::Descending);
+        track_status.set_smoothed(true);
+
+        // Convert struct to byte stream
+        let array = track_status.encode();
+
+        assert_eq!(array, vec![0x12]);
+
+        // New message
+        let (object, length) = TrackStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 1);
+        assert_eq!(object.get_climbing_mode(), ClimbingMode::Descending);
+        assert!(object.get_smoothed());
+        assert!(!object.get_tentative());
+        assert!(!object.get_ghost());
+    }
+}
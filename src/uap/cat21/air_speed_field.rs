@@ -0,0 +1,213 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, get_bits, set_bits, DataItem};
+
+// Air Speed and True Airspeed
+//
+// I021/150 Air Speed
+// | 16 | 15 .. 1                                  | bit
+// | IM | IAS in 2^-14 NM/s or Mach in 0.001       |
+//
+// I021/151 True Airspeed
+// | 16 | 15 .. 1      | bit
+// | RE | TAS in knots |
+//
+
+/// Air Speed
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AirSpeed {
+    speed: u16, // 2 bytes
+}
+
+/*
+* Implementation AirSpeed
+*/
+impl AirSpeed {
+    /*
+     * Set indicated airspeed in NM/s
+     * 0 <= speed < 2
+     */
+    pub fn set_indicated_airspeed(&mut self, speed: f64) -> Result<(), CatError> {
+        let value = from_unsigned(speed, Self::FACTOR_IAS, 15)?;
+        self.speed = value as u16;
+        Ok(())
+    }
+
+    /*
+     * Get indicated airspeed in NM/s, none when the air speed is a Mach number
+     */
+    pub fn get_indicated_airspeed(&self) -> Option<f64> {
+        if self.is_mach() {
+            return None;
+        }
+        Some(get_bits(self.speed as u32, 15, 15) as f64 * Self::FACTOR_IAS)
+    }
+
+    /*
+     * Set Mach number
+     * 0 <= mach < 32.768
+     */
+    pub fn set_mach(&mut self, mach: f64) -> Result<(), CatError> {
+        let value = from_unsigned(mach, Self::FACTOR_MACH, 15)?;
+        self.speed = set_bits(Self::MACH as u32, 15, 15, value) as u16;
+        Ok(())
+    }
+
+    /*
+     * Get Mach number, none when the air speed is an indicated airspeed
+     */
+    pub fn get_mach(&self) -> Option<f64> {
+        if !self.is_mach() {
+            return None;
+        }
+        Some(get_bits(self.speed as u32, 15, 15) as f64 * Self::FACTOR_MACH)
+    }
+
+    /*
+     * Check for Mach number
+     */
+    fn is_mach(&self) -> bool {
+        self.speed & Self::MACH != 0
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * IM bit of Mach number.
+     */
+    const MACH: u16 = 0x8000;
+
+    /*
+     * Conversion factor of indicated airspeed.
+     */
+    const FACTOR_IAS: f64 = 1.0 / 16384.0;
+
+    /*
+     * Conversion factor of Mach number.
+     */
+    const FACTOR_MACH: f64 = 0.001;
+}
+
+impl DataItem for AirSpeed {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let speed = u16::from_be_bytes(fixed_array(array)?);
+        Ok((AirSpeed { speed }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.speed.to_be_bytes().to_vec()
+    }
+}
+
+/// True Airspeed
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrueAirspeed {
+    speed: u16, // 2 bytes
+}
+
+/*
+* Implementation TrueAirspeed
+*/
+impl TrueAirspeed {
+    /*
+     * Set true airspeed in knots
+     * 0 <= speed < 32768
+     */
+    pub fn set_speed(&mut self, speed: u16) -> Result<(), CatError> {
+        if speed > 0x7fff {
+            return Err(CatError::ValueInvalid);
+        }
+        self.speed = set_bits(self.speed as u32, 15, 15, speed as u32) as u16;
+        Ok(())
+    }
+
+    /*
+     * Get true airspeed in knots
+     */
+    pub fn get_speed(&self) -> u16 {
+        get_bits(self.speed as u32, 15, 15) as u16
+    }
+
+    /*
+     * Set value exceeds defined range
+     */
+    pub fn set_range_exceeded(&mut self, value: bool) {
+        self.speed = set_bits(self.speed as u32, 16, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get value exceeds defined range
+     */
+    pub fn get_range_exceeded(&self) -> bool {
+        get_bits(self.speed as u32, 16, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TrueAirspeed {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let speed = u16::from_be_bytes(fixed_array(array)?);
+        Ok((TrueAirspeed { speed }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.speed.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_air_speed() {
+        // Create message
+        let mut air_speed = AirSpeed::default();
+        air_speed.set_mach(0.8).unwrap();
+
+        // Convert struct to byte stream
+        let array = air_speed.encode();
+
+        assert_eq!(array, vec![0x83, 0x20]);
+
+        // New message
+        let (object, length) = AirSpeed::decode(&array).unwrap();
+
+        assert_eq!(length, AirSpeed::MESSAGE_LENGTH);
+        assert_eq!(object.get_mach(), Some(0.8));
+        assert_eq!(object.get_indicated_airspeed(), None);
+
+        air_speed.set_indicated_airspeed(0.0625).unwrap();
+
+        assert_eq!(air_speed.encode(), vec![0x04, 0x00]);
+        assert_eq!(air_speed.get_indicated_airspeed(), Some(0.0625));
+    }
+
+    #[test]
+    fn check_true_airspeed() {
+        // Create message
+        let mut true_airspeed = TrueAirspeed::default();
+        true_airspeed.set_speed(450).unwrap();
+
+        // Convert struct to byte stream
+        let array = true_airspeed.encode();
+
+        assert_eq!(array, vec![0x01, 0xc2]);
+
+        // New message
+        let (object, length) = TrueAirspeed::decode(&array).unwrap();
+
+        assert_eq!(length, TrueAirspeed::MESSAGE_LENGTH);
+        assert_eq!(object.get_speed(), 450);
+        assert!(!object.get_range_exceeded());
+        assert_eq!(true_airspeed.set_speed(0x8000), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+
+// Data Ages
+//
+// Compound data item, ages of the data provided in 0.1 s
+// | AOS | TRD | M3A | QI  | TI  | MAM | GH  | FX |
+// | FL  | ISA | FSA | AS  | TAS | MH  | BVR | FX |
+// | GVR | GV  | TAR | TI  | TS  | MET | ROA | FX |
+// | ARA | SCC | spare ...                   | FX |
+//
+
+/// Data of a data age
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum DataAge {
+    AircraftOperationalStatus,
+    TargetReportDescriptor,
+    Mode3ACode,
+    QualityIndicators,
+    TrajectoryIntent,
+    MessageAmplitude,
+    GeometricHeight,
+    FlightLevel,
+    IntermediateSelectedAltitude,
+    FinalSelectedAltitude,
+    AirSpeed,
+    TrueAirspeed,
+    MagneticHeading,
+    BarometricVerticalRate,
+    GeometricVerticalRate,
+    GroundVector,
+    TrackAngleRate,
+    TargetIdentification,
+    TargetStatus,
+    MetInformation,
+    RollAngle,
+    AcasResolution,
+    SurfaceCapabilities,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct DataAges {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation DataAges
+*/
+impl DataAges {
+    /*
+     * Set age in seconds
+     * 0 <= age <= 25.5
+     */
+    pub fn set_age(&mut self, data: DataAge, age: f32) -> Result<(), CatError> {
+        let value = from_unsigned(age as f64, Self::FACTOR as f64, 8)? as u8;
+        self.field.set_subfield(data as usize, vec![value]);
+        Ok(())
+    }
+
+    /*
+     * Get age in seconds when present
+     */
+    pub fn get_age(&self, data: DataAge) -> Option<f32> {
+        let value = self.field.get_subfield(data as usize)?[0];
+        Some(value as f32 * Self::FACTOR)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 23] = [SubfieldLength::Fixed(1); 23];
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 0.1;
+}
+
+impl DataItem for DataAges {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((DataAges { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_data_ages() {
+        // Create message
+        let mut data_ages = DataAges::default();
+        data_ages
+            .set_age(DataAge::AircraftOperationalStatus, 1.0)
+            .unwrap();
+        data_ages.set_age(DataAge::AcasResolution, 0.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = data_ages.encode();
+
+        assert_eq!(array, vec![0x81, 0x01, 0x01, 0x80, 0x0a, 0x05]);
+
+        // New message
+        let (object, length) = DataAges::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(
+            object.get_age(DataAge::AircraftOperationalStatus),
+            Some(1.0)
+        );
+        assert_eq!(object.get_age(DataAge::AcasResolution), Some(0.5));
+        assert_eq!(object.get_age(DataAge::FlightLevel), None);
+        assert_eq!(
+            data_ages.set_age(DataAge::RollAngle, 25.6),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
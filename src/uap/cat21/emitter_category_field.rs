@@ -0,0 +1,120 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Emitter Category
+//
+
+/// Characteristics of the originating ADS-B unit
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Emitter {
+    NoCategory = 0,
+    Light = 1,
+    Small = 2,
+    Medium = 3,
+    HighVortexLarge = 4,
+    Heavy = 5,
+    HighPerformance = 6,
+    Reserved = 7,
+    Rotocraft = 10,
+    GliderSailplane = 11,
+    LighterThanAir = 12,
+    UnmannedAerialVehicle = 13,
+    SpaceVehicle = 14,
+    Ultralight = 15,
+    ParachutistSkydiver = 16,
+    SurfaceEmergencyVehicle = 20,
+    SurfaceServiceVehicle = 21,
+    FixedGroundObstruction = 22,
+    ClusterObstacle = 23,
+    LineObstacle = 24,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct EmitterCategory {
+    category: u8, // 1 byte
+}
+
+/*
+* Implementation EmitterCategory
+*/
+impl EmitterCategory {
+    /*
+     * Set emitter category
+     */
+    pub fn set_category(&mut self, category: Emitter) {
+        self.category = category as u8;
+    }
+
+    /*
+     * Get emitter category
+     */
+    pub fn get_category(&self) -> Emitter {
+        match self.category {
+            0 => Emitter::NoCategory,
+            1 => Emitter::Light,
+            2 => Emitter::Small,
+            3 => Emitter::Medium,
+            4 => Emitter::HighVortexLarge,
+            5 => Emitter::Heavy,
+            6 => Emitter::HighPerformance,
+            10 => Emitter::Rotocraft,
+            11 => Emitter::GliderSailplane,
+            12 => Emitter::LighterThanAir,
+            13 => Emitter::UnmannedAerialVehicle,
+            14 => Emitter::SpaceVehicle,
+            15 => Emitter::Ultralight,
+            16 => Emitter::ParachutistSkydiver,
+            20 => Emitter::SurfaceEmergencyVehicle,
+            21 => Emitter::SurfaceServiceVehicle,
+            22 => Emitter::FixedGroundObstruction,
+            23 => Emitter::ClusterObstacle,
+            24 => Emitter::LineObstacle,
+            _ => Emitter::Reserved,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for EmitterCategory {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [category] = fixed_array(array)?;
+        Ok((EmitterCategory { category }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.category]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_emitter_category() {
+        // Create message
+        let mut emitter_category = EmitterCategory::default();
+        emitter_category.set_category(Emitter::Heavy);
+
+        // Convert struct to byte stream
+        let array = emitter_category.encode();
+
+        assert_eq!(array, vec![0x05]);
+
+        // New message
+        let (object, length) = EmitterCategory::decode(&array).unwrap();
+
+        assert_eq!(length, EmitterCategory::MESSAGE_LENGTH);
+        assert_eq!(object.get_category(), Emitter::Heavy);
+        assert_eq!(
+            EmitterCategory::decode(&[0x11]).unwrap().0.get_category(),
+            Emitter::Reserved
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, get_bits, set_bits, DataItem};
+
+// Airborne Ground Vector
+//
+// | 32 | 31 .. 17                       | 16 .. 1                          | bit
+// | RE | ground speed in 2^-14 NM/s    | track angle in 360/2^16 degree   |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct GroundVector {
+    vector: u32, // 4 bytes
+}
+
+/*
+* Implementation GroundVector
+*/
+impl GroundVector {
+    /*
+     * Set ground speed in NM/s
+     * 0 <= speed < 2
+     */
+    pub fn set_ground_speed(&mut self, speed: f64) -> Result<(), CatError> {
+        let value = from_unsigned(speed, Self::FACTOR_SPEED, 15)?;
+        self.vector = set_bits(self.vector, 31, 15, value);
+        Ok(())
+    }
+
+    /*
+     * Get ground speed in NM/s
+     */
+    pub fn get_ground_speed(&self) -> f64 {
+        get_bits(self.vector, 31, 15) as f64 * Self::FACTOR_SPEED
+    }
+
+    /*
+     * Set track angle in degree
+     * 0 <= angle < 360
+     */
+    pub fn set_track_angle(&mut self, angle: f64) -> Result<(), CatError> {
+        let value = from_unsigned(angle, Self::FACTOR_ANGLE, 16)?;
+        self.vector = set_bits(self.vector, 16, 16, value);
+        Ok(())
+    }
+
+    /*
+     * Get track angle in degree
+     */
+    pub fn get_track_angle(&self) -> f64 {
+        get_bits(self.vector, 16, 16) as f64 * Self::FACTOR_ANGLE
+    }
+
+    /*
+     * Set value exceeds defined range
+     */
+    pub fn set_range_exceeded(&mut self, value: bool) {
+        self.vector = set_bits(self.vector, 32, 1, value as u32);
+    }
+
+    /*
+     * Get value exceeds defined range
+     */
+    pub fn get_range_exceeded(&self) -> bool {
+        get_bits(self.vector, 32, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of ground speed.
+     */
+    const FACTOR_SPEED: f64 = 1.0 / 16384.0;
+
+    /*
+     * Conversion factor of track angle.
+     */
+    const FACTOR_ANGLE: f64 = 360.0 / 65536.0;
+}
+
+impl DataItem for GroundVector {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let vector = u32::from_be_bytes(fixed_array(array)?);
+        Ok((GroundVector { vector }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.vector.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_ground_vector() {
+        // Create message
+        let mut ground_vector = GroundVector::default();
+        ground_vector.set_ground_speed(0.125).unwrap();
+        ground_vector.set_track_angle(180.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = ground_vector.encode();
+
+        assert_eq!(array, vec![0x08, 0x00, 0x80, 0x00]);
+
+        // New message
+        let (object, length) = GroundVector::decode(&array).unwrap();
+
+        assert_eq!(length, GroundVector::MESSAGE_LENGTH);
+        assert_eq!(object.get_ground_speed(), 0.125);
+        assert_eq!(object.get_track_angle(), 180.0);
+        assert!(!object.get_range_exceeded());
+        assert_eq!(
+            ground_vector.set_ground_speed(2.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, get_bits, set_bits, DataItem};
+
+// Time of Message Reception - High Precision
+//
+// | 32 31 | 30 .. 1                        | bit
+// |  FSI  | fractional second in 2^-30 s   |
+//
+
+/// Full second indication relative to the time of message reception
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum FullSecondIndication {
+    Same,
+    PlusOne,
+    MinusOne,
+    Reserved,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct HighPrecisionTime {
+    time: u32, // 4 bytes
+}
+
+/*
+* Implementation HighPrecisionTime
+*/
+impl HighPrecisionTime {
+    /*
+     * Set full second indication
+     */
+    pub fn set_full_second(&mut self, indication: FullSecondIndication) {
+        self.time = set_bits(self.time, 32, 2, indication as u32);
+    }
+
+    /*
+     * Get full second indication
+     */
+    pub fn get_full_second(&self) -> FullSecondIndication {
+        match get_bits(self.time, 32, 2) {
+            0 => FullSecondIndication::Same,
+            1 => FullSecondIndication::PlusOne,
+            2 => FullSecondIndication::MinusOne,
+            _ => FullSecondIndication::Reserved,
+        }
+    }
+
+    /*
+     * Set fractional part of the second in s
+     * 0 <= fraction < 1
+     */
+    pub fn set_fraction(&mut self, fraction: f64) -> Result<(), CatError> {
+        if !(0.0..1.0).contains(&fraction) {
+            return Err(CatError::ValueInvalid);
+        }
+        let value = from_unsigned(fraction, Self::FACTOR, 30)?;
+        self.time = set_bits(self.time, 30, 30, value);
+        Ok(())
+    }
+
+    /*
+     * Get fractional part of the second in s
+     */
+    pub fn get_fraction(&self) -> f64 {
+        get_bits(self.time, 30, 30) as f64 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f64 = 1.0 / 1073741824.0;
+}
+
+impl DataItem for HighPrecisionTime {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let time = u32::from_be_bytes(fixed_array(array)?);
+        Ok((HighPrecisionTime { time }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.time.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_high_precision_time() {
+        // Create message
+        let mut time = HighPrecisionTime::default();
+        time.set_full_second(FullSecondIndication::PlusOne);
+        time.set_fraction(0.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = time.encode();
+
+        assert_eq!(array, vec![0x60, 0x00, 0x00, 0x00]);
+
+        // New message
+        let (object, length) = HighPrecisionTime::decode(&array).unwrap();
+
+        assert_eq!(length, HighPrecisionTime::MESSAGE_LENGTH);
+        assert_eq!(object.get_full_second(), FullSecondIndication::PlusOne);
+        assert_eq!(object.get_fraction(), 0.5);
+        assert_eq!(time.set_fraction(1.0), Err(CatError::ValueInvalid));
+    }
+}
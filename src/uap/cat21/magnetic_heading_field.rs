@@ -0,0 +1,82 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Magnetic Heading
+//
+// 16 bit in 360/2^16 degree
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct MagneticHeading {
+    heading: u16, // 2 bytes
+}
+
+/*
+* Implementation MagneticHeading
+*/
+impl MagneticHeading {
+    /*
+     * Set magnetic heading in degree
+     * 0 <= heading < 360
+     */
+    pub fn set_heading(&mut self, heading: f32) -> Result<(), CatError> {
+        self.heading = from_unsigned(heading as f64, Self::FACTOR as f64, 16)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get magnetic heading in degree
+     */
+    pub fn get_heading(&self) -> f32 {
+        self.heading as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 360.0 / 65536.0;
+}
+
+impl DataItem for MagneticHeading {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let heading = u16::from_be_bytes(fixed_array(array)?);
+        Ok((MagneticHeading { heading }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.heading.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_magnetic_heading() {
+        // Create message
+        let mut magnetic_heading = MagneticHeading::default();
+        magnetic_heading.set_heading(90.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = magnetic_heading.encode();
+
+        assert_eq!(array, vec![0x40, 0x00]);
+
+        // New message
+        let (object, length) = MagneticHeading::decode(&array).unwrap();
+
+        assert_eq!(length, MagneticHeading::MESSAGE_LENGTH);
+        assert_eq!(object.get_heading(), 90.0);
+        assert_eq!(
+            magnetic_heading.set_heading(360.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Message Amplitude
+//
+// Two's complement amplitude in dBm
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct MessageAmplitude {
+    amplitude: i8, // 1 byte
+}
+
+/*
+* Implementation MessageAmplitude
+*/
+impl MessageAmplitude {
+    /*
+     * Set amplitude of the received message in dBm
+     */
+    pub fn set_amplitude(&mut self, amplitude: i8) {
+        self.amplitude = amplitude;
+    }
+
+    /*
+     * Get amplitude of the received message in dBm
+     */
+    pub fn get_amplitude(&self) -> i8 {
+        self.amplitude
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for MessageAmplitude {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let amplitude = i8::from_be_bytes(fixed_array(array)?);
+        Ok((MessageAmplitude { amplitude }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.amplitude.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_message_amplitude() {
+        // Create message
+        let mut message_amplitude = MessageAmplitude::default();
+        message_amplitude.set_amplitude(-70);
+
+        // Convert struct to byte stream
+        let array = message_amplitude.encode();
+
+        assert_eq!(array, vec![0xba]);
+
+        // New message
+        let (object, length) = MessageAmplitude::decode(&array).unwrap();
+
+        assert_eq!(length, MessageAmplitude::MESSAGE_LENGTH);
+        assert_eq!(object.get_amplitude(), -70);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_signed, DataItem};
+
+// Met Information
+//
+// Compound data item, primary subfield followed by present subfields
+// | WS | WD | TMP | TRB | spare ... | FX |
+//
+
+/// Subfields of met information
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    WindSpeed,
+    WindDirection,
+    Temperature,
+    Turbulence,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct MetInformation {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation MetInformation
+*/
+impl MetInformation {
+    /*
+     * Set wind speed in knots
+     */
+    pub fn set_wind_speed(&mut self, speed: u16) {
+        self.field
+            .set_subfield(Subfield::WindSpeed as usize, speed.to_be_bytes().to_vec());
+    }
+
+    /*
+     * Get wind speed in knots
+     */
+    pub fn get_wind_speed(&self) -> Option<u16> {
+        self.get_u16(Subfield::WindSpeed)
+    }
+
+    /*
+     * Set wind direction in degree
+     * 1 <= direction <= 360
+     */
+    pub fn set_wind_direction(&mut self, direction: u16) -> Result<(), CatError> {
+        if !(1..=360).contains(&direction) {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_subfield(
+            Subfield::WindDirection as usize,
+            direction.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get wind direction in degree
+     */
+    pub fn get_wind_direction(&self) -> Option<u16> {
+        self.get_u16(Subfield::WindDirection)
+    }
+
+    /*
+     * Set temperature in degree Celsius
+     * -100 <= temperature <= 100
+     */
+    pub fn set_temperature(&mut self, temperature: f32) -> Result<(), CatError> {
+        if !(-100.0..=100.0).contains(&temperature) {
+            return Err(CatError::ValueInvalid);
+        }
+        let value = from_signed(temperature as f64, Self::FACTOR as f64, 16)? as u16;
+        self.field
+            .set_subfield(Subfield::Temperature as usize, value.to_be_bytes().to_vec());
+        Ok(())
+    }
+
+    /*
+     * Get temperature in degree Celsius
+     */
+    pub fn get_temperature(&self) -> Option<f32> {
+        self.get_u16(Subfield::Temperature)
+            .map(|value| value as i16 as f32 * Self::FACTOR)
+    }
+
+    /*
+     * Set turbulence
+     * 0 <= turbulence <= 15
+     */
+    pub fn set_turbulence(&mut self, turbulence: u8) -> Result<(), CatError> {
+        if turbulence > 15 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field
+            .set_subfield(Subfield::Turbulence as usize, vec![turbulence]);
+        Ok(())
+    }
+
+    /*
+     * Get turbulence
+     */
+    pub fn get_turbulence(&self) -> Option<u8> {
+        self.field
+            .get_subfield(Subfield::Turbulence as usize)
+            .map(|value| value[0])
+    }
+
+    /*
+     * Get 2 byte subfield
+     */
+    fn get_u16(&self, subfield: Subfield) -> Option<u16> {
+        self.field
+            .get_subfield(subfield as usize)
+            .map(|value| u16::from_be_bytes([value[0], value[1]]))
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 4] = [
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+    ];
+
+    /*
+     * Conversion factor of temperature.
+     */
+    const FACTOR: f32 = 0.25;
+}
+
+impl DataItem for MetInformation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((MetInformation { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_met_information() {
+        // Create message
+        let mut met_information = MetInformation::default();
+        met_information.set_wind_speed(50);
+        met_information.set_temperature(-40.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = met_information.encode();
+
+        assert_eq!(array, vec![0xa0, 0x00, 0x32, 0xff, 0x5e]);
+
+        // New message
+        let (object, length) = MetInformation::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_wind_speed(), Some(50));
+        assert_eq!(object.get_temperature(), Some(-40.5));
+        assert_eq!(object.get_wind_direction(), None);
+        assert_eq!(
+            met_information.set_wind_direction(0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,22 @@
+pub mod air_speed_field;
+pub mod data_ages_field;
+pub mod emitter_category_field;
+pub mod ground_vector_field;
+pub mod high_precision_time_field;
+pub mod magnetic_heading_field;
+pub mod message_amplitude_field;
+pub mod met_information_field;
+pub mod mops_version_field;
+pub mod operational_status_field;
+pub mod position_field;
+pub mod quality_indicators_field;
+pub mod receiver_id_field;
+pub mod roll_angle_field;
+pub mod selected_altitude_field;
+pub mod service_management_field;
+pub mod surface_capabilities_field;
+pub mod target_report_descriptor_field;
+pub mod target_status_field;
+pub mod track_angle_rate_field;
+pub mod trajectory_intent_field;
+pub mod vertical_rate_field;
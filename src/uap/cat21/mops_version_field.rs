@@ -0,0 +1,129 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// MOPS Version
+//
+// |   8   |  7  | 6 5 4 | 3 2 1 | bit
+// | spare | VNS |  VN   |  LTT  |
+//
+
+/// Link technology type
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum LinkTechnology {
+    Other,
+    Uat,
+    ExtendedSquitter,
+    Vdl4,
+    NotAssigned,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct MopsVersion {
+    version: u8, // 1 byte
+}
+
+/*
+* Implementation MopsVersion
+*/
+impl MopsVersion {
+    /*
+     * Set version not supported
+     */
+    pub fn set_not_supported(&mut self, value: bool) {
+        self.version = set_bits(self.version as u32, 7, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get version not supported
+     */
+    pub fn get_not_supported(&self) -> bool {
+        get_bits(self.version as u32, 7, 1) == 1
+    }
+
+    /*
+     * Set version number, 0 is ED102/DO-260, 1 is DO-260A, 2 is ED102A/DO-260B
+     * 0 <= version <= 7
+     */
+    pub fn set_version(&mut self, version: u8) -> Result<(), CatError> {
+        if version > 0x07 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.version = set_bits(self.version as u32, 6, 3, version as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get version number
+     */
+    pub fn get_version(&self) -> u8 {
+        get_bits(self.version as u32, 6, 3) as u8
+    }
+
+    /*
+     * Set link technology type
+     */
+    pub fn set_link_technology(&mut self, link: LinkTechnology) {
+        self.version = set_bits(self.version as u32, 3, 3, link as u32) as u8;
+    }
+
+    /*
+     * Get link technology type
+     */
+    pub fn get_link_technology(&self) -> LinkTechnology {
+        match get_bits(self.version as u32, 3, 3) {
+            0 => LinkTechnology::Other,
+            1 => LinkTechnology::Uat,
+            2 => LinkTechnology::ExtendedSquitter,
+            3 => LinkTechnology::Vdl4,
+            _ => LinkTechnology::NotAssigned,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for MopsVersion {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [version] = fixed_array(array)?;
+        Ok((MopsVersion { version }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.version]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mops_version() {
+        // Create message
+        let mut mops_version = MopsVersion::default();
+        mops_version.set_version(2).unwrap();
+        mops_version.set_link_technology(LinkTechnology::ExtendedSquitter);
+
+        // Convert struct to byte stream
+        let array = mops_version.encode();
+
+        assert_eq!(array, vec![0x12]);
+
+        // New message
+        let (object, length) = MopsVersion::decode(&array).unwrap();
+
+        assert_eq!(length, MopsVersion::MESSAGE_LENGTH);
+        assert_eq!(object.get_version(), 2);
+        assert_eq!(
+            object.get_link_technology(),
+            LinkTechnology::ExtendedSquitter
+        );
+        assert!(!object.get_not_supported());
+        assert_eq!(mops_version.set_version(8), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,193 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Aircraft Operational Status
+//
+// | 8  | 7 6 | 5  |  4  |   3    |  2   | 1  | bit
+// | RA | TC  | TS | ARV | CDTI/A | TCAS | SA |
+//
+
+/// Target change report capability
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TargetChangeCapability {
+    NoCapability,
+    TcpOnly,
+    MultipleTcp,
+    Reserved,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct OperationalStatus {
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation OperationalStatus
+*/
+impl OperationalStatus {
+    /*
+     * Set TCAS resolution advisory active
+     */
+    pub fn set_resolution_advisory(&mut self, value: bool) {
+        self.set_bit(8, value);
+    }
+
+    /*
+     * Get TCAS resolution advisory active
+     */
+    pub fn get_resolution_advisory(&self) -> bool {
+        self.get_bit(8)
+    }
+
+    /*
+     * Set target change report capability
+     */
+    pub fn set_target_change(&mut self, capability: TargetChangeCapability) {
+        self.status = set_bits(self.status as u32, 7, 2, capability as u32) as u8;
+    }
+
+    /*
+     * Get target change report capability
+     */
+    pub fn get_target_change(&self) -> TargetChangeCapability {
+        match get_bits(self.status as u32, 7, 2) {
+            0 => TargetChangeCapability::NoCapability,
+            1 => TargetChangeCapability::TcpOnly,
+            2 => TargetChangeCapability::MultipleTcp,
+            _ => TargetChangeCapability::Reserved,
+        }
+    }
+
+    /*
+     * Set target state report capability
+     */
+    pub fn set_target_state(&mut self, value: bool) {
+        self.set_bit(5, value);
+    }
+
+    /*
+     * Get target state report capability
+     */
+    pub fn get_target_state(&self) -> bool {
+        self.get_bit(5)
+    }
+
+    /*
+     * Set air-referenced velocity report capability
+     */
+    pub fn set_air_referenced_velocity(&mut self, value: bool) {
+        self.set_bit(4, value);
+    }
+
+    /*
+     * Get air-referenced velocity report capability
+     */
+    pub fn get_air_referenced_velocity(&self) -> bool {
+        self.get_bit(4)
+    }
+
+    /*
+     * Set cockpit display of traffic information airborne operational
+     */
+    pub fn set_cdti(&mut self, value: bool) {
+        self.set_bit(3, value);
+    }
+
+    /*
+     * Get cockpit display of traffic information airborne operational
+     */
+    pub fn get_cdti(&self) -> bool {
+        self.get_bit(3)
+    }
+
+    /*
+     * Set TCAS system status not operational
+     */
+    pub fn set_tcas_not_operational(&mut self, value: bool) {
+        self.set_bit(2, value);
+    }
+
+    /*
+     * Get TCAS system status not operational
+     */
+    pub fn get_tcas_not_operational(&self) -> bool {
+        self.get_bit(2)
+    }
+
+    /*
+     * Set single antenna only
+     */
+    pub fn set_single_antenna(&mut self, value: bool) {
+        self.set_bit(1, value);
+    }
+
+    /*
+     * Get single antenna only
+     */
+    pub fn get_single_antenna(&self) -> bool {
+        self.get_bit(1)
+    }
+
+    /*
+     * Set bit of the status
+     */
+    fn set_bit(&mut self, bit: u8, value: bool) {
+        self.status = set_bits(self.status as u32, bit, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get bit of the status
+     */
+    fn get_bit(&self, bit: u8) -> bool {
+        get_bits(self.status as u32, bit, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for OperationalStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [status] = fixed_array(array)?;
+        Ok((OperationalStatus { status }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.status]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_operational_status() {
+        // Create message
+        let mut operational_status = OperationalStatus::default();
+        operational_status.set_resolution_advisory(true);
+        operational_status.set_target_change(TargetChangeCapability::MultipleTcp);
+        operational_status.set_single_antenna(true);
+
+        // Convert struct to byte stream
+        let array = operational_status.encode();
+
+        assert_eq!(array, vec![0xc1]);
+
+        // New message
+        let (object, length) = OperationalStatus::decode(&array).unwrap();
+
+        assert_eq!(length, OperationalStatus::MESSAGE_LENGTH);
+        assert!(object.get_resolution_advisory());
+        assert_eq!(
+            object.get_target_change(),
+            TargetChangeCapability::MultipleTcp
+        );
+        assert!(!object.get_target_state());
+        assert!(object.get_single_antenna());
+    }
+}
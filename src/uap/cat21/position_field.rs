@@ -0,0 +1,195 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, to_signed, DataItem};
+
+// Position in WGS-84 Co-ordinates
+//
+// I021/130 Position, 24 bit latitude and longitude in 180/2^23 degree
+// I021/131 High-Resolution Position, 32 bit latitude and longitude in 180/2^30 degree
+//
+
+/// Position in WGS-84 Co-ordinates
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct WgsPosition {
+    position: [u8; 6], // 6 bytes
+}
+
+/*
+* Implementation WgsPosition
+*/
+impl WgsPosition {
+    /*
+     * Set latitude and longitude in degree
+     * -90 <= latitude <= 90
+     * -180 <= longitude < 180
+     */
+    pub fn set_lat_lon(&mut self, latitude: f64, longitude: f64) -> Result<(), CatError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..180.0).contains(&longitude) {
+            return Err(CatError::ValueInvalid);
+        }
+        let latitude = from_signed(latitude, Self::FACTOR, 24)?.to_be_bytes();
+        let longitude = from_signed(longitude, Self::FACTOR, 24)?.to_be_bytes();
+
+        self.position[0..3].copy_from_slice(&latitude[1..4]);
+        self.position[3..6].copy_from_slice(&longitude[1..4]);
+        Ok(())
+    }
+
+    /*
+     * Get latitude in degree
+     */
+    pub fn get_latitude(&self) -> f64 {
+        Self::to_i24(&self.position[0..3]) as f64 * Self::FACTOR
+    }
+
+    /*
+     * Get longitude in degree
+     */
+    pub fn get_longitude(&self) -> f64 {
+        Self::to_i24(&self.position[3..6]) as f64 * Self::FACTOR
+    }
+
+    /*
+     * Convert 24 bit two's complement to signed value
+     */
+    fn to_i24(array: &[u8]) -> i32 {
+        to_signed(u32::from_be_bytes([0, array[0], array[1], array[2]]), 24)
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f64 = 180.0 / 8388608.0;
+}
+
+impl DataItem for WgsPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let position = fixed_array(array)?;
+        Ok((WgsPosition { position }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.position.to_vec()
+    }
+}
+
+/// High-Resolution Position in WGS-84 Co-ordinates
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct HighResolutionPosition {
+    latitude: i32,  // 4 bytes
+    longitude: i32, // 4 bytes
+}
+
+/*
+* Implementation HighResolutionPosition
+*/
+impl HighResolutionPosition {
+    /*
+     * Set latitude and longitude in degree
+     * -90 <= latitude <= 90
+     * -180 <= longitude < 180
+     */
+    pub fn set_lat_lon(&mut self, latitude: f64, longitude: f64) -> Result<(), CatError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..180.0).contains(&longitude) {
+            return Err(CatError::ValueInvalid);
+        }
+        self.latitude = from_signed(latitude, Self::FACTOR, 32)? as i32;
+        self.longitude = from_signed(longitude, Self::FACTOR, 32)? as i32;
+        Ok(())
+    }
+
+    /*
+     * Get latitude in degree
+     */
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude as f64 * Self::FACTOR
+    }
+
+    /*
+     * Get longitude in degree
+     */
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude as f64 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f64 = 180.0 / 1073741824.0;
+}
+
+impl DataItem for HighResolutionPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 8] = fixed_array(array)?;
+        let latitude = i32::from_be_bytes([array[0], array[1], array[2], array[3]]);
+        let longitude = i32::from_be_bytes([array[4], array[5], array[6], array[7]]);
+        Ok((
+            HighResolutionPosition {
+                latitude,
+                longitude,
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.latitude.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.longitude.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_wgs_position() {
+        // Create message
+        let mut position = WgsPosition::default();
+        position.set_lat_lon(45.0, -90.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = position.encode();
+
+        assert_eq!(array, vec![0x20, 0x00, 0x00, 0xc0, 0x00, 0x00]);
+
+        // New message
+        let (object, length) = WgsPosition::decode(&array).unwrap();
+
+        assert_eq!(length, WgsPosition::MESSAGE_LENGTH);
+        assert_eq!(object.get_latitude(), 45.0);
+        assert_eq!(object.get_longitude(), -90.0);
+        assert_eq!(position.set_lat_lon(91.0, 0.0), Err(CatError::ValueInvalid));
+    }
+
+    #[test]
+    fn check_high_resolution_position() {
+        // Create message
+        let mut position = HighResolutionPosition::default();
+        position.set_lat_lon(45.0, -90.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = position.encode();
+
+        assert_eq!(array, vec![0x10, 0x00, 0x00, 0x00, 0xe0, 0x00, 0x00, 0x00]);
+
+        // New message
+        let (object, length) = HighResolutionPosition::decode(&array).unwrap();
+
+        assert_eq!(length, HighResolutionPosition::MESSAGE_LENGTH);
+        assert_eq!(object.get_latitude(), 45.0);
+        assert_eq!(object.get_longitude(), -90.0);
+    }
+}
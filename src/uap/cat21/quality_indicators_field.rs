@@ -0,0 +1,208 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Quality Indicators
+//
+// | 8 7 6       | 5 4 3 2      | 1  | bit
+// | NUCr/NACv   | NUCp/NIC     | FX |
+// | NICbaro | SIL | NACp       | FX |
+// | spare | SIL-S | SDA | GVA  | FX |
+// | PIC | spare                | FX |
+//
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct QualityIndicators {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation QualityIndicators
+*/
+impl QualityIndicators {
+    /*
+     * Set navigation uncertainty category for velocity or navigation accuracy category for velocity
+     * 0 <= nac_v <= 7
+     */
+    pub fn set_nac_v(&mut self, nac_v: u8) -> Result<(), CatError> {
+        self.set_checked(0, 8, 3, nac_v)
+    }
+
+    /*
+     * Get navigation accuracy category for velocity
+     */
+    pub fn get_nac_v(&self) -> u8 {
+        self.field.get_bits(0, 8, 3)
+    }
+
+    /*
+     * Set navigation uncertainty category for position or navigation integrity category
+     * 0 <= nic <= 15
+     */
+    pub fn set_nic(&mut self, nic: u8) -> Result<(), CatError> {
+        self.set_checked(0, 5, 4, nic)
+    }
+
+    /*
+     * Get navigation integrity category
+     */
+    pub fn get_nic(&self) -> u8 {
+        self.field.get_bits(0, 5, 4)
+    }
+
+    /*
+     * Set barometric altitude cross checked
+     */
+    pub fn set_nic_baro(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get barometric altitude cross checked
+     */
+    pub fn get_nic_baro(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set surveillance integrity level
+     * 0 <= sil <= 3
+     */
+    pub fn set_sil(&mut self, sil: u8) -> Result<(), CatError> {
+        self.set_checked(1, 7, 2, sil)
+    }
+
+    /*
+     * Get surveillance integrity level
+     */
+    pub fn get_sil(&self) -> u8 {
+        self.field.get_bits(1, 7, 2)
+    }
+
+    /*
+     * Set navigation accuracy category for position
+     * 0 <= nac_p <= 15
+     */
+    pub fn set_nac_p(&mut self, nac_p: u8) -> Result<(), CatError> {
+        self.set_checked(1, 5, 4, nac_p)
+    }
+
+    /*
+     * Get navigation accuracy category for position
+     */
+    pub fn get_nac_p(&self) -> u8 {
+        self.field.get_bits(1, 5, 4)
+    }
+
+    /*
+     * Set SIL supplement, probability per sample instead of per hour
+     */
+    pub fn set_sil_per_sample(&mut self, value: bool) {
+        self.field.set_bit(2, 6, value);
+    }
+
+    /*
+     * Get SIL supplement, probability per sample instead of per hour
+     */
+    pub fn get_sil_per_sample(&self) -> bool {
+        self.field.get_bit(2, 6)
+    }
+
+    /*
+     * Set system design assurance
+     * 0 <= sda <= 3
+     */
+    pub fn set_sda(&mut self, sda: u8) -> Result<(), CatError> {
+        self.set_checked(2, 5, 2, sda)
+    }
+
+    /*
+     * Get system design assurance
+     */
+    pub fn get_sda(&self) -> u8 {
+        self.field.get_bits(2, 5, 2)
+    }
+
+    /*
+     * Set geometric altitude accuracy
+     * 0 <= gva <= 3
+     */
+    pub fn set_gva(&mut self, gva: u8) -> Result<(), CatError> {
+        self.set_checked(2, 3, 2, gva)
+    }
+
+    /*
+     * Get geometric altitude accuracy
+     */
+    pub fn get_gva(&self) -> u8 {
+        self.field.get_bits(2, 3, 2)
+    }
+
+    /*
+     * Set position integrity category
+     * 0 <= pic <= 15
+     */
+    pub fn set_pic(&mut self, pic: u8) -> Result<(), CatError> {
+        self.set_checked(3, 8, 4, pic)
+    }
+
+    /*
+     * Get position integrity category
+     */
+    pub fn get_pic(&self) -> u8 {
+        self.field.get_bits(3, 8, 4)
+    }
+
+    /*
+     * Set bits of an octet, checks the value fits into the bit width
+     */
+    fn set_checked(&mut self, octet: usize, bit: u8, width: u8, value: u8) -> Result<(), CatError> {
+        if value >= 0x1 << width {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(octet, bit, width, value);
+        Ok(())
+    }
+}
+
+impl DataItem for QualityIndicators {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((QualityIndicators { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_quality_indicators() {
+        // Create message
+        let mut quality_indicators = QualityIndicators::default();
+        quality_indicators.set_nac_v(2).unwrap();
+        quality_indicators.set_nic(8).unwrap();
+        quality_indicators.set_sil(3).unwrap();
+        quality_indicators.set_nac_p(9).unwrap();
+
+        // Convert struct to byte stream
+        let array = quality_indicators.encode();
+
+        assert_eq!(array, vec![0x51, 0x72]);
+
+        // New message
+        let (object, length) = QualityIndicators::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_nac_v(), 2);
+        assert_eq!(object.get_nic(), 8);
+        assert_eq!(object.get_sil(), 3);
+        assert_eq!(object.get_nac_p(), 9);
+        assert_eq!(object.get_pic(), 0);
+        assert_eq!(quality_indicators.set_sil(4), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,71 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Receiver ID
+//
+// Receiver of a distributed ground station
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ReceiverId {
+    receiver: u8, // 1 byte
+}
+
+/*
+* Implementation ReceiverId
+*/
+impl ReceiverId {
+    /*
+     * Set receiver identification
+     */
+    pub fn set_receiver(&mut self, receiver: u8) {
+        self.receiver = receiver;
+    }
+
+    /*
+     * Get receiver identification
+     */
+    pub fn get_receiver(&self) -> u8 {
+        self.receiver
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ReceiverId {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [receiver] = fixed_array(array)?;
+        Ok((ReceiverId { receiver }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.receiver]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_receiver_id() {
+        // Create message
+        let mut receiver_id = ReceiverId::default();
+        receiver_id.set_receiver(42);
+
+        // Convert struct to byte stream
+        let array = receiver_id.encode();
+
+        assert_eq!(array, vec![0x2a]);
+
+        // New message
+        let (object, length) = ReceiverId::decode(&array).unwrap();
+
+        assert_eq!(length, ReceiverId::MESSAGE_LENGTH);
+        assert_eq!(object.get_receiver(), 42);
+    }
+}
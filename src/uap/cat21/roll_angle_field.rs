@@ -0,0 +1,82 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Roll Angle
+//
+// 16 bit two's complement in 0.01 degree, negative is left wing down
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct RollAngle {
+    angle: i16, // 2 bytes
+}
+
+/*
+* Implementation RollAngle
+*/
+impl RollAngle {
+    /*
+     * Set roll angle in degree
+     * -180 <= angle <= 180
+     */
+    pub fn set_angle(&mut self, angle: f32) -> Result<(), CatError> {
+        if !(-180.0..=180.0).contains(&angle) {
+            return Err(CatError::ValueInvalid);
+        }
+        self.angle = from_signed(angle as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get roll angle in degree
+     */
+    pub fn get_angle(&self) -> f32 {
+        self.angle as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 0.01;
+}
+
+impl DataItem for RollAngle {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let angle = i16::from_be_bytes(fixed_array(array)?);
+        Ok((RollAngle { angle }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.angle.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_roll_angle() {
+        // Create message
+        let mut roll_angle = RollAngle::default();
+        roll_angle.set_angle(-10.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = roll_angle.encode();
+
+        assert_eq!(array, vec![0xfb, 0xe6]);
+
+        // New message
+        let (object, length) = RollAngle::decode(&array).unwrap();
+
+        assert_eq!(length, RollAngle::MESSAGE_LENGTH);
+        assert_eq!(object.get_angle(), -10.5);
+        assert_eq!(roll_angle.set_angle(181.0), Err(CatError::ValueInvalid));
+    }
+}
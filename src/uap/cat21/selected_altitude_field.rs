@@ -0,0 +1,264 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, get_bits, set_bits, to_signed, DataItem};
+
+// Selected Altitude
+//
+// I021/146 Selected Altitude
+// | 16  | 15 14  | 13 .. 1                  | bit
+// | SAS | Source | altitude in 25 ft        |
+//
+// I021/148 Final State Selected Altitude
+// | 16 | 15 | 14 | 13 .. 1                  | bit
+// | MV | AH | AM | altitude in 25 ft        |
+//
+
+/// Source of the selected altitude
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum AltitudeSource {
+    Unknown,
+    Aircraft,
+    FcuMcp,
+    Fms,
+}
+
+/// Intermediate State Selected Altitude
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct SelectedAltitude {
+    altitude: u16, // 2 bytes
+}
+
+/*
+* Implementation SelectedAltitude
+*/
+impl SelectedAltitude {
+    /*
+     * Set source information provided
+     */
+    pub fn set_source_available(&mut self, value: bool) {
+        self.altitude = set_bits(self.altitude as u32, 16, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get source information provided
+     */
+    pub fn get_source_available(&self) -> bool {
+        get_bits(self.altitude as u32, 16, 1) == 1
+    }
+
+    /*
+     * Set source of the selected altitude
+     */
+    pub fn set_source(&mut self, source: AltitudeSource) {
+        self.altitude = set_bits(self.altitude as u32, 15, 2, source as u32) as u16;
+    }
+
+    /*
+     * Get source of the selected altitude
+     */
+    pub fn get_source(&self) -> AltitudeSource {
+        match get_bits(self.altitude as u32, 15, 2) {
+            1 => AltitudeSource::Aircraft,
+            2 => AltitudeSource::FcuMcp,
+            3 => AltitudeSource::Fms,
+            _ => AltitudeSource::Unknown,
+        }
+    }
+
+    /*
+     * Set altitude in ft
+     * -1300 <= altitude <= 100000
+     */
+    pub fn set_altitude(&mut self, altitude: f32) -> Result<(), CatError> {
+        self.altitude = set_altitude(self.altitude, altitude)?;
+        Ok(())
+    }
+
+    /*
+     * Get altitude in ft
+     */
+    pub fn get_altitude(&self) -> f32 {
+        get_altitude(self.altitude)
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for SelectedAltitude {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let altitude = u16::from_be_bytes(fixed_array(array)?);
+        Ok((SelectedAltitude { altitude }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.altitude.to_be_bytes().to_vec()
+    }
+}
+
+/// Final State Selected Altitude
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct FinalStateSelectedAltitude {
+    altitude: u16, // 2 bytes
+}
+
+/*
+* Implementation FinalStateSelectedAltitude
+*/
+impl FinalStateSelectedAltitude {
+    /*
+     * Set manage vertical mode active
+     */
+    pub fn set_manage_vertical_mode(&mut self, value: bool) {
+        self.altitude = set_bits(self.altitude as u32, 16, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get manage vertical mode active
+     */
+    pub fn get_manage_vertical_mode(&self) -> bool {
+        get_bits(self.altitude as u32, 16, 1) == 1
+    }
+
+    /*
+     * Set altitude hold mode active
+     */
+    pub fn set_altitude_hold(&mut self, value: bool) {
+        self.altitude = set_bits(self.altitude as u32, 15, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get altitude hold mode active
+     */
+    pub fn get_altitude_hold(&self) -> bool {
+        get_bits(self.altitude as u32, 15, 1) == 1
+    }
+
+    /*
+     * Set approach mode active
+     */
+    pub fn set_approach_mode(&mut self, value: bool) {
+        self.altitude = set_bits(self.altitude as u32, 14, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get approach mode active
+     */
+    pub fn get_approach_mode(&self) -> bool {
+        get_bits(self.altitude as u32, 14, 1) == 1
+    }
+
+    /*
+     * Set altitude in ft
+     * -1300 <= altitude <= 100000
+     */
+    pub fn set_altitude(&mut self, altitude: f32) -> Result<(), CatError> {
+        self.altitude = set_altitude(self.altitude, altitude)?;
+        Ok(())
+    }
+
+    /*
+     * Get altitude in ft
+     */
+    pub fn get_altitude(&self) -> f32 {
+        get_altitude(self.altitude)
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for FinalStateSelectedAltitude {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let altitude = u16::from_be_bytes(fixed_array(array)?);
+        Ok((
+            FinalStateSelectedAltitude { altitude },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.altitude.to_be_bytes().to_vec()
+    }
+}
+
+/*
+ * Set 13 bit two's complement altitude in ft, checks the range
+ */
+fn set_altitude(field: u16, altitude: f32) -> Result<u16, CatError> {
+    if !(-1300.0..=100000.0).contains(&altitude) {
+        return Err(CatError::ValueInvalid);
+    }
+    let value = from_signed(altitude as f64, FACTOR as f64, 13)?;
+    Ok(set_bits(field as u32, 13, 13, value) as u16)
+}
+
+/*
+ * Get 13 bit two's complement altitude in ft
+ */
+fn get_altitude(field: u16) -> f32 {
+    to_signed(get_bits(field as u32, 13, 13), 13) as f32 * FACTOR
+}
+
+/*
+ * Conversion factor.
+ */
+const FACTOR: f32 = 25.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_selected_altitude() {
+        // Create message
+        let mut selected_altitude = SelectedAltitude::default();
+        selected_altitude.set_source_available(true);
+        selected_altitude.set_source(AltitudeSource::FcuMcp);
+        selected_altitude.set_altitude(35000.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = selected_altitude.encode();
+
+        assert_eq!(array, vec![0xc5, 0x78]);
+
+        // New message
+        let (object, length) = SelectedAltitude::decode(&array).unwrap();
+
+        assert_eq!(length, SelectedAltitude::MESSAGE_LENGTH);
+        assert!(object.get_source_available());
+        assert_eq!(object.get_source(), AltitudeSource::FcuMcp);
+        assert_eq!(object.get_altitude(), 35000.0);
+        assert_eq!(
+            selected_altitude.set_altitude(-1325.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+
+    #[test]
+    fn check_final_state_selected_altitude() {
+        // Create message
+        let mut selected_altitude = FinalStateSelectedAltitude::default();
+        selected_altitude.set_manage_vertical_mode(true);
+        selected_altitude.set_altitude(-1000.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = selected_altitude.encode();
+
+        assert_eq!(array, vec![0x9f, 0xd8]);
+
+        // New message
+        let (object, length) = FinalStateSelectedAltitude::decode(&array).unwrap();
+
+        assert_eq!(length, FinalStateSelectedAltitude::MESSAGE_LENGTH);
+        assert!(object.get_manage_vertical_mode());
+        assert!(!object.get_altitude_hold());
+        assert!(!object.get_approach_mode());
+        assert_eq!(object.get_altitude(), -1000.0);
+    }
+}
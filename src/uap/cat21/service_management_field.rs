@@ -0,0 +1,82 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Service Management
+//
+// Report period in 0.5 s
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ServiceManagement {
+    period: u8, // 1 byte
+}
+
+/*
+* Implementation ServiceManagement
+*/
+impl ServiceManagement {
+    /*
+     * Set report period in s
+     * 0 <= period <= 127.5
+     */
+    pub fn set_report_period(&mut self, period: f32) -> Result<(), CatError> {
+        self.period = from_unsigned(period as f64, Self::FACTOR as f64, 8)? as u8;
+        Ok(())
+    }
+
+    /*
+     * Get report period in s
+     */
+    pub fn get_report_period(&self) -> f32 {
+        self.period as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 0.5;
+}
+
+impl DataItem for ServiceManagement {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [period] = fixed_array(array)?;
+        Ok((ServiceManagement { period }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.period]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_management() {
+        // Create message
+        let mut service_management = ServiceManagement::default();
+        service_management.set_report_period(2.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = service_management.encode();
+
+        assert_eq!(array, vec![0x05]);
+
+        // New message
+        let (object, length) = ServiceManagement::decode(&array).unwrap();
+
+        assert_eq!(length, ServiceManagement::MESSAGE_LENGTH);
+        assert_eq!(object.get_report_period(), 2.5);
+        assert_eq!(
+            service_management.set_report_period(128.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Surface Capabilities and Characteristics
+//
+// | 8 7   |  6  |   5    |    4   |  3  |   2   | 1  | bit
+// | spare | POA | CDTI/S | B2 low | RAS | IDENT | FX |
+// | 8 7 6 5 | spare                              | FX |
+// |  L+W    |                                    |    |
+//
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SurfaceCapabilities {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation SurfaceCapabilities
+*/
+impl SurfaceCapabilities {
+    /*
+     * Set position transmitted is the ADS-B position reference point
+     */
+    pub fn set_position_offset_applied(&mut self, value: bool) {
+        self.field.set_bit(0, 6, value);
+    }
+
+    /*
+     * Get position transmitted is the ADS-B position reference point
+     */
+    pub fn get_position_offset_applied(&self) -> bool {
+        self.field.get_bit(0, 6)
+    }
+
+    /*
+     * Set cockpit display of traffic information surface operational
+     */
+    pub fn set_cdti(&mut self, value: bool) {
+        self.field.set_bit(0, 5, value);
+    }
+
+    /*
+     * Get cockpit display of traffic information surface operational
+     */
+    pub fn get_cdti(&self) -> bool {
+        self.field.get_bit(0, 5)
+    }
+
+    /*
+     * Set class B2 transmit power less than 70 W
+     */
+    pub fn set_b2_low(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get class B2 transmit power less than 70 W
+     */
+    pub fn get_b2_low(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set aircraft receiving ATC services
+     */
+    pub fn set_receiving_atc_services(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get aircraft receiving ATC services
+     */
+    pub fn get_receiving_atc_services(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set IDENT switch active
+     */
+    pub fn set_ident(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get IDENT switch active
+     */
+    pub fn get_ident(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set length and width code of the aircraft
+     * 0 <= code <= 15
+     */
+    pub fn set_length_width(&mut self, code: u8) -> Result<(), CatError> {
+        if code > 0x0f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(1, 8, 4, code);
+        Ok(())
+    }
+
+    /*
+     * Get length and width code of the aircraft when present
+     */
+    pub fn get_length_width(&self) -> Option<u8> {
+        if self.field.len() < 2 {
+            return None;
+        }
+        Some(self.field.get_bits(1, 8, 4))
+    }
+}
+
+impl DataItem for SurfaceCapabilities {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((SurfaceCapabilities { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_surface_capabilities() {
+        // Create message
+        let mut surface_capabilities = SurfaceCapabilities::default();
+        surface_capabilities.set_position_offset_applied(true);
+        surface_capabilities.set_ident(true);
+        surface_capabilities.set_length_width(5).unwrap();
+
+        // Convert struct to byte stream
+        let array = surface_capabilities.encode();
+
+        assert_eq!(array, vec![0x23, 0x50]);
+
+        // New message
+        let (object, length) = SurfaceCapabilities::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert!(object.get_position_offset_applied());
+        assert!(object.get_ident());
+        assert!(!object.get_cdti());
+        assert_eq!(object.get_length_width(), Some(5));
+        assert_eq!(
+            surface_capabilities.set_length_width(16),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Target Report Descriptor
+//
+// | 8 7 6 | 5 4 |  3  |  2   | 1  | bit
+// |  ATP  | ARC | RC  | RAB  | FX |
+// | DCR | GBS | SIM | TST | SAA | CL | FX |
+// | spare | LLC | IPC | NOGO | CPR | LDPJ | RCF | FX |
+//
+
+/// Type of the target address
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum AddressType {
+    IcaoAddress,
+    DuplicateAddress,
+    SurfaceVehicleAddress,
+    AnonymousAddress,
+    Reserved,
+}
+
+/// Altitude reporting capability
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum AltitudeCapability {
+    Resolution25Ft,
+    Resolution100Ft,
+    Unknown,
+    Invalid,
+}
+
+/// Confidence level of the target report
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ConfidenceLevel {
+    Valid,
+    Suspect,
+    NoInformation,
+    Reserved,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TargetReportDescriptor {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TargetReportDescriptor
+*/
+impl TargetReportDescriptor {
+    /*
+     * Set address type
+     */
+    pub fn set_address_type(&mut self, address_type: AddressType) {
+        self.field.set_bits(0, 8, 3, address_type as u8);
+    }
+
+    /*
+     * Get address type
+     */
+    pub fn get_address_type(&self) -> AddressType {
+        match self.field.get_bits(0, 8, 3) {
+            0 => AddressType::IcaoAddress,
+            1 => AddressType::DuplicateAddress,
+            2 => AddressType::SurfaceVehicleAddress,
+            3 => AddressType::AnonymousAddress,
+            _ => AddressType::Reserved,
+        }
+    }
+
+    /*
+     * Set altitude reporting capability
+     */
+    pub fn set_altitude_capability(&mut self, capability: AltitudeCapability) {
+        self.field.set_bits(0, 5, 2, capability as u8);
+    }
+
+    /*
+     * Get altitude reporting capability
+     */
+    pub fn get_altitude_capability(&self) -> AltitudeCapability {
+        match self.field.get_bits(0, 5, 2) {
+            0 => AltitudeCapability::Resolution25Ft,
+            1 => AltitudeCapability::Resolution100Ft,
+            2 => AltitudeCapability::Unknown,
+            _ => AltitudeCapability::Invalid,
+        }
+    }
+
+    /*
+     * Set range check passed, CPR validation pending
+     */
+    pub fn set_range_check(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get range check passed, CPR validation pending
+     */
+    pub fn get_range_check(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set report from field monitor (fixed transponder)
+     */
+    pub fn set_field_monitor(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get report from field monitor (fixed transponder)
+     */
+    pub fn get_field_monitor(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set differential correction
+     */
+    pub fn set_differential_correction(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get differential correction
+     */
+    pub fn get_differential_correction(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set ground bit
+     */
+    pub fn set_ground(&mut self, value: bool) {
+        self.field.set_bit(1, 7, value);
+    }
+
+    /*
+     * Get ground bit
+     */
+    pub fn get_ground(&self) -> bool {
+        self.field.get_bit(1, 7)
+    }
+
+    /*
+     * Set simulated target report
+     */
+    pub fn set_simulated(&mut self, value: bool) {
+        self.field.set_bit(1, 6, value);
+    }
+
+    /*
+     * Get simulated target report
+     */
+    pub fn get_simulated(&self) -> bool {
+        self.field.get_bit(1, 6)
+    }
+
+    /*
+     * Set test target report
+     */
+    pub fn set_test(&mut self, value: bool) {
+        self.field.set_bit(1, 5, value);
+    }
+
+    /*
+     * Get test target report
+     */
+    pub fn get_test(&self) -> bool {
+        self.field.get_bit(1, 5)
+    }
+
+    /*
+     * Set selected altitude available
+     */
+    pub fn set_selected_altitude_available(&mut self, value: bool) {
+        self.field.set_bit(1, 4, value);
+    }
+
+    /*
+     * Get selected altitude available
+     */
+    pub fn get_selected_altitude_available(&self) -> bool {
+        self.field.get_bit(1, 4)
+    }
+
+    /*
+     * Set confidence level
+     */
+    pub fn set_confidence_level(&mut self, level: ConfidenceLevel) {
+        self.field.set_bits(1, 3, 2, level as u8);
+    }
+
+    /*
+     * Get confidence level
+     */
+    pub fn get_confidence_level(&self) -> ConfidenceLevel {
+        match self.field.get_bits(1, 3, 2) {
+            0 => ConfidenceLevel::Valid,
+            1 => ConfidenceLevel::Suspect,
+            2 => ConfidenceLevel::NoInformation,
+            _ => ConfidenceLevel::Reserved,
+        }
+    }
+
+    /*
+     * Set list lookup check failed
+     */
+    pub fn set_list_lookup_failed(&mut self, value: bool) {
+        self.field.set_bit(2, 7, value);
+    }
+
+    /*
+     * Get list lookup check failed
+     */
+    pub fn get_list_lookup_failed(&self) -> bool {
+        self.field.get_bit(2, 7)
+    }
+
+    /*
+     * Set independent position check failed
+     */
+    pub fn set_position_check_failed(&mut self, value: bool) {
+        self.field.set_bit(2, 6, value);
+    }
+
+    /*
+     * Get independent position check failed
+     */
+    pub fn get_position_check_failed(&self) -> bool {
+        self.field.get_bit(2, 6)
+    }
+
+    /*
+     * Set no-go bit
+     */
+    pub fn set_no_go(&mut self, value: bool) {
+        self.field.set_bit(2, 5, value);
+    }
+
+    /*
+     * Get no-go bit
+     */
+    pub fn get_no_go(&self) -> bool {
+        self.field.get_bit(2, 5)
+    }
+
+    /*
+     * Set compact position reporting validation failed
+     */
+    pub fn set_cpr_failed(&mut self, value: bool) {
+        self.field.set_bit(2, 4, value);
+    }
+
+    /*
+     * Get compact position reporting validation failed
+     */
+    pub fn get_cpr_failed(&self) -> bool {
+        self.field.get_bit(2, 4)
+    }
+
+    /*
+     * Set local decoding position jump
+     */
+    pub fn set_position_jump(&mut self, value: bool) {
+        self.field.set_bit(2, 3, value);
+    }
+
+    /*
+     * Get local decoding position jump
+     */
+    pub fn get_position_jump(&self) -> bool {
+        self.field.get_bit(2, 3)
+    }
+
+    /*
+     * Set range check failed
+     */
+    pub fn set_range_check_failed(&mut self, value: bool) {
+        self.field.set_bit(2, 2, value);
+    }
+
+    /*
+     * Get range check failed
+     */
+    pub fn get_range_check_failed(&self) -> bool {
+        self.field.get_bit(2, 2)
+    }
+}
+
+impl DataItem for TargetReportDescriptor {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TargetReportDescriptor { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_report_descriptor() {
+        // Create message
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_address_type(AddressType::IcaoAddress);
+        target_report_descriptor.set_altitude_capability(AltitudeCapability::Resolution100Ft);
+        target_report_descriptor.set_simulated(true);
+
+        // Convert struct to byte stream
+        let array = target_report_descriptor.encode();
+
+        assert_eq!(array, vec![0x09, 0x20]);
+
+        // New message
+        let (object, length) = TargetReportDescriptor::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_address_type(), AddressType::IcaoAddress);
+        assert_eq!(
+            object.get_altitude_capability(),
+            AltitudeCapability::Resolution100Ft
+        );
+        assert!(object.get_simulated());
+        assert!(!object.get_ground());
+        assert!(!object.get_no_go());
+    }
+}
@@ -0,0 +1,173 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Target Status
+//
+// |  8  |  7   |  6 | 5 4 3 | 2 1 | bit
+// | ICF | LNAV | ME |  PS   | SS  |
+//
+
+/// Priority status
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum PriorityStatus {
+    NoEmergency,
+    GeneralEmergency,
+    LifeguardMedical,
+    MinimumFuel,
+    NoCommunications,
+    UnlawfulInterference,
+    DownedAircraft,
+    Unknown,
+}
+
+/// Surveillance status
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SurveillanceStatus {
+    NoCondition,
+    PermanentAlert,
+    TemporaryAlert,
+    SpiSet,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TargetStatus {
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation TargetStatus
+*/
+impl TargetStatus {
+    /*
+     * Set intent change flag
+     */
+    pub fn set_intent_change(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 8, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get intent change flag
+     */
+    pub fn get_intent_change(&self) -> bool {
+        get_bits(self.status as u32, 8, 1) == 1
+    }
+
+    /*
+     * Set LNAV mode not engaged
+     */
+    pub fn set_lnav_not_engaged(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 7, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get LNAV mode not engaged
+     */
+    pub fn get_lnav_not_engaged(&self) -> bool {
+        get_bits(self.status as u32, 7, 1) == 1
+    }
+
+    /*
+     * Set military emergency
+     */
+    pub fn set_military_emergency(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 6, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get military emergency
+     */
+    pub fn get_military_emergency(&self) -> bool {
+        get_bits(self.status as u32, 6, 1) == 1
+    }
+
+    /*
+     * Set priority status
+     */
+    pub fn set_priority_status(&mut self, status: PriorityStatus) {
+        self.status = set_bits(self.status as u32, 5, 3, status as u32) as u8;
+    }
+
+    /*
+     * Get priority status
+     */
+    pub fn get_priority_status(&self) -> PriorityStatus {
+        match get_bits(self.status as u32, 5, 3) {
+            0 => PriorityStatus::NoEmergency,
+            1 => PriorityStatus::GeneralEmergency,
+            2 => PriorityStatus::LifeguardMedical,
+            3 => PriorityStatus::MinimumFuel,
+            4 => PriorityStatus::NoCommunications,
+            5 => PriorityStatus::UnlawfulInterference,
+            6 => PriorityStatus::DownedAircraft,
+            _ => PriorityStatus::Unknown,
+        }
+    }
+
+    /*
+     * Set surveillance status
+     */
+    pub fn set_surveillance_status(&mut self, status: SurveillanceStatus) {
+        self.status = set_bits(self.status as u32, 2, 2, status as u32) as u8;
+    }
+
+    /*
+     * Get surveillance status
+     */
+    pub fn get_surveillance_status(&self) -> SurveillanceStatus {
+        match get_bits(self.status as u32, 2, 2) {
+            0 => SurveillanceStatus::NoCondition,
+            1 => SurveillanceStatus::PermanentAlert,
+            2 => SurveillanceStatus::TemporaryAlert,
+            _ => SurveillanceStatus::SpiSet,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TargetStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [status] = fixed_array(array)?;
+        Ok((TargetStatus { status }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.status]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_status() {
+        // Create message
+        let mut target_status = TargetStatus::default();
+        target_status.set_intent_change(true);
+        target_status.set_priority_status(PriorityStatus::NoCommunications);
+        target_status.set_surveillance_status(SurveillanceStatus::SpiSet);
+
+        // Convert struct to byte stream
+        let array = target_status.encode();
+
+        assert_eq!(array, vec![0x93]);
+
+        // New message
+        let (object, length) = TargetStatus::decode(&array).unwrap();
+
+        assert_eq!(length, TargetStatus::MESSAGE_LENGTH);
+        assert!(object.get_intent_change());
+        assert!(!object.get_lnav_not_engaged());
+        assert_eq!(
+            object.get_priority_status(),
+            PriorityStatus::NoCommunications
+        );
+        assert_eq!(object.get_surveillance_status(), SurveillanceStatus::SpiSet);
+    }
+}
@@ -0,0 +1,80 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, get_bits, to_signed, DataItem};
+
+// Track Angle Rate
+//
+// | 16 .. 11 | 10 .. 1                                    | bit
+// |  spare   | two's complement rate in 1/32 degree/s     |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrackAngleRate {
+    rate: u16, // 2 bytes
+}
+
+/*
+* Implementation TrackAngleRate
+*/
+impl TrackAngleRate {
+    /*
+     * Set track angle rate in degree/s, positive is turning right
+     * -16 <= rate < 16
+     */
+    pub fn set_rate(&mut self, rate: f32) -> Result<(), CatError> {
+        self.rate = from_signed(rate as f64, Self::FACTOR as f64, 10)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get track angle rate in degree/s
+     */
+    pub fn get_rate(&self) -> f32 {
+        to_signed(get_bits(self.rate as u32, 10, 10), 10) as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 32.0;
+}
+
+impl DataItem for TrackAngleRate {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let rate = u16::from_be_bytes(fixed_array(array)?);
+        Ok((TrackAngleRate { rate }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.rate.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_angle_rate() {
+        // Create message
+        let mut track_angle_rate = TrackAngleRate::default();
+        track_angle_rate.set_rate(-1.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = track_angle_rate.encode();
+
+        assert_eq!(array, vec![0x03, 0xd0]);
+
+        // New message
+        let (object, length) = TrackAngleRate::decode(&array).unwrap();
+
+        assert_eq!(length, TrackAngleRate::MESSAGE_LENGTH);
+        assert_eq!(object.get_rate(), -1.5);
+        assert_eq!(track_angle_rate.set_rate(16.0), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,371 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_signed, from_unsigned, to_signed, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Trajectory Intent
+//
+// Compound data item, primary subfield followed by present subfields
+// | TIS | TID | spare ... | FX |
+//
+// Trajectory Intent Status
+// |  8  |  7  | spare | FX |
+// | NAV | NVB |       |    |
+//
+// Trajectory Intent Data, repetition factor followed by points of 15 bytes
+// | TCA | NC | TCP# | altitude | latitude | longitude | type TD TRA TOA | TOV | TTR |
+//
+
+/// Subfields of trajectory intent
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    Status,
+    Data,
+}
+
+/// Trajectory change point of the trajectory intent data
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrajectoryPoint {
+    point: [u8; 15], // 15 bytes
+}
+
+/*
+* Implementation TrajectoryPoint
+*/
+impl TrajectoryPoint {
+    /*
+     * Set trajectory change point number
+     * 0 <= number <= 63
+     */
+    pub fn set_number(&mut self, number: u8) -> Result<(), CatError> {
+        if number > 0x3f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.point[0] = (self.point[0] & 0x40) | number;
+        Ok(())
+    }
+
+    /*
+     * Get trajectory change point number when available
+     */
+    pub fn get_number(&self) -> Option<u8> {
+        if self.point[0] & 0x80 != 0 {
+            return None;
+        }
+        Some(self.point[0] & 0x3f)
+    }
+
+    /*
+     * Set non-compliance with the trajectory change point
+     */
+    pub fn set_non_compliance(&mut self, value: bool) {
+        self.point[0] = (self.point[0] & !0x40) | ((value as u8) << 6);
+    }
+
+    /*
+     * Get non-compliance with the trajectory change point
+     */
+    pub fn get_non_compliance(&self) -> bool {
+        self.point[0] & 0x40 != 0
+    }
+
+    /*
+     * Set altitude in ft
+     * -1500 <= altitude <= 150000
+     */
+    pub fn set_altitude(&mut self, altitude: f32) -> Result<(), CatError> {
+        if !(-1500.0..=150000.0).contains(&altitude) {
+            return Err(CatError::ValueInvalid);
+        }
+        let value = from_signed(altitude as f64, Self::FACTOR_ALTITUDE as f64, 16)? as u16;
+        self.point[1..3].copy_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    /*
+     * Get altitude in ft
+     */
+    pub fn get_altitude(&self) -> f32 {
+        i16::from_be_bytes([self.point[1], self.point[2]]) as f32 * Self::FACTOR_ALTITUDE
+    }
+
+    /*
+     * Set latitude and longitude in degree
+     * -90 <= latitude <= 90
+     * -180 <= longitude < 180
+     */
+    pub fn set_lat_lon(&mut self, latitude: f64, longitude: f64) -> Result<(), CatError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..180.0).contains(&longitude) {
+            return Err(CatError::ValueInvalid);
+        }
+        let latitude = from_signed(latitude, Self::FACTOR_POSITION, 24)?.to_be_bytes();
+        let longitude = from_signed(longitude, Self::FACTOR_POSITION, 24)?.to_be_bytes();
+
+        self.point[3..6].copy_from_slice(&latitude[1..4]);
+        self.point[6..9].copy_from_slice(&longitude[1..4]);
+        Ok(())
+    }
+
+    /*
+     * Get latitude in degree
+     */
+    pub fn get_latitude(&self) -> f64 {
+        Self::to_i24(&self.point[3..6]) as f64 * Self::FACTOR_POSITION
+    }
+
+    /*
+     * Get longitude in degree
+     */
+    pub fn get_longitude(&self) -> f64 {
+        Self::to_i24(&self.point[6..9]) as f64 * Self::FACTOR_POSITION
+    }
+
+    /*
+     * Set point type
+     * 0 <= point_type <= 15
+     */
+    pub fn set_point_type(&mut self, point_type: u8) -> Result<(), CatError> {
+        if point_type > 0x0f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.point[9] = (self.point[9] & 0x0f) | (point_type << 4);
+        Ok(())
+    }
+
+    /*
+     * Get point type
+     */
+    pub fn get_point_type(&self) -> u8 {
+        self.point[9] >> 4
+    }
+
+    /*
+     * Set time over point in s
+     * 0 <= time < 2^24
+     */
+    pub fn set_time_over_point(&mut self, time: u32) -> Result<(), CatError> {
+        if time > 0xff_ffff {
+            return Err(CatError::ValueInvalid);
+        }
+        self.point[10..13].copy_from_slice(&time.to_be_bytes()[1..4]);
+        Ok(())
+    }
+
+    /*
+     * Get time over point in s when available
+     */
+    pub fn get_time_over_point(&self) -> Option<u32> {
+        if self.point[9] & 0x01 != 0 {
+            return None;
+        }
+        Some(u32::from_be_bytes([
+            0,
+            self.point[10],
+            self.point[11],
+            self.point[12],
+        ]))
+    }
+
+    /*
+     * Set turn radius in NM
+     * 0 <= radius < 655.36
+     */
+    pub fn set_turn_radius(&mut self, radius: f32) -> Result<(), CatError> {
+        let value = from_unsigned(radius as f64, Self::FACTOR_RADIUS as f64, 16)? as u16;
+        self.point[13..15].copy_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    /*
+     * Get turn radius in NM when available
+     */
+    pub fn get_turn_radius(&self) -> Option<f32> {
+        if self.point[9] & 0x02 != 0 {
+            return None;
+        }
+        Some(u16::from_be_bytes([self.point[13], self.point[14]]) as f32 * Self::FACTOR_RADIUS)
+    }
+
+    /*
+     * Convert 24 bit two's complement to signed value
+     */
+    fn to_i24(array: &[u8]) -> i32 {
+        to_signed(u32::from_be_bytes([0, array[0], array[1], array[2]]), 24)
+    }
+
+    /*
+     * Length of one trajectory change point.
+     */
+    pub const POINT_LENGTH: usize = 15;
+
+    /*
+     * Conversion factor of altitude.
+     */
+    const FACTOR_ALTITUDE: f32 = 10.0;
+
+    /*
+     * Conversion factor of latitude and longitude.
+     */
+    const FACTOR_POSITION: f64 = 180.0 / 8388608.0;
+
+    /*
+     * Conversion factor of turn radius.
+     */
+    const FACTOR_RADIUS: f32 = 0.01;
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrajectoryIntent {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation TrajectoryIntent
+*/
+impl TrajectoryIntent {
+    /*
+     * Set trajectory intent data not available
+     */
+    pub fn set_unavailable(&mut self, value: bool) {
+        self.set_status_bit(8, value);
+    }
+
+    /*
+     * Get trajectory intent data not available
+     */
+    pub fn get_unavailable(&self) -> Option<bool> {
+        self.get_status().map(|status| status.get_bit(0, 8))
+    }
+
+    /*
+     * Set trajectory intent data not valid
+     */
+    pub fn set_invalid(&mut self, value: bool) {
+        self.set_status_bit(7, value);
+    }
+
+    /*
+     * Get trajectory intent data not valid
+     */
+    pub fn get_invalid(&self) -> Option<bool> {
+        self.get_status().map(|status| status.get_bit(0, 7))
+    }
+
+    /*
+     * Insert trajectory change point
+     */
+    pub fn insert_point(&mut self, point: TrajectoryPoint) -> Result<(), CatError> {
+        let mut vector = match self.field.get_subfield(Subfield::Data as usize) {
+            Some(value) => value.to_vec(),
+            None => vec![0],
+        };
+        if vector[0] == u8::MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        vector[0] += 1;
+        vector.extend_from_slice(&point.point);
+        self.field.set_subfield(Subfield::Data as usize, vector);
+        Ok(())
+    }
+
+    /*
+     * Get all trajectory change points
+     */
+    pub fn get_points(&self) -> Vec<TrajectoryPoint> {
+        match self.field.get_subfield(Subfield::Data as usize) {
+            Some(value) => value[1..]
+                .chunks_exact(TrajectoryPoint::POINT_LENGTH)
+                .map(|chunk| TrajectoryPoint {
+                    point: chunk.try_into().expect("chunk of point length"),
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /*
+     * Get trajectory intent status when present
+     */
+    fn get_status(&self) -> Option<ExtendedField> {
+        self.field.get_item(Subfield::Status as usize)
+    }
+
+    /*
+     * Set bit of the trajectory intent status
+     */
+    fn set_status_bit(&mut self, bit: u8, value: bool) {
+        let mut status = self.get_status().unwrap_or_default();
+        status.set_bit(0, bit, value);
+        self.field.set_item(Subfield::Status as usize, &status);
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 2] = [
+        SubfieldLength::Extended,
+        SubfieldLength::Repetitive(TrajectoryPoint::POINT_LENGTH),
+    ];
+}
+
+impl DataItem for TrajectoryIntent {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((TrajectoryIntent { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_trajectory_intent() {
+        // Create trajectory change point
+        let mut point = TrajectoryPoint::default();
+        point.set_number(3).unwrap();
+        point.set_altitude(35000.0).unwrap();
+        point.set_lat_lon(45.0, -90.0).unwrap();
+        point.set_point_type(1).unwrap();
+        point.set_time_over_point(3600).unwrap();
+        point.set_turn_radius(1.5).unwrap();
+
+        // Create message
+        let mut trajectory_intent = TrajectoryIntent::default();
+        trajectory_intent.set_invalid(true);
+        trajectory_intent.insert_point(point).unwrap();
+
+        // Convert struct to byte stream
+        let array = trajectory_intent.encode();
+
+        assert_eq!(
+            array,
+            vec![
+                0xc0, 0x40, 0x01, 0x03, 0x0d, 0xac, 0x20, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x10, 0x00,
+                0x0e, 0x10, 0x00, 0x96
+            ]
+        );
+
+        // New message
+        let (object, length) = TrajectoryIntent::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_invalid(), Some(true));
+        assert_eq!(object.get_unavailable(), Some(false));
+
+        let points = object.get_points();
+
+        assert_eq!(points, vec![point]);
+        assert_eq!(points[0].get_number(), Some(3));
+        assert_eq!(points[0].get_altitude(), 35000.0);
+        assert_eq!(points[0].get_latitude(), 45.0);
+        assert_eq!(points[0].get_longitude(), -90.0);
+        assert_eq!(points[0].get_point_type(), 1);
+        assert_eq!(points[0].get_time_over_point(), Some(3600));
+        assert_eq!(points[0].get_turn_radius(), Some(1.5));
+    }
+}
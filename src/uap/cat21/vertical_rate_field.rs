@@ -0,0 +1,99 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, get_bits, set_bits, to_signed, DataItem};
+
+// Barometric and Geometric Vertical Rate
+//
+// | 16 | 15 .. 1                                  | bit
+// | RE | two's complement rate in 6.25 ft/min     |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VerticalRate {
+    rate: u16, // 2 bytes
+}
+
+/*
+* Implementation VerticalRate
+*/
+impl VerticalRate {
+    /*
+     * Set vertical rate in ft/min
+     * -102400 <= rate < 102400
+     */
+    pub fn set_rate(&mut self, rate: f32) -> Result<(), CatError> {
+        let value = from_signed(rate as f64, Self::FACTOR as f64, 15)?;
+        self.rate = set_bits(self.rate as u32, 15, 15, value) as u16;
+        Ok(())
+    }
+
+    /*
+     * Get vertical rate in ft/min
+     */
+    pub fn get_rate(&self) -> f32 {
+        to_signed(get_bits(self.rate as u32, 15, 15), 15) as f32 * Self::FACTOR
+    }
+
+    /*
+     * Set value exceeds defined range
+     */
+    pub fn set_range_exceeded(&mut self, value: bool) {
+        self.rate = set_bits(self.rate as u32, 16, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get value exceeds defined range
+     */
+    pub fn get_range_exceeded(&self) -> bool {
+        get_bits(self.rate as u32, 16, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 6.25;
+}
+
+impl DataItem for VerticalRate {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let rate = u16::from_be_bytes(fixed_array(array)?);
+        Ok((VerticalRate { rate }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.rate.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_vertical_rate() {
+        // Create message
+        let mut vertical_rate = VerticalRate::default();
+        vertical_rate.set_rate(-1000.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = vertical_rate.encode();
+
+        assert_eq!(array, vec![0x7f, 0x60]);
+
+        // New message
+        let (object, length) = VerticalRate::decode(&array).unwrap();
+
+        assert_eq!(length, VerticalRate::MESSAGE_LENGTH);
+        assert_eq!(object.get_rate(), -1000.0);
+        assert!(!object.get_range_exceeded());
+        assert_eq!(
+            vertical_rate.set_rate(102400.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Ground Station Status
+//
+// |  8   |  7  |  6  |  5  |  4  |  3  |  2  | 1  | bit
+// | NOGO | ODP | OXT | MSC | TSV | SPO | RN  | FX |
+// |             GSSP                     | FX |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct GroundStationStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation GroundStationStatus
+*/
+impl GroundStationStatus {
+    /*
+     * Set operational release status, no-go when set
+     */
+    pub fn set_nogo(&mut self, value: bool) {
+        self.field.set_bit(0, 8, value);
+    }
+
+    /*
+     * Get operational release status, no-go when set
+     */
+    pub fn get_nogo(&self) -> bool {
+        self.field.get_bit(0, 8)
+    }
+
+    /*
+     * Set data processor overload
+     */
+    pub fn set_processor_overload(&mut self, value: bool) {
+        self.field.set_bit(0, 7, value);
+    }
+
+    /*
+     * Get data processor overload
+     */
+    pub fn get_processor_overload(&self) -> bool {
+        self.field.get_bit(0, 7)
+    }
+
+    /*
+     * Set ground interface data communications overload
+     */
+    pub fn set_transfer_overload(&mut self, value: bool) {
+        self.field.set_bit(0, 6, value);
+    }
+
+    /*
+     * Get ground interface data communications overload
+     */
+    pub fn get_transfer_overload(&self) -> bool {
+        self.field.get_bit(0, 6)
+    }
+
+    /*
+     * Set monitoring system connected
+     */
+    pub fn set_monitoring_connected(&mut self, value: bool) {
+        self.field.set_bit(0, 5, value);
+    }
+
+    /*
+     * Get monitoring system connected
+     */
+    pub fn get_monitoring_connected(&self) -> bool {
+        self.field.get_bit(0, 5)
+    }
+
+    /*
+     * Set invalid time source
+     */
+    pub fn set_time_source_invalid(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get invalid time source
+     */
+    pub fn get_time_source_invalid(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set spoofing attack indication
+     */
+    pub fn set_spoofing(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get spoofing attack indication
+     */
+    pub fn get_spoofing(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set renumbering indication for track numbers
+     */
+    pub fn set_renumbering(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get renumbering indication for track numbers
+     */
+    pub fn get_renumbering(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set ground station status reporting period in s
+     * 0 <= period <= 127
+     */
+    pub fn set_reporting_period(&mut self, period: u8) -> Result<(), CatError> {
+        if period > Self::PERIOD_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(1, 8, 7, period);
+        Ok(())
+    }
+
+    /*
+     * Get ground station status reporting period in s
+     */
+    pub fn get_reporting_period(&self) -> Option<u8> {
+        if self.field.len() < 2 {
+            return None;
+        }
+        Some(self.field.get_bits(1, 8, 7))
+    }
+
+    /*
+     * Maximum reporting period in 7 bits.
+     */
+    const PERIOD_MAX: u8 = 0x7f;
+}
+
+impl DataItem for GroundStationStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((GroundStationStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_ground_station_status() {
+        // Create message
+        let mut ground_station_status = GroundStationStatus::default();
+        ground_station_status.set_monitoring_connected(true);
+        ground_station_status.set_reporting_period(10).unwrap();
+
+        // Convert struct to byte stream
+        let array = ground_station_status.encode();
+
+        assert_eq!(array, vec![0x11, 0x14]);
+
+        // New message
+        let (object, length) = GroundStationStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert!(object.get_monitoring_connected());
+        assert!(!object.get_nogo());
+        assert_eq!(object.get_reporting_period(), Some(10));
+        assert_eq!(
+            ground_station_status.set_reporting_period(128),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod ground_station_status_field;
+pub mod operational_range_field;
+pub mod report_type_field;
+pub mod service_configuration_field;
+pub mod service_statistics_field;
+pub mod service_status_field;
+pub mod service_type_field;
@@ -0,0 +1,71 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Operational Range
+//
+// Currently active operational range of the ground station
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct OperationalRange {
+    range: u8, // 1 byte
+}
+
+/*
+* Implementation OperationalRange
+*/
+impl OperationalRange {
+    /*
+     * Set operational range in NM
+     */
+    pub fn set_range(&mut self, range: u8) {
+        self.range = range;
+    }
+
+    /*
+     * Get operational range in NM
+     */
+    pub fn get_range(&self) -> u8 {
+        self.range
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for OperationalRange {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [range] = fixed_array(array)?;
+        Ok((OperationalRange { range }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.range]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_operational_range() {
+        // Create message
+        let mut operational_range = OperationalRange::default();
+        operational_range.set_range(250);
+
+        // Convert struct to byte stream
+        let array = operational_range.encode();
+
+        assert_eq!(array, vec![0xfa]);
+
+        // New message
+        let (object, length) = OperationalRange::decode(&array).unwrap();
+
+        assert_eq!(length, OperationalRange::MESSAGE_LENGTH);
+        assert_eq!(object.get_range(), 250);
+    }
+}
@@ -0,0 +1,84 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Report Type
+//
+
+/// Types of ground station reports
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum GroundStationReport {
+    Unknown,
+    GroundStationStatus,
+    ServiceStatus,
+    ServiceStatistics,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ReportType {
+    report: u8, // 1 byte
+}
+
+/*
+* Implementation ReportType
+*/
+impl ReportType {
+    /*
+     * Set report type
+     */
+    pub fn set_report_type(&mut self, report_type: GroundStationReport) {
+        self.report = report_type as u8;
+    }
+
+    /*
+     * Get report type
+     */
+    pub fn get_report_type(&self) -> GroundStationReport {
+        match self.report {
+            1 => GroundStationReport::GroundStationStatus,
+            2 => GroundStationReport::ServiceStatus,
+            3 => GroundStationReport::ServiceStatistics,
+            _ => GroundStationReport::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ReportType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [report] = fixed_array(array)?;
+        Ok((ReportType { report }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.report]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_report_type() {
+        // Create message
+        let mut report_type = ReportType::default();
+        report_type.set_report_type(GroundStationReport::ServiceStatus);
+
+        // Convert struct to byte stream
+        let array = report_type.encode();
+
+        assert_eq!(array, vec![0x02]);
+
+        // New message
+        let (object, length) = ReportType::decode(&array).unwrap();
+
+        assert_eq!(length, ReportType::MESSAGE_LENGTH);
+        assert_eq!(object.get_report_type(), GroundStationReport::ServiceStatus);
+    }
+}
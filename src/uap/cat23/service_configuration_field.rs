@@ -0,0 +1,155 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_unsigned, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Service Configuration
+//
+// Report period octet followed by octets with FX bit
+// | 8 .. 1 |                                bit
+// |   RP   |
+// | 8 7 6  | 5 4 3 2 | 1  |
+// |   SC   |  spare  | FX |
+// |       SSRP       | FX |
+//
+
+/// Class of the service
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ServiceClass {
+    NoInformation,
+    NonRadarAirspace,
+    Reserved,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ServiceConfiguration {
+    report_period: u8,    // 1 byte
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation ServiceConfiguration
+*/
+impl ServiceConfiguration {
+    /*
+     * Set report period of the service in s
+     * 0 <= period < 128
+     */
+    pub fn set_report_period(&mut self, period: f32) -> Result<(), CatError> {
+        self.report_period = from_unsigned(period as f64, Self::FACTOR as f64, 8)? as u8;
+        Ok(())
+    }
+
+    /*
+     * Get report period of the service in s
+     */
+    pub fn get_report_period(&self) -> f32 {
+        self.report_period as f32 * Self::FACTOR
+    }
+
+    /*
+     * Set service class
+     */
+    pub fn set_service_class(&mut self, class: ServiceClass) {
+        self.field.set_bits(0, 8, 3, class as u8);
+    }
+
+    /*
+     * Get service class
+     */
+    pub fn get_service_class(&self) -> ServiceClass {
+        match self.field.get_bits(0, 8, 3) {
+            0 => ServiceClass::NoInformation,
+            1 => ServiceClass::NonRadarAirspace,
+            _ => ServiceClass::Reserved,
+        }
+    }
+
+    /*
+     * Set service status reporting period in s
+     * 0 <= period <= 127
+     */
+    pub fn set_status_period(&mut self, period: u8) -> Result<(), CatError> {
+        if period > Self::PERIOD_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(1, 8, 7, period);
+        Ok(())
+    }
+
+    /*
+     * Get service status reporting period in s
+     */
+    pub fn get_status_period(&self) -> Option<u8> {
+        if self.field.len() < 2 {
+            return None;
+        }
+        Some(self.field.get_bits(1, 8, 7))
+    }
+
+    /*
+     * Conversion factor of report period.
+     */
+    const FACTOR: f32 = 0.5;
+
+    /*
+     * Maximum status reporting period in 7 bits.
+     */
+    const PERIOD_MAX: u8 = 0x7f;
+}
+
+impl DataItem for ServiceConfiguration {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let report_period = *array.first().ok_or(CatError::SizeInvalid(0))?;
+
+        // Octets with FX bit follow the report period
+        let (field, length) = ExtendedField::decode(&array[1..])?;
+        Ok((
+            ServiceConfiguration {
+                report_period,
+                field,
+            },
+            1 + length,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.report_period];
+        vector.extend(self.field.encode());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_configuration() {
+        // Create message
+        let mut service_configuration = ServiceConfiguration::default();
+        service_configuration.set_report_period(2.5).unwrap();
+        service_configuration.set_service_class(ServiceClass::NonRadarAirspace);
+        service_configuration.set_status_period(30).unwrap();
+
+        // Convert struct to byte stream
+        let array = service_configuration.encode();
+
+        assert_eq!(array, vec![0x05, 0x21, 0x3c]);
+
+        // New message
+        let (object, length) = ServiceConfiguration::decode(&array).unwrap();
+
+        assert_eq!(length, 3);
+        assert_eq!(object.get_report_period(), 2.5);
+        assert_eq!(object.get_service_class(), ServiceClass::NonRadarAirspace);
+        assert_eq!(object.get_status_period(), Some(30));
+        assert_eq!(
+            service_configuration.set_report_period(128.0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            ServiceConfiguration::decode(&[0x05]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
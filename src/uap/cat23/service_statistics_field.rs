@@ -0,0 +1,179 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Service Statistics
+//
+// Repetition factor followed by counters
+// | 48 .. 41 | 40  | 39 .. 33 | 32 .. 1 | bit
+// |   TYPE   | REF |  spare   | COUNTER |
+//
+
+/// Types of service statistics counters
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum StatisticsType {
+    UnknownMessages = 0,
+    TooOldMessages = 1,
+    FailedConversions = 2,
+    TotalReceived = 3,
+    TotalTransmitted = 4,
+    TisBManagement = 20,
+    BasicMessages = 21,
+    HighDynamicMessages = 22,
+    FullPositionMessages = 23,
+    BasicGroundMessages = 24,
+    TcpMessages = 25,
+    UtcTimeMessages = 26,
+    DataMessages = 27,
+    HighResolutionMessages = 28,
+    AirborneTargetMessages = 29,
+    GroundTargetMessages = 30,
+    GroundVehicleMessages = 31,
+    TwoSlotTcpMessages = 32,
+    Undefined = 255,
+}
+
+/// Counter of service statistics
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct StatisticsCounter {
+    pub typ: StatisticsType,
+    /// Counted since the last report, otherwise since UTC midnight
+    pub since_last_report: bool,
+    pub counter: u32,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ServiceStatistics {
+    counters: Vec<[u8; 6]>, // 6 bytes each
+}
+
+/*
+* Implementation ServiceStatistics
+*/
+impl ServiceStatistics {
+    /*
+     * Insert statistics counter
+     */
+    pub fn insert_counter(&mut self, counter: StatisticsCounter) -> Result<(), CatError> {
+        if counter.typ == StatisticsType::Undefined || self.counters.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        let mut value = [0u8; Self::COUNTER_LENGTH];
+        value[0] = counter.typ as u8;
+        value[1] = (counter.since_last_report as u8) << 7;
+        value[2..].copy_from_slice(&counter.counter.to_be_bytes());
+        self.counters.push(value);
+        Ok(())
+    }
+
+    /*
+     * Get all statistics counters
+     */
+    pub fn get_counters(&self) -> Vec<StatisticsCounter> {
+        self.counters
+            .iter()
+            .map(|value| StatisticsCounter {
+                typ: Self::statistics_type(value[0]),
+                since_last_report: value[1] & 0x80 != 0,
+                counter: u32::from_be_bytes([value[2], value[3], value[4], value[5]]),
+            })
+            .collect()
+    }
+
+    /*
+     * Convert type octet to statistics type.
+     */
+    fn statistics_type(value: u8) -> StatisticsType {
+        match value {
+            0 => StatisticsType::UnknownMessages,
+            1 => StatisticsType::TooOldMessages,
+            2 => StatisticsType::FailedConversions,
+            3 => StatisticsType::TotalReceived,
+            4 => StatisticsType::TotalTransmitted,
+            20 => StatisticsType::TisBManagement,
+            21 => StatisticsType::BasicMessages,
+            22 => StatisticsType::HighDynamicMessages,
+            23 => StatisticsType::FullPositionMessages,
+            24 => StatisticsType::BasicGroundMessages,
+            25 => StatisticsType::TcpMessages,
+            26 => StatisticsType::UtcTimeMessages,
+            27 => StatisticsType::DataMessages,
+            28 => StatisticsType::HighResolutionMessages,
+            29 => StatisticsType::AirborneTargetMessages,
+            30 => StatisticsType::GroundTargetMessages,
+            31 => StatisticsType::GroundVehicleMessages,
+            32 => StatisticsType::TwoSlotTcpMessages,
+            _ => StatisticsType::Undefined,
+        }
+    }
+
+    /*
+     * Length of one counter.
+     */
+    const COUNTER_LENGTH: usize = 6;
+}
+
+impl DataItem for ServiceStatistics {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::COUNTER_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let counters = array[1..length]
+            .chunks_exact(Self::COUNTER_LENGTH)
+            .map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5]])
+            .collect();
+
+        Ok((ServiceStatistics { counters }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.counters.len() as u8];
+
+        for counter in self.counters.iter() {
+            vector.extend_from_slice(counter);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_statistics() {
+        // Create message
+        let counter = StatisticsCounter {
+            typ: StatisticsType::TotalReceived,
+            since_last_report: true,
+            counter: 70000,
+        };
+        let mut service_statistics = ServiceStatistics::default();
+        service_statistics.insert_counter(counter).unwrap();
+
+        // Convert struct to byte stream
+        let array = service_statistics.encode();
+
+        assert_eq!(array, vec![0x01, 0x03, 0x80, 0x00, 0x01, 0x11, 0x70]);
+
+        // New message
+        let (object, length) = ServiceStatistics::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_counters(), vec![counter]);
+        assert_eq!(
+            service_statistics.insert_counter(StatisticsCounter {
+                typ: StatisticsType::Undefined,
+                since_last_report: false,
+                counter: 0,
+            }),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            ServiceStatistics::decode(&[0x01, 0x03, 0x80]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
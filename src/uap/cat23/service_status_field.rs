@@ -0,0 +1,87 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Service Status
+//
+// | 8 7 6 5 | 4 3 2 | 1  | bit
+// |  spare  | STAT  | FX |
+//
+
+/// Status of the service
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ServiceState {
+    Unknown,
+    Failed,
+    Disabled,
+    Degraded,
+    Normal,
+    Initialisation,
+    Reserved,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ServiceStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation ServiceStatus
+*/
+impl ServiceStatus {
+    /*
+     * Set status of the service
+     */
+    pub fn set_status(&mut self, status: ServiceState) {
+        self.field.set_bits(0, 4, 3, status as u8);
+    }
+
+    /*
+     * Get status of the service
+     */
+    pub fn get_status(&self) -> ServiceState {
+        match self.field.get_bits(0, 4, 3) {
+            0 => ServiceState::Unknown,
+            1 => ServiceState::Failed,
+            2 => ServiceState::Disabled,
+            3 => ServiceState::Degraded,
+            4 => ServiceState::Normal,
+            5 => ServiceState::Initialisation,
+            _ => ServiceState::Reserved,
+        }
+    }
+}
+
+impl DataItem for ServiceStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((ServiceStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_status() {
+        // Create message
+        let mut service_status = ServiceStatus::default();
+        service_status.set_status(ServiceState::Degraded);
+
+        // Convert struct to byte stream
+        let array = service_status.encode();
+
+        assert_eq!(array, vec![0x06]);
+
+        // New message
+        let (object, length) = ServiceStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 1);
+        assert_eq!(object.get_status(), ServiceState::Degraded);
+    }
+}
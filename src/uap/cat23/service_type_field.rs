@@ -0,0 +1,124 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Service Type and Identification
+//
+// | 8 7 6 5 | 4 3 2 1 | bit
+// |   SID   |  STYP   |
+//
+
+/// Types of ground station services
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum GroundStationService {
+    Unknown,
+    AdsBVdl4,
+    AdsBExtendedSquitter,
+    AdsBUat,
+    TisBVdl4,
+    TisBExtendedSquitter,
+    TisBUat,
+    FisBVdl4,
+    GrasVdl4,
+    Multilateration,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ServiceType {
+    service: u8, // 1 byte
+}
+
+/*
+* Implementation ServiceType
+*/
+impl ServiceType {
+    /*
+     * Set service identification
+     * 0 <= id <= 15
+     */
+    pub fn set_service_id(&mut self, id: u8) -> Result<(), CatError> {
+        if id > 0x0f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.service = set_bits(self.service as u32, 8, 4, id as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get service identification
+     */
+    pub fn get_service_id(&self) -> u8 {
+        get_bits(self.service as u32, 8, 4) as u8
+    }
+
+    /*
+     * Set type of service
+     */
+    pub fn set_service_type(&mut self, service_type: GroundStationService) {
+        self.service = set_bits(self.service as u32, 4, 4, service_type as u32) as u8;
+    }
+
+    /*
+     * Get type of service
+     */
+    pub fn get_service_type(&self) -> GroundStationService {
+        match get_bits(self.service as u32, 4, 4) {
+            1 => GroundStationService::AdsBVdl4,
+            2 => GroundStationService::AdsBExtendedSquitter,
+            3 => GroundStationService::AdsBUat,
+            4 => GroundStationService::TisBVdl4,
+            5 => GroundStationService::TisBExtendedSquitter,
+            6 => GroundStationService::TisBUat,
+            7 => GroundStationService::FisBVdl4,
+            8 => GroundStationService::GrasVdl4,
+            9 => GroundStationService::Multilateration,
+            _ => GroundStationService::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ServiceType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [service] = fixed_array(array)?;
+        Ok((ServiceType { service }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.service]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_type() {
+        // Create message
+        let mut service_type = ServiceType::default();
+        service_type.set_service_id(3).unwrap();
+        service_type.set_service_type(GroundStationService::AdsBExtendedSquitter);
+
+        // Convert struct to byte stream
+        let array = service_type.encode();
+
+        assert_eq!(array, vec![0x32]);
+
+        // New message
+        let (object, length) = ServiceType::decode(&array).unwrap();
+
+        assert_eq!(length, ServiceType::MESSAGE_LENGTH);
+        assert_eq!(object.get_service_id(), 3);
+        assert_eq!(
+            object.get_service_type(),
+            GroundStationService::AdsBExtendedSquitter
+        );
+        assert_eq!(service_type.set_service_id(16), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,82 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Video Message Type
+//
+
+/// Types of radar video messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum VideoMessage {
+    Unknown,
+    VideoSummary,
+    Video,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VideoMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation VideoMessageType
+*/
+impl VideoMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: VideoMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> VideoMessage {
+        match self.message {
+            1 => VideoMessage::VideoSummary,
+            2 => VideoMessage::Video,
+            _ => VideoMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for VideoMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((VideoMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_video_message_type() {
+        // Create message
+        let mut message_type = VideoMessageType::default();
+        message_type.set_message_type(VideoMessage::Video);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x02]);
+
+        // New message
+        let (object, length) = VideoMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, VideoMessageType::MESSAGE_LENGTH);
+        assert_eq!(object.get_message_type(), VideoMessage::Video);
+    }
+}
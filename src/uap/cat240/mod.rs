@@ -0,0 +1,7 @@
+pub mod message_type_field;
+pub mod record_header_field;
+pub mod video_block_field;
+pub mod video_counters_field;
+pub mod video_header_field;
+pub mod video_resolution_field;
+pub mod video_summary_field;
@@ -0,0 +1,72 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Video Record Header
+//
+// | 32 .. 1   | bit
+// | MSG_INDEX |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VideoRecordHeader {
+    index: [u8; 4], // 4 bytes
+}
+
+/*
+* Implementation VideoRecordHeader
+*/
+impl VideoRecordHeader {
+    /*
+     * Set message index, incremented for each video message
+     */
+    pub fn set_index(&mut self, index: u32) {
+        self.index = index.to_be_bytes();
+    }
+
+    /*
+     * Get message index
+     */
+    pub fn get_index(&self) -> u32 {
+        u32::from_be_bytes(self.index)
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for VideoRecordHeader {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let index = fixed_array(array)?;
+        Ok((VideoRecordHeader { index }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.index.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_video_record_header() {
+        // Create message
+        let mut record_header = VideoRecordHeader::default();
+        record_header.set_index(70000);
+
+        // Convert struct to byte stream
+        let array = record_header.encode();
+
+        assert_eq!(array, vec![0x00, 0x01, 0x11, 0x70]);
+
+        // New message
+        let (object, length) = VideoRecordHeader::decode(&array).unwrap();
+
+        assert_eq!(length, VideoRecordHeader::MESSAGE_LENGTH);
+        assert_eq!(object.get_index(), 70000);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Video Block Low, Medium and High Data Volume
+//
+// Repetition factor followed by video octets in blocks of 4, 64 or 256 bytes
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct VideoBlock<const SIZE: usize> {
+    data: Vec<u8>, // SIZE bytes each repetition
+}
+
+/// I240/050 with blocks of 4 bytes
+pub type VideoBlockLow = VideoBlock<4>;
+/// I240/051 with blocks of 64 bytes
+pub type VideoBlockMedium = VideoBlock<64>;
+/// I240/052 with blocks of 256 bytes
+pub type VideoBlockHigh = VideoBlock<256>;
+
+/*
+* Implementation VideoBlock
+*/
+impl<const SIZE: usize> VideoBlock<SIZE> {
+    /*
+     * Set video octets, padded with zeros to a multiple of the block size
+     * At most 255 blocks
+     */
+    pub fn set_data(&mut self, data: &[u8]) -> Result<(), CatError> {
+        let repetition = data.len().div_ceil(SIZE);
+
+        if repetition > u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.data = data.to_vec();
+        self.data.resize(repetition * SIZE, 0);
+        Ok(())
+    }
+
+    /*
+     * Get video octets including padding
+     */
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /*
+     * Maximum number of video octets.
+     */
+    pub const DATA_MAX: usize = u8::MAX as usize * SIZE;
+}
+
+impl<const SIZE: usize> DataItem for VideoBlock<SIZE> {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * SIZE;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let data = array[1..length].to_vec();
+
+        Ok((VideoBlock { data }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![(self.data.len() / SIZE) as u8];
+        vector.extend_from_slice(&self.data);
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_video_block() {
+        // Create message
+        let mut video_block = VideoBlockLow::default();
+        video_block
+            .set_data(&[0x12, 0x34, 0x56, 0x78, 0x9a])
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = video_block.encode();
+
+        assert_eq!(
+            array,
+            vec![0x02, 0x12, 0x34, 0x56, 0x78, 0x9a, 0x00, 0x00, 0x00]
+        );
+
+        // New message
+        let (object, length) = VideoBlockLow::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_data(), &array[1..]);
+        assert_eq!(
+            video_block.set_data(&[0; VideoBlockLow::DATA_MAX + 1]),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            VideoBlockMedium::decode(&[0x01, 0x00]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
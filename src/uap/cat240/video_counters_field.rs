@@ -0,0 +1,112 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Video Octets & Video Cells Counters
+//
+// | 40 .. 25 | 24 .. 1  | bit
+// |  NB_VB   | NB_CELLS |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VideoCounters {
+    octets: [u8; 2], // 2 bytes
+    cells: [u8; 3],  // 3 bytes
+}
+
+/*
+* Implementation VideoCounters
+*/
+impl VideoCounters {
+    /*
+     * Set number of valid octets in the video blocks
+     */
+    pub fn set_octets(&mut self, octets: u16) {
+        self.octets = octets.to_be_bytes();
+    }
+
+    /*
+     * Get number of valid octets in the video blocks
+     */
+    pub fn get_octets(&self) -> u16 {
+        u16::from_be_bytes(self.octets)
+    }
+
+    /*
+     * Set number of valid cells in the video blocks
+     * 0 <= cells <= 16777215
+     */
+    pub fn set_cells(&mut self, cells: u32) -> Result<(), CatError> {
+        if cells > Self::CELLS_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.cells.copy_from_slice(&cells.to_be_bytes()[1..4]);
+        Ok(())
+    }
+
+    /*
+     * Get number of valid cells in the video blocks
+     */
+    pub fn get_cells(&self) -> u32 {
+        let [high, middle, low] = self.cells;
+        u32::from_be_bytes([0, high, middle, low])
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Maximum number of cells in 24 bits.
+     */
+    const CELLS_MAX: u32 = 0x00ff_ffff;
+}
+
+impl DataItem for VideoCounters {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [o0, o1, c0, c1, c2] = fixed_array(array)?;
+        Ok((
+            VideoCounters {
+                octets: [o0, o1],
+                cells: [c0, c1, c2],
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.octets.to_vec();
+        vector.extend_from_slice(&self.cells);
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_video_counters() {
+        // Create message
+        let mut video_counters = VideoCounters::default();
+        video_counters.set_octets(1000);
+        video_counters.set_cells(2000).unwrap();
+
+        // Convert struct to byte stream
+        let array = video_counters.encode();
+
+        assert_eq!(array, vec![0x03, 0xe8, 0x00, 0x07, 0xd0]);
+
+        // New message
+        let (object, length) = VideoCounters::decode(&array).unwrap();
+
+        assert_eq!(length, VideoCounters::MESSAGE_LENGTH);
+        assert_eq!(object.get_octets(), 1000);
+        assert_eq!(object.get_cells(), 2000);
+        assert_eq!(
+            video_counters.set_cells(0x0100_0000),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Video Header Nano and Video Header Femto
+//
+// | 96 .. 81 | 80 .. 65 | 64 .. 33 | 32 .. 1  | bit
+// | START_AZ |  END_AZ  | START_RG | CELL_DUR |
+//
+// Cell duration is in ns for I240/040 and in fs for I240/041
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VideoHeader {
+    start_azimuth: [u8; 2], // 2 bytes
+    end_azimuth: [u8; 2],   // 2 bytes
+    start_range: [u8; 4],   // 4 bytes
+    cell_duration: [u8; 4], // 4 bytes
+}
+
+/*
+* Implementation VideoHeader
+*/
+impl VideoHeader {
+    /*
+     * Set start azimuth of the cells in degree
+     * 0 <= azimuth < 360
+     */
+    pub fn set_start_azimuth(&mut self, azimuth: f64) -> Result<(), CatError> {
+        self.start_azimuth = Self::azimuth_bytes(azimuth)?;
+        Ok(())
+    }
+
+    /*
+     * Get start azimuth of the cells in degree
+     */
+    pub fn get_start_azimuth(&self) -> f64 {
+        u16::from_be_bytes(self.start_azimuth) as f64 * Self::AZIMUTH_FACTOR
+    }
+
+    /*
+     * Set end azimuth of the cells in degree
+     * 0 <= azimuth < 360
+     */
+    pub fn set_end_azimuth(&mut self, azimuth: f64) -> Result<(), CatError> {
+        self.end_azimuth = Self::azimuth_bytes(azimuth)?;
+        Ok(())
+    }
+
+    /*
+     * Get end azimuth of the cells in degree
+     */
+    pub fn get_end_azimuth(&self) -> f64 {
+        u16::from_be_bytes(self.end_azimuth) as f64 * Self::AZIMUTH_FACTOR
+    }
+
+    /*
+     * Set range of the first cell in number of cells
+     */
+    pub fn set_start_range(&mut self, range: u32) {
+        self.start_range = range.to_be_bytes();
+    }
+
+    /*
+     * Get range of the first cell in number of cells
+     */
+    pub fn get_start_range(&self) -> u32 {
+        u32::from_be_bytes(self.start_range)
+    }
+
+    /*
+     * Set duration of one cell in ns (nano) or fs (femto)
+     */
+    pub fn set_cell_duration(&mut self, duration: u32) {
+        self.cell_duration = duration.to_be_bytes();
+    }
+
+    /*
+     * Get duration of one cell in ns (nano) or fs (femto)
+     */
+    pub fn get_cell_duration(&self) -> u32 {
+        u32::from_be_bytes(self.cell_duration)
+    }
+
+    /*
+     * Convert azimuth in degree to two bytes.
+     */
+    fn azimuth_bytes(azimuth: f64) -> Result<[u8; 2], CatError> {
+        if !(0.0..360.0).contains(&azimuth) {
+            return Err(CatError::ValueInvalid);
+        }
+        let value = (azimuth / Self::AZIMUTH_FACTOR).round() as u32 & 0xffff;
+        Ok((value as u16).to_be_bytes())
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of azimuth.
+     */
+    const AZIMUTH_FACTOR: f64 = 360.0 / 65536.0;
+}
+
+impl DataItem for VideoHeader {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [a0, a1, b0, b1, r0, r1, r2, r3, d0, d1, d2, d3] = fixed_array(array)?;
+        Ok((
+            VideoHeader {
+                start_azimuth: [a0, a1],
+                end_azimuth: [b0, b1],
+                start_range: [r0, r1, r2, r3],
+                cell_duration: [d0, d1, d2, d3],
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = Vec::with_capacity(Self::MESSAGE_LENGTH);
+        vector.extend_from_slice(&self.start_azimuth);
+        vector.extend_from_slice(&self.end_azimuth);
+        vector.extend_from_slice(&self.start_range);
+        vector.extend_from_slice(&self.cell_duration);
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_video_header() {
+        // Create message
+        let mut video_header = VideoHeader::default();
+        video_header.set_start_azimuth(90.0).unwrap();
+        video_header.set_end_azimuth(91.0546875).unwrap();
+        video_header.set_start_range(16);
+        video_header.set_cell_duration(416);
+
+        // Convert struct to byte stream
+        let array = video_header.encode();
+
+        assert_eq!(
+            array,
+            vec![0x40, 0x00, 0x40, 0xc0, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x01, 0xa0]
+        );
+
+        // New message
+        let (object, length) = VideoHeader::decode(&array).unwrap();
+
+        assert_eq!(length, VideoHeader::MESSAGE_LENGTH);
+        assert_eq!(object.get_start_azimuth(), 90.0);
+        assert_eq!(object.get_end_azimuth(), 91.0546875);
+        assert_eq!(object.get_start_range(), 16);
+        assert_eq!(object.get_cell_duration(), 416);
+        assert_eq!(
+            video_header.set_start_azimuth(360.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Video Cells Resolution & Data Compression Indicator
+//
+// | 16 | 15 .. 9 | 8 .. 1 | bit
+// | C  |  spare  |  RES   |
+//
+
+/// Resolution of the video cells
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum CellResolution {
+    Unknown,
+    Monobit,
+    Low,
+    Medium,
+    High,
+    VeryHigh,
+    UltraHigh,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VideoResolution {
+    compression: u8, // 1 byte
+    resolution: u8,  // 1 byte
+}
+
+/*
+* Implementation VideoResolution
+*/
+impl VideoResolution {
+    /*
+     * Set compression of the video blocks, zlib compressed when set
+     */
+    pub fn set_compression(&mut self, value: bool) {
+        self.compression = (value as u8) << 7;
+    }
+
+    /*
+     * Get compression of the video blocks, zlib compressed when set
+     */
+    pub fn get_compression(&self) -> bool {
+        self.compression & 0x80 != 0
+    }
+
+    /*
+     * Set resolution of the video cells
+     */
+    pub fn set_resolution(&mut self, resolution: CellResolution) {
+        self.resolution = resolution as u8;
+    }
+
+    /*
+     * Get resolution of the video cells
+     */
+    pub fn get_resolution(&self) -> CellResolution {
+        match self.resolution {
+            1 => CellResolution::Monobit,
+            2 => CellResolution::Low,
+            3 => CellResolution::Medium,
+            4 => CellResolution::High,
+            5 => CellResolution::VeryHigh,
+            6 => CellResolution::UltraHigh,
+            _ => CellResolution::Unknown,
+        }
+    }
+
+    /*
+     * Get number of bits of one video cell
+     * 1, 2, 4, 8, 16 or 32 bits
+     */
+    pub fn get_cell_bits(&self) -> Result<u32, CatError> {
+        match self.get_resolution() {
+            CellResolution::Unknown => Err(CatError::ValueInvalid),
+            _ => Ok(1 << (self.resolution - 1)),
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for VideoResolution {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [compression, resolution] = fixed_array(array)?;
+        Ok((
+            VideoResolution {
+                compression,
+                resolution,
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.compression, self.resolution]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_video_resolution() {
+        // Create message
+        let mut video_resolution = VideoResolution::default();
+        video_resolution.set_compression(true);
+        video_resolution.set_resolution(CellResolution::Medium);
+
+        // Convert struct to byte stream
+        let array = video_resolution.encode();
+
+        assert_eq!(array, vec![0x80, 0x03]);
+
+        // New message
+        let (object, length) = VideoResolution::decode(&array).unwrap();
+
+        assert_eq!(length, VideoResolution::MESSAGE_LENGTH);
+        assert!(object.get_compression());
+        assert_eq!(object.get_resolution(), CellResolution::Medium);
+        assert_eq!(object.get_cell_bits(), Ok(4));
+        assert_eq!(
+            VideoResolution::default().get_cell_bits(),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
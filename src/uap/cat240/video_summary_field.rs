@@ -0,0 +1,82 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Video Summary
+//
+// Repetition factor followed by ASCII characters
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct VideoSummary {
+    summary: Vec<u8>, // 1 byte each
+}
+
+/*
+* Implementation VideoSummary
+*/
+impl VideoSummary {
+    /*
+     * Set summary text
+     * At most 255 ASCII characters
+     */
+    pub fn set_summary(&mut self, summary: &str) -> Result<(), CatError> {
+        if !summary.is_ascii() || summary.len() > u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.summary = summary.as_bytes().to_vec();
+        Ok(())
+    }
+
+    /*
+     * Get summary text
+     */
+    pub fn get_summary(&self) -> String {
+        String::from_utf8_lossy(&self.summary).into_owned()
+    }
+}
+
+impl DataItem for VideoSummary {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let summary = array[1..length].to_vec();
+
+        Ok((VideoSummary { summary }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.summary.len() as u8];
+        vector.extend_from_slice(&self.summary);
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_video_summary() {
+        // Create message
+        let mut video_summary = VideoSummary::default();
+        video_summary.set_summary("PSR1").unwrap();
+
+        // Convert struct to byte stream
+        let array = video_summary.encode();
+
+        assert_eq!(array, vec![0x04, 0x50, 0x53, 0x52, 0x31]);
+
+        // New message
+        let (object, length) = VideoSummary::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_summary(), "PSR1");
+        assert_eq!(
+            video_summary.set_summary("Radar \u{b0}"),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
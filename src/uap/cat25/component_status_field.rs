@@ -0,0 +1,146 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Component Status
+//
+// Repetition factor followed by the component states
+// | 24 .. 9 | 8 .. 3  | 2 1 | bit
+// |   CID   | ERRCODE | CS  |
+//
+
+/// State of a ground system component
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ComponentState {
+    Running,
+    Failed,
+    Degraded,
+    Undefined,
+}
+
+/// Status of one ground system component
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Component {
+    pub identification: u16,
+    /// Error code in 6 bits
+    pub error_code: u8,
+    pub state: ComponentState,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ComponentStatus {
+    components: Vec<[u8; 3]>, // 3 bytes each
+}
+
+/*
+* Implementation ComponentStatus
+*/
+impl ComponentStatus {
+    /*
+     * Insert component status
+     * 0 <= error_code <= 63
+     */
+    pub fn insert_component(&mut self, component: Component) -> Result<(), CatError> {
+        if component.error_code > Self::ERROR_CODE_MAX || self.components.len() >= u8::MAX as usize
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        let [high, low] = component.identification.to_be_bytes();
+        self.components
+            .push([high, low, component.error_code << 2 | component.state as u8]);
+        Ok(())
+    }
+
+    /*
+     * Get all component states
+     */
+    pub fn get_components(&self) -> Vec<Component> {
+        self.components
+            .iter()
+            .map(|value| Component {
+                identification: u16::from_be_bytes([value[0], value[1]]),
+                error_code: value[2] >> 2,
+                state: match value[2] & 0x03 {
+                    0 => ComponentState::Running,
+                    1 => ComponentState::Failed,
+                    2 => ComponentState::Degraded,
+                    _ => ComponentState::Undefined,
+                },
+            })
+            .collect()
+    }
+
+    /*
+     * Length of one component status.
+     */
+    const COMPONENT_LENGTH: usize = 3;
+
+    /*
+     * Maximum error code in 6 bits.
+     */
+    const ERROR_CODE_MAX: u8 = 0x3f;
+}
+
+impl DataItem for ComponentStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::COMPONENT_LENGTH;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let components = array[1..length]
+            .chunks_exact(Self::COMPONENT_LENGTH)
+            .map(|chunk| [chunk[0], chunk[1], chunk[2]])
+            .collect();
+
+        Ok((ComponentStatus { components }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.components.len() as u8];
+
+        for component in self.components.iter() {
+            vector.extend_from_slice(component);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_component_status() {
+        // Create message
+        let component = Component {
+            identification: 0x1234,
+            error_code: 5,
+            state: ComponentState::Degraded,
+        };
+        let mut component_status = ComponentStatus::default();
+        component_status.insert_component(component).unwrap();
+
+        // Convert struct to byte stream
+        let array = component_status.encode();
+
+        assert_eq!(array, vec![0x01, 0x12, 0x34, 0x16]);
+
+        // New message
+        let (object, length) = ComponentStatus::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_components(), vec![component]);
+        assert_eq!(
+            component_status.insert_component(Component {
+                error_code: 64,
+                ..component
+            }),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            ComponentStatus::decode(&[0x01, 0x12, 0x34]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// System and Service Error Codes
+//
+// Repetition factor followed by the error codes
+// | 8 .. 1 | bit
+// |  ERR   |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ErrorCodes {
+    codes: Vec<u8>, // 1 byte each
+}
+
+/*
+* Implementation ErrorCodes
+*/
+impl ErrorCodes {
+    /*
+     * Insert system or service error code
+     */
+    pub fn insert_code(&mut self, code: u8) -> Result<(), CatError> {
+        if self.codes.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.codes.push(code);
+        Ok(())
+    }
+
+    /*
+     * Get all system and service error codes
+     */
+    pub fn get_codes(&self) -> &[u8] {
+        &self.codes
+    }
+}
+
+impl DataItem for ErrorCodes {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition;
+
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let codes = array[1..length].to_vec();
+
+        Ok((ErrorCodes { codes }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.codes.len() as u8];
+        vector.extend_from_slice(&self.codes);
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_error_codes() {
+        // Create message
+        let mut error_codes = ErrorCodes::default();
+        error_codes.insert_code(3).unwrap();
+        error_codes.insert_code(42).unwrap();
+
+        // Convert struct to byte stream
+        let array = error_codes.encode();
+
+        assert_eq!(array, vec![0x02, 0x03, 0x2a]);
+
+        // New message
+        let (object, length) = ErrorCodes::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_codes(), &[3, 42]);
+        assert_eq!(
+            ErrorCodes::decode(&[0x02, 0x03]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
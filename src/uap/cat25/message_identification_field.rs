@@ -0,0 +1,91 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Message Identification
+//
+// | 24 .. 1 | bit
+// |   MID   |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct MessageIdentification {
+    identification: [u8; 3], // 3 bytes
+}
+
+/*
+* Implementation MessageIdentification
+*/
+impl MessageIdentification {
+    /*
+     * Set message sequence identification
+     * 0 <= identification <= 16777215
+     */
+    pub fn set_identification(&mut self, identification: u32) -> Result<(), CatError> {
+        if identification > Self::IDENTIFICATION_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.identification
+            .copy_from_slice(&identification.to_be_bytes()[1..4]);
+        Ok(())
+    }
+
+    /*
+     * Get message sequence identification
+     */
+    pub fn get_identification(&self) -> u32 {
+        let [high, middle, low] = self.identification;
+        u32::from_be_bytes([0, high, middle, low])
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Maximum identification in 24 bits.
+     */
+    const IDENTIFICATION_MAX: u32 = 0x00ff_ffff;
+}
+
+impl DataItem for MessageIdentification {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let identification = fixed_array(array)?;
+        Ok((
+            MessageIdentification { identification },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.identification.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_message_identification() {
+        // Create message
+        let mut message_identification = MessageIdentification::default();
+        message_identification.set_identification(70000).unwrap();
+
+        // Convert struct to byte stream
+        let array = message_identification.encode();
+
+        assert_eq!(array, vec![0x01, 0x11, 0x70]);
+
+        // New message
+        let (object, length) = MessageIdentification::decode(&array).unwrap();
+
+        assert_eq!(length, MessageIdentification::MESSAGE_LENGTH);
+        assert_eq!(object.get_identification(), 70000);
+        assert_eq!(
+            message_identification.set_identification(0x0100_0000),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod component_status_field;
+pub mod error_codes_field;
+pub mod message_identification_field;
+pub mod report_type_field;
+pub mod service_designator_field;
+pub mod system_status_field;
@@ -0,0 +1,103 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, DataItem};
+
+// Report Type
+//
+// | 8 .. 2 | 1  | bit
+// |   RT   | RG |
+//
+
+/// Types of ground system reports
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SystemReport {
+    Unknown,
+    ServiceAndSystemStatus,
+    ComponentStatus,
+    ServiceStatistics,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ReportType {
+    report: u8, // 1 byte
+}
+
+/*
+* Implementation ReportType
+*/
+impl ReportType {
+    /*
+     * Set report type
+     */
+    pub fn set_report_type(&mut self, report_type: SystemReport) {
+        self.report = (self.report & 0x01) | ((report_type as u8) << 1);
+    }
+
+    /*
+     * Get report type
+     */
+    pub fn get_report_type(&self) -> SystemReport {
+        match get_bits(self.report as u32, 8, 7) {
+            1 => SystemReport::ServiceAndSystemStatus,
+            2 => SystemReport::ComponentStatus,
+            3 => SystemReport::ServiceStatistics,
+            _ => SystemReport::Unknown,
+        }
+    }
+
+    /*
+     * Set report generation, event-driven when set, otherwise periodic
+     */
+    pub fn set_event_driven(&mut self, value: bool) {
+        self.report = (self.report & 0xfe) | value as u8;
+    }
+
+    /*
+     * Get report generation, event-driven when set, otherwise periodic
+     */
+    pub fn get_event_driven(&self) -> bool {
+        self.report & 0x01 != 0
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ReportType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [report] = fixed_array(array)?;
+        Ok((ReportType { report }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.report]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_report_type() {
+        // Create message
+        let mut report_type = ReportType::default();
+        report_type.set_report_type(SystemReport::ComponentStatus);
+        report_type.set_event_driven(true);
+
+        // Convert struct to byte stream
+        let array = report_type.encode();
+
+        assert_eq!(array, vec![0x05]);
+
+        // New message
+        let (object, length) = ReportType::decode(&array).unwrap();
+
+        assert_eq!(length, ReportType::MESSAGE_LENGTH);
+        assert_eq!(object.get_report_type(), SystemReport::ComponentStatus);
+        assert!(object.get_event_driven());
+    }
+}
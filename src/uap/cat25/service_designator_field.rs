@@ -0,0 +1,77 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::data_item::DataItem;
+
+// Service Designator
+//
+// Eight characters with 6 bit International Alphabet No. 5 coding
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ServiceDesignator {
+    designator: AircraftIdentification, // 6 bytes
+}
+
+/*
+* Implementation ServiceDesignator
+*/
+impl ServiceDesignator {
+    /*
+     * Set service designator, padded with spaces to eight characters
+     * Characters A..Z, 0..9 and space
+     */
+    pub fn set_designator(&mut self, designator: &str) -> Result<(), CatError> {
+        self.designator.set_identification(designator)
+    }
+
+    /*
+     * Get service designator without trailing spaces
+     */
+    pub fn get_designator(&self) -> String {
+        self.designator.get_identification()
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ServiceDesignator {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (designator, length) = AircraftIdentification::decode(array)?;
+        Ok((ServiceDesignator { designator }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.designator.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_designator() {
+        // Create message
+        let mut service_designator = ServiceDesignator::default();
+        service_designator.set_designator("ADSB").unwrap();
+
+        // Convert struct to byte stream
+        let array = service_designator.encode();
+
+        assert_eq!(array, vec![0x04, 0x44, 0xc2, 0x82, 0x08, 0x20]);
+
+        // New message
+        let (object, length) = ServiceDesignator::decode(&array).unwrap();
+
+        assert_eq!(length, ServiceDesignator::MESSAGE_LENGTH);
+        assert_eq!(object.get_designator(), "ADSB");
+        assert_eq!(
+            service_designator.set_designator("SERVICE01"),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// System and Service Status
+//
+// | 8 7  |  6  | 5 .. 2 | 1  | bit
+// | NOGO | OPS | SSTAT  | FX |
+//
+
+/// Operational release status of the data
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ReleaseStatus {
+    Operational,
+    Degraded,
+    NoGo,
+    Undefined,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SystemStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation SystemStatus
+*/
+impl SystemStatus {
+    /*
+     * Set operational release status of the data
+     */
+    pub fn set_release_status(&mut self, status: ReleaseStatus) {
+        self.field.set_bits(0, 8, 2, status as u8);
+    }
+
+    /*
+     * Get operational release status of the data
+     */
+    pub fn get_release_status(&self) -> ReleaseStatus {
+        match self.field.get_bits(0, 8, 2) {
+            0 => ReleaseStatus::Operational,
+            1 => ReleaseStatus::Degraded,
+            2 => ReleaseStatus::NoGo,
+            _ => ReleaseStatus::Undefined,
+        }
+    }
+
+    /*
+     * Set system in test or maintenance, otherwise in operational use
+     */
+    pub fn set_maintenance(&mut self, value: bool) {
+        self.field.set_bit(0, 6, value);
+    }
+
+    /*
+     * Get system in test or maintenance, otherwise in operational use
+     */
+    pub fn get_maintenance(&self) -> bool {
+        self.field.get_bit(0, 6)
+    }
+
+    /*
+     * Set service specific status
+     * 0 <= status <= 15
+     */
+    pub fn set_service_status(&mut self, status: u8) -> Result<(), CatError> {
+        if status > Self::STATUS_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(0, 5, 4, status);
+        Ok(())
+    }
+
+    /*
+     * Get service specific status
+     */
+    pub fn get_service_status(&self) -> u8 {
+        self.field.get_bits(0, 5, 4)
+    }
+
+    /*
+     * Maximum service status in 4 bits.
+     */
+    const STATUS_MAX: u8 = 0x0f;
+}
+
+impl DataItem for SystemStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((SystemStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_system_status() {
+        // Create message
+        let mut system_status = SystemStatus::default();
+        system_status.set_release_status(ReleaseStatus::Degraded);
+        system_status.set_service_status(5).unwrap();
+
+        // Convert struct to byte stream
+        let array = system_status.encode();
+
+        assert_eq!(array, vec![0x4a]);
+
+        // New message
+        let (object, length) = SystemStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 1);
+        assert_eq!(object.get_release_status(), ReleaseStatus::Degraded);
+        assert!(!object.get_maintenance());
+        assert_eq!(object.get_service_status(), 5);
+        assert_eq!(
+            system_status.set_service_status(16),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Code Confidence Indicator, used for Mode-3/A and Mode-2 Code
+//
+// | 16 .. 13 | 12 .. 1            | bit
+// |  spare   | QA4 QA2 .. QD2 QD1 |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CodeConfidence {
+    confidence: u16, // 2 bytes
+}
+
+/*
+* Implementation CodeConfidence
+*/
+impl CodeConfidence {
+    /*
+     * Set low quality pulses, one bit per code pulse
+     * 0 <= confidence <= 0xfff
+     */
+    pub fn set_confidence(&mut self, confidence: u16) -> Result<(), CatError> {
+        if confidence > Self::CONFIDENCE_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.confidence = confidence;
+        Ok(())
+    }
+
+    /*
+     * Get low quality pulses, one bit per code pulse
+     */
+    pub fn get_confidence(&self) -> u16 {
+        self.confidence & Self::CONFIDENCE_MAX
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Maximum confidence value in 12 bits.
+     */
+    const CONFIDENCE_MAX: u16 = 0xfff;
+}
+
+impl DataItem for CodeConfidence {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let confidence = u16::from_be_bytes(fixed_array(array)?);
+        Ok((CodeConfidence { confidence }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.confidence.to_be_bytes().to_vec()
+    }
+}
+
+// Mode-1 Code Confidence Indicator
+//
+// | 8 7 6 | 5 .. 1              | bit
+// | spare | QA4 QA2 QA1 QB2 QB1 |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct Mode1CodeConfidence {
+    confidence: u8, // 1 byte
+}
+
+/*
+* Implementation Mode1CodeConfidence
+*/
+impl Mode1CodeConfidence {
+    /*
+     * Set low quality pulses, one bit per code pulse
+     * 0 <= confidence <= 0x1f
+     */
+    pub fn set_confidence(&mut self, confidence: u8) -> Result<(), CatError> {
+        if confidence > Self::CONFIDENCE_MAX {
+            return Err(CatError::ValueInvalid);
+        }
+        self.confidence = confidence;
+        Ok(())
+    }
+
+    /*
+     * Get low quality pulses, one bit per code pulse
+     */
+    pub fn get_confidence(&self) -> u8 {
+        self.confidence & Self::CONFIDENCE_MAX
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Maximum confidence value in 5 bits.
+     */
+    const CONFIDENCE_MAX: u8 = 0x1f;
+}
+
+impl DataItem for Mode1CodeConfidence {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [confidence] = fixed_array(array)?;
+        Ok((Mode1CodeConfidence { confidence }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.confidence]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_code_confidence() {
+        // Create message
+        let mut code_confidence = CodeConfidence::default();
+        code_confidence.set_confidence(0x801).unwrap();
+        let mut mode_1_confidence = Mode1CodeConfidence::default();
+        mode_1_confidence.set_confidence(0x11).unwrap();
+
+        // Convert struct to byte stream
+        let array = code_confidence.encode();
+
+        assert_eq!(array, vec![0x08, 0x01]);
+        assert_eq!(mode_1_confidence.encode(), vec![0x11]);
+
+        // New message
+        let (object, _length) = CodeConfidence::decode(&array).unwrap();
+
+        assert_eq!(object.get_confidence(), 0x801);
+        assert_eq!(
+            code_confidence.set_confidence(0x1000),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            mode_1_confidence.set_confidence(0x20),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Communications/ACAS Capability and Flight Status
+//
+// | 16 15 14 | 13 12 11 | 10 |   9   |  8   |  7  |  6  |  5 .. 1  | bit
+// |   COM    |   STAT   | SI | spare | MSSC | ARC | AIC | B1A B1B  |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CommunicationsCapability {
+    capability: u16, // 2 bytes
+}
+
+/*
+* Implementation CommunicationsCapability
+*/
+impl CommunicationsCapability {
+    /*
+     * Set communications capability of the transponder
+     * 0 <= com <= 7
+     */
+    pub fn set_com(&mut self, com: u8) -> Result<(), CatError> {
+        self.set_field(16, 3, com)
+    }
+
+    /*
+     * Get communications capability of the transponder
+     */
+    pub fn get_com(&self) -> u8 {
+        get_bits(self.capability as u32, 16, 3) as u8
+    }
+
+    /*
+     * Set flight status
+     * 0 <= stat <= 7
+     */
+    pub fn set_stat(&mut self, stat: u8) -> Result<(), CatError> {
+        self.set_field(13, 3, stat)
+    }
+
+    /*
+     * Get flight status
+     */
+    pub fn get_stat(&self) -> u8 {
+        get_bits(self.capability as u32, 13, 3) as u8
+    }
+
+    /*
+     * Set II-code capable, SI-code capable otherwise
+     */
+    pub fn set_si(&mut self, value: bool) {
+        self.capability = set_bits(self.capability as u32, 10, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get II-code capable, SI-code capable otherwise
+     */
+    pub fn get_si(&self) -> bool {
+        get_bits(self.capability as u32, 10, 1) == 1
+    }
+
+    /*
+     * Set Mode-S specific service capability
+     */
+    pub fn set_mssc(&mut self, value: bool) {
+        self.capability = set_bits(self.capability as u32, 8, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get Mode-S specific service capability
+     */
+    pub fn get_mssc(&self) -> bool {
+        get_bits(self.capability as u32, 8, 1) == 1
+    }
+
+    /*
+     * Set altitude reporting capability in 25 ft, 100 ft otherwise
+     */
+    pub fn set_arc(&mut self, value: bool) {
+        self.capability = set_bits(self.capability as u32, 7, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get altitude reporting capability in 25 ft, 100 ft otherwise
+     */
+    pub fn get_arc(&self) -> bool {
+        get_bits(self.capability as u32, 7, 1) == 1
+    }
+
+    /*
+     * Set aircraft identification capability
+     */
+    pub fn set_aic(&mut self, value: bool) {
+        self.capability = set_bits(self.capability as u32, 6, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get aircraft identification capability
+     */
+    pub fn get_aic(&self) -> bool {
+        get_bits(self.capability as u32, 6, 1) == 1
+    }
+
+    /*
+     * Set BDS 1,0 bit 16
+     */
+    pub fn set_b1a(&mut self, value: bool) {
+        self.capability = set_bits(self.capability as u32, 5, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get BDS 1,0 bit 16
+     */
+    pub fn get_b1a(&self) -> bool {
+        get_bits(self.capability as u32, 5, 1) == 1
+    }
+
+    /*
+     * Set BDS 1,0 bits 37 to 40
+     * 0 <= b1b <= 15
+     */
+    pub fn set_b1b(&mut self, b1b: u8) -> Result<(), CatError> {
+        self.set_field(4, 4, b1b)
+    }
+
+    /*
+     * Get BDS 1,0 bits 37 to 40
+     */
+    pub fn get_b1b(&self) -> u8 {
+        get_bits(self.capability as u32, 4, 4) as u8
+    }
+
+    /*
+     * Set bit field, checks the value fits into the width
+     */
+    fn set_field(&mut self, bit: u8, width: u8, value: u8) -> Result<(), CatError> {
+        if value as u32 >= 0x1 << width {
+            return Err(CatError::ValueInvalid);
+        }
+        self.capability = set_bits(self.capability as u32, bit, width, value as u32) as u16;
+        Ok(())
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for CommunicationsCapability {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let capability = u16::from_be_bytes(fixed_array(array)?);
+        Ok((
+            CommunicationsCapability { capability },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.capability.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_communications_capability() {
+        // Create message
+        let mut communications_capability = CommunicationsCapability::default();
+        communications_capability.set_com(1).unwrap();
+        communications_capability.set_stat(5).unwrap();
+        communications_capability.set_arc(true);
+        communications_capability.set_b1b(0xa).unwrap();
+
+        // Convert struct to byte stream
+        let array = communications_capability.encode();
+
+        assert_eq!(array, vec![0x34, 0x4a]);
+
+        // New message
+        let (object, _length) = CommunicationsCapability::decode(&array).unwrap();
+
+        assert_eq!(object.get_com(), 1);
+        assert_eq!(object.get_stat(), 5);
+        assert!(object.get_arc());
+        assert!(!object.get_mssc());
+        assert_eq!(object.get_b1b(), 0xa);
+        assert_eq!(
+            communications_capability.set_com(8),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
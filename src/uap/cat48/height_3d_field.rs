@@ -0,0 +1,79 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, to_signed, DataItem};
+
+// Height Measured by a 3D Radar
+//
+// | 16 15 | 14 .. 1        | bit
+// | spare | 3D height 25 ft |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct Height3D {
+    height: u16, // 2 bytes
+}
+
+/*
+* Implementation Height3D
+*/
+impl Height3D {
+    /*
+     * Set height in ft
+     * -204800 <= height < 204800
+     */
+    pub fn set_height(&mut self, height: f32) -> Result<(), CatError> {
+        self.height = from_signed(height as f64, Self::FACTOR as f64, 14)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get height in ft
+     */
+    pub fn get_height(&self) -> f32 {
+        to_signed(self.height as u32, 14) as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 25.0;
+}
+
+impl DataItem for Height3D {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let height = u16::from_be_bytes(fixed_array(array)?);
+        Ok((Height3D { height }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.height.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_height_3d() {
+        // Create message
+        let mut height_3d = Height3D::default();
+        height_3d.set_height(-100.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = height_3d.encode();
+
+        assert_eq!(array, vec![0x3f, 0xfc]);
+
+        // New message
+        let (object, _length) = Height3D::decode(&array).unwrap();
+
+        assert_eq!(object.get_height(), -100.0);
+        assert_eq!(height_3d.set_height(204800.0), Err(CatError::ValueInvalid));
+    }
+}
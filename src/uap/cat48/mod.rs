@@ -0,0 +1,13 @@
+pub mod cartesian_position_field;
+pub mod code_confidence_field;
+pub mod communications_capability_field;
+pub mod height_3d_field;
+pub mod mode_1_code_field;
+pub mod mode_c_code_field;
+pub mod plot_characteristics_field;
+pub mod polar_velocity_field;
+pub mod radial_doppler_speed_field;
+pub mod target_report_descriptor_field;
+pub mod track_quality_field;
+pub mod track_status_field;
+pub mod warning_error_field;
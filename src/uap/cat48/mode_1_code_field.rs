@@ -0,0 +1,122 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Mode-1 Code in Octal Representation
+//
+// | 8 | 7 | 6 | 5 .. 1             | bit
+// | V | G | L | A4 A2 A1 B2 B1     |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct Mode1Code {
+    code: u8, // 1 byte
+}
+
+/*
+* Implementation Mode1Code
+*/
+impl Mode1Code {
+    /*
+     * Set code, octal digit A and two bits of B
+     * 0 <= code <= 0x1f
+     */
+    pub fn set_code(&mut self, code: u8) -> Result<(), CatError> {
+        if code > 0x1f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.code = set_bits(self.code as u32, 5, 5, code as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get code, octal digit A and two bits of B
+     */
+    pub fn get_code(&self) -> u8 {
+        get_bits(self.code as u32, 5, 5) as u8
+    }
+
+    /*
+     * Set code not validated
+     */
+    pub fn set_not_validated(&mut self, value: bool) {
+        self.code = set_bits(self.code as u32, 8, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get code not validated
+     */
+    pub fn get_not_validated(&self) -> bool {
+        get_bits(self.code as u32, 8, 1) == 1
+    }
+
+    /*
+     * Set garbled code
+     */
+    pub fn set_garbled(&mut self, value: bool) {
+        self.code = set_bits(self.code as u32, 7, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get garbled code
+     */
+    pub fn get_garbled(&self) -> bool {
+        get_bits(self.code as u32, 7, 1) == 1
+    }
+
+    /*
+     * Set code not extracted during the last scan
+     */
+    pub fn set_not_extracted(&mut self, value: bool) {
+        self.code = set_bits(self.code as u32, 6, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get code not extracted during the last scan
+     */
+    pub fn get_not_extracted(&self) -> bool {
+        get_bits(self.code as u32, 6, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for Mode1Code {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [code] = fixed_array(array)?;
+        Ok((Mode1Code { code }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.code]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mode_1_code() {
+        // Create message
+        let mut mode_1_code = Mode1Code::default();
+        mode_1_code.set_code(0x15).unwrap();
+        mode_1_code.set_not_extracted(true);
+
+        // Convert struct to byte stream
+        let array = mode_1_code.encode();
+
+        assert_eq!(array, vec![0x35]);
+
+        // New message
+        let (object, _length) = Mode1Code::decode(&array).unwrap();
+
+        assert_eq!(object.get_code(), 0x15);
+        assert!(object.get_not_extracted());
+        assert!(!object.get_garbled());
+        assert_eq!(mode_1_code.set_code(0x20), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,130 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Mode-C Code and Code Confidence Indicator
+//
+// | 32| 31| 30 .. 29 | 28 .. 17                 | bit
+// | V | G |  spare   | C1 A1 C2 A2 .. D2 B4 D4  |
+// | 16 .. 13 | 12 .. 1                         | bit
+// |  spare   | QC1 QA1 QC2 QA2 .. QD2 QB4 QD4  |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ModeCCode {
+    code: u32, // 4 bytes
+}
+
+/*
+* Implementation ModeCCode
+*/
+impl ModeCCode {
+    /*
+     * Set Mode-C reply in Gray notation
+     * 0 <= code <= 0xfff
+     */
+    pub fn set_code(&mut self, code: u16) -> Result<(), CatError> {
+        if code > 0xfff {
+            return Err(CatError::ValueInvalid);
+        }
+        self.code = set_bits(self.code, 28, 12, code as u32);
+        Ok(())
+    }
+
+    /*
+     * Get Mode-C reply in Gray notation
+     */
+    pub fn get_code(&self) -> u16 {
+        get_bits(self.code, 28, 12) as u16
+    }
+
+    /*
+     * Set low quality pulses, one bit per code pulse
+     * 0 <= confidence <= 0xfff
+     */
+    pub fn set_confidence(&mut self, confidence: u16) -> Result<(), CatError> {
+        if confidence > 0xfff {
+            return Err(CatError::ValueInvalid);
+        }
+        self.code = set_bits(self.code, 12, 12, confidence as u32);
+        Ok(())
+    }
+
+    /*
+     * Get low quality pulses, one bit per code pulse
+     */
+    pub fn get_confidence(&self) -> u16 {
+        get_bits(self.code, 12, 12) as u16
+    }
+
+    /*
+     * Set code not validated
+     */
+    pub fn set_not_validated(&mut self, value: bool) {
+        self.code = set_bits(self.code, 32, 1, value as u32);
+    }
+
+    /*
+     * Get code not validated
+     */
+    pub fn get_not_validated(&self) -> bool {
+        get_bits(self.code, 32, 1) == 1
+    }
+
+    /*
+     * Set garbled code
+     */
+    pub fn set_garbled(&mut self, value: bool) {
+        self.code = set_bits(self.code, 31, 1, value as u32);
+    }
+
+    /*
+     * Get garbled code
+     */
+    pub fn get_garbled(&self) -> bool {
+        get_bits(self.code, 31, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ModeCCode {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let code = u32::from_be_bytes(fixed_array(array)?);
+        Ok((ModeCCode { code }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.code.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mode_c_code() {
+        // Create message
+        let mut mode_c_code = ModeCCode::default();
+        mode_c_code.set_code(0x123).unwrap();
+        mode_c_code.set_confidence(0x004).unwrap();
+        mode_c_code.set_not_validated(true);
+
+        // Convert struct to byte stream
+        let array = mode_c_code.encode();
+
+        assert_eq!(array, vec![0x81, 0x23, 0x00, 0x04]);
+
+        // New message
+        let (object, _length) = ModeCCode::decode(&array).unwrap();
+
+        assert_eq!(object.get_code(), 0x123);
+        assert_eq!(object.get_confidence(), 0x004);
+        assert!(object.get_not_validated());
+        assert!(!object.get_garbled());
+    }
+}
@@ -0,0 +1,232 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_signed, from_unsigned, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Radar Plot Characteristics
+//
+// Compound data item, primary subfield followed by present subfields
+// |  8  |  7  |  6  |  5  |  4  |  3  |  2  | 1  | bit
+// | SRL | SRR | SAM | PRL | PAM | RPD | APD | FX |
+//
+
+/// Subfields of radar plot characteristics
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    SsrRunLength,
+    SsrReplies,
+    SsrAmplitude,
+    PsrRunLength,
+    PsrAmplitude,
+    RangeDifference,
+    AzimuthDifference,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct PlotCharacteristics {
+    primary: ExtendedField,     // 1 byte
+    subfields: [Option<u8>; 7], // 1 byte each
+}
+
+/*
+* Implementation PlotCharacteristics
+*/
+impl PlotCharacteristics {
+    /*
+     * Set SSR plot runlength in degree
+     * 0 <= runlength < 11.25
+     */
+    pub fn set_ssr_runlength(&mut self, runlength: f32) -> Result<(), CatError> {
+        let value = from_unsigned(runlength as f64, Self::FACTOR_RUNLENGTH as f64, 8)?;
+        self.set_subfield(Subfield::SsrRunLength, value as u8);
+        Ok(())
+    }
+
+    /*
+     * Get SSR plot runlength in degree
+     */
+    pub fn get_ssr_runlength(&self) -> Option<f32> {
+        self.get_subfield(Subfield::SsrRunLength)
+            .map(|value| value as f32 * Self::FACTOR_RUNLENGTH)
+    }
+
+    /*
+     * Set number of received replies for M(SSR)
+     */
+    pub fn set_ssr_replies(&mut self, replies: u8) {
+        self.set_subfield(Subfield::SsrReplies, replies);
+    }
+
+    /*
+     * Get number of received replies for M(SSR)
+     */
+    pub fn get_ssr_replies(&self) -> Option<u8> {
+        self.get_subfield(Subfield::SsrReplies)
+    }
+
+    /*
+     * Set amplitude of M(SSR) reply in dBm
+     */
+    pub fn set_ssr_amplitude(&mut self, amplitude: i8) {
+        self.set_subfield(Subfield::SsrAmplitude, amplitude as u8);
+    }
+
+    /*
+     * Get amplitude of M(SSR) reply in dBm
+     */
+    pub fn get_ssr_amplitude(&self) -> Option<i8> {
+        self.get_subfield(Subfield::SsrAmplitude)
+            .map(|value| value as i8)
+    }
+
+    /*
+     * Set primary plot runlength in degree
+     * 0 <= runlength < 11.25
+     */
+    pub fn set_psr_runlength(&mut self, runlength: f32) -> Result<(), CatError> {
+        let value = from_unsigned(runlength as f64, Self::FACTOR_RUNLENGTH as f64, 8)?;
+        self.set_subfield(Subfield::PsrRunLength, value as u8);
+        Ok(())
+    }
+
+    /*
+     * Get primary plot runlength in degree
+     */
+    pub fn get_psr_runlength(&self) -> Option<f32> {
+        self.get_subfield(Subfield::PsrRunLength)
+            .map(|value| value as f32 * Self::FACTOR_RUNLENGTH)
+    }
+
+    /*
+     * Set amplitude of primary plot in dBm
+     */
+    pub fn set_psr_amplitude(&mut self, amplitude: i8) {
+        self.set_subfield(Subfield::PsrAmplitude, amplitude as u8);
+    }
+
+    /*
+     * Get amplitude of primary plot in dBm
+     */
+    pub fn get_psr_amplitude(&self) -> Option<i8> {
+        self.get_subfield(Subfield::PsrAmplitude)
+            .map(|value| value as i8)
+    }
+
+    /*
+     * Set range difference PSR - SSR in NM
+     * -0.5 <= difference < 0.5
+     */
+    pub fn set_range_difference(&mut self, difference: f32) -> Result<(), CatError> {
+        let value = from_signed(difference as f64, Self::FACTOR_RANGE as f64, 8)?;
+        self.set_subfield(Subfield::RangeDifference, value as u8);
+        Ok(())
+    }
+
+    /*
+     * Get range difference PSR - SSR in NM
+     */
+    pub fn get_range_difference(&self) -> Option<f32> {
+        self.get_subfield(Subfield::RangeDifference)
+            .map(|value| value as i8 as f32 * Self::FACTOR_RANGE)
+    }
+
+    /*
+     * Set azimuth difference PSR - SSR in degree
+     * -2.8125 <= difference < 2.8125
+     */
+    pub fn set_azimuth_difference(&mut self, difference: f32) -> Result<(), CatError> {
+        let value = from_signed(difference as f64, Self::FACTOR_AZIMUTH as f64, 8)?;
+        self.set_subfield(Subfield::AzimuthDifference, value as u8);
+        Ok(())
+    }
+
+    /*
+     * Get azimuth difference PSR - SSR in degree
+     */
+    pub fn get_azimuth_difference(&self) -> Option<f32> {
+        self.get_subfield(Subfield::AzimuthDifference)
+            .map(|value| value as i8 as f32 * Self::FACTOR_AZIMUTH)
+    }
+
+    /*
+     * Set subfield and its bit in the primary subfield
+     */
+    fn set_subfield(&mut self, subfield: Subfield, value: u8) {
+        self.primary.set_bit(0, 8 - subfield as u8, true);
+        self.subfields[subfield as usize] = Some(value);
+    }
+
+    /*
+     * Get subfield when present
+     */
+    fn get_subfield(&self, subfield: Subfield) -> Option<u8> {
+        self.subfields[subfield as usize]
+    }
+
+    /*
+     * Conversion factor of runlength.
+     */
+    const FACTOR_RUNLENGTH: f32 = 360.0 / 8192.0;
+
+    /*
+     * Conversion factor of range difference.
+     */
+    const FACTOR_RANGE: f32 = 1.0 / 256.0;
+
+    /*
+     * Conversion factor of azimuth difference.
+     */
+    const FACTOR_AZIMUTH: f32 = 360.0 / 16384.0;
+}
+
+impl DataItem for PlotCharacteristics {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (primary, mut length) = ExtendedField::decode(array)?;
+        let mut subfields = [None; 7];
+
+        for (index, subfield) in subfields.iter_mut().enumerate() {
+            if primary.get_bit(0, 8 - index as u8) {
+                *subfield = Some(*array.get(length).ok_or(CatError::SizeInvalid(0))?);
+                length += 1;
+            }
+        }
+        Ok((PlotCharacteristics { primary, subfields }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.primary.encode();
+        vector.extend(self.subfields.iter().flatten());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_plot_characteristics() {
+        // Create message
+        let mut plot_characteristics = PlotCharacteristics::default();
+        plot_characteristics.set_ssr_replies(12);
+        plot_characteristics.set_psr_amplitude(-20);
+        plot_characteristics.set_range_difference(-0.25).unwrap();
+
+        // Convert struct to byte stream
+        let array = plot_characteristics.encode();
+
+        assert_eq!(array, vec![0x4c, 0x0c, 0xec, 0xc0]);
+
+        // New message
+        let (object, length) = PlotCharacteristics::decode(&array).unwrap();
+
+        assert_eq!(length, 4);
+        assert_eq!(object.get_ssr_runlength(), None);
+        assert_eq!(object.get_ssr_replies(), Some(12));
+        assert_eq!(object.get_psr_amplitude(), Some(-20));
+        assert_eq!(object.get_range_difference(), Some(-0.25));
+        assert_eq!(
+            PlotCharacteristics::decode(&array[0..3]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Calculated Track Velocity in Polar Co-ordinates
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct PolarVelocity {
+    ground_speed: u16, // 2 bytes
+    heading: u16,      // 2 bytes
+}
+
+/*
+* Implementation PolarVelocity
+*/
+impl PolarVelocity {
+    /*
+     * Set ground speed in NM/s
+     * 0 <= speed < 4
+     */
+    pub fn set_ground_speed(&mut self, speed: f32) -> Result<(), CatError> {
+        self.ground_speed = from_unsigned(speed as f64, Self::FACTOR_SPEED as f64, 16)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get ground speed in NM/s
+     */
+    pub fn get_ground_speed(&self) -> f32 {
+        self.ground_speed as f32 * Self::FACTOR_SPEED
+    }
+
+    /*
+     * Set heading in degree
+     * 0 <= heading < 360
+     */
+    pub fn set_heading(&mut self, heading: f32) -> Result<(), CatError> {
+        self.heading = from_unsigned(heading as f64, Self::FACTOR_HEADING as f64, 16)? as u16;
+        Ok(())
+    }
+
+    /*
+     * Get heading in degree
+     */
+    pub fn get_heading(&self) -> f32 {
+        self.heading as f32 * Self::FACTOR_HEADING
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of ground speed.
+     */
+    const FACTOR_SPEED: f32 = 1.0 / 16384.0;
+
+    /*
+     * Conversion factor of heading.
+     */
+    const FACTOR_HEADING: f32 = 360.0 / 65536.0;
+}
+
+impl DataItem for PolarVelocity {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let ground_speed = u16::from_be_bytes([array[0], array[1]]);
+        let heading = u16::from_be_bytes([array[2], array[3]]);
+        Ok((
+            PolarVelocity {
+                ground_speed,
+                heading,
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.ground_speed.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.heading.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_polar_velocity() {
+        // Create message
+        let mut polar_velocity = PolarVelocity::default();
+        polar_velocity.set_ground_speed(0.125).unwrap();
+        polar_velocity.set_heading(180.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = polar_velocity.encode();
+
+        assert_eq!(array, vec![0x08, 0x00, 0x80, 0x00]);
+
+        // New message
+        let (object, _length) = PolarVelocity::decode(&array).unwrap();
+
+        assert_eq!(object.get_ground_speed(), 0.125);
+        assert_eq!(object.get_heading(), 180.0);
+        assert_eq!(
+            polar_velocity.set_ground_speed(4.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, to_signed, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Radial Doppler Speed
+//
+// Compound data item, primary subfield followed by present subfields
+// |  8  |  7  | 6 .. 2 | 1  | bit
+// | CAL | RDS | spare  | FX |
+//
+// Calculated Doppler Speed
+// | 16 | 15 .. 11 | 10 .. 1    | bit
+// | D  |  spare   | CAL in m/s |
+//
+// Raw Doppler Speed, repetition factor followed by
+// | DOP 2 bytes | AMB 2 bytes | FRQ 2 bytes |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct RadialDopplerSpeed {
+    primary: ExtendedField,     // 1 byte
+    calculated: Option<u16>,    // 2 bytes
+    raw: Option<Vec<[u16; 3]>>, // 1 + 6 bytes each
+}
+
+/*
+* Implementation RadialDopplerSpeed
+*/
+impl RadialDopplerSpeed {
+    /*
+     * Set calculated Doppler speed in m/s
+     * -512 <= speed < 512
+     */
+    pub fn set_calculated_speed(&mut self, speed: i16, doubtful: bool) -> Result<(), CatError> {
+        if !(-512..512).contains(&speed) {
+            return Err(CatError::ValueInvalid);
+        }
+        let field = set_bits(0, 10, 10, speed as u32);
+        let field = set_bits(field, 16, 1, doubtful as u32);
+
+        self.primary.set_bit(0, 8, true);
+        self.calculated = Some(field as u16);
+        Ok(())
+    }
+
+    /*
+     * Get calculated Doppler speed in m/s and doubtful flag
+     */
+    pub fn get_calculated_speed(&self) -> Option<(i16, bool)> {
+        self.calculated.map(|field| {
+            (
+                to_signed(get_bits(field as u32, 10, 10), 10) as i16,
+                get_bits(field as u32, 16, 1) == 1,
+            )
+        })
+    }
+
+    /*
+     * Insert raw Doppler speed in m/s, ambiguity range in m/s and transmitter frequency in MHz
+     */
+    pub fn insert_raw_speed(
+        &mut self,
+        speed: u16,
+        ambiguity: u16,
+        frequency: u16,
+    ) -> Result<(), CatError> {
+        let raw = self.raw.get_or_insert_with(Vec::new);
+
+        if raw.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        raw.push([speed, ambiguity, frequency]);
+        self.primary.set_bit(0, 7, true);
+        Ok(())
+    }
+
+    /*
+     * Get all raw Doppler speeds with ambiguity range and transmitter frequency
+     */
+    pub fn get_raw_speeds(&self) -> Vec<(u16, u16, u16)> {
+        self.raw
+            .iter()
+            .flatten()
+            .map(|entry| (entry[0], entry[1], entry[2]))
+            .collect()
+    }
+
+    /*
+     * Length of one raw Doppler speed.
+     */
+    pub const RAW_LENGTH: usize = 6;
+}
+
+impl DataItem for RadialDopplerSpeed {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (primary, mut length) = ExtendedField::decode(array)?;
+        let mut calculated = None;
+        let mut raw = None;
+
+        if primary.get_bit(0, 8) {
+            calculated = Some(u16::from_be_bytes(fixed_array(&array[length..])?));
+            length += 2;
+        }
+        if primary.get_bit(0, 7) {
+            let repetition = *array.get(length).ok_or(CatError::SizeInvalid(0))? as usize;
+            let end = length + 1 + repetition * Self::RAW_LENGTH;
+            let slice = array.get(length + 1..end).ok_or(CatError::SizeInvalid(0))?;
+
+            raw = Some(
+                slice
+                    .chunks_exact(Self::RAW_LENGTH)
+                    .map(|chunk| {
+                        [
+                            u16::from_be_bytes([chunk[0], chunk[1]]),
+                            u16::from_be_bytes([chunk[2], chunk[3]]),
+                            u16::from_be_bytes([chunk[4], chunk[5]]),
+                        ]
+                    })
+                    .collect(),
+            );
+            length = end;
+        }
+        Ok((
+            RadialDopplerSpeed {
+                primary,
+                calculated,
+                raw,
+            },
+            length,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.primary.encode();
+
+        if let Some(calculated) = self.calculated {
+            vector.extend_from_slice(&calculated.to_be_bytes());
+        }
+        if let Some(raw) = &self.raw {
+            vector.push(raw.len() as u8);
+            for value in raw.iter().flatten() {
+                vector.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_radial_doppler_speed() {
+        // Create message
+        let mut radial_doppler_speed = RadialDopplerSpeed::default();
+        radial_doppler_speed.set_calculated_speed(-2, true).unwrap();
+        radial_doppler_speed
+            .insert_raw_speed(100, 200, 1030)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = radial_doppler_speed.encode();
+
+        assert_eq!(
+            array,
+            vec![0xc0, 0x83, 0xfe, 0x01, 0x00, 0x64, 0x00, 0xc8, 0x04, 0x06]
+        );
+
+        // New message
+        let (object, length) = RadialDopplerSpeed::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_calculated_speed(), Some((-2, true)));
+        assert_eq!(object.get_raw_speeds(), vec![(100, 200, 1030)]);
+        assert_eq!(
+            RadialDopplerSpeed::decode(&array[0..9]),
+            Err(CatError::SizeInvalid(0))
+        );
+        assert_eq!(
+            radial_doppler_speed.set_calculated_speed(512, false),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
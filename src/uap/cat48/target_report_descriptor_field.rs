@@ -0,0 +1,249 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Target Report Descriptor
+//
+// | 8 7 6 |  5  |  4  |  3  |  2  | 1  | bit
+// |  TYP  | SIM | RDP | SPI | RAB | FX |
+// |  TST  | ERR | XPP | ME  | MI  | FOE/FRI | FX |
+//
+
+/// Type of detection
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum DetectionType {
+    NoDetection,
+    SinglePsr,
+    SingleSsr,
+    SsrPsr,
+    SingleModeSAllCall,
+    SingleModeSRollCall,
+    ModeSAllCallPsr,
+    ModeSRollCallPsr,
+}
+
+/// Friend or foe mode 4 interrogation
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum FoeFri {
+    NoMode4,
+    Friendly,
+    Unknown,
+    NoReply,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TargetReportDescriptor {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TargetReportDescriptor
+*/
+impl TargetReportDescriptor {
+    /*
+     * Set detection type
+     */
+    pub fn set_typ(&mut self, typ: DetectionType) {
+        self.field.set_bits(0, 8, 3, typ as u8);
+    }
+
+    /*
+     * Get detection type
+     */
+    pub fn get_typ(&self) -> DetectionType {
+        match self.field.get_bits(0, 8, 3) {
+            0 => DetectionType::NoDetection,
+            1 => DetectionType::SinglePsr,
+            2 => DetectionType::SingleSsr,
+            3 => DetectionType::SsrPsr,
+            4 => DetectionType::SingleModeSAllCall,
+            5 => DetectionType::SingleModeSRollCall,
+            6 => DetectionType::ModeSAllCallPsr,
+            _ => DetectionType::ModeSRollCallPsr,
+        }
+    }
+
+    /*
+     * Set simulated target report
+     */
+    pub fn set_simulated(&mut self, value: bool) {
+        self.field.set_bit(0, 5, value);
+    }
+
+    /*
+     * Get simulated target report
+     */
+    pub fn get_simulated(&self) -> bool {
+        self.field.get_bit(0, 5)
+    }
+
+    /*
+     * Set report from RDP chain 2
+     */
+    pub fn set_rdp_chain_2(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get report from RDP chain 2
+     */
+    pub fn get_rdp_chain_2(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set special position identification
+     */
+    pub fn set_spi(&mut self, value: bool) {
+        self.field.set_bit(0, 3, value);
+    }
+
+    /*
+     * Get special position identification
+     */
+    pub fn get_spi(&self) -> bool {
+        self.field.get_bit(0, 3)
+    }
+
+    /*
+     * Set report from field monitor (fixed transponder)
+     */
+    pub fn set_field_monitor(&mut self, value: bool) {
+        self.field.set_bit(0, 2, value);
+    }
+
+    /*
+     * Get report from field monitor (fixed transponder)
+     */
+    pub fn get_field_monitor(&self) -> bool {
+        self.field.get_bit(0, 2)
+    }
+
+    /*
+     * Set test target report
+     */
+    pub fn set_test(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get test target report
+     */
+    pub fn get_test(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set extended range present
+     */
+    pub fn set_extended_range(&mut self, value: bool) {
+        self.field.set_bit(1, 7, value);
+    }
+
+    /*
+     * Get extended range present
+     */
+    pub fn get_extended_range(&self) -> bool {
+        self.field.get_bit(1, 7)
+    }
+
+    /*
+     * Set X-pulse present
+     */
+    pub fn set_x_pulse(&mut self, value: bool) {
+        self.field.set_bit(1, 6, value);
+    }
+
+    /*
+     * Get X-pulse present
+     */
+    pub fn get_x_pulse(&self) -> bool {
+        self.field.get_bit(1, 6)
+    }
+
+    /*
+     * Set military emergency
+     */
+    pub fn set_military_emergency(&mut self, value: bool) {
+        self.field.set_bit(1, 5, value);
+    }
+
+    /*
+     * Get military emergency
+     */
+    pub fn get_military_emergency(&self) -> bool {
+        self.field.get_bit(1, 5)
+    }
+
+    /*
+     * Set military identification
+     */
+    pub fn set_military_identification(&mut self, value: bool) {
+        self.field.set_bit(1, 4, value);
+    }
+
+    /*
+     * Get military identification
+     */
+    pub fn get_military_identification(&self) -> bool {
+        self.field.get_bit(1, 4)
+    }
+
+    /*
+     * Set foe or friend mode 4 interrogation
+     */
+    pub fn set_foe_fri(&mut self, foe_fri: FoeFri) {
+        self.field.set_bits(1, 3, 2, foe_fri as u8);
+    }
+
+    /*
+     * Get foe or friend mode 4 interrogation
+     */
+    pub fn get_foe_fri(&self) -> FoeFri {
+        match self.field.get_bits(1, 3, 2) {
+            0 => FoeFri::NoMode4,
+            1 => FoeFri::Friendly,
+            2 => FoeFri::Unknown,
+            _ => FoeFri::NoReply,
+        }
+    }
+}
+
+impl DataItem for TargetReportDescriptor {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TargetReportDescriptor { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_report_descriptor() {
+        // Create message
+        let mut target_report_descriptor = TargetReportDescriptor::default();
+        target_report_descriptor.set_typ(DetectionType::ModeSRollCallPsr);
+        target_report_descriptor.set_spi(true);
+        target_report_descriptor.set_foe_fri(FoeFri::NoReply);
+
+        // Convert struct to byte stream
+        let array = target_report_descriptor.encode();
+
+        assert_eq!(array, vec![0xe5, 0x06]);
+
+        // New message
+        let (object, length) = TargetReportDescriptor::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_typ(), DetectionType::ModeSRollCallPsr);
+        assert!(object.get_spi());
+        assert!(!object.get_simulated());
+        assert_eq!(object.get_foe_fri(), FoeFri::NoReply);
+    }
+}
@@ -0,0 +1,147 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_unsigned, DataItem};
+
+// Track Quality
+//
+// Standard deviations of position and velocity
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrackQuality {
+    sigma_x: u8, // 1 byte
+    sigma_y: u8, // 1 byte
+    sigma_v: u8, // 1 byte
+    sigma_h: u8, // 1 byte
+}
+
+/*
+* Implementation TrackQuality
+*/
+impl TrackQuality {
+    /*
+     * Set standard deviation of position in NM
+     * 0 <= sigma < 2
+     */
+    pub fn set_sigma_position(&mut self, sigma_x: f32, sigma_y: f32) -> Result<(), CatError> {
+        let sigma_x = from_unsigned(sigma_x as f64, Self::FACTOR_POSITION as f64, 8)? as u8;
+        let sigma_y = from_unsigned(sigma_y as f64, Self::FACTOR_POSITION as f64, 8)? as u8;
+
+        self.sigma_x = sigma_x;
+        self.sigma_y = sigma_y;
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of position x in NM
+     */
+    pub fn get_sigma_x(&self) -> f32 {
+        self.sigma_x as f32 * Self::FACTOR_POSITION
+    }
+
+    /*
+     * Get standard deviation of position y in NM
+     */
+    pub fn get_sigma_y(&self) -> f32 {
+        self.sigma_y as f32 * Self::FACTOR_POSITION
+    }
+
+    /*
+     * Set standard deviation of ground speed in NM/s
+     */
+    pub fn set_sigma_speed(&mut self, sigma: f32) -> Result<(), CatError> {
+        self.sigma_v = from_unsigned(sigma as f64, Self::FACTOR_SPEED as f64, 8)? as u8;
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of ground speed in NM/s
+     */
+    pub fn get_sigma_speed(&self) -> f32 {
+        self.sigma_v as f32 * Self::FACTOR_SPEED
+    }
+
+    /*
+     * Set standard deviation of heading in degree
+     */
+    pub fn set_sigma_heading(&mut self, sigma: f32) -> Result<(), CatError> {
+        self.sigma_h = from_unsigned(sigma as f64, Self::FACTOR_HEADING as f64, 8)? as u8;
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of heading in degree
+     */
+    pub fn get_sigma_heading(&self) -> f32 {
+        self.sigma_h as f32 * Self::FACTOR_HEADING
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of position.
+     */
+    const FACTOR_POSITION: f32 = 1.0 / 128.0;
+
+    /*
+     * Conversion factor of ground speed.
+     */
+    const FACTOR_SPEED: f32 = 1.0 / 16384.0;
+
+    /*
+     * Conversion factor of heading.
+     */
+    const FACTOR_HEADING: f32 = 360.0 / 4096.0;
+}
+
+impl DataItem for TrackQuality {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [sigma_x, sigma_y, sigma_v, sigma_h] = fixed_array(array)?;
+        Ok((
+            TrackQuality {
+                sigma_x,
+                sigma_y,
+                sigma_v,
+                sigma_h,
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.sigma_x, self.sigma_y, self.sigma_v, self.sigma_h]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_quality() {
+        // Create message
+        let mut track_quality = TrackQuality::default();
+        track_quality.set_sigma_position(0.5, 1.0).unwrap();
+        track_quality.set_sigma_speed(0.0).unwrap();
+        track_quality.set_sigma_heading(8.7890625).unwrap();
+
+        // Convert struct to byte stream
+        let array = track_quality.encode();
+
+        assert_eq!(array, vec![0x40, 0x80, 0x00, 0x64]);
+
+        // New message
+        let (object, _length) = TrackQuality::decode(&array).unwrap();
+
+        assert_eq!(object.get_sigma_x(), 0.5);
+        assert_eq!(object.get_sigma_y(), 1.0);
+        assert_eq!(object.get_sigma_heading(), 8.7890625);
+        assert_eq!(
+            track_quality.set_sigma_position(2.0, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Track Status
+//
+// |  8  |  7 6  |  5  |  4  |  3 2  | 1  | bit
+// | CNF |  RAD  | DOU | MAH |  CDM  | FX |
+// | TRE | GHO   | SUP | TCC | spare | FX |
+//
+
+/// Type of sensor maintaining the track
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TrackSensor {
+    Combined,
+    Psr,
+    Ssr,
+    Invalid,
+}
+
+/// Climbing or descending mode
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ClimbingMode {
+    Maintaining,
+    Climbing,
+    Descending,
+    Unknown,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrackStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TrackStatus
+*/
+impl TrackStatus {
+    /*
+     * Set tentative track
+     */
+    pub fn set_tentative(&mut self, value: bool) {
+        self.field.set_bit(0, 8, value);
+    }
+
+    /*
+     * Get tentative track
+     */
+    pub fn get_tentative(&self) -> bool {
+        self.field.get_bit(0, 8)
+    }
+
+    /*
+     * Set sensor maintaining the track
+     */
+    pub fn set_sensor(&mut self, sensor: TrackSensor) {
+        self.field.set_bits(0, 7, 2, sensor as u8);
+    }
+
+    /*
+     * Get sensor maintaining the track
+     */
+    pub fn get_sensor(&self) -> TrackSensor {
+        match self.field.get_bits(0, 7, 2) {
+            0 => TrackSensor::Combined,
+            1 => TrackSensor::Psr,
+            2 => TrackSensor::Ssr,
+            _ => TrackSensor::Invalid,
+        }
+    }
+
+    /*
+     * Set doubtful association
+     */
+    pub fn set_doubtful(&mut self, value: bool) {
+        self.field.set_bit(0, 5, value);
+    }
+
+    /*
+     * Get doubtful association
+     */
+    pub fn get_doubtful(&self) -> bool {
+        self.field.get_bit(0, 5)
+    }
+
+    /*
+     * Set horizontal manoeuvre sensed
+     */
+    pub fn set_manoeuvre(&mut self, value: bool) {
+        self.field.set_bit(0, 4, value);
+    }
+
+    /*
+     * Get horizontal manoeuvre sensed
+     */
+    pub fn get_manoeuvre(&self) -> bool {
+        self.field.get_bit(0, 4)
+    }
+
+    /*
+     * Set climbing or descending mode
+     */
+    pub fn set_climbing_mode(&mut self, mode: ClimbingMode) {
+        self.field.set_bits(0, 3, 2, mode as u8);
+    }
+
+    /*
+     * Get climbing or descending mode
+     */
+    pub fn get_climbing_mode(&self) -> ClimbingMode {
+        match self.field.get_bits(0, 3, 2) {
+            0 => ClimbingMode::Maintaining,
+            1 => ClimbingMode::Climbing,
+            2 => ClimbingMode::Descending,
+            _ => ClimbingMode::Unknown,
+        }
+    }
+
+    /*
+     * Set end of track
+     */
+    pub fn set_end_of_track(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get end of track
+     */
+    pub fn get_end_of_track(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set ghost target track
+     */
+    pub fn set_ghost(&mut self, value: bool) {
+        self.field.set_bit(1, 7, value);
+    }
+
+    /*
+     * Get ghost target track
+     */
+    pub fn get_ghost(&self) -> bool {
+        self.field.get_bit(1, 7)
+    }
+
+    /*
+     * Set track maintained with neighbour node information
+     */
+    pub fn set_supplementary(&mut self, value: bool) {
+        self.field.set_bit(1, 6, value);
+    }
+
+    /*
+     * Get track maintained with neighbour node information
+     */
+    pub fn get_supplementary(&self) -> bool {
+        self.field.get_bit(1, 6)
+    }
+
+    /*
+     * Set slant range correction applied
+     */
+    pub fn set_slant_correction(&mut self, value: bool) {
+        self.field.set_bit(1, 5, value);
+    }
+
+    /*
+     * Get slant range correction applied
+     */
+    pub fn get_slant_correction(&self) -> bool {
+        self.field.get_bit(1, 5)
+    }
+}
+
+impl DataItem for TrackStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TrackStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_status() {
+        // Create message
+        let mut track_status = TrackStatus::default();
+        track_status.set_sensor(TrackSensor::Ssr);
+        track_status.set_climbing_mode(ClimbingMode::Descending);
+
+        // Convert struct to byte stream
+        let array = track_status.encode();
+
+        assert_eq!(array, vec![0x44]);
+
+        // Second octet
+        track_status.set_end_of_track(true);
+        let array = track_status.encode();
+
+        assert_eq!(array, vec![0x45, 0x80]);
+
+        // New message
+        let (object, length) = TrackStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_sensor(), TrackSensor::Ssr);
+        assert_eq!(object.get_climbing_mode(), ClimbingMode::Descending);
+        assert!(object.get_end_of_track());
+        assert!(!object.get_ghost());
+    }
+}
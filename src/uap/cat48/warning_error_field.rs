@@ -0,0 +1,77 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Warning/Error Conditions and Target Classification
+//
+// | 8 .. 2 | 1  | bit
+// |  CODE  | FX |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct WarningError {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation WarningError
+*/
+impl WarningError {
+    /*
+     * Insert warning/error condition code
+     * 0 < code <= 127
+     */
+    pub fn insert_code(&mut self, code: u8) -> Result<(), CatError> {
+        if code == 0 || code > 0x7f {
+            return Err(CatError::ValueInvalid);
+        }
+        let octet = self.get_codes().len();
+        self.field.set_bits(octet, 8, 7, code);
+        Ok(())
+    }
+
+    /*
+     * Get all warning/error condition codes
+     */
+    pub fn get_codes(&self) -> Vec<u8> {
+        (0..self.field.len())
+            .map(|octet| self.field.get_bits(octet, 8, 7))
+            .filter(|code| *code != 0)
+            .collect()
+    }
+}
+
+impl DataItem for WarningError {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((WarningError { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_warning_error() {
+        // Create message
+        let mut warning_error = WarningError::default();
+        warning_error.insert_code(2).unwrap();
+        warning_error.insert_code(25).unwrap();
+
+        // Convert struct to byte stream
+        let array = warning_error.encode();
+
+        assert_eq!(array, vec![0x05, 0x32]);
+
+        // New message
+        let (object, length) = WarningError::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_codes(), vec![2, 25]);
+        assert_eq!(warning_error.insert_code(128), Err(CatError::ValueInvalid));
+    }
+}
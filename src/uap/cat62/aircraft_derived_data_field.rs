@@ -0,0 +1,376 @@
+use crate::category::CatError;
+use crate::uap::acas_resolution_field::AcasResolution;
+use crate::uap::aircraft_address_field::AircraftAddress;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_signed, from_unsigned, DataItem};
+use crate::uap::mode_s_mb_data_field::ModeSMbData;
+
+// Aircraft Derived Data
+//
+// Compound data item, primary subfield followed by present subfields
+// | ADR | ID  | MHG | IAS | TAS | SAL | FSS | FX |
+// | TIS | TID | COM | SAB | ACS | BVR | GVR | FX |
+// | RAN | TAR | TAN | GSP | VUN | MET | EMC | FX |
+// | POS | GAL | PUN | MB  | IAR | MAC | BPS | FX |
+//
+
+/// Subfields of aircraft derived data
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    TargetAddress,
+    TargetIdentification,
+    MagneticHeading,
+    IndicatedAirspeed,
+    TrueAirspeed,
+    SelectedAltitude,
+    FinalStateSelectedAltitude,
+    TrajectoryIntentStatus,
+    TrajectoryIntentData,
+    CommunicationsCapability,
+    AdsBStatus,
+    AcasResolution,
+    BarometricVerticalRate,
+    GeometricVerticalRate,
+    RollAngle,
+    TrackAngleRate,
+    TrackAngle,
+    GroundSpeed,
+    VelocityUncertainty,
+    MeteorologicalData,
+    EmitterCategory,
+    Position,
+    GeometricAltitude,
+    PositionUncertainty,
+    ModeSMbData,
+    IndicatedAirspeedData,
+    MachNumber,
+    BarometricPressureSetting,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct AircraftDerivedData {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation AircraftDerivedData
+*/
+impl AircraftDerivedData {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set target address
+     */
+    pub fn set_address(&mut self, address: AircraftAddress) {
+        self.field
+            .set_item(Subfield::TargetAddress as usize, &address);
+    }
+
+    /*
+     * Get target address
+     */
+    pub fn get_address(&self) -> Option<AircraftAddress> {
+        self.field.get_item(Subfield::TargetAddress as usize)
+    }
+
+    /*
+     * Set target identification
+     */
+    pub fn set_identification(&mut self, identification: AircraftIdentification) {
+        self.field
+            .set_item(Subfield::TargetIdentification as usize, &identification);
+    }
+
+    /*
+     * Get target identification
+     */
+    pub fn get_identification(&self) -> Option<AircraftIdentification> {
+        self.field.get_item(Subfield::TargetIdentification as usize)
+    }
+
+    /*
+     * Set magnetic heading in degree
+     * 0 <= heading < 360
+     */
+    pub fn set_magnetic_heading(&mut self, heading: f32) -> Result<(), CatError> {
+        let value = from_unsigned(heading as f64, Self::FACTOR_ANGLE as f64, 16)?;
+        self.set_u16(Subfield::MagneticHeading, value as u16);
+        Ok(())
+    }
+
+    /*
+     * Get magnetic heading in degree
+     */
+    pub fn get_magnetic_heading(&self) -> Option<f32> {
+        self.get_u16(Subfield::MagneticHeading)
+            .map(|value| value as f32 * Self::FACTOR_ANGLE)
+    }
+
+    /*
+     * Set true airspeed in knots
+     */
+    pub fn set_true_airspeed(&mut self, speed: u16) {
+        self.set_u16(Subfield::TrueAirspeed, speed);
+    }
+
+    /*
+     * Get true airspeed in knots
+     */
+    pub fn get_true_airspeed(&self) -> Option<u16> {
+        self.get_u16(Subfield::TrueAirspeed)
+    }
+
+    /*
+     * Set barometric vertical rate in ft/min
+     * -204800 <= rate < 204800
+     */
+    pub fn set_barometric_vertical_rate(&mut self, rate: f32) -> Result<(), CatError> {
+        let value = from_signed(rate as f64, Self::FACTOR_RATE as f64, 16)?;
+        self.set_u16(Subfield::BarometricVerticalRate, value as u16);
+        Ok(())
+    }
+
+    /*
+     * Get barometric vertical rate in ft/min
+     */
+    pub fn get_barometric_vertical_rate(&self) -> Option<f32> {
+        self.get_u16(Subfield::BarometricVerticalRate)
+            .map(|value| value as i16 as f32 * Self::FACTOR_RATE)
+    }
+
+    /*
+     * Set geometric vertical rate in ft/min
+     * -204800 <= rate < 204800
+     */
+    pub fn set_geometric_vertical_rate(&mut self, rate: f32) -> Result<(), CatError> {
+        let value = from_signed(rate as f64, Self::FACTOR_RATE as f64, 16)?;
+        self.set_u16(Subfield::GeometricVerticalRate, value as u16);
+        Ok(())
+    }
+
+    /*
+     * Get geometric vertical rate in ft/min
+     */
+    pub fn get_geometric_vertical_rate(&self) -> Option<f32> {
+        self.get_u16(Subfield::GeometricVerticalRate)
+            .map(|value| value as i16 as f32 * Self::FACTOR_RATE)
+    }
+
+    /*
+     * Set track angle in degree
+     * 0 <= angle < 360
+     */
+    pub fn set_track_angle(&mut self, angle: f32) -> Result<(), CatError> {
+        let value = from_unsigned(angle as f64, Self::FACTOR_ANGLE as f64, 16)?;
+        self.set_u16(Subfield::TrackAngle, value as u16);
+        Ok(())
+    }
+
+    /*
+     * Get track angle in degree
+     */
+    pub fn get_track_angle(&self) -> Option<f32> {
+        self.get_u16(Subfield::TrackAngle)
+            .map(|value| value as f32 * Self::FACTOR_ANGLE)
+    }
+
+    /*
+     * Set ground speed in NM/s
+     * -2 <= speed < 2
+     */
+    pub fn set_ground_speed(&mut self, speed: f32) -> Result<(), CatError> {
+        let value = from_signed(speed as f64, Self::FACTOR_SPEED as f64, 16)?;
+        self.set_u16(Subfield::GroundSpeed, value as u16);
+        Ok(())
+    }
+
+    /*
+     * Get ground speed in NM/s
+     */
+    pub fn get_ground_speed(&self) -> Option<f32> {
+        self.get_u16(Subfield::GroundSpeed)
+            .map(|value| value as i16 as f32 * Self::FACTOR_SPEED)
+    }
+
+    /*
+     * Set emitter category
+     */
+    pub fn set_emitter_category(&mut self, category: u8) {
+        self.field
+            .set_subfield(Subfield::EmitterCategory as usize, vec![category]);
+    }
+
+    /*
+     * Get emitter category
+     */
+    pub fn get_emitter_category(&self) -> Option<u8> {
+        self.get_subfield(Subfield::EmitterCategory)
+            .map(|value| value[0])
+    }
+
+    /*
+     * Set ACAS resolution advisory report
+     */
+    pub fn set_acas_resolution(&mut self, acas_resolution: AcasResolution) {
+        self.field
+            .set_item(Subfield::AcasResolution as usize, &acas_resolution);
+    }
+
+    /*
+     * Get ACAS resolution advisory report
+     */
+    pub fn get_acas_resolution(&self) -> Option<AcasResolution> {
+        self.field.get_item(Subfield::AcasResolution as usize)
+    }
+
+    /*
+     * Set Mode S MB data
+     */
+    pub fn set_mode_s_mb_data(&mut self, mode_s_mb_data: &ModeSMbData) {
+        self.field
+            .set_item(Subfield::ModeSMbData as usize, mode_s_mb_data);
+    }
+
+    /*
+     * Get Mode S MB data
+     */
+    pub fn get_mode_s_mb_data(&self) -> Option<ModeSMbData> {
+        self.field.get_item(Subfield::ModeSMbData as usize)
+    }
+
+    /*
+     * Set two byte subfield
+     */
+    fn set_u16(&mut self, subfield: Subfield, value: u16) {
+        self.field
+            .set_subfield(subfield as usize, value.to_be_bytes().to_vec());
+    }
+
+    /*
+     * Get two byte subfield when present
+     */
+    fn get_u16(&self, subfield: Subfield) -> Option<u16> {
+        let value = self.get_subfield(subfield)?;
+        Some(u16::from_be_bytes([value[0], value[1]]))
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 28] = [
+        SubfieldLength::Fixed(3),
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Extended,
+        SubfieldLength::Repetitive(15),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(8),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Repetitive(8),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+    ];
+
+    /*
+     * Conversion factor of heading and track angle.
+     */
+    const FACTOR_ANGLE: f32 = 360.0 / 65536.0;
+
+    /*
+     * Conversion factor of vertical rate.
+     */
+    const FACTOR_RATE: f32 = 6.25;
+
+    /*
+     * Conversion factor of ground speed.
+     */
+    const FACTOR_SPEED: f32 = 1.0 / 16384.0;
+}
+
+impl DataItem for AircraftDerivedData {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((AircraftDerivedData { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_aircraft_derived_data() {
+        // Create message
+        let mut address = AircraftAddress::default();
+        address.set_address(0x3c6586).unwrap();
+        let mut mode_s_mb_data = ModeSMbData::default();
+        mode_s_mb_data.insert_mb_data(0x1234, 6, 0).unwrap();
+
+        let mut aircraft_derived_data = AircraftDerivedData::default();
+        aircraft_derived_data.set_address(address);
+        aircraft_derived_data.set_magnetic_heading(90.0).unwrap();
+        aircraft_derived_data
+            .set_barometric_vertical_rate(-600.0)
+            .unwrap();
+        aircraft_derived_data.set_mode_s_mb_data(&mode_s_mb_data);
+
+        // Convert struct to byte stream
+        let array = aircraft_derived_data.encode();
+
+        assert_eq!(
+            &array[0..11],
+            &[0xa1, 0x05, 0x01, 0x10, 0x3c, 0x65, 0x86, 0x40, 0x00, 0xff, 0xa0]
+        );
+        assert_eq!(array.len(), 11 + 9);
+
+        // New message
+        let (object, length) = AircraftDerivedData::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, aircraft_derived_data);
+        assert_eq!(object.get_address(), Some(address));
+        assert_eq!(object.get_magnetic_heading(), Some(90.0));
+        assert_eq!(object.get_barometric_vertical_rate(), Some(-600.0));
+        assert_eq!(object.get_mode_s_mb_data(), Some(mode_s_mb_data));
+        assert_eq!(object.get_true_airspeed(), None);
+        assert_eq!(
+            aircraft_derived_data.set_subfield(Subfield::TrueAirspeed, vec![0x01]),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, get_bits, set_bits, to_signed, DataItem};
+
+// Vertical track data items, each 2 bytes two's complement
+//
+// I062/136 Measured Flight Level in 1/4 FL
+// I062/130 Calculated Track Geometric Altitude in 6.25 ft
+// I062/135 Calculated Track Barometric Altitude, QNH bit and 15 bit in 1/4 FL
+// I062/220 Calculated Rate of Climb/Descent in 6.25 ft/min
+//
+
+/// Measured Flight Level
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct MeasuredFlightLevel {
+    level: i16, // 2 bytes
+}
+
+/*
+* Implementation MeasuredFlightLevel
+*/
+impl MeasuredFlightLevel {
+    /*
+     * Set flight level in FL
+     * -8192 <= level < 8192
+     */
+    pub fn set_flight_level(&mut self, level: f32) -> Result<(), CatError> {
+        self.level = from_signed(level as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get flight level in FL
+     */
+    pub fn get_flight_level(&self) -> f32 {
+        self.level as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 4.0;
+}
+
+impl DataItem for MeasuredFlightLevel {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let level = i16::from_be_bytes(fixed_array(array)?);
+        Ok((MeasuredFlightLevel { level }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.level.to_be_bytes().to_vec()
+    }
+}
+
+/// Calculated Track Geometric Altitude
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct GeometricAltitude {
+    altitude: i16, // 2 bytes
+}
+
+/*
+* Implementation GeometricAltitude
+*/
+impl GeometricAltitude {
+    /*
+     * Set altitude in ft
+     * -204800 <= altitude < 204800
+     */
+    pub fn set_altitude(&mut self, altitude: f32) -> Result<(), CatError> {
+        self.altitude = from_signed(altitude as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get altitude in ft
+     */
+    pub fn get_altitude(&self) -> f32 {
+        self.altitude as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 6.25;
+}
+
+impl DataItem for GeometricAltitude {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let altitude = i16::from_be_bytes(fixed_array(array)?);
+        Ok((GeometricAltitude { altitude }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.altitude.to_be_bytes().to_vec()
+    }
+}
+
+/// Calculated Track Barometric Altitude
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct BarometricAltitude {
+    altitude: u16, // 2 bytes
+}
+
+/*
+* Implementation BarometricAltitude
+*/
+impl BarometricAltitude {
+    /*
+     * Set altitude in FL
+     * -4096 <= altitude < 4096
+     */
+    pub fn set_altitude(&mut self, altitude: f32) -> Result<(), CatError> {
+        let converted_altitude = from_signed(altitude as f64, Self::FACTOR as f64, 15)?;
+        self.altitude = set_bits(self.altitude as u32, 15, 15, converted_altitude) as u16;
+        Ok(())
+    }
+
+    /*
+     * Get altitude in FL
+     */
+    pub fn get_altitude(&self) -> f32 {
+        to_signed(get_bits(self.altitude as u32, 15, 15), 15) as f32 * Self::FACTOR
+    }
+
+    /*
+     * Set QNH correction applied
+     */
+    pub fn set_qnh(&mut self, value: bool) {
+        self.altitude = set_bits(self.altitude as u32, 16, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get QNH correction applied
+     */
+    pub fn get_qnh(&self) -> bool {
+        get_bits(self.altitude as u32, 16, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 4.0;
+}
+
+impl DataItem for BarometricAltitude {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let altitude = u16::from_be_bytes(fixed_array(array)?);
+        Ok((BarometricAltitude { altitude }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.altitude.to_be_bytes().to_vec()
+    }
+}
+
+/// Calculated Rate of Climb/Descent
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct RateOfClimb {
+    rate: i16, // 2 bytes
+}
+
+/*
+* Implementation RateOfClimb
+*/
+impl RateOfClimb {
+    /*
+     * Set rate of climb/descent in ft/min
+     * -204800 <= rate < 204800
+     */
+    pub fn set_rate(&mut self, rate: f32) -> Result<(), CatError> {
+        self.rate = from_signed(rate as f64, Self::FACTOR as f64, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get rate of climb/descent in ft/min
+     */
+    pub fn get_rate(&self) -> f32 {
+        self.rate as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 6.25;
+}
+
+impl DataItem for RateOfClimb {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let rate = i16::from_be_bytes(fixed_array(array)?);
+        Ok((RateOfClimb { rate }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.rate.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_altitude() {
+        // Create message
+        let mut measured_flight_level = MeasuredFlightLevel::default();
+        measured_flight_level.set_flight_level(-1.25).unwrap();
+        let mut geometric_altitude = GeometricAltitude::default();
+        geometric_altitude.set_altitude(37500.0).unwrap();
+        let mut barometric_altitude = BarometricAltitude::default();
+        barometric_altitude.set_altitude(-0.5).unwrap();
+        barometric_altitude.set_qnh(true);
+        let mut rate_of_climb = RateOfClimb::default();
+        rate_of_climb.set_rate(-1500.0).unwrap();
+
+        // Convert struct to byte stream
+        assert_eq!(measured_flight_level.encode(), vec![0xff, 0xfb]);
+        assert_eq!(geometric_altitude.encode(), vec![0x17, 0x70]);
+        assert_eq!(barometric_altitude.encode(), vec![0xff, 0xfe]);
+        assert_eq!(rate_of_climb.encode(), vec![0xff, 0x10]);
+
+        // New message
+        let (object, _length) = BarometricAltitude::decode(&barometric_altitude.encode()).unwrap();
+
+        assert_eq!(object.get_altitude(), -0.5);
+        assert!(object.get_qnh());
+        assert_eq!(
+            RateOfClimb::decode(&rate_of_climb.encode())
+                .unwrap()
+                .0
+                .get_rate(),
+            -1500.0
+        );
+        assert_eq!(
+            barometric_altitude.set_altitude(4096.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
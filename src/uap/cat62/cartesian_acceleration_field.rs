@@ -0,0 +1,98 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Calculated Acceleration in Cartesian Co-ordinates
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CartesianAcceleration {
+    ax: i8, // 1 byte
+    ay: i8, // 1 byte
+}
+
+/*
+* Implementation CartesianAcceleration
+*/
+impl CartesianAcceleration {
+    /*
+     * Set acceleration in m/s²
+     * -32 <= ax, ay < 32
+     */
+    pub fn set_acceleration(&mut self, ax: f32, ay: f32) -> Result<(), CatError> {
+        let ax = from_signed(ax as f64, Self::FACTOR as f64, 8)? as i8;
+        let ay = from_signed(ay as f64, Self::FACTOR as f64, 8)? as i8;
+
+        self.ax = ax;
+        self.ay = ay;
+        Ok(())
+    }
+
+    /*
+     * Get acceleration ax in m/s²
+     */
+    pub fn get_ax(&self) -> f32 {
+        self.ax as f32 * Self::FACTOR
+    }
+
+    /*
+     * Get acceleration ay in m/s²
+     */
+    pub fn get_ay(&self) -> f32 {
+        self.ay as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 0.25;
+}
+
+impl DataItem for CartesianAcceleration {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [ax, ay] = fixed_array(array)?;
+        Ok((
+            CartesianAcceleration {
+                ax: ax as i8,
+                ay: ay as i8,
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.ax as u8, self.ay as u8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cartesian_acceleration() {
+        // Create message
+        let mut cartesian_acceleration = CartesianAcceleration::default();
+        cartesian_acceleration.set_acceleration(-1.0, 2.5).unwrap();
+
+        // Convert struct to byte stream
+        let array = cartesian_acceleration.encode();
+
+        assert_eq!(array, vec![0xfc, 0x0a]);
+
+        // New message
+        let (object, _length) = CartesianAcceleration::decode(&array).unwrap();
+
+        assert_eq!(object.get_ax(), -1.0);
+        assert_eq!(object.get_ay(), 2.5);
+        assert_eq!(
+            cartesian_acceleration.set_acceleration(32.0, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, to_signed, DataItem};
+
+// Calculated Track Position in Cartesian Co-ordinates
+//
+// | 24 bit X in 0.5 m | 24 bit Y in 0.5 m |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CartesianPosition {
+    position: [u8; 6], // 6 bytes
+}
+
+/*
+* Implementation CartesianPosition
+*/
+impl CartesianPosition {
+    /*
+     * Set position in m
+     * -4194304 <= x, y < 4194304
+     */
+    pub fn set_position(&mut self, x: f64, y: f64) -> Result<(), CatError> {
+        let x = from_signed(x, Self::FACTOR, 24)?.to_be_bytes();
+        let y = from_signed(y, Self::FACTOR, 24)?.to_be_bytes();
+
+        self.position[0..3].copy_from_slice(&x[1..4]);
+        self.position[3..6].copy_from_slice(&y[1..4]);
+        Ok(())
+    }
+
+    /*
+     * Get position x in m
+     */
+    pub fn get_x(&self) -> f64 {
+        Self::to_i24(&self.position[0..3]) as f64 * Self::FACTOR
+    }
+
+    /*
+     * Get position y in m
+     */
+    pub fn get_y(&self) -> f64 {
+        Self::to_i24(&self.position[3..6]) as f64 * Self::FACTOR
+    }
+
+    /*
+     * Convert 24 bit two's complement to signed value
+     */
+    fn to_i24(array: &[u8]) -> i32 {
+        to_signed(u32::from_be_bytes([0, array[0], array[1], array[2]]), 24)
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f64 = 0.5;
+}
+
+impl DataItem for CartesianPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let position = fixed_array(array)?;
+        Ok((CartesianPosition { position }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.position.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cartesian_position() {
+        // Create message
+        let mut cartesian_position = CartesianPosition::default();
+        cartesian_position.set_position(-1.5, 1000.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = cartesian_position.encode();
+
+        assert_eq!(array, vec![0xff, 0xff, 0xfd, 0x00, 0x07, 0xd0]);
+
+        // New message
+        let (object, _length) = CartesianPosition::decode(&array).unwrap();
+
+        assert_eq!(object.get_x(), -1.5);
+        assert_eq!(object.get_y(), 1000.0);
+        assert_eq!(
+            cartesian_position.set_position(4194304.0, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
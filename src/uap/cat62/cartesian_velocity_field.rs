@@ -0,0 +1,96 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Calculated Track Velocity in Cartesian Co-ordinates
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CartesianVelocity {
+    vx: i16, // 2 bytes
+    vy: i16, // 2 bytes
+}
+
+/*
+* Implementation CartesianVelocity
+*/
+impl CartesianVelocity {
+    /*
+     * Set velocity in m/s
+     * -8192 <= vx, vy < 8192
+     */
+    pub fn set_velocity(&mut self, vx: f32, vy: f32) -> Result<(), CatError> {
+        let vx = from_signed(vx as f64, Self::FACTOR as f64, 16)? as i16;
+        let vy = from_signed(vy as f64, Self::FACTOR as f64, 16)? as i16;
+
+        self.vx = vx;
+        self.vy = vy;
+        Ok(())
+    }
+
+    /*
+     * Get velocity vx in m/s
+     */
+    pub fn get_vx(&self) -> f32 {
+        self.vx as f32 * Self::FACTOR
+    }
+
+    /*
+     * Get velocity vy in m/s
+     */
+    pub fn get_vy(&self) -> f32 {
+        self.vy as f32 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 0.25;
+}
+
+impl DataItem for CartesianVelocity {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let vx = i16::from_be_bytes([array[0], array[1]]);
+        let vy = i16::from_be_bytes([array[2], array[3]]);
+        Ok((CartesianVelocity { vx, vy }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.vx.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.vy.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cartesian_velocity() {
+        // Create message
+        let mut cartesian_velocity = CartesianVelocity::default();
+        cartesian_velocity.set_velocity(-0.25, 200.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = cartesian_velocity.encode();
+
+        assert_eq!(array, vec![0xff, 0xff, 0x03, 0x20]);
+
+        // New message
+        let (object, _length) = CartesianVelocity::decode(&array).unwrap();
+
+        assert_eq!(object.get_vx(), -0.25);
+        assert_eq!(object.get_vy(), 200.0);
+        assert_eq!(
+            cartesian_velocity.set_velocity(8192.0, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
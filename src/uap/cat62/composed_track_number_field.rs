@@ -0,0 +1,111 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Composed Track Number
+//
+// Extended data item of three byte units
+// | 24 .. 17           | 16 .. 2      | 1  | bit
+// | System Unit Ident. | Track Number | FX |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ComposedTrackNumber {
+    tracks: Vec<(u8, u16)>, // 3 bytes each
+}
+
+/*
+* Implementation ComposedTrackNumber
+*/
+impl ComposedTrackNumber {
+    /*
+     * Insert track number of a system unit, the first is the master track
+     * 0 <= number <= 32767
+     */
+    pub fn insert_track(&mut self, unit: u8, number: u16) -> Result<(), CatError> {
+        if number > 0x7fff {
+            return Err(CatError::ValueInvalid);
+        }
+        self.tracks.push((unit, number));
+        Ok(())
+    }
+
+    /*
+     * Get all track numbers with system unit identification
+     */
+    pub fn get_tracks(&self) -> Vec<(u8, u16)> {
+        self.tracks.clone()
+    }
+
+    /*
+     * Length of one track number.
+     */
+    pub const TRACK_LENGTH: usize = 3;
+}
+
+impl DataItem for ComposedTrackNumber {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut tracks = Vec::new();
+
+        loop {
+            let offset = tracks.len() * Self::TRACK_LENGTH;
+            let unit = array
+                .get(offset..offset + Self::TRACK_LENGTH)
+                .ok_or(CatError::SizeInvalid(0))?;
+            let number = u16::from_be_bytes([unit[1], unit[2]]);
+            tracks.push((unit[0], number >> 1));
+
+            // Check field extension
+            if number & 0x01 == 0 {
+                break;
+            }
+        }
+        let length = tracks.len() * Self::TRACK_LENGTH;
+        Ok((ComposedTrackNumber { tracks }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = Vec::new();
+
+        for (index, (unit, number)) in self.tracks.iter().enumerate() {
+            let fx = (index + 1 < self.tracks.len()) as u16;
+            vector.push(*unit);
+            vector.extend_from_slice(&((number << 1) | fx).to_be_bytes());
+        }
+        if vector.is_empty() {
+            // At least the master track is present
+            vector.extend_from_slice(&[0; Self::TRACK_LENGTH]);
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_composed_track_number() {
+        // Create message
+        let mut composed_track_number = ComposedTrackNumber::default();
+        composed_track_number.insert_track(1, 100).unwrap();
+        composed_track_number.insert_track(2, 32767).unwrap();
+
+        // Convert struct to byte stream
+        let array = composed_track_number.encode();
+
+        assert_eq!(array, vec![0x01, 0x00, 0xc9, 0x02, 0xff, 0xfe]);
+
+        // New message
+        let (object, length) = ComposedTrackNumber::decode(&array).unwrap();
+
+        assert_eq!(length, 6);
+        assert_eq!(object.get_tracks(), vec![(1, 100), (2, 32767)]);
+        assert_eq!(
+            ComposedTrackNumber::decode(&array[0..5]),
+            Err(CatError::SizeInvalid(0))
+        );
+        assert_eq!(
+            composed_track_number.insert_track(3, 32768),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
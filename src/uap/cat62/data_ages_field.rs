@@ -0,0 +1,129 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+
+// Track Data Ages
+//
+// Compound data item, ages of the data provided in 1/4 s
+// | MFL | MD1 | MD2 | MDA | MD4 | MD5 | MHG | FX |
+// | IAS | TAS | SAL | FSS | TID | COM | SAB | FX |
+// | ACS | BVR | GVR | RAN | TAR | TAN | GSP | FX |
+// | VUN | MET | EMC | POS | GAL | PUN | MB  | FX |
+// | IAR | MAC | BPS | spare ...             | FX |
+//
+
+/// Data of a track data age
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum DataAge {
+    MeasuredFlightLevel,
+    Mode1,
+    Mode2,
+    Mode3A,
+    Mode4,
+    Mode5,
+    MagneticHeading,
+    IndicatedAirspeed,
+    TrueAirspeed,
+    SelectedAltitude,
+    FinalStateSelectedAltitude,
+    TrajectoryIntent,
+    CommunicationsCapability,
+    AdsBStatus,
+    AcasResolution,
+    BarometricVerticalRate,
+    GeometricVerticalRate,
+    RollAngle,
+    TrackAngleRate,
+    TrackAngle,
+    GroundSpeed,
+    VelocityUncertainty,
+    MeteorologicalData,
+    EmitterCategory,
+    Position,
+    GeometricAltitude,
+    PositionUncertainty,
+    ModeSMbData,
+    IndicatedAirspeedData,
+    MachNumber,
+    BarometricPressureSetting,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrackDataAges {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation TrackDataAges
+*/
+impl TrackDataAges {
+    /*
+     * Set age in seconds
+     * 0 <= age < 64
+     */
+    pub fn set_age(&mut self, data: DataAge, age: f32) -> Result<(), CatError> {
+        let value = from_unsigned(age as f64, Self::FACTOR as f64, 8)? as u8;
+        self.field.set_subfield(data as usize, vec![value]);
+        Ok(())
+    }
+
+    /*
+     * Get age in seconds when present
+     */
+    pub fn get_age(&self, data: DataAge) -> Option<f32> {
+        let value = self.field.get_subfield(data as usize)?[0];
+        Some(value as f32 * Self::FACTOR)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 31] = [SubfieldLength::Fixed(1); 31];
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 4.0;
+}
+
+impl DataItem for TrackDataAges {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((TrackDataAges { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_data_ages() {
+        // Create message
+        let mut data_ages = TrackDataAges::default();
+        data_ages.set_age(DataAge::Mode3A, 2.0).unwrap();
+        data_ages
+            .set_age(DataAge::BarometricPressureSetting, 63.75)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = data_ages.encode();
+
+        assert_eq!(array, vec![0x11, 0x01, 0x01, 0x01, 0x20, 0x08, 0xff]);
+
+        // New message
+        let (object, length) = TrackDataAges::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_age(DataAge::Mode3A), Some(2.0));
+        assert_eq!(
+            object.get_age(DataAge::BarometricPressureSetting),
+            Some(63.75)
+        );
+        assert_eq!(object.get_age(DataAge::Position), None);
+    }
+}
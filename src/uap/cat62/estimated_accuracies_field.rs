@@ -0,0 +1,185 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+
+// Estimated Accuracies
+//
+// Compound data item, primary subfield followed by present subfields
+// | APC | COV | APW | AGA | ABA | ATV | AA | FX |
+// | ARC | spare ...                       | FX |
+//
+
+/// Subfields of estimated accuracies
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    CartesianPosition,
+    CovarianceXY,
+    WgsPosition,
+    GeometricAltitude,
+    BarometricAltitude,
+    Velocity,
+    Acceleration,
+    RateOfClimb,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct EstimatedAccuracies {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation EstimatedAccuracies
+*/
+impl EstimatedAccuracies {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set standard deviation of cartesian position in m
+     * 0 <= x, y < 32768
+     */
+    pub fn set_position_accuracy(&mut self, x: f32, y: f32) -> Result<(), CatError> {
+        let x = from_unsigned(x as f64, Self::FACTOR_POSITION as f64, 16)? as u16;
+        let y = from_unsigned(y as f64, Self::FACTOR_POSITION as f64, 16)? as u16;
+
+        let mut vector = x.to_be_bytes().to_vec();
+        vector.extend_from_slice(&y.to_be_bytes());
+        self.field
+            .set_subfield(Subfield::CartesianPosition as usize, vector);
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of cartesian position x and y in m
+     */
+    pub fn get_position_accuracy(&self) -> Option<(f32, f32)> {
+        let value = self.get_subfield(Subfield::CartesianPosition)?;
+        let x = u16::from_be_bytes([value[0], value[1]]) as f32 * Self::FACTOR_POSITION;
+        let y = u16::from_be_bytes([value[2], value[3]]) as f32 * Self::FACTOR_POSITION;
+        Some((x, y))
+    }
+
+    /*
+     * Set standard deviation of geometric altitude in ft
+     * 0 <= accuracy < 1600
+     */
+    pub fn set_geometric_altitude_accuracy(&mut self, accuracy: f32) -> Result<(), CatError> {
+        let value = from_unsigned(accuracy as f64, Self::FACTOR_ALTITUDE as f64, 8)? as u8;
+        self.field
+            .set_subfield(Subfield::GeometricAltitude as usize, vec![value]);
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of geometric altitude in ft
+     */
+    pub fn get_geometric_altitude_accuracy(&self) -> Option<f32> {
+        let value = self.get_subfield(Subfield::GeometricAltitude)?;
+        Some(value[0] as f32 * Self::FACTOR_ALTITUDE)
+    }
+
+    /*
+     * Set standard deviation of velocity in m/s
+     * 0 <= vx, vy < 64
+     */
+    pub fn set_velocity_accuracy(&mut self, vx: f32, vy: f32) -> Result<(), CatError> {
+        let vx = from_unsigned(vx as f64, Self::FACTOR_VELOCITY as f64, 8)? as u8;
+        let vy = from_unsigned(vy as f64, Self::FACTOR_VELOCITY as f64, 8)? as u8;
+        self.field
+            .set_subfield(Subfield::Velocity as usize, vec![vx, vy]);
+        Ok(())
+    }
+
+    /*
+     * Get standard deviation of velocity vx and vy in m/s
+     */
+    pub fn get_velocity_accuracy(&self) -> Option<(f32, f32)> {
+        let value = self.get_subfield(Subfield::Velocity)?;
+        Some((
+            value[0] as f32 * Self::FACTOR_VELOCITY,
+            value[1] as f32 * Self::FACTOR_VELOCITY,
+        ))
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 8] = [
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+    ];
+
+    /*
+     * Conversion factor of position.
+     */
+    const FACTOR_POSITION: f32 = 0.5;
+
+    /*
+     * Conversion factor of geometric altitude.
+     */
+    const FACTOR_ALTITUDE: f32 = 6.25;
+
+    /*
+     * Conversion factor of velocity.
+     */
+    const FACTOR_VELOCITY: f32 = 0.25;
+}
+
+impl DataItem for EstimatedAccuracies {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((EstimatedAccuracies { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_estimated_accuracies() {
+        // Create message
+        let mut estimated_accuracies = EstimatedAccuracies::default();
+        estimated_accuracies
+            .set_position_accuracy(50.0, 75.5)
+            .unwrap();
+        estimated_accuracies
+            .set_velocity_accuracy(1.0, 2.5)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = estimated_accuracies.encode();
+
+        assert_eq!(array, vec![0x84, 0x00, 0x64, 0x00, 0x97, 0x04, 0x0a]);
+
+        // New message
+        let (object, length) = EstimatedAccuracies::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_position_accuracy(), Some((50.0, 75.5)));
+        assert_eq!(object.get_velocity_accuracy(), Some((1.0, 2.5)));
+        assert_eq!(object.get_geometric_altitude_accuracy(), None);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+use crate::uap::data_source_field::DataSource;
+
+// Flight Plan Related Data
+//
+// Compound data item, primary subfield followed by present subfields
+// | TAG | CSN | IFI | FCT | TAC | WTC | DEP | FX |
+// | DST | RDS | CFL | CTL | TOD | AST | STS | FX |
+// | STD | STA | PEM | PEC | spare ...       | FX |
+//
+
+/// Subfields of flight plan related data
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    FppsIdentificationTag,
+    Callsign,
+    IfpsFlightId,
+    FlightCategory,
+    TypeOfAircraft,
+    WakeTurbulenceCategory,
+    DepartureAirport,
+    DestinationAirport,
+    RunwayDesignation,
+    ClearedFlightLevel,
+    ControlPosition,
+    TimeOfDepartureArrival,
+    AircraftStand,
+    StandStatus,
+    StandardInstrumentDeparture,
+    StandardInstrumentArrival,
+    PreEmergencyMode3A,
+    PreEmergencyCallsign,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct FlightPlanRelatedData {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation FlightPlanRelatedData
+*/
+impl FlightPlanRelatedData {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Set identification tag of the FPPS
+     */
+    pub fn set_tag(&mut self, tag: DataSource) {
+        self.field
+            .set_item(Subfield::FppsIdentificationTag as usize, &tag);
+    }
+
+    /*
+     * Get identification tag of the FPPS
+     */
+    pub fn get_tag(&self) -> Option<DataSource> {
+        self.field
+            .get_item(Subfield::FppsIdentificationTag as usize)
+    }
+
+    /*
+     * Set callsign, up to seven characters
+     */
+    pub fn set_callsign(&mut self, callsign: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::Callsign, callsign, 7)
+    }
+
+    /*
+     * Get callsign without trailing spaces
+     */
+    pub fn get_callsign(&self) -> Option<String> {
+        self.get_text(Subfield::Callsign)
+    }
+
+    /*
+     * Set ICAO type of aircraft, up to four characters
+     */
+    pub fn set_aircraft_type(&mut self, aircraft_type: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::TypeOfAircraft, aircraft_type, 4)
+    }
+
+    /*
+     * Get ICAO type of aircraft without trailing spaces
+     */
+    pub fn get_aircraft_type(&self) -> Option<String> {
+        self.get_text(Subfield::TypeOfAircraft)
+    }
+
+    /*
+     * Set wake turbulence category L, M, H or J
+     */
+    pub fn set_wake_turbulence(&mut self, category: char) -> Result<(), CatError> {
+        if !matches!(category, 'L' | 'M' | 'H' | 'J') {
+            return Err(CatError::ValueInvalid);
+        }
+        self.set_text(Subfield::WakeTurbulenceCategory, &category.to_string(), 1)
+    }
+
+    /*
+     * Get wake turbulence category
+     */
+    pub fn get_wake_turbulence(&self) -> Option<char> {
+        self.get_subfield(Subfield::WakeTurbulenceCategory)
+            .map(|value| value[0] as char)
+    }
+
+    /*
+     * Set ICAO departure airport, four characters
+     */
+    pub fn set_departure(&mut self, airport: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::DepartureAirport, airport, 4)
+    }
+
+    /*
+     * Get ICAO departure airport
+     */
+    pub fn get_departure(&self) -> Option<String> {
+        self.get_text(Subfield::DepartureAirport)
+    }
+
+    /*
+     * Set ICAO destination airport, four characters
+     */
+    pub fn set_destination(&mut self, airport: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::DestinationAirport, airport, 4)
+    }
+
+    /*
+     * Get ICAO destination airport
+     */
+    pub fn get_destination(&self) -> Option<String> {
+        self.get_text(Subfield::DestinationAirport)
+    }
+
+    /*
+     * Set runway designation, up to three characters
+     */
+    pub fn set_runway(&mut self, runway: &str) -> Result<(), CatError> {
+        self.set_text(Subfield::RunwayDesignation, runway, 3)
+    }
+
+    /*
+     * Get runway designation without trailing spaces
+     */
+    pub fn get_runway(&self) -> Option<String> {
+        self.get_text(Subfield::RunwayDesignation)
+    }
+
+    /*
+     * Set current cleared flight level in FL
+     * 0 <= level < 16384
+     */
+    pub fn set_cleared_flight_level(&mut self, level: f32) -> Result<(), CatError> {
+        let value = from_unsigned(level as f64, Self::FACTOR as f64, 16)? as u16;
+        self.field.set_subfield(
+            Subfield::ClearedFlightLevel as usize,
+            value.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get current cleared flight level in FL
+     */
+    pub fn get_cleared_flight_level(&self) -> Option<f32> {
+        let value = self.get_subfield(Subfield::ClearedFlightLevel)?;
+        Some(u16::from_be_bytes([value[0], value[1]]) as f32 * Self::FACTOR)
+    }
+
+    /*
+     * Set ASCII text subfield, padded with spaces
+     */
+    fn set_text(&mut self, subfield: Subfield, text: &str, length: usize) -> Result<(), CatError> {
+        if text.len() > length || !text.chars().all(|c| c.is_ascii_graphic() || c == ' ') {
+            return Err(CatError::ValueInvalid);
+        }
+        let data = format!("{:<length$}", text).into_bytes();
+        self.field.set_subfield(subfield as usize, data);
+        Ok(())
+    }
+
+    /*
+     * Get ASCII text subfield without trailing spaces
+     */
+    fn get_text(&self, subfield: Subfield) -> Option<String> {
+        let value = self.get_subfield(subfield)?;
+        Some(String::from_utf8_lossy(value).trim_end().to_string())
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 18] = [
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(3),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Repetitive(4),
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(7),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(7),
+    ];
+
+    /*
+     * Conversion factor of cleared flight level.
+     */
+    const FACTOR: f32 = 1.0 / 4.0;
+}
+
+impl DataItem for FlightPlanRelatedData {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((FlightPlanRelatedData { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_flight_plan_data() {
+        // Create message
+        let mut flight_plan_data = FlightPlanRelatedData::default();
+        flight_plan_data.set_callsign("DLH4AB").unwrap();
+        flight_plan_data.set_wake_turbulence('M').unwrap();
+        flight_plan_data.set_destination("EDDF").unwrap();
+        flight_plan_data.set_cleared_flight_level(350.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = flight_plan_data.encode();
+
+        assert_eq!(&array[0..2], &[0x45, 0xa0]);
+        assert_eq!(&array[2..9], b"DLH4AB ");
+        assert_eq!(array.len(), 2 + 7 + 1 + 4 + 2);
+
+        // New message
+        let (object, length) = FlightPlanRelatedData::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, flight_plan_data);
+        assert_eq!(object.get_callsign(), Some("DLH4AB".to_string()));
+        assert_eq!(object.get_wake_turbulence(), Some('M'));
+        assert_eq!(object.get_destination(), Some("EDDF".to_string()));
+        assert_eq!(object.get_cleared_flight_level(), Some(350.0));
+        assert_eq!(object.get_departure(), None);
+        assert_eq!(
+            flight_plan_data.set_callsign("DLH4ABCD"),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
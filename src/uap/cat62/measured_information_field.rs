@@ -0,0 +1,219 @@
+use crate::category::CatError;
+use crate::uap::cat48::target_report_descriptor_field::DetectionType;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_signed, DataItem};
+use crate::uap::data_source_field::DataSource;
+use crate::uap::flight_level_field::FlightLevel;
+use crate::uap::mode_3a_code_field::Mode3ACode;
+use crate::uap::polar_position_field::PolarPosition;
+
+// Measured Information
+//
+// Compound data item, primary subfield followed by present subfields
+// | SID | POS | HEI | MDC | MDA | TYP | spare | FX |
+//
+// Report Type
+// | 8 7 6 |  5  |  4  |  3  | 2 1   | bit
+// |  TYP  | SIM | RAB | TST | spare |
+//
+
+/// Subfields of measured information
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    SensorIdentification,
+    MeasuredPosition,
+    MeasuredHeight,
+    ModeCCode,
+    Mode3ACode,
+    ReportType,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct MeasuredInformation {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation MeasuredInformation
+*/
+impl MeasuredInformation {
+    /*
+     * Set sensor identification
+     */
+    pub fn set_sensor(&mut self, sensor: DataSource) {
+        self.field
+            .set_item(Subfield::SensorIdentification as usize, &sensor);
+    }
+
+    /*
+     * Get sensor identification
+     */
+    pub fn get_sensor(&self) -> Option<DataSource> {
+        self.field.get_item(Subfield::SensorIdentification as usize)
+    }
+
+    /*
+     * Set measured position in polar co-ordinates
+     */
+    pub fn set_position(&mut self, position: PolarPosition) {
+        self.field
+            .set_item(Subfield::MeasuredPosition as usize, &position);
+    }
+
+    /*
+     * Get measured position in polar co-ordinates
+     */
+    pub fn get_position(&self) -> Option<PolarPosition> {
+        self.field.get_item(Subfield::MeasuredPosition as usize)
+    }
+
+    /*
+     * Set measured 3D height in ft
+     * -819200 <= height < 819200
+     */
+    pub fn set_height(&mut self, height: f32) -> Result<(), CatError> {
+        let value = from_signed(height as f64, Self::FACTOR as f64, 16)? as u16;
+        self.field.set_subfield(
+            Subfield::MeasuredHeight as usize,
+            value.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    /*
+     * Get measured 3D height in ft
+     */
+    pub fn get_height(&self) -> Option<f32> {
+        let value = self.field.get_subfield(Subfield::MeasuredHeight as usize)?;
+        Some(i16::from_be_bytes([value[0], value[1]]) as f32 * Self::FACTOR)
+    }
+
+    /*
+     * Set last measured Mode C code
+     */
+    pub fn set_mode_c_code(&mut self, mode_c_code: FlightLevel) {
+        self.field
+            .set_item(Subfield::ModeCCode as usize, &mode_c_code);
+    }
+
+    /*
+     * Get last measured Mode C code
+     */
+    pub fn get_mode_c_code(&self) -> Option<FlightLevel> {
+        self.field.get_item(Subfield::ModeCCode as usize)
+    }
+
+    /*
+     * Set last measured Mode 3/A code
+     */
+    pub fn set_mode_3a_code(&mut self, mode_3a_code: Mode3ACode) {
+        self.field
+            .set_item(Subfield::Mode3ACode as usize, &mode_3a_code);
+    }
+
+    /*
+     * Get last measured Mode 3/A code
+     */
+    pub fn get_mode_3a_code(&self) -> Option<Mode3ACode> {
+        self.field.get_item(Subfield::Mode3ACode as usize)
+    }
+
+    /*
+     * Set report type with simulated, field monitor and test flags
+     */
+    pub fn set_report_type(
+        &mut self,
+        typ: DetectionType,
+        simulated: bool,
+        field_monitor: bool,
+        test: bool,
+    ) {
+        let value = ((typ as u8) << 5)
+            | ((simulated as u8) << 4)
+            | ((field_monitor as u8) << 3)
+            | ((test as u8) << 2);
+        self.field
+            .set_subfield(Subfield::ReportType as usize, vec![value]);
+    }
+
+    /*
+     * Get report type
+     */
+    pub fn get_report_type(&self) -> Option<DetectionType> {
+        let value = self.field.get_subfield(Subfield::ReportType as usize)?[0];
+        let typ = match value >> 5 {
+            0 => DetectionType::NoDetection,
+            1 => DetectionType::SinglePsr,
+            2 => DetectionType::SingleSsr,
+            3 => DetectionType::SsrPsr,
+            4 => DetectionType::SingleModeSAllCall,
+            5 => DetectionType::SingleModeSRollCall,
+            6 => DetectionType::ModeSAllCallPsr,
+            _ => DetectionType::ModeSRollCallPsr,
+        };
+        Some(typ)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 6] = [
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+    ];
+
+    /*
+     * Conversion factor of height.
+     */
+    const FACTOR: f32 = 25.0;
+}
+
+impl DataItem for MeasuredInformation {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((MeasuredInformation { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_measured_information() {
+        // Create message
+        let mut sensor = DataSource::default();
+        sensor.set_source_id_sac(0x19);
+        sensor.set_source_id_sic(0x0e);
+        let mut position = PolarPosition::default();
+        position.set_rho(10.0).unwrap();
+        position.set_theta(45.0).unwrap();
+
+        let mut measured_information = MeasuredInformation::default();
+        measured_information.set_sensor(sensor);
+        measured_information.set_position(position);
+        measured_information.set_report_type(DetectionType::SsrPsr, false, false, true);
+
+        // Convert struct to byte stream
+        let array = measured_information.encode();
+
+        assert_eq!(array, vec![0xc4, 0x19, 0x0e, 0x0a, 0x00, 0x20, 0x00, 0x64]);
+
+        // New message
+        let (object, length) = MeasuredInformation::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_sensor(), Some(sensor));
+        assert_eq!(object.get_position(), Some(position));
+        assert_eq!(object.get_report_type(), Some(DetectionType::SsrPsr));
+        assert_eq!(object.get_height(), None);
+    }
+}
@@ -0,0 +1,17 @@
+pub mod aircraft_derived_data_field;
+pub mod altitude_field;
+pub mod cartesian_acceleration_field;
+pub mod cartesian_position_field;
+pub mod cartesian_velocity_field;
+pub mod composed_track_number_field;
+pub mod data_ages_field;
+pub mod estimated_accuracies_field;
+pub mod flight_plan_data_field;
+pub mod measured_information_field;
+pub mod mode_5_data_field;
+pub mod mode_of_movement_field;
+pub mod target_size_field;
+pub mod track_mode_3a_code_field;
+pub mod track_status_field;
+pub mod update_ages_field;
+pub mod wgs_position_field;
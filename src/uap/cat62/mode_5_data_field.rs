@@ -0,0 +1,103 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::DataItem;
+
+// Mode 5 Data Reports and Extended Mode 1 Code
+//
+// Compound data item, primary subfield followed by present subfields
+// | SUM | PMN | POS | GA | EM1 | TOS | XP | FX |
+//
+
+/// Subfields of Mode 5 data reports
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Subfield {
+    Summary,
+    PinNationalOriginMission,
+    Position,
+    GnssAltitude,
+    ExtendedMode1Code,
+    TimeOffset,
+    XPulsePresence,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Mode5Data {
+    field: CompoundField, // 1+ bytes
+}
+
+/*
+* Implementation Mode5Data
+*/
+impl Mode5Data {
+    /*
+     * Set raw subfield bytes, checks the byte length of the subfield
+     */
+    pub fn set_subfield(&mut self, subfield: Subfield, data: Vec<u8>) -> Result<(), CatError> {
+        self.field
+            .set_checked_subfield(&Self::LAYOUT, subfield as usize, data)
+    }
+
+    /*
+     * Get raw subfield bytes when present
+     */
+    pub fn get_subfield(&self, subfield: Subfield) -> Option<&[u8]> {
+        self.field.get_subfield(subfield as usize)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 7] = [
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(4),
+        SubfieldLength::Fixed(6),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+    ];
+}
+
+impl DataItem for Mode5Data {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((Mode5Data { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mode_5_data() {
+        // Create message
+        let mut mode_5_data = Mode5Data::default();
+        mode_5_data
+            .set_subfield(Subfield::Summary, vec![0x80])
+            .unwrap();
+        mode_5_data
+            .set_subfield(Subfield::ExtendedMode1Code, vec![0x01, 0x23])
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = mode_5_data.encode();
+
+        assert_eq!(array, vec![0x88, 0x80, 0x01, 0x23]);
+
+        // New message
+        let (object, length) = Mode5Data::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_subfield(Subfield::Summary), Some(&[0x80][..]));
+        assert_eq!(object.get_subfield(Subfield::Position), None);
+        assert_eq!(
+            mode_5_data.set_subfield(Subfield::Position, vec![0x00]),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Mode of Movement
+//
+// | 8 7   | 6 5  | 4 3  |  2  |   1   | bit
+// | TRANS | LONG | VERT | ADF | spare |
+//
+
+/// Transversal acceleration
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Transversal {
+    ConstantCourse,
+    RightTurn,
+    LeftTurn,
+    Undetermined,
+}
+
+/// Longitudinal acceleration
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Longitudinal {
+    ConstantSpeed,
+    IncreasingSpeed,
+    DecreasingSpeed,
+    Undetermined,
+}
+
+/// Vertical rate
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Vertical {
+    Level,
+    Climb,
+    Descent,
+    Undetermined,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ModeOfMovement {
+    movement: u8, // 1 byte
+}
+
+/*
+* Implementation ModeOfMovement
+*/
+impl ModeOfMovement {
+    /*
+     * Set transversal acceleration
+     */
+    pub fn set_transversal(&mut self, transversal: Transversal) {
+        self.movement = (self.movement & 0x3f) | ((transversal as u8) << 6);
+    }
+
+    /*
+     * Get transversal acceleration
+     */
+    pub fn get_transversal(&self) -> Transversal {
+        match self.movement >> 6 {
+            0 => Transversal::ConstantCourse,
+            1 => Transversal::RightTurn,
+            2 => Transversal::LeftTurn,
+            _ => Transversal::Undetermined,
+        }
+    }
+
+    /*
+     * Set longitudinal acceleration
+     */
+    pub fn set_longitudinal(&mut self, longitudinal: Longitudinal) {
+        self.movement = (self.movement & 0xcf) | ((longitudinal as u8) << 4);
+    }
+
+    /*
+     * Get longitudinal acceleration
+     */
+    pub fn get_longitudinal(&self) -> Longitudinal {
+        match (self.movement >> 4) & 0x03 {
+            0 => Longitudinal::ConstantSpeed,
+            1 => Longitudinal::IncreasingSpeed,
+            2 => Longitudinal::DecreasingSpeed,
+            _ => Longitudinal::Undetermined,
+        }
+    }
+
+    /*
+     * Set vertical rate
+     */
+    pub fn set_vertical(&mut self, vertical: Vertical) {
+        self.movement = (self.movement & 0xf3) | ((vertical as u8) << 2);
+    }
+
+    /*
+     * Get vertical rate
+     */
+    pub fn get_vertical(&self) -> Vertical {
+        match (self.movement >> 2) & 0x03 {
+            0 => Vertical::Level,
+            1 => Vertical::Climb,
+            2 => Vertical::Descent,
+            _ => Vertical::Undetermined,
+        }
+    }
+
+    /*
+     * Set altitude discrepancy flag
+     */
+    pub fn set_altitude_discrepancy(&mut self, value: bool) {
+        self.movement = (self.movement & 0xfd) | ((value as u8) << 1);
+    }
+
+    /*
+     * Get altitude discrepancy flag
+     */
+    pub fn get_altitude_discrepancy(&self) -> bool {
+        (self.movement >> 1) & 0x01 == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ModeOfMovement {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [movement] = fixed_array(array)?;
+        Ok((ModeOfMovement { movement }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.movement]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mode_of_movement() {
+        // Create message
+        let mut mode_of_movement = ModeOfMovement::default();
+        mode_of_movement.set_transversal(Transversal::LeftTurn);
+        mode_of_movement.set_longitudinal(Longitudinal::IncreasingSpeed);
+        mode_of_movement.set_vertical(Vertical::Descent);
+        mode_of_movement.set_altitude_discrepancy(true);
+
+        // Convert struct to byte stream
+        let array = mode_of_movement.encode();
+
+        assert_eq!(array, vec![0x9a]);
+
+        // New message
+        let (object, _length) = ModeOfMovement::decode(&array).unwrap();
+
+        assert_eq!(object.get_transversal(), Transversal::LeftTurn);
+        assert_eq!(object.get_longitudinal(), Longitudinal::IncreasingSpeed);
+        assert_eq!(object.get_vertical(), Vertical::Descent);
+        assert!(object.get_altitude_discrepancy());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::category::CatError;
+use crate::uap::data_item::{from_unsigned, DataItem};
+use crate::uap::extended_field::ExtendedField;
+
+// Target Size and Orientation
+//
+// | 8 .. 2      | 1  | bit
+// | LENGTH      | FX |
+// | ORIENTATION | FX |
+// | WIDTH       | FX |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TargetSize {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TargetSize
+*/
+impl TargetSize {
+    /*
+     * Set length in m
+     * 0 <= length <= 127
+     */
+    pub fn set_length(&mut self, length: u8) -> Result<(), CatError> {
+        if length > 0x7f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(0, 8, 7, length);
+        Ok(())
+    }
+
+    /*
+     * Get length in m
+     */
+    pub fn get_length(&self) -> u8 {
+        self.field.get_bits(0, 8, 7)
+    }
+
+    /*
+     * Set orientation in degree
+     * 0 <= orientation < 360
+     */
+    pub fn set_orientation(&mut self, orientation: f32) -> Result<(), CatError> {
+        let value = from_unsigned(orientation as f64, Self::FACTOR as f64, 7)?;
+        self.field.set_bits(1, 8, 7, value as u8);
+        Ok(())
+    }
+
+    /*
+     * Get orientation in degree when present
+     */
+    pub fn get_orientation(&self) -> Option<f32> {
+        if self.field.len() < 2 {
+            return None;
+        }
+        Some(self.field.get_bits(1, 8, 7) as f32 * Self::FACTOR)
+    }
+
+    /*
+     * Set width in m
+     * 0 <= width <= 127
+     */
+    pub fn set_width(&mut self, width: u8) -> Result<(), CatError> {
+        if width > 0x7f {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(2, 8, 7, width);
+        Ok(())
+    }
+
+    /*
+     * Get width in m when present
+     */
+    pub fn get_width(&self) -> Option<u8> {
+        if self.field.len() < 3 {
+            return None;
+        }
+        Some(self.field.get_bits(2, 8, 7))
+    }
+
+    /*
+     * Conversion factor of orientation.
+     */
+    const FACTOR: f32 = 360.0 / 128.0;
+}
+
+impl DataItem for TargetSize {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TargetSize { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_size() {
+        // Create message
+        let mut target_size = TargetSize::default();
+        target_size.set_length(70).unwrap();
+        target_size.set_orientation(90.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = target_size.encode();
+
+        assert_eq!(array, vec![0x8d, 0x40]);
+
+        // New message
+        let (object, length) = TargetSize::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_length(), 70);
+        assert_eq!(object.get_orientation(), Some(90.0));
+        assert_eq!(object.get_width(), None);
+        assert_eq!(target_size.set_width(128), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,92 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Track Mode 3/A Code
+//
+// | 16 15 | 14 | 13    | 12 .. 1               | bit
+// | spare | CH | spare | A4 A2 A1 B4 .. D2 D1  |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrackMode3ACode {
+    code: u16, // 2 bytes
+}
+
+/*
+* Implementation TrackMode3ACode
+*/
+impl TrackMode3ACode {
+    /*
+     * Set code as octal number
+     * 0o0000 <= code <= 0o7777
+     */
+    pub fn set_code(&mut self, code: u16) -> Result<(), CatError> {
+        if code > 0o7777 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.code = set_bits(self.code as u32, 12, 12, code as u32) as u16;
+        Ok(())
+    }
+
+    /*
+     * Get code as octal number
+     */
+    pub fn get_code(&self) -> u16 {
+        get_bits(self.code as u32, 12, 12) as u16
+    }
+
+    /*
+     * Set code changed
+     */
+    pub fn set_changed(&mut self, value: bool) {
+        self.code = set_bits(self.code as u32, 14, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get code changed
+     */
+    pub fn get_changed(&self) -> bool {
+        get_bits(self.code as u32, 14, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TrackMode3ACode {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let code = u16::from_be_bytes(fixed_array(array)?);
+        Ok((TrackMode3ACode { code }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.code.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_mode_3a_code() {
+        // Create message
+        let mut track_mode_3a_code = TrackMode3ACode::default();
+        track_mode_3a_code.set_code(0o7500).unwrap();
+        track_mode_3a_code.set_changed(true);
+
+        // Convert struct to byte stream
+        let array = track_mode_3a_code.encode();
+
+        assert_eq!(array, vec![0x2f, 0x40]);
+
+        // New message
+        let (object, _length) = TrackMode3ACode::decode(&array).unwrap();
+
+        assert_eq!(object.get_code(), 0o7500);
+        assert!(object.get_changed());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Track Status
+//
+// |  8   |  7   |  6   | 5 4 3 |  2   | 1  | bit
+// | MON  | SPI  | MRH  |  SRC  | CNF  | FX |
+// | SIM  | TSE  | TSB  |  FPC  | AFF  | STP | KOS | FX |
+// | AMA  | MD4  |  ME  |  MI   | MD5  | FX |
+// | CST  | PSR  | SSR  |  MDS  | ADS  | SUC | AAC | FX |
+// | SDS  | EMS  | PFT  | FPLT  | FX   |
+// | DUPT | DUPF | DUPM |  SFC  | IDD  | IEC | MLAT | FX |
+//
+
+/// Single bit flags of the track status
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum TrackFlag {
+    /// Monosensor track
+    Mono,
+    /// SPI present in the last report
+    Spi,
+    /// Geometric altitude more reliable than barometric
+    MostReliableHeight,
+    /// Tentative track
+    Tentative,
+    /// Simulated track
+    Simulated,
+    /// Last message transmitted for the track
+    LastMessage,
+    /// First message transmitted for the track
+    FirstMessage,
+    /// Flight plan correlated
+    FlightPlanCorrelated,
+    /// ADS-B data inconsistent with other surveillance information
+    AdsBInconsistent,
+    /// Slave track promotion
+    SlaveTrackPromotion,
+    /// Background service used
+    BackgroundService,
+    /// Track resulting from amalgamation process
+    Amalgamation,
+    /// Military emergency
+    MilitaryEmergency,
+    /// Military identification
+    MilitaryIdentification,
+    /// Coasted track, no update from any sensor
+    Coasted,
+    /// Age of the last received PSR track update is higher than the system threshold
+    PsrAged,
+    /// Age of the last received SSR track update is higher than the system threshold
+    SsrAged,
+    /// Age of the last received Mode S track update is higher than the system threshold
+    ModeSAged,
+    /// Age of the last received ADS-B track update is higher than the system threshold
+    AdsBAged,
+    /// Special used code
+    SpecialUsedCode,
+    /// Assigned code conflict
+    AssignedCodeConflict,
+    /// Potential false track
+    PotentialFalseTrack,
+    /// Track created or updated with flight plan data
+    FlightPlanTrack,
+    /// Duplicate Mode 3/A code
+    DuplicateMode3A,
+    /// Duplicate flight plan
+    DuplicateFlightPlan,
+    /// Duplicate flight plan due to manual correlation
+    DuplicateManual,
+    /// Surface target
+    Surface,
+    /// Duplicate flight identification
+    DuplicateIdentification,
+    /// Inconsistent emergency code
+    InconsistentEmergency,
+    /// Age of the last received MLAT track update is higher than the system threshold
+    MlatAged,
+}
+
+/// Source of calculated track altitude
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum AltitudeSource {
+    NoSource,
+    Gnss,
+    Radar3D,
+    Triangulation,
+    HeightFromCoverage,
+    SpeedLookUpTable,
+    DefaultHeight,
+    Multilateration,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TrackStatus {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation TrackStatus
+*/
+impl TrackStatus {
+    /*
+     * Set single bit flag
+     */
+    pub fn set_flag(&mut self, flag: TrackFlag, value: bool) {
+        let (octet, bit) = Self::flag_position(flag);
+        self.field.set_bit(octet, bit, value);
+    }
+
+    /*
+     * Get single bit flag
+     */
+    pub fn get_flag(&self, flag: TrackFlag) -> bool {
+        let (octet, bit) = Self::flag_position(flag);
+        self.field.get_bit(octet, bit)
+    }
+
+    /*
+     * Set source of calculated track altitude
+     */
+    pub fn set_altitude_source(&mut self, source: AltitudeSource) {
+        self.field.set_bits(0, 5, 3, source as u8);
+    }
+
+    /*
+     * Get source of calculated track altitude
+     */
+    pub fn get_altitude_source(&self) -> AltitudeSource {
+        match self.field.get_bits(0, 5, 3) {
+            0 => AltitudeSource::NoSource,
+            1 => AltitudeSource::Gnss,
+            2 => AltitudeSource::Radar3D,
+            3 => AltitudeSource::Triangulation,
+            4 => AltitudeSource::HeightFromCoverage,
+            5 => AltitudeSource::SpeedLookUpTable,
+            6 => AltitudeSource::DefaultHeight,
+            _ => AltitudeSource::Multilateration,
+        }
+    }
+
+    /*
+     * Set emergency status indication
+     * 0 <= emergency <= 7
+     */
+    pub fn set_emergency(&mut self, emergency: u8) -> Result<(), CatError> {
+        if emergency > 7 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.field.set_bits(4, 6, 3, emergency);
+        Ok(())
+    }
+
+    /*
+     * Get emergency status indication
+     */
+    pub fn get_emergency(&self) -> u8 {
+        self.field.get_bits(4, 6, 3)
+    }
+
+    /*
+     * Octet and bit position of a flag
+     */
+    fn flag_position(flag: TrackFlag) -> (usize, u8) {
+        match flag {
+            TrackFlag::Mono => (0, 8),
+            TrackFlag::Spi => (0, 7),
+            TrackFlag::MostReliableHeight => (0, 6),
+            TrackFlag::Tentative => (0, 2),
+            TrackFlag::Simulated => (1, 8),
+            TrackFlag::LastMessage => (1, 7),
+            TrackFlag::FirstMessage => (1, 6),
+            TrackFlag::FlightPlanCorrelated => (1, 5),
+            TrackFlag::AdsBInconsistent => (1, 4),
+            TrackFlag::SlaveTrackPromotion => (1, 3),
+            TrackFlag::BackgroundService => (1, 2),
+            TrackFlag::Amalgamation => (2, 8),
+            TrackFlag::MilitaryEmergency => (2, 5),
+            TrackFlag::MilitaryIdentification => (2, 4),
+            TrackFlag::Coasted => (3, 8),
+            TrackFlag::PsrAged => (3, 7),
+            TrackFlag::SsrAged => (3, 6),
+            TrackFlag::ModeSAged => (3, 5),
+            TrackFlag::AdsBAged => (3, 4),
+            TrackFlag::SpecialUsedCode => (3, 3),
+            TrackFlag::AssignedCodeConflict => (3, 2),
+            TrackFlag::PotentialFalseTrack => (4, 3),
+            TrackFlag::FlightPlanTrack => (4, 2),
+            TrackFlag::DuplicateMode3A => (5, 8),
+            TrackFlag::DuplicateFlightPlan => (5, 7),
+            TrackFlag::DuplicateManual => (5, 6),
+            TrackFlag::Surface => (5, 5),
+            TrackFlag::DuplicateIdentification => (5, 4),
+            TrackFlag::InconsistentEmergency => (5, 3),
+            TrackFlag::MlatAged => (5, 2),
+        }
+    }
+}
+
+impl DataItem for TrackStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((TrackStatus { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_status() {
+        // Create message
+        let mut track_status = TrackStatus::default();
+        track_status.set_flag(TrackFlag::Mono, true);
+        track_status.set_altitude_source(AltitudeSource::Radar3D);
+        track_status.set_flag(TrackFlag::Coasted, true);
+
+        // Convert struct to byte stream
+        let array = track_status.encode();
+
+        assert_eq!(array, vec![0x89, 0x01, 0x01, 0x80]);
+
+        // New message
+        let (object, length) = TrackStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 4);
+        assert!(object.get_flag(TrackFlag::Mono));
+        assert!(object.get_flag(TrackFlag::Coasted));
+        assert!(!object.get_flag(TrackFlag::Surface));
+        assert_eq!(object.get_altitude_source(), AltitudeSource::Radar3D);
+        assert_eq!(track_status.set_emergency(8), Err(CatError::ValueInvalid));
+    }
+}
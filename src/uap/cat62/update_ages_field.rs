@@ -0,0 +1,129 @@
+use crate::category::CatError;
+use crate::uap::compound_field::{CompoundField, SubfieldLength};
+use crate::uap::data_item::{from_unsigned, DataItem};
+
+// System Track Update Ages
+//
+// Compound data item, ages of the last sensor updates in 1/4 s
+// | TRK | PSR | SSR | MDS | ADS | ES | VDL | FX |
+// | UAT | LOP | MLT | spare ...             | FX |
+//
+
+/// Sensor of a track update age
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum UpdateAge {
+    Track,
+    Psr,
+    Ssr,
+    ModeS,
+    AdsC,
+    AdsBExtendedSquitter,
+    AdsBVdl,
+    AdsBUat,
+    Loran,
+    Multilateration,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SystemTrackUpdateAges {
+    field: CompoundField, // 2+ bytes
+}
+
+/*
+* Implementation SystemTrackUpdateAges
+*/
+impl SystemTrackUpdateAges {
+    /*
+     * Set age in seconds
+     * 0 <= age < 64, ADS-C age 0 <= age < 16384
+     */
+    pub fn set_age(&mut self, sensor: UpdateAge, age: f32) -> Result<(), CatError> {
+        let value = if sensor == UpdateAge::AdsC {
+            (from_unsigned(age as f64, Self::FACTOR as f64, 16)? as u16)
+                .to_be_bytes()
+                .to_vec()
+        } else {
+            vec![from_unsigned(age as f64, Self::FACTOR as f64, 8)? as u8]
+        };
+        self.field.set_subfield(sensor as usize, value);
+        Ok(())
+    }
+
+    /*
+     * Get age in seconds when present
+     */
+    pub fn get_age(&self, sensor: UpdateAge) -> Option<f32> {
+        let value = self
+            .field
+            .get_subfield(sensor as usize)?
+            .iter()
+            .fold(0_u32, |value, byte| (value << 8) | *byte as u32);
+        Some(value as f32 * Self::FACTOR)
+    }
+
+    /*
+     * Subfield lengths.
+     */
+    const LAYOUT: [SubfieldLength; 10] = [
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+    ];
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 4.0;
+}
+
+impl DataItem for SystemTrackUpdateAges {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = CompoundField::decode(array, &Self::LAYOUT)?;
+        Ok((SystemTrackUpdateAges { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_update_ages() {
+        // Create message
+        let mut update_ages = SystemTrackUpdateAges::default();
+        update_ages.set_age(UpdateAge::Track, 1.5).unwrap();
+        update_ages.set_age(UpdateAge::AdsC, 100.0).unwrap();
+        update_ages
+            .set_age(UpdateAge::Multilateration, 0.25)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = update_ages.encode();
+
+        assert_eq!(array, vec![0x89, 0x20, 0x06, 0x01, 0x90, 0x01]);
+
+        // New message
+        let (object, length) = SystemTrackUpdateAges::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_age(UpdateAge::Track), Some(1.5));
+        assert_eq!(object.get_age(UpdateAge::AdsC), Some(100.0));
+        assert_eq!(object.get_age(UpdateAge::Multilateration), Some(0.25));
+        assert_eq!(object.get_age(UpdateAge::Psr), None);
+        assert_eq!(
+            update_ages.set_age(UpdateAge::Psr, 64.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
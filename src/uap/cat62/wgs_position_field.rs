@@ -0,0 +1,103 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Calculated Position in WGS-84 Co-ordinates
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct WgsPosition {
+    latitude: i32,  // 4 bytes
+    longitude: i32, // 4 bytes
+}
+
+/*
+* Implementation WgsPosition
+*/
+impl WgsPosition {
+    /*
+     * Set latitude and longitude in degree
+     * -90 <= latitude <= 90
+     * -180 <= longitude < 180
+     */
+    pub fn set_lat_lon(&mut self, latitude: f64, longitude: f64) -> Result<(), CatError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..180.0).contains(&longitude) {
+            return Err(CatError::ValueInvalid);
+        }
+        self.latitude = from_signed(latitude, Self::FACTOR, 32)? as i32;
+        self.longitude = from_signed(longitude, Self::FACTOR, 32)? as i32;
+        Ok(())
+    }
+
+    /*
+     * Get latitude in degree
+     */
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude as f64 * Self::FACTOR
+    }
+
+    /*
+     * Get longitude in degree
+     */
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude as f64 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f64 = 180.0 / 33554432.0;
+}
+
+impl DataItem for WgsPosition {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 8] = fixed_array(array)?;
+        let latitude = i32::from_be_bytes([array[0], array[1], array[2], array[3]]);
+        let longitude = i32::from_be_bytes([array[4], array[5], array[6], array[7]]);
+        Ok((
+            WgsPosition {
+                latitude,
+                longitude,
+            },
+            Self::MESSAGE_LENGTH,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.latitude.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.longitude.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_wgs_position() {
+        // Create message
+        let mut wgs_position = WgsPosition::default();
+        wgs_position.set_lat_lon(45.0, -90.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = wgs_position.encode();
+
+        assert_eq!(array, vec![0x00, 0x80, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00]);
+
+        // New message
+        let (object, _length) = WgsPosition::decode(&array).unwrap();
+
+        assert_eq!(object.get_latitude(), 45.0);
+        assert_eq!(object.get_longitude(), -90.0);
+        assert_eq!(
+            wgs_position.set_lat_lon(90.5, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
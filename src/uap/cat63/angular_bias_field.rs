@@ -0,0 +1,79 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Azimuth or Elevation Bias
+//
+// Used for SSR/Mode S azimuth, PSR azimuth and PSR elevation bias in two's complement
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct AngularBias {
+    bias: i16, // 2 bytes
+}
+
+/*
+* Implementation AngularBias
+*/
+impl AngularBias {
+    /*
+     * Set bias in degree
+     * -180 <= bias < 180
+     */
+    pub fn set_bias(&mut self, bias: f64) -> Result<(), CatError> {
+        self.bias = from_signed(bias, Self::FACTOR, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get bias in degree
+     */
+    pub fn get_bias(&self) -> f64 {
+        self.bias as f64 * Self::FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f64 = 360.0 / 65536.0;
+}
+
+impl DataItem for AngularBias {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let bias = i16::from_be_bytes(fixed_array(array)?);
+        Ok((AngularBias { bias }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.bias.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_angular_bias() {
+        // Create message
+        let mut angular_bias = AngularBias::default();
+        angular_bias.set_bias(-1.40625).unwrap();
+
+        // Convert struct to byte stream
+        let array = angular_bias.encode();
+
+        assert_eq!(array, vec![0xff, 0x00]);
+
+        // New message
+        let (object, length) = AngularBias::decode(&array).unwrap();
+
+        assert_eq!(length, AngularBias::MESSAGE_LENGTH);
+        assert_eq!(object.get_bias(), -1.40625);
+        assert_eq!(angular_bias.set_bias(180.0), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod angular_bias_field;
+pub mod range_bias_field;
+pub mod sensor_configuration_field;
+pub mod time_stamping_bias_field;
@@ -0,0 +1,111 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, DataItem};
+
+// Range Gain and Bias
+//
+// Used for SSR/Mode S and PSR, both in two's complement
+// | 32 .. 17 | 16 .. 1 | bit
+// |   GAIN   |  BIAS   |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct RangeBias {
+    gain: i16, // 2 bytes
+    bias: i16, // 2 bytes
+}
+
+/*
+* Implementation RangeBias
+*/
+impl RangeBias {
+    /*
+     * Set range gain
+     * -0.32768 <= gain < 0.32768
+     */
+    pub fn set_gain(&mut self, gain: f64) -> Result<(), CatError> {
+        self.gain = from_signed(gain, Self::FACTOR_GAIN, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get range gain
+     */
+    pub fn get_gain(&self) -> f64 {
+        self.gain as f64 * Self::FACTOR_GAIN
+    }
+
+    /*
+     * Set range bias in NM
+     * -256 <= bias < 256
+     */
+    pub fn set_bias(&mut self, bias: f64) -> Result<(), CatError> {
+        self.bias = from_signed(bias, Self::FACTOR_BIAS, 16)? as i16;
+        Ok(())
+    }
+
+    /*
+     * Get range bias in NM
+     */
+    pub fn get_bias(&self) -> f64 {
+        self.bias as f64 * Self::FACTOR_BIAS
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor of range gain.
+     */
+    const FACTOR_GAIN: f64 = 0.00001;
+
+    /*
+     * Conversion factor of range bias.
+     */
+    const FACTOR_BIAS: f64 = 1.0 / 128.0;
+}
+
+impl DataItem for RangeBias {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let array: [u8; 4] = fixed_array(array)?;
+        let range_bias = RangeBias {
+            gain: i16::from_be_bytes([array[0], array[1]]),
+            bias: i16::from_be_bytes([array[2], array[3]]),
+        };
+        Ok((range_bias, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = self.gain.to_be_bytes().to_vec();
+        vector.extend_from_slice(&self.bias.to_be_bytes());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_range_bias() {
+        // Create message
+        let mut range_bias = RangeBias::default();
+        range_bias.set_gain(-0.0005).unwrap();
+        range_bias.set_bias(0.25).unwrap();
+
+        // Convert struct to byte stream
+        let array = range_bias.encode();
+
+        assert_eq!(array, vec![0xff, 0xce, 0x00, 0x20]);
+
+        // New message
+        let (object, length) = RangeBias::decode(&array).unwrap();
+
+        assert_eq!(length, RangeBias::MESSAGE_LENGTH);
+        assert!((object.get_gain() + 0.0005).abs() < 1e-9);
+        assert_eq!(object.get_bias(), 0.25);
+        assert_eq!(range_bias.set_bias(256.0), Err(CatError::ValueInvalid));
+    }
+}
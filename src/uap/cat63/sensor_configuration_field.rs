@@ -0,0 +1,197 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Sensor Configuration and Status
+//
+// | 8 7 |  6  |  5  |  4  |  3  |  2  | 1  | bit
+// | CON | PSR | SSR | MDS | ADS | MLT | FX |
+// |  8  |  7  |  6  |  5  |  4  |  3  |   2   | 1  | bit
+// | OPS | ODP | OXT | MSC | TSV | NPW | spare | FX |
+//
+
+/// Connection status of the sensor
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SensorConnection {
+    Operational,
+    Degraded,
+    Initialisation,
+    NotConnected,
+}
+
+/// Part of the sensor reported as no-go
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SensorPart {
+    Psr = 6,
+    Ssr = 5,
+    ModeS = 4,
+    AdsB = 3,
+    Multilateration = 2,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SensorConfiguration {
+    field: ExtendedField, // 1+ bytes
+}
+
+/*
+* Implementation SensorConfiguration
+*/
+impl SensorConfiguration {
+    /*
+     * Set connection status of the sensor
+     */
+    pub fn set_connection(&mut self, connection: SensorConnection) {
+        self.field.set_bits(0, 8, 2, connection as u8);
+    }
+
+    /*
+     * Get connection status of the sensor
+     */
+    pub fn get_connection(&self) -> SensorConnection {
+        match self.field.get_bits(0, 8, 2) {
+            0 => SensorConnection::Operational,
+            1 => SensorConnection::Degraded,
+            2 => SensorConnection::Initialisation,
+            _ => SensorConnection::NotConnected,
+        }
+    }
+
+    /*
+     * Set part of the sensor no-go
+     */
+    pub fn set_nogo(&mut self, part: SensorPart, value: bool) {
+        self.field.set_bit(0, part as u8, value);
+    }
+
+    /*
+     * Get part of the sensor no-go
+     */
+    pub fn get_nogo(&self, part: SensorPart) -> bool {
+        self.field.get_bit(0, part as u8)
+    }
+
+    /*
+     * Set sensor data not used by the tracker, operator inhibited
+     */
+    pub fn set_inhibited(&mut self, value: bool) {
+        self.field.set_bit(1, 8, value);
+    }
+
+    /*
+     * Get sensor data not used by the tracker
+     */
+    pub fn get_inhibited(&self) -> bool {
+        self.field.get_bit(1, 8)
+    }
+
+    /*
+     * Set data processor overload
+     */
+    pub fn set_processor_overload(&mut self, value: bool) {
+        self.field.set_bit(1, 7, value);
+    }
+
+    /*
+     * Get data processor overload
+     */
+    pub fn get_processor_overload(&self) -> bool {
+        self.field.get_bit(1, 7)
+    }
+
+    /*
+     * Set transmission subsystem overload
+     */
+    pub fn set_transfer_overload(&mut self, value: bool) {
+        self.field.set_bit(1, 6, value);
+    }
+
+    /*
+     * Get transmission subsystem overload
+     */
+    pub fn get_transfer_overload(&self) -> bool {
+        self.field.get_bit(1, 6)
+    }
+
+    /*
+     * Set monitoring system disconnected
+     */
+    pub fn set_monitoring_disconnected(&mut self, value: bool) {
+        self.field.set_bit(1, 5, value);
+    }
+
+    /*
+     * Get monitoring system disconnected
+     */
+    pub fn get_monitoring_disconnected(&self) -> bool {
+        self.field.get_bit(1, 5)
+    }
+
+    /*
+     * Set invalid time source
+     */
+    pub fn set_time_source_invalid(&mut self, value: bool) {
+        self.field.set_bit(1, 4, value);
+    }
+
+    /*
+     * Get invalid time source
+     */
+    pub fn get_time_source_invalid(&self) -> bool {
+        self.field.get_bit(1, 4)
+    }
+
+    /*
+     * Set no plot received for a certain period
+     */
+    pub fn set_no_plot(&mut self, value: bool) {
+        self.field.set_bit(1, 3, value);
+    }
+
+    /*
+     * Get no plot received for a certain period
+     */
+    pub fn get_no_plot(&self) -> bool {
+        self.field.get_bit(1, 3)
+    }
+}
+
+impl DataItem for SensorConfiguration {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let (field, length) = ExtendedField::decode(array)?;
+        Ok((SensorConfiguration { field }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.field.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_sensor_configuration() {
+        // Create message
+        let mut sensor_configuration = SensorConfiguration::default();
+        sensor_configuration.set_connection(SensorConnection::Degraded);
+        sensor_configuration.set_nogo(SensorPart::Psr, true);
+        sensor_configuration.set_time_source_invalid(true);
+
+        // Convert struct to byte stream
+        let array = sensor_configuration.encode();
+
+        assert_eq!(array, vec![0x61, 0x08]);
+
+        // New message
+        let (object, length) = SensorConfiguration::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(object.get_connection(), SensorConnection::Degraded);
+        assert!(object.get_nogo(SensorPart::Psr));
+        assert!(!object.get_nogo(SensorPart::Ssr));
+        assert!(object.get_time_source_invalid());
+        assert!(!object.get_inhibited());
+    }
+}
@@ -0,0 +1,71 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Time Stamping Bias
+//
+// Plot time stamping bias in two's complement
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TimeStampingBias {
+    bias: i16, // 2 bytes
+}
+
+/*
+* Implementation TimeStampingBias
+*/
+impl TimeStampingBias {
+    /*
+     * Set time stamping bias in ms
+     */
+    pub fn set_bias(&mut self, bias: i16) {
+        self.bias = bias;
+    }
+
+    /*
+     * Get time stamping bias in ms
+     */
+    pub fn get_bias(&self) -> i16 {
+        self.bias
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TimeStampingBias {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let bias = i16::from_be_bytes(fixed_array(array)?);
+        Ok((TimeStampingBias { bias }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.bias.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_time_stamping_bias() {
+        // Create message
+        let mut time_stamping_bias = TimeStampingBias::default();
+        time_stamping_bias.set_bias(-250);
+
+        // Convert struct to byte stream
+        let array = time_stamping_bias.encode();
+
+        assert_eq!(array, vec![0xff, 0x06]);
+
+        // New message
+        let (object, length) = TimeStampingBias::decode(&array).unwrap();
+
+        assert_eq!(length, TimeStampingBias::MESSAGE_LENGTH);
+        assert_eq!(object.get_bias(), -250);
+    }
+}
@@ -0,0 +1,69 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Batch Number
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct BatchNumber {
+    batch: u8, // 1 byte
+}
+
+/*
+* Implementation BatchNumber
+*/
+impl BatchNumber {
+    /*
+     * Set batch number
+     */
+    pub fn set_batch(&mut self, batch: u8) {
+        self.batch = batch;
+    }
+
+    /*
+     * Get batch number
+     */
+    pub fn get_batch(&self) -> u8 {
+        self.batch
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for BatchNumber {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [batch] = fixed_array(array)?;
+        Ok((BatchNumber { batch }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.batch]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_batch_number() {
+        // Create message
+        let mut batch_number = BatchNumber::default();
+        batch_number.set_batch(42);
+
+        // Convert struct to byte stream
+        let array = batch_number.encode();
+
+        assert_eq!(array, vec![0x2a]);
+
+        // New message
+        let (object, length) = BatchNumber::decode(&array).unwrap();
+
+        assert_eq!(length, BatchNumber::MESSAGE_LENGTH);
+        assert_eq!(object.get_batch(), 42);
+    }
+}
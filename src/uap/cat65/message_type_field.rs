@@ -0,0 +1,84 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// SDPS Service Status Message Type
+//
+
+/// Types of SDPS service status messages
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SdpsMessage {
+    Unknown,
+    SdpsStatus,
+    EndOfBatch,
+    ServiceStatusReport,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct SdpsMessageType {
+    message: u8, // 1 byte
+}
+
+/*
+* Implementation SdpsMessageType
+*/
+impl SdpsMessageType {
+    /*
+     * Set message type
+     */
+    pub fn set_message_type(&mut self, message_type: SdpsMessage) {
+        self.message = message_type as u8;
+    }
+
+    /*
+     * Get message type
+     */
+    pub fn get_message_type(&self) -> SdpsMessage {
+        match self.message {
+            1 => SdpsMessage::SdpsStatus,
+            2 => SdpsMessage::EndOfBatch,
+            3 => SdpsMessage::ServiceStatusReport,
+            _ => SdpsMessage::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for SdpsMessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [message] = fixed_array(array)?;
+        Ok((SdpsMessageType { message }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_sdps_message_type() {
+        // Create message
+        let mut message_type = SdpsMessageType::default();
+        message_type.set_message_type(SdpsMessage::EndOfBatch);
+
+        // Convert struct to byte stream
+        let array = message_type.encode();
+
+        assert_eq!(array, vec![0x02]);
+
+        // New message
+        let (object, length) = SdpsMessageType::decode(&array).unwrap();
+
+        assert_eq!(length, SdpsMessageType::MESSAGE_LENGTH);
+        assert_eq!(object.get_message_type(), SdpsMessage::EndOfBatch);
+    }
+}
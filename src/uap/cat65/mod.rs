@@ -0,0 +1,4 @@
+pub mod batch_number_field;
+pub mod message_type_field;
+pub mod sdps_status_field;
+pub mod service_status_report_field;
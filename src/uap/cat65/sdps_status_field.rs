@@ -0,0 +1,162 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// SDPS Configuration and Status
+//
+// | 8 7  |  6  |  5  | 4 3 |  2   |   1   | bit
+// | NOGO | OVL | TSV | PSS | STTN | spare |
+//
+
+/// Operational release status of the SDPS
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SdpsOperational {
+    Operational,
+    Degraded,
+    NotConnected,
+    Unknown,
+}
+
+/// Selected processing system of the SDPS
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ProcessingSystem {
+    NotApplicable,
+    Sdps1,
+    Sdps2,
+    Sdps3,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct SdpsStatus {
+    status: u8, // 1 byte
+}
+
+/*
+* Implementation SdpsStatus
+*/
+impl SdpsStatus {
+    /*
+     * Set operational release status
+     */
+    pub fn set_operational(&mut self, operational: SdpsOperational) {
+        self.status = set_bits(self.status as u32, 8, 2, operational as u32) as u8;
+    }
+
+    /*
+     * Get operational release status
+     */
+    pub fn get_operational(&self) -> SdpsOperational {
+        match get_bits(self.status as u32, 8, 2) {
+            0 => SdpsOperational::Operational,
+            1 => SdpsOperational::Degraded,
+            2 => SdpsOperational::NotConnected,
+            _ => SdpsOperational::Unknown,
+        }
+    }
+
+    /*
+     * Set overload
+     */
+    pub fn set_overload(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 6, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get overload
+     */
+    pub fn get_overload(&self) -> bool {
+        get_bits(self.status as u32, 6, 1) == 1
+    }
+
+    /*
+     * Set invalid time source
+     */
+    pub fn set_time_source_invalid(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 5, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get invalid time source
+     */
+    pub fn get_time_source_invalid(&self) -> bool {
+        get_bits(self.status as u32, 5, 1) == 1
+    }
+
+    /*
+     * Set selected processing system
+     */
+    pub fn set_processing_system(&mut self, system: ProcessingSystem) {
+        self.status = set_bits(self.status as u32, 4, 2, system as u32) as u8;
+    }
+
+    /*
+     * Get selected processing system
+     */
+    pub fn get_processing_system(&self) -> ProcessingSystem {
+        match get_bits(self.status as u32, 4, 2) {
+            0 => ProcessingSystem::NotApplicable,
+            1 => ProcessingSystem::Sdps1,
+            2 => ProcessingSystem::Sdps2,
+            _ => ProcessingSystem::Sdps3,
+        }
+    }
+
+    /*
+     * Set track re-numbering indication
+     */
+    pub fn set_renumbering(&mut self, value: bool) {
+        self.status = set_bits(self.status as u32, 2, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get track re-numbering indication
+     */
+    pub fn get_renumbering(&self) -> bool {
+        get_bits(self.status as u32, 2, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for SdpsStatus {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [status] = fixed_array(array)?;
+        Ok((SdpsStatus { status }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.status]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_sdps_status() {
+        // Create message
+        let mut sdps_status = SdpsStatus::default();
+        sdps_status.set_operational(SdpsOperational::Degraded);
+        sdps_status.set_processing_system(ProcessingSystem::Sdps2);
+        sdps_status.set_renumbering(true);
+
+        // Convert struct to byte stream
+        let array = sdps_status.encode();
+
+        assert_eq!(array, vec![0x4a]);
+
+        // New message
+        let (object, length) = SdpsStatus::decode(&array).unwrap();
+
+        assert_eq!(length, SdpsStatus::MESSAGE_LENGTH);
+        assert_eq!(object.get_operational(), SdpsOperational::Degraded);
+        assert_eq!(object.get_processing_system(), ProcessingSystem::Sdps2);
+        assert!(object.get_renumbering());
+        assert!(!object.get_overload());
+    }
+}
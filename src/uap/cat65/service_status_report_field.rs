@@ -0,0 +1,110 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Service Status Report
+//
+
+/// Reports of the service status
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ServiceReport {
+    Unknown,
+    ServiceDegradation,
+    ServiceDegradationEnded,
+    MainRadarOutOfService,
+    InterruptedByOperator,
+    InterruptedByContingency,
+    ReadyForRestart,
+    EndedByOperator,
+    MainRadarFailure,
+    RestartedByOperator,
+    MainRadarOperational,
+    MainRadarDegraded,
+    ContinuityInterrupted,
+    ContinuityRestarted,
+    SynchronisedOnBackupRadar,
+    SynchronisedOnMainRadar,
+    AllRadarsFailed,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ServiceStatusReport {
+    report: u8, // 1 byte
+}
+
+/*
+* Implementation ServiceStatusReport
+*/
+impl ServiceStatusReport {
+    /*
+     * Set service status report
+     */
+    pub fn set_report(&mut self, report: ServiceReport) {
+        self.report = report as u8;
+    }
+
+    /*
+     * Get service status report
+     */
+    pub fn get_report(&self) -> ServiceReport {
+        match self.report {
+            1 => ServiceReport::ServiceDegradation,
+            2 => ServiceReport::ServiceDegradationEnded,
+            3 => ServiceReport::MainRadarOutOfService,
+            4 => ServiceReport::InterruptedByOperator,
+            5 => ServiceReport::InterruptedByContingency,
+            6 => ServiceReport::ReadyForRestart,
+            7 => ServiceReport::EndedByOperator,
+            8 => ServiceReport::MainRadarFailure,
+            9 => ServiceReport::RestartedByOperator,
+            10 => ServiceReport::MainRadarOperational,
+            11 => ServiceReport::MainRadarDegraded,
+            12 => ServiceReport::ContinuityInterrupted,
+            13 => ServiceReport::ContinuityRestarted,
+            14 => ServiceReport::SynchronisedOnBackupRadar,
+            15 => ServiceReport::SynchronisedOnMainRadar,
+            16 => ServiceReport::AllRadarsFailed,
+            _ => ServiceReport::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ServiceStatusReport {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [report] = fixed_array(array)?;
+        Ok((ServiceStatusReport { report }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.report]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_status_report() {
+        // Create message
+        let mut service_status_report = ServiceStatusReport::default();
+        service_status_report.set_report(ServiceReport::MainRadarDegraded);
+
+        // Convert struct to byte stream
+        let array = service_status_report.encode();
+
+        assert_eq!(array, vec![0x0b]);
+
+        // New message
+        let (object, length) = ServiceStatusReport::decode(&array).unwrap();
+
+        assert_eq!(length, ServiceStatusReport::MESSAGE_LENGTH);
+        assert_eq!(object.get_report(), ServiceReport::MainRadarDegraded);
+    }
+}
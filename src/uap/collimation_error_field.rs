@@ -0,0 +1,173 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Collimation Error
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CollimationError {
+    range_error: i8,   // 1 byte
+    azimuth_error: i8, // 1 byte
+}
+
+/*
+* Implementation CollimationError
+*/
+impl CollimationError {
+    /*
+     * Convert byte stream to struct.
+     */
+    pub fn from_bytes(&mut self, array: &[u8; Self::MESSAGE_LENGTH]) {
+        self.range_error = array[0] as i8;
+        self.azimuth_error = array[1] as i8;
+    }
+
+    /*
+     * Convert struct to byte stream.
+     */
+    pub fn to_bytes(&self) -> [u8; Self::MESSAGE_LENGTH] {
+        let mut array = [0u8; Self::MESSAGE_LENGTH];
+        array[0] = self.range_error as u8;
+        array[1] = self.azimuth_error as u8;
+        array
+    }
+
+    /*
+     * Create fixed length array from slice.
+     */
+    pub fn array_of_byte_message(array: &[u8]) -> [u8; Self::MESSAGE_LENGTH] {
+        array.try_into().expect("slice with incorrect length")
+    }
+
+    /*
+     * Set range error in nautical miles
+     * -128/128 <= range <= 127/128
+     */
+    pub fn set_range_error(&mut self, range: f32) -> Result<(), CatError> {
+        let converted_range = (range / Self::RANGE_FACTOR).round();
+
+        if !converted_range.is_finite()
+            || converted_range < i8::MIN as f32
+            || converted_range > i8::MAX as f32
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        self.range_error = converted_range as i8;
+        Ok(())
+    }
+
+    /*
+     * Get range error in nautical miles
+     */
+    pub fn get_range_error(&self) -> f32 {
+        self.range_error as f32 * Self::RANGE_FACTOR
+    }
+
+    /*
+     * Set azimuth error in degrees
+     * -128*360/(2^14) <= azimuth <= 127*360/(2^14)
+     */
+    pub fn set_azimuth_error(&mut self, azimuth: f64) -> Result<(), CatError> {
+        let converted_azimuth = (azimuth / Self::ANGLE_FACTOR).round();
+
+        if !converted_azimuth.is_finite()
+            || converted_azimuth < i8::MIN as f64
+            || converted_azimuth > i8::MAX as f64
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        self.azimuth_error = converted_azimuth as i8;
+        Ok(())
+    }
+
+    /*
+     * Get azimuth error in degrees
+     */
+    pub fn get_azimuth_error(&self) -> f64 {
+        self.azimuth_error as f64 * Self::ANGLE_FACTOR
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const RANGE_FACTOR: f32 = 1.0 / 128.0;
+    const ANGLE_FACTOR: f64 = 360.0 / (1 << 14) as f64;
+}
+
+impl DataItem for CollimationError {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut collimation_error = CollimationError::default();
+        collimation_error.from_bytes(&fixed_array(array)?);
+        Ok((collimation_error, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_collimation_error() {
+        // Create message
+        let mut collimation_error = CollimationError::default();
+        collimation_error.set_range_error(-0.5).unwrap();
+        collimation_error.set_azimuth_error(1.0).unwrap();
+
+        // Convert struct to byte stream
+        let array = collimation_error.to_bytes();
+
+        assert_eq!(array, [0xc0, 0x2e]);
+
+        // New message
+        let mut object = CollimationError::default();
+
+        // Convert byte stream to struct
+        object.from_bytes(&array);
+
+        assert_eq!(object.get_range_error(), -0.5);
+        assert_eq!(
+            collimation_error.get_azimuth_error(),
+            object.get_azimuth_error()
+        );
+        assert!((object.get_azimuth_error() - 1.0).abs() < CollimationError::ANGLE_FACTOR);
+    }
+
+    #[test]
+    fn check_collimation_error_invalid() {
+        let mut collimation_error = CollimationError::default();
+
+        assert_eq!(
+            collimation_error.set_range_error(1.0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            collimation_error.set_azimuth_error(-3.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+
+    #[test]
+    fn check_collimation_error_nan() {
+        let mut collimation_error = CollimationError::default();
+
+        assert_eq!(
+            collimation_error.set_range_error(f32::NAN),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            collimation_error.set_azimuth_error(f64::NAN),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(collimation_error, CollimationError::default());
+    }
+}
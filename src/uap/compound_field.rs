@@ -0,0 +1,242 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+use crate::uap::extended_field::ExtendedField;
+
+// Compound data item
+//
+// Primary subfield with one presence bit per subfield and FX bit,
+// followed by the present subfields in order of the presence bits
+// |  8  |  7  |  6  |  5  |  4  |  3  |  2  | 1  | bit
+// | SF1 | SF2 | SF3 | SF4 | SF5 | SF6 | SF7 | FX |
+//
+
+/// Byte length of a subfield
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SubfieldLength {
+    /// Fixed number of bytes
+    Fixed(usize),
+    /// Repetition factor followed by entries of fixed number of bytes
+    Repetitive(usize),
+    /// Octets with FX bit
+    Extended,
+    /// Length octet including itself
+    Explicit,
+    /// Nested compound with the subfield lengths of its UAP
+    Compound(&'static [SubfieldLength]),
+    /// Spare subfield, never present
+    Spare,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct CompoundField {
+    primary: ExtendedField,          // 1+ bytes
+    subfields: Vec<Option<Vec<u8>>>, // variable bytes each
+}
+
+/*
+* Implementation CompoundField
+*/
+impl CompoundField {
+    /*
+     * Decode compound data item with the subfield lengths of its UAP.
+     * Returns the data item and its byte length.
+     */
+    pub fn decode(array: &[u8], layout: &[SubfieldLength]) -> Result<(Self, usize), CatError> {
+        let (primary, mut length) = ExtendedField::decode(array)?;
+        let mut compound_field = CompoundField::default();
+
+        for index in 0..primary.len() * 7 {
+            if !primary.get_bit(index / 7, 8 - (index % 7) as u8) {
+                continue;
+            }
+            // Length of undefined subfields is unknown
+            let subfield_length = match layout.get(index) {
+                Some(subfield) => Self::subfield_length(&array[length..], *subfield)?,
+                None => return Err(CatError::ValueInvalid),
+            };
+            let slice = array
+                .get(length..length + subfield_length)
+                .ok_or(CatError::SizeInvalid(0))?;
+
+            compound_field.set_subfield(index, slice.to_vec());
+            length += subfield_length;
+        }
+        compound_field.primary = primary;
+
+        Ok((compound_field, length))
+    }
+
+    /*
+     * Encode compound data item.
+     */
+    pub fn encode(&self) -> Vec<u8> {
+        let mut vector = self.primary.encode();
+        vector.extend(self.subfields.iter().flatten().flatten());
+        vector
+    }
+
+    /*
+     * Set subfield bytes and its bit in the primary subfield
+     * Index starts with 0 for the first subfield
+     */
+    pub fn set_subfield(&mut self, index: usize, data: Vec<u8>) {
+        self.primary.set_bit(index / 7, 8 - (index % 7) as u8, true);
+
+        if self.subfields.len() <= index {
+            self.subfields.resize(index + 1, None);
+        }
+        self.subfields[index] = Some(data);
+    }
+
+    /*
+     * Get subfield bytes when present
+     * Index starts with 0 for the first subfield
+     */
+    pub fn get_subfield(&self, index: usize) -> Option<&[u8]> {
+        self.subfields.get(index)?.as_deref()
+    }
+
+    /*
+     * Set subfield bytes, checks the byte length against the subfield length of the UAP
+     */
+    pub fn set_checked_subfield(
+        &mut self,
+        layout: &[SubfieldLength],
+        index: usize,
+        data: Vec<u8>,
+    ) -> Result<(), CatError> {
+        let subfield = *layout.get(index).ok_or(CatError::ValueInvalid)?;
+
+        match Self::subfield_length(&data, subfield) {
+            Ok(length) if length == data.len() => {
+                self.set_subfield(index, data);
+                Ok(())
+            }
+            _ => Err(CatError::ValueInvalid),
+        }
+    }
+
+    /*
+     * Set subfield from data item
+     */
+    pub fn set_item<T: DataItem>(&mut self, index: usize, item: &T) {
+        self.set_subfield(index, item.encode());
+    }
+
+    /*
+     * Get subfield as data item when present
+     */
+    pub fn get_item<T: DataItem>(&self, index: usize) -> Option<T> {
+        let (item, _length) = T::decode(self.get_subfield(index)?).ok()?;
+        Some(item)
+    }
+
+    /*
+     * Byte length of a subfield at the start of the byte stream.
+     */
+    fn subfield_length(array: &[u8], subfield: SubfieldLength) -> Result<usize, CatError> {
+        match subfield {
+            SubfieldLength::Fixed(length) => Ok(length),
+            SubfieldLength::Repetitive(length) => {
+                let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+                Ok(1 + repetition * length)
+            }
+            SubfieldLength::Extended => ExtendedField::decode(array).map(|(_, length)| length),
+            SubfieldLength::Explicit => match array.first() {
+                Some(0) | None => Err(CatError::SizeInvalid(0)),
+                Some(length) => Ok(*length as usize),
+            },
+            SubfieldLength::Compound(layout) => {
+                Self::decode(array, layout).map(|(_, length)| length)
+            }
+            SubfieldLength::Spare => Err(CatError::ValueInvalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUT: &[SubfieldLength] = &[
+        SubfieldLength::Fixed(2),
+        SubfieldLength::Repetitive(2),
+        SubfieldLength::Extended,
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Fixed(1),
+        SubfieldLength::Explicit,
+    ];
+
+    #[test]
+    fn check_compound_field() {
+        // Create message
+        let mut compound_field = CompoundField::default();
+        compound_field.set_subfield(0, vec![0x12, 0x34]);
+        compound_field.set_subfield(2, vec![0x03, 0x02]);
+        compound_field.set_subfield(7, vec![0x02, 0xff]);
+
+        // Convert struct to byte stream
+        let array = compound_field.encode();
+
+        assert_eq!(array, vec![0xa1, 0x80, 0x12, 0x34, 0x03, 0x02, 0x02, 0xff]);
+
+        // New message
+        let (object, length) = CompoundField::decode(&array, LAYOUT).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object, compound_field);
+        assert_eq!(object.get_subfield(1), None);
+        assert_eq!(object.get_subfield(2), Some(&[0x03, 0x02][..]));
+
+        // Explicit subfield is longer than the byte stream
+        assert_eq!(
+            CompoundField::decode(&array[0..7], LAYOUT),
+            Err(CatError::SizeInvalid(0))
+        );
+        // Subfield length must match the layout
+        let mut object = object;
+
+        assert_eq!(
+            object.set_checked_subfield(LAYOUT, 1, vec![0x01, 0xaa]),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            object.set_checked_subfield(LAYOUT, 1, vec![0x01, 0xaa, 0xbb]),
+            Ok(())
+        );
+        assert_eq!(object.get_subfield(1), Some(&[0x01, 0xaa, 0xbb][..]));
+
+        // Subfield is not defined in the layout
+        assert_eq!(
+            CompoundField::decode(&[0x01, 0x40, 0x00], LAYOUT),
+            Err(CatError::ValueInvalid)
+        );
+
+        // Spare subfield is never present
+        let layout = [SubfieldLength::Spare, SubfieldLength::Fixed(1)];
+
+        assert_eq!(
+            CompoundField::decode(&[0x80, 0x00], &layout),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(CompoundField::decode(&[0x40, 0x12], &layout).unwrap().1, 2);
+    }
+
+    #[test]
+    fn check_nested_compound_field() {
+        const NESTED: &[SubfieldLength] = &[
+            SubfieldLength::Compound(&[SubfieldLength::Fixed(1), SubfieldLength::Fixed(2)]),
+            SubfieldLength::Fixed(1),
+        ];
+        let array = [0xc0, 0x40, 0x12, 0x34, 0x56];
+
+        let (object, length) = CompoundField::decode(&array, NESTED).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_subfield(0), Some(&[0x40, 0x12, 0x34][..]));
+        assert_eq!(object.get_subfield(1), Some(&[0x56][..]));
+        assert_eq!(object.encode(), array.to_vec());
+    }
+}
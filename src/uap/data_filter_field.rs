@@ -0,0 +1,125 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+/// Types of data filter
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum DataFilterEnum {
+    Invalid,
+    Weather,
+    JammingStrobe,
+    Psr,
+    SsrModeS,
+    SsrModeSPsr,
+    EnhancedSurveillance,
+    PsrEnhancedSurveillance,
+    PsrEnhancedSurveillanceModeSOutside,
+    PsrEnhancedSurveillanceModeS,
+}
+
+// Data Filter
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct DataFilter {
+    filter: u8, // 1 byte
+}
+
+/*
+* Implementation DataFilter
+*/
+impl DataFilter {
+    /*
+     * Convert byte stream to struct.
+     * Filter type must be defined.
+     */
+    pub fn from_bytes(&mut self, array: &[u8; Self::MESSAGE_LENGTH]) -> Result<(), CatError> {
+        if array[0] > DataFilterEnum::PsrEnhancedSurveillanceModeS as u8 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.filter = array[0];
+        Ok(())
+    }
+
+    /*
+     * Convert struct to byte stream.
+     */
+    pub fn to_bytes(&self) -> [u8; Self::MESSAGE_LENGTH] {
+        let mut array = [0u8; Self::MESSAGE_LENGTH];
+        array[0] = self.filter;
+        array
+    }
+
+    /*
+     * Create fixed length array from slice.
+     */
+    pub fn array_of_byte_message(array: &[u8]) -> [u8; Self::MESSAGE_LENGTH] {
+        array.try_into().expect("slice with incorrect length")
+    }
+
+    /*
+     * Set data filter
+     */
+    pub fn set_data_filter(&mut self, data_filter: DataFilterEnum) {
+        self.filter = data_filter as u8;
+    }
+
+    /*
+     * Get data filter
+     */
+    pub fn get_data_filter(&self) -> DataFilterEnum {
+        match self.filter {
+            1 => DataFilterEnum::Weather,
+            2 => DataFilterEnum::JammingStrobe,
+            3 => DataFilterEnum::Psr,
+            4 => DataFilterEnum::SsrModeS,
+            5 => DataFilterEnum::SsrModeSPsr,
+            6 => DataFilterEnum::EnhancedSurveillance,
+            7 => DataFilterEnum::PsrEnhancedSurveillance,
+            8 => DataFilterEnum::PsrEnhancedSurveillanceModeSOutside,
+            9 => DataFilterEnum::PsrEnhancedSurveillanceModeS,
+            _ => DataFilterEnum::Invalid,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for DataFilter {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut data_filter = DataFilter::default();
+        data_filter.from_bytes(&fixed_array(array)?)?;
+        Ok((data_filter, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_data_filter() {
+        // Create message
+        let mut data_filter = DataFilter::default();
+        data_filter.set_data_filter(DataFilterEnum::SsrModeSPsr);
+
+        // Convert struct to byte stream
+        let array = data_filter.to_bytes();
+
+        // New message
+        let mut object = DataFilter::default();
+
+        // Convert byte stream to struct
+        object.from_bytes(&array).unwrap();
+
+        assert_eq!(data_filter.get_data_filter(), object.get_data_filter());
+        assert_eq!(object.from_bytes(&[10]), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::category::CatError;
+
+/// Data item which is decoded from and encoded to a byte stream
+pub trait DataItem: Sized {
+    /*
+     * Decode data item from the start of the byte stream.
+     * Returns the data item and its byte length.
+     */
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError>;
+
+    /*
+     * Encode data item to byte stream.
+     */
+    fn encode(&self) -> Vec<u8>;
+}
+
+/*
+ * Create fixed length array from the start of a slice, checks the length.
+ */
+pub fn fixed_array<const N: usize>(array: &[u8]) -> Result<[u8; N], CatError> {
+    match array.get(..N) {
+        Some(slice) => Ok(slice.try_into().expect("slice with incorrect length")),
+        None => Err(CatError::SizeInvalid(0)),
+    }
+}
+
+/*
+ * Get bits, highest bit position from right to left.
+ */
+pub fn get_bits(field: u32, bit: u8, width: u8) -> u32 {
+    let shift = bit - width;
+    let mask = ((0x1_u64 << width) - 1) as u32;
+    (field >> shift) & mask
+}
+
+/*
+ * Set bits, highest bit position from right to left.
+ */
+pub fn set_bits(field: u32, bit: u8, width: u8, value: u32) -> u32 {
+    let shift = bit - width;
+    let mask = ((0x1_u64 << width) - 1) as u32;
+    (field & !(mask << shift)) | ((value & mask) << shift)
+}
+
+/*
+ * Byte length of a compound data item defined by its primary subfield.
+ * Each subfield is given by its bit position from right to left and its byte length.
+ */
+pub fn compound_length(primary: u8, subfields: &[(u8, usize)]) -> usize {
+    let present: usize = subfields
+        .iter()
+        .filter(|(bit, _)| get_bits(primary as u32, *bit, 1) == 1)
+        .map(|(_, length)| length)
+        .sum();
+    1 + present
+}
+
+/*
+ * Sign extend two's complement value of given bit width.
+ */
+pub fn to_signed(field: u32, width: u8) -> i32 {
+    let shift = 32 - width;
+    ((field << shift) as i32) >> shift
+}
+
+/*
+ * Convert value with factor into two's complement of given bit width, checks the range.
+ */
+pub fn from_signed(value: f64, factor: f64, width: u8) -> Result<u32, CatError> {
+    let converted = (value / factor).round();
+    let max = ((0x1_i64 << (width - 1)) - 1) as f64;
+
+    if !(-max - 1.0..=max).contains(&converted) {
+        return Err(CatError::ValueInvalid);
+    }
+    let mask = ((0x1_u64 << width) - 1) as u32;
+    Ok((converted as i32) as u32 & mask)
+}
+
+/*
+ * Convert value with factor into unsigned value of given bit width, checks the range.
+ */
+pub fn from_unsigned(value: f64, factor: f64, width: u8) -> Result<u32, CatError> {
+    let converted = (value / factor).round();
+    let max = ((0x1_u64 << width) - 1) as f64;
+
+    if !(0.0..=max).contains(&converted) {
+        return Err(CatError::ValueInvalid);
+    }
+    Ok(converted as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_bits() {
+        let field = set_bits(0, 14, 3, 0b101);
+
+        assert_eq!(field, 0b0010_1000_0000_0000);
+        assert_eq!(get_bits(field, 14, 3), 0b101);
+        assert_eq!(to_signed(0x3fff, 14), -1);
+        assert_eq!(to_signed(0x1fff, 14), 8191);
+        assert_eq!(from_signed(-0.25, 0.25, 14), Ok(0x3fff));
+        assert_eq!(from_signed(8192.0, 1.0, 14), Err(CatError::ValueInvalid));
+        assert_eq!(from_unsigned(255.0, 1.0, 8), Ok(255));
+        assert_eq!(from_unsigned(-1.0, 1.0, 8), Err(CatError::ValueInvalid));
+        assert_eq!(fixed_array::<2>(&[1, 2, 3]), Ok([1, 2]));
+        assert_eq!(fixed_array::<4>(&[1, 2, 3]), Err(CatError::SizeInvalid(0)));
+    }
+
+    #[test]
+    fn check_compound_length() {
+        let subfields = [(8, 1), (5, 1), (3, 2)];
+
+        assert_eq!(compound_length(0x00, &subfields), 1);
+        assert_eq!(compound_length(0x90, &subfields), 3);
+        assert_eq!(compound_length(0x94, &subfields), 5);
+    }
+}
@@ -1,12 +1,14 @@
-use deku::prelude::*;
 use std::mem;
 
-// System Identification Code (SIC) and System Area Code (SAC)
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// System Area Code (SAC) and System Identification Code (SIC)
 //
-#[derive(Default, Debug, PartialEq, Clone, Copy, DekuRead, DekuWrite)]
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
 pub struct DataSource {
-    sic: u8, // 1 byte
     sac: u8, // 1 byte
+    sic: u8, // 1 byte
 }
 
 /*
@@ -24,7 +26,7 @@ impl DataSource {
      * Get source id SIC
      */
     pub fn get_source_id_sic(&self) -> u8 {
-        return self.sic;
+        self.sic
     }
 
     /*
@@ -38,7 +40,7 @@ impl DataSource {
      * Get source id SAC
      */
     pub fn get_source_id_sac(&self) -> u8 {
-        return self.sac;
+        self.sac
     }
 
     /*
@@ -47,9 +49,19 @@ impl DataSource {
     pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
 }
 
+impl DataItem for DataSource {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [sac, sic] = fixed_array(array)?;
+        Ok((DataSource { sac, sic }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.sac, self.sic]
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::uap::antenna_rotation_field::AntennaRotation;
     use super::*;
 
     #[test]
@@ -60,13 +72,14 @@ mod tests {
         data_source.set_source_id_sac(26);
 
         // Convert struct to byte stream
-        let vector = data_source.to_bytes().unwrap();
-        let array: &[u8] = &vector;
+        let array = data_source.encode();
+
+        assert_eq!(array, vec![26, 42]);
 
         // New message
-        let (_rest, object) = DataSource::from_bytes((array, 0)).unwrap();
-        let vvv = array.to_vec();
+        let (object, length) = DataSource::decode(&array).unwrap();
 
+        assert_eq!(length, DataSource::MESSAGE_LENGTH);
         assert_eq!(data_source.get_source_id_sic(), object.get_source_id_sic());
         assert_eq!(data_source.get_source_id_sac(), object.get_source_id_sac());
     }
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Explicit length data item, used for Reserved Expansion Field and Special Purpose Field
+//
+// | LEN | data ... |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ExplicitField {
+    data: Vec<u8>, // LEN - 1 bytes
+}
+
+/// Site specific content of an explicit length data item
+pub trait ExplicitContent: Sized {
+    /*
+     * Convert data bytes without length octet to content.
+     */
+    fn from_bytes(data: &[u8]) -> Result<Self, CatError>;
+
+    /*
+     * Convert content to data bytes without length octet.
+     */
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+/// Explicit length data items of a record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+pub enum ExplicitItem {
+    ReservedExpansion,
+    SpecialPurpose,
+}
+
+/// Parser of site specific content, called with data bytes without length octet
+type ExplicitParser = Box<dyn FnMut(&[u8])>;
+
+/// Parsers of site specific content registered per category and explicit item
+#[derive(Default)]
+pub struct ExplicitParsers {
+    parsers: HashMap<(u8, ExplicitItem), ExplicitParser>,
+}
+
+/*
+* Implementation ExplicitParsers
+*/
+impl ExplicitParsers {
+    /*
+     * Register handler of site specific content for a category and explicit item.
+     * The content is parsed during decode, a registered handler is replaced.
+     * The handler gets the parse error when the content does not fit its type,
+     * the decode goes on and the record keeps the raw bytes.
+     */
+    pub fn register<T, F>(&mut self, category: u8, item: ExplicitItem, mut handler: F)
+    where
+        T: ExplicitContent,
+        F: FnMut(Result<T, CatError>) + 'static,
+    {
+        let parser = move |data: &[u8]| handler(T::from_bytes(data));
+        self.parsers.insert((category, item), Box::new(parser));
+    }
+
+    /*
+     * Parse explicit length data item with the registered handler.
+     * Data items without registered handler are kept as raw bytes only.
+     */
+    pub fn parse(&mut self, category: u8, item: ExplicitItem, field: &ExplicitField) {
+        if let Some(parser) = self.parsers.get_mut(&(category, item)) {
+            parser(field.get_data());
+        }
+    }
+}
+
+/*
+* Implementation ExplicitField
+*/
+impl ExplicitField {
+    /*
+     * Convert byte stream to struct.
+     * Returns the number of bytes defined by the length octet.
+     */
+    pub fn from_bytes(&mut self, array: &[u8]) -> Result<usize, CatError> {
+        let length = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+
+        // Length octet counts itself
+        if length == 0 {
+            return Err(CatError::ValueInvalid);
+        }
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(array.len()));
+        }
+        self.data = array[1..length].to_vec();
+        Ok(length)
+    }
+
+    /*
+     * Convert struct to byte stream.
+     */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut vector = Vec::with_capacity(1 + self.data.len());
+        vector.push((1 + self.data.len()) as u8);
+        vector.extend_from_slice(&self.data);
+        vector
+    }
+
+    /*
+     * Set raw data without length octet
+     * 0 <= length <= 254
+     */
+    pub fn set_data(&mut self, data: &[u8]) -> Result<(), CatError> {
+        if data.len() > Self::MAX_DATA_LENGTH {
+            return Err(CatError::SizeInvalid(Self::MAX_DATA_LENGTH));
+        }
+        self.data = data.to_vec();
+        Ok(())
+    }
+
+    /*
+     * Get raw data without length octet
+     */
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /*
+     * Set site specific content
+     */
+    pub fn set_content<T: ExplicitContent>(&mut self, content: &T) -> Result<(), CatError> {
+        self.set_data(&content.to_bytes())
+    }
+
+    /*
+     * Get site specific content
+     */
+    pub fn get_content<T: ExplicitContent>(&self) -> Result<T, CatError> {
+        T::from_bytes(&self.data)
+    }
+
+    /*
+     * Maximum data length, the length octet counts itself.
+     */
+    pub const MAX_DATA_LENGTH: usize = u8::MAX as usize - 1;
+}
+
+impl DataItem for ExplicitField {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut explicit_field = ExplicitField::default();
+        let length = explicit_field.from_bytes(array)?;
+        Ok((explicit_field, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Site specific content with a single counter
+    #[derive(Debug, PartialEq)]
+    struct SiteCounter {
+        counter: u16,
+    }
+
+    impl ExplicitContent for SiteCounter {
+        fn from_bytes(data: &[u8]) -> Result<Self, CatError> {
+            let array: [u8; 2] = data.try_into().map_err(|_| CatError::SizeInvalid(0))?;
+            Ok(SiteCounter {
+                counter: u16::from_be_bytes(array),
+            })
+        }
+
+        fn to_bytes(&self) -> Vec<u8> {
+            self.counter.to_be_bytes().to_vec()
+        }
+    }
+
+    #[test]
+    fn check_explicit_field() {
+        // Create message
+        let mut explicit_field = ExplicitField::default();
+        explicit_field.set_data(&[0x12, 0x34, 0x56]).unwrap();
+
+        // Convert struct to byte stream
+        let array = explicit_field.to_bytes();
+
+        assert_eq!(array, vec![0x04, 0x12, 0x34, 0x56]);
+
+        // New message
+        let mut object = ExplicitField::default();
+
+        // Convert byte stream to struct
+        let length = object.from_bytes(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(explicit_field.get_data(), object.get_data());
+
+        // Length octet of zero and length octet behind the byte stream
+        assert_eq!(object.from_bytes(&[0x00]), Err(CatError::ValueInvalid));
+        assert_eq!(
+            object.from_bytes(&[0x04, 0x12]),
+            Err(CatError::SizeInvalid(2))
+        );
+    }
+
+    #[test]
+    fn check_explicit_parsers() {
+        let counters = Rc::new(RefCell::new(Vec::new()));
+
+        let mut parsers = ExplicitParsers::default();
+        let handler_counters = Rc::clone(&counters);
+        parsers.register(
+            34,
+            ExplicitItem::SpecialPurpose,
+            move |content: Result<SiteCounter, CatError>| {
+                handler_counters
+                    .borrow_mut()
+                    .push(content.map(|content| content.counter));
+            },
+        );
+
+        let mut explicit_field = ExplicitField::default();
+        explicit_field.set_data(&[0x10, 0x92]).unwrap();
+
+        // Only the registered category and explicit item is parsed
+        parsers.parse(34, ExplicitItem::SpecialPurpose, &explicit_field);
+        parsers.parse(34, ExplicitItem::ReservedExpansion, &explicit_field);
+        parsers.parse(48, ExplicitItem::SpecialPurpose, &explicit_field);
+
+        assert_eq!(*counters.borrow(), vec![Ok(4242)]);
+
+        // Content which does not fit the registered type
+        explicit_field.set_data(&[0x10]).unwrap();
+        parsers.parse(34, ExplicitItem::SpecialPurpose, &explicit_field);
+
+        assert_eq!(
+            *counters.borrow(),
+            vec![Ok(4242), Err(CatError::SizeInvalid(0))]
+        );
+    }
+
+    #[test]
+    fn check_explicit_content() {
+        // Create message
+        let mut explicit_field = ExplicitField::default();
+        explicit_field
+            .set_content(&SiteCounter { counter: 4242 })
+            .unwrap();
+
+        let content: SiteCounter = explicit_field.get_content().unwrap();
+
+        assert_eq!(content, SiteCounter { counter: 4242 });
+        assert_eq!(
+            explicit_field.set_data(&[0u8; 255]),
+            Err(CatError::SizeInvalid(254))
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Extended length data item
+//
+// Each octet holds seven data bits and the FX bit
+// | 8 | 7 | 6 | 5 | 4 | 3 | 2 | 1 | bit
+// |      data bits        ...     | FX|
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ExtendedField {
+    octets: Vec<u8>, // 1 byte each
+}
+
+/*
+* Implementation ExtendedField
+*/
+impl ExtendedField {
+    /*
+     * Get bits of an octet, highest bit position from right to left
+     * 2 <= bit <= 8
+     */
+    pub fn get_bits(&self, octet: usize, bit: u8, width: u8) -> u8 {
+        match self.octets.get(octet) {
+            Some(value) => (value >> (bit - width)) & ((0x1_u16 << width) - 1) as u8,
+            None => 0,
+        }
+    }
+
+    /*
+     * Set bits of an octet, highest bit position from right to left
+     * Adds octets with FX bit when the octet does not exist yet.
+     * 2 <= bit <= 8
+     */
+    pub fn set_bits(&mut self, octet: usize, bit: u8, width: u8, value: u8) {
+        while self.octets.len() <= octet {
+            if let Some(last) = self.octets.last_mut() {
+                *last |= Self::FX;
+            }
+            self.octets.push(0);
+        }
+        let shift = bit - width;
+        let mask = ((0x1_u16 << width) - 1) as u8;
+        let field = self.octets[octet];
+        self.octets[octet] = (field & !(mask << shift)) | ((value & mask) << shift);
+    }
+
+    /*
+     * Get bit of an octet
+     */
+    pub fn get_bit(&self, octet: usize, bit: u8) -> bool {
+        self.get_bits(octet, bit, 1) == 1
+    }
+
+    /*
+     * Set bit of an octet
+     */
+    pub fn set_bit(&mut self, octet: usize, bit: u8, value: bool) {
+        self.set_bits(octet, bit, 1, value as u8);
+    }
+
+    /*
+     * Number of octets
+     */
+    pub fn len(&self) -> usize {
+        self.octets.len()
+    }
+
+    /*
+     * Check for octets
+     */
+    pub fn is_empty(&self) -> bool {
+        self.octets.is_empty()
+    }
+
+    /*
+     * FX bit of each octet.
+     */
+    const FX: u8 = 0x01;
+}
+
+impl DataItem for ExtendedField {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut octets = Vec::new();
+
+        loop {
+            let octet = *array.get(octets.len()).ok_or(CatError::SizeInvalid(0))?;
+            octets.push(octet);
+
+            // Check field extension
+            if octet & Self::FX == 0 {
+                break;
+            }
+        }
+        let length = octets.len();
+        Ok((ExtendedField { octets }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        if self.octets.is_empty() {
+            // At least the first octet is present
+            return vec![0];
+        }
+        self.octets.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_extended_field() {
+        // Create message
+        let mut extended_field = ExtendedField::default();
+        extended_field.set_bits(0, 8, 3, 0b101);
+        extended_field.set_bit(2, 2, true);
+
+        // Convert struct to byte stream
+        let array = extended_field.encode();
+
+        assert_eq!(array, vec![0xa1, 0x01, 0x02]);
+
+        // New message
+        let (object, length) = ExtendedField::decode(&array).unwrap();
+
+        assert_eq!(length, 3);
+        assert_eq!(object.get_bits(0, 8, 3), 0b101);
+        assert!(object.get_bit(2, 2));
+        assert!(!object.get_bit(3, 2));
+        assert_eq!(
+            ExtendedField::decode(&[0x01]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
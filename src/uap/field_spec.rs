@@ -9,7 +9,7 @@ pub struct FieldSpec {
     fspec: u8, // 1 byte
 }
 
-struct FieldSpecIterator<'a> {
+pub struct FieldSpecIterator<'a> {
     fspec: &'a FieldSpec,
     index: u8,
 }
@@ -74,9 +74,9 @@ impl FieldSpec {
      */
     pub fn set_fspec_bit(&mut self, index: u8) {
         // Range SF1..SF7..FX
-        if index >= 1 && index <= 8 {
+        if (1..=8).contains(&index) {
             // Shift by 1..8
-            let pattern = (0x1 as u8) << (8 - index);
+            let pattern = 0x1_u8 << (8 - index);
             let field = u8::from_be(self.fspec);
 
             let result = field | pattern;
@@ -89,19 +89,18 @@ impl FieldSpec {
      */
     pub fn get_fspec_bit(&self, index: u8) -> bool {
         // Range SF1..SF7..FX
-        if index >= 1 && index <= 8 {
+        if (1..=8).contains(&index) {
             // Shift by 1..8
-            let pattern = (0x1 as u8) << (8 - index);
+            let pattern = 0x1_u8 << (8 - index);
             let field = u8::from_be(self.fspec);
 
-            let result = field & pattern == pattern;
-            return result;
+            return field & pattern == pattern;
         }
 
-        return false;
+        false
     }
 
-    pub fn iter(&self) -> FieldSpecIterator {
+    pub fn iter(&self) -> FieldSpecIterator<'_> {
         FieldSpecIterator {
             fspec: self,
             index: 1,
@@ -155,6 +154,6 @@ mod tests {
 
         let bit = field_spec.get_fspec_bit(1);
 
-        assert_eq!(bit, true);
+        assert!(bit);
     }
 }
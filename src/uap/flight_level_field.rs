@@ -0,0 +1,114 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, from_signed, get_bits, set_bits, to_signed, DataItem};
+
+// Flight Level in Binary Representation
+//
+// | 16| 15| 14 .. 1                | bit
+// | V | G | Flight Level 1/4 FL    |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct FlightLevel {
+    level: u16, // 2 bytes
+}
+
+/*
+* Implementation FlightLevel
+*/
+impl FlightLevel {
+    /*
+     * Set flight level in FL
+     * -2048 <= level < 2048
+     */
+    pub fn set_flight_level(&mut self, level: f32) -> Result<(), CatError> {
+        let converted_level = from_signed(level as f64, Self::FACTOR as f64, 14)?;
+        self.level = set_bits(self.level as u32, 14, 14, converted_level) as u16;
+        Ok(())
+    }
+
+    /*
+     * Get flight level in FL
+     */
+    pub fn get_flight_level(&self) -> f32 {
+        to_signed(get_bits(self.level as u32, 14, 14), 14) as f32 * Self::FACTOR
+    }
+
+    /*
+     * Set code not validated
+     */
+    pub fn set_not_validated(&mut self, value: bool) {
+        self.level = set_bits(self.level as u32, 16, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get code not validated
+     */
+    pub fn get_not_validated(&self) -> bool {
+        get_bits(self.level as u32, 16, 1) == 1
+    }
+
+    /*
+     * Set garbled code
+     */
+    pub fn set_garbled(&mut self, value: bool) {
+        self.level = set_bits(self.level as u32, 15, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get garbled code
+     */
+    pub fn get_garbled(&self) -> bool {
+        get_bits(self.level as u32, 15, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+
+    /*
+     * Conversion factor.
+     */
+    const FACTOR: f32 = 1.0 / 4.0;
+}
+
+impl DataItem for FlightLevel {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let level = u16::from_be_bytes(fixed_array(array)?);
+        Ok((FlightLevel { level }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.level.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_flight_level() {
+        // Create message
+        let mut flight_level = FlightLevel::default();
+        flight_level.set_flight_level(-12.25).unwrap();
+        flight_level.set_not_validated(true);
+
+        // Convert struct to byte stream
+        let array = flight_level.encode();
+
+        assert_eq!(array, vec![0xbf, 0xcf]);
+
+        // New message
+        let (object, _length) = FlightLevel::decode(&array).unwrap();
+
+        assert_eq!(object.get_flight_level(), -12.25);
+        assert!(object.get_not_validated());
+        assert!(!object.get_garbled());
+        assert_eq!(
+            flight_level.set_flight_level(2048.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+}
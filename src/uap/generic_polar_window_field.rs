@@ -1,7 +1,12 @@
 use std::mem;
 
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
 // Generic Polar Window
 //
+// | RHO start | RHO end | THETA start | THETA end | 2 bytes each
+//
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
 pub struct GenericPolarWindow {
     rho_start: u16,   // 2 bytes
@@ -18,10 +23,10 @@ impl GenericPolarWindow {
      * Convert byte stream to struct.
      */
     pub fn from_bytes(&mut self, array: &[u8; Self::MESSAGE_LENGTH]) {
-        self.rho_start = array[6] as u16 + (array[7] as u16) << 8;
-        self.rho_end = array[4] as u16 + (array[5] as u16) << 8;
-        self.theta_start = array[2] as u16 + (array[3] as u16) << 8;
-        self.theta_end = array[0] as u16 + (array[1] as u16) << 8;
+        self.rho_start = u16::from_be_bytes([array[0], array[1]]);
+        self.rho_end = u16::from_be_bytes([array[2], array[3]]);
+        self.theta_start = u16::from_be_bytes([array[4], array[5]]);
+        self.theta_end = u16::from_be_bytes([array[6], array[7]]);
     }
 
     /*
@@ -34,14 +39,14 @@ impl GenericPolarWindow {
         let theta_end_bytes = self.theta_end.to_be_bytes();
 
         let mut array = [0u8; Self::MESSAGE_LENGTH];
-        array[7] = rho_start_bytes[0];
-        array[6] = rho_start_bytes[1];
-        array[5] = rho_end_bytes[0];
-        array[4] = rho_end_bytes[1];
-        array[3] = theta_start_bytes[0];
-        array[2] = theta_start_bytes[1];
-        array[1] = theta_end_bytes[0];
-        array[0] = theta_end_bytes[1];
+        array[0] = rho_start_bytes[0];
+        array[1] = rho_start_bytes[1];
+        array[2] = rho_end_bytes[0];
+        array[3] = rho_end_bytes[1];
+        array[4] = theta_start_bytes[0];
+        array[5] = theta_start_bytes[1];
+        array[6] = theta_end_bytes[0];
+        array[7] = theta_end_bytes[1];
         array
     }
 
@@ -54,66 +59,52 @@ impl GenericPolarWindow {
 
     /*
      * Set rho in nautical miles
-     * 1/256 <= start <= 256
-     * 1/256 <= end   <= 256
+     * 0 <= start < 256
+     * 0 <= end   < 256
      */
-    pub fn set_rho(&mut self, start: f32, end: f32) {
-        // Start
-        let trunc = (start * GenericPolarWindow::RANGE_FACTOR).trunc();
-        let converted_start = trunc as u16;
-
-        self.rho_start = converted_start.to_be();
+    pub fn set_rho(&mut self, start: f32, end: f32) -> Result<(), CatError> {
+        let rho_start = convert(start as f64, GenericPolarWindow::RANGE_FACTOR as f64)?;
+        let rho_end = convert(end as f64, GenericPolarWindow::RANGE_FACTOR as f64)?;
 
-        // End
-        let trunc = (end * GenericPolarWindow::RANGE_FACTOR).trunc();
-        let converted_end = trunc as u16;
-
-        self.rho_end = converted_end.to_be();
+        self.rho_start = rho_start;
+        self.rho_end = rho_end;
+        Ok(())
     }
 
     /*
      * Get rho in nautical miles
-     * 1/256 <= start <= 256
-     * 1/256 <= end   <= 256
+     * 0 <= start < 256
+     * 0 <= end   < 256
      */
     pub fn get_rho(&self) -> (f32, f32) {
-        let converted_start: f32 =
-            u16::from_be(self.rho_start) as f32 * GenericPolarWindow::RANGE_FACTOR;
-        let converted_end: f32 =
-            u16::from_be(self.rho_end) as f32 * GenericPolarWindow::RANGE_FACTOR;
+        let converted_start: f32 = self.rho_start as f32 * GenericPolarWindow::RANGE_FACTOR;
+        let converted_end: f32 = self.rho_end as f32 * GenericPolarWindow::RANGE_FACTOR;
 
         (converted_start, converted_end)
     }
 
     /*
      * Set theta in degrees
-     * 360/(2^16) <= start <= 360
-     * 360/(2^16) <= end   <= 360
+     * 0 <= start < 360
+     * 0 <= end   < 360
      */
-    pub fn set_theta(&mut self, start: f64, end: f64) {
-        // Start
-        let trunc = (start * GenericPolarWindow::ANGLE_FACTOR).trunc();
-        let converted_start = trunc as u16;
-
-        self.theta_start = converted_start.to_be();
+    pub fn set_theta(&mut self, start: f64, end: f64) -> Result<(), CatError> {
+        let theta_start = convert(start, GenericPolarWindow::ANGLE_FACTOR)?;
+        let theta_end = convert(end, GenericPolarWindow::ANGLE_FACTOR)?;
 
-        // End
-        let trunc = (end * GenericPolarWindow::ANGLE_FACTOR).trunc();
-        let converted_end = trunc as u16;
-
-        self.theta_end = converted_end.to_be();
+        self.theta_start = theta_start;
+        self.theta_end = theta_end;
+        Ok(())
     }
 
     /*
      * Get theta in degrees
-     * 360/(2^16) <= start <= 360
-     * 360/(2^16) <= end   <= 360
+     * 0 <= start < 360
+     * 0 <= end   < 360
      */
     pub fn get_theta(&self) -> (f64, f64) {
-        let converted_start: f64 =
-            u16::from_be(self.theta_start) as f64 * GenericPolarWindow::ANGLE_FACTOR;
-        let converted_end: f64 =
-            u16::from_be(self.theta_end) as f64 * GenericPolarWindow::ANGLE_FACTOR;
+        let converted_start: f64 = self.theta_start as f64 * GenericPolarWindow::ANGLE_FACTOR;
+        let converted_end: f64 = self.theta_end as f64 * GenericPolarWindow::ANGLE_FACTOR;
 
         (converted_start, converted_end)
     }
@@ -126,10 +117,34 @@ impl GenericPolarWindow {
     /*
      * Conversion factor.
      */
-    const RANGE_FACTOR: f32 = 1.0 / 256 as f32;
+    const RANGE_FACTOR: f32 = 1.0 / 256_f32;
     const ANGLE_FACTOR: f64 = 360.0 / u32::pow(2, 16) as f64;
 }
 
+/*
+ * Convert value with factor into unsigned 16 bit, checks the range.
+ */
+fn convert(value: f64, factor: f64) -> Result<u16, CatError> {
+    let converted = (value / factor).round();
+
+    if !(0.0..=u16::MAX as f64).contains(&converted) {
+        return Err(CatError::ValueInvalid);
+    }
+    Ok(converted as u16)
+}
+
+impl DataItem for GenericPolarWindow {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut generic_polar_window = GenericPolarWindow::default();
+        generic_polar_window.from_bytes(&fixed_array(array)?);
+        Ok((generic_polar_window, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,8 +153,8 @@ mod tests {
     fn check_polar_window() {
         // Create message
         let mut generic_polar_window = GenericPolarWindow::default();
-        generic_polar_window.set_rho(0.1, 123.4);
-        generic_polar_window.set_theta(0.1, 12.34);
+        generic_polar_window.set_rho(0.1, 123.4).unwrap();
+        generic_polar_window.set_theta(0.1, 12.34).unwrap();
 
         // Convert struct to byte stream
         let array = generic_polar_window.to_bytes();
@@ -153,4 +168,60 @@ mod tests {
         assert_eq!(generic_polar_window.get_rho(), object.get_rho());
         assert_eq!(generic_polar_window.get_theta(), object.get_theta());
     }
+
+    #[test]
+    fn check_polar_window_captured() {
+        // Rho 0.5 to 123.5 NM, theta 45 to 270 degrees
+        let array = [0x00, 0x80, 0x7b, 0x80, 0x20, 0x00, 0xc0, 0x00];
+
+        let mut object = GenericPolarWindow::default();
+        object.from_bytes(&array);
+
+        assert_eq!(object.get_rho(), (0.5, 123.5));
+        assert_eq!(object.get_theta(), (45.0, 270.0));
+
+        // Encoding gives the same bytes
+        let mut generic_polar_window = GenericPolarWindow::default();
+        generic_polar_window.set_rho(0.5, 123.5).unwrap();
+        generic_polar_window.set_theta(45.0, 270.0).unwrap();
+
+        assert_eq!(generic_polar_window.to_bytes(), array);
+    }
+
+    #[test]
+    fn check_polar_window_invalid() {
+        let mut generic_polar_window = GenericPolarWindow::default();
+
+        assert_eq!(
+            generic_polar_window.set_rho(-1.0, 10.0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            generic_polar_window.set_rho(0.0, 256.0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            generic_polar_window.set_theta(0.0, 360.0),
+            Err(CatError::ValueInvalid)
+        );
+    }
+
+    #[test]
+    fn check_polar_window_unchanged() {
+        let mut generic_polar_window = GenericPolarWindow::default();
+        generic_polar_window.set_rho(1.0, 2.0).unwrap();
+        generic_polar_window.set_theta(10.0, 20.0).unwrap();
+        let expected = generic_polar_window;
+
+        // Valid start value must not be stored when end value is invalid
+        assert_eq!(
+            generic_polar_window.set_rho(5.0, 256.0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            generic_polar_window.set_theta(45.0, 360.0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(generic_polar_window, expected);
+    }
 }
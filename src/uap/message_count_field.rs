@@ -0,0 +1,190 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Message Count Values
+//
+// Repetition factor followed by counters
+// | 16 15 14 13 12 | 11 .. 1 | bit
+// |      TYP       | COUNTER |
+//
+
+/// Types of message counters
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum MessageCountType {
+    NoDetection,
+    SinglePsr,
+    SingleSsr,
+    SsrPsr,
+    SingleAllCall,
+    SingleRollCall,
+    AllCallPsr,
+    RollCallPsr,
+    FilterWeather,
+    FilterJammingStrobe,
+    FilterPsr,
+    FilterSsrModeS,
+    FilterSsrModeSPsr,
+    FilterEnhancedSurveillance,
+    FilterPsrEnhancedSurveillance,
+    FilterPsrEnhancedSurveillanceModeSOutside,
+    FilterPsrEnhancedSurveillanceModeS,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct MessageCount {
+    counters: Vec<(MessageCountType, u16)>, // 2 bytes each
+}
+
+/*
+* Implementation MessageCount
+*/
+impl MessageCount {
+    /*
+     * Insert counter of message type
+     * 0 <= count <= 2047
+     */
+    pub fn insert_count(&mut self, typ: MessageCountType, count: u16) -> Result<(), CatError> {
+        if count > Self::COUNTER_MAX || self.counters.len() >= u8::MAX as usize {
+            return Err(CatError::ValueInvalid);
+        }
+        self.counters.push((typ, count));
+        Ok(())
+    }
+
+    /*
+     * Get all counters with message type
+     */
+    pub fn get_counts(&self) -> Vec<(MessageCountType, u16)> {
+        self.counters.clone()
+    }
+
+    /*
+     * Length of one counter.
+     */
+    pub const COUNTER_LENGTH: usize = 2;
+
+    /*
+     * Maximum counter value in 11 bits.
+     */
+    const COUNTER_MAX: u16 = 0x7ff;
+}
+
+/*
+ * Convert TYP subfield to message type.
+ */
+fn message_count_type(typ: u16) -> Result<MessageCountType, CatError> {
+    match typ {
+        0 => Ok(MessageCountType::NoDetection),
+        1 => Ok(MessageCountType::SinglePsr),
+        2 => Ok(MessageCountType::SingleSsr),
+        3 => Ok(MessageCountType::SsrPsr),
+        4 => Ok(MessageCountType::SingleAllCall),
+        5 => Ok(MessageCountType::SingleRollCall),
+        6 => Ok(MessageCountType::AllCallPsr),
+        7 => Ok(MessageCountType::RollCallPsr),
+        8 => Ok(MessageCountType::FilterWeather),
+        9 => Ok(MessageCountType::FilterJammingStrobe),
+        10 => Ok(MessageCountType::FilterPsr),
+        11 => Ok(MessageCountType::FilterSsrModeS),
+        12 => Ok(MessageCountType::FilterSsrModeSPsr),
+        13 => Ok(MessageCountType::FilterEnhancedSurveillance),
+        14 => Ok(MessageCountType::FilterPsrEnhancedSurveillance),
+        15 => Ok(MessageCountType::FilterPsrEnhancedSurveillanceModeSOutside),
+        16 => Ok(MessageCountType::FilterPsrEnhancedSurveillanceModeS),
+        _ => Err(CatError::ValueInvalid),
+    }
+}
+
+impl DataItem for MessageCount {
+    /*
+     * Decode the counters given by the repetition factor.
+     */
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::COUNTER_LENGTH;
+
+        // Counters run over the end of the byte stream
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let counters = array[1..length]
+            .chunks_exact(Self::COUNTER_LENGTH)
+            .map(|chunk| {
+                let counter = u16::from_be_bytes([chunk[0], chunk[1]]);
+                Ok((
+                    message_count_type(counter >> 11)?,
+                    counter & Self::COUNTER_MAX,
+                ))
+            })
+            .collect::<Result<Vec<_>, CatError>>()?;
+
+        Ok((MessageCount { counters }, length))
+    }
+
+    /*
+     * Encode the repetition factor followed by the counters.
+     */
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.counters.len() as u8];
+
+        for (typ, count) in self.counters.iter() {
+            let counter = ((*typ as u16) << 11) | count;
+            vector.extend_from_slice(&counter.to_be_bytes());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_message_count() {
+        // Create message
+        let mut message_count = MessageCount::default();
+        message_count
+            .insert_count(MessageCountType::SinglePsr, 1234)
+            .unwrap();
+        message_count
+            .insert_count(MessageCountType::FilterPsrEnhancedSurveillanceModeS, 2047)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = message_count.encode();
+
+        assert_eq!(array, vec![0x02, 0x0c, 0xd2, 0x87, 0xff]);
+
+        // Convert byte stream to struct
+        let (object, length) = MessageCount::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(
+            object.get_counts(),
+            vec![
+                (MessageCountType::SinglePsr, 1234),
+                (MessageCountType::FilterPsrEnhancedSurveillanceModeS, 2047)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_message_count_invalid() {
+        let mut message_count = MessageCount::default();
+
+        assert_eq!(
+            message_count.insert_count(MessageCountType::SingleSsr, 2048),
+            Err(CatError::ValueInvalid)
+        );
+
+        // Second counter is missing
+        let array = [0x02, 0x0c, 0xd2];
+
+        assert_eq!(MessageCount::decode(&array), Err(CatError::SizeInvalid(0)));
+
+        // Undefined message type 17
+        let array = [0x01, 0x88, 0x00];
+
+        assert_eq!(MessageCount::decode(&array), Err(CatError::ValueInvalid));
+    }
+}
@@ -1,5 +1,8 @@
 use std::mem;
 
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
 /// Types of messages
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum MessageTypeEnum {
@@ -79,6 +82,18 @@ impl MessageType {
     pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
 }
 
+impl DataItem for MessageType {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut message_type = MessageType::default();
+        message_type.from_bytes(&fixed_array(array)?);
+        Ok((message_type, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,45 @@
+pub mod acas_resolution_field;
+pub mod aircraft_address_field;
+pub mod aircraft_identification_field;
 pub mod antenna_rotation_field;
+pub mod cat01;
+pub mod cat02;
+pub mod cat04;
+pub mod cat07;
+pub mod cat08;
+pub mod cat10;
+pub mod cat11;
+pub mod cat19;
+pub mod cat20;
+pub mod cat21;
+pub mod cat23;
+pub mod cat240;
+pub mod cat25;
+pub mod cat48;
+pub mod cat62;
+pub mod cat63;
+pub mod cat65;
+pub mod collimation_error_field;
+pub mod compound_field;
+pub mod data_filter_field;
+pub mod data_item;
 pub mod data_source_field;
+pub mod explicit_field;
+pub mod extended_field;
 pub mod field_spec;
+pub mod flight_level_field;
 pub mod generic_polar_window_field;
+pub mod message_count_field;
 pub mod message_type_field;
+pub mod mode_3a_code_field;
+pub mod mode_s_mb_data_field;
+pub mod polar_position_field;
 pub mod position_source_field;
 pub mod sector_number_field;
+pub mod service_identification_field;
 pub mod system_configuration_field;
 pub mod system_processing_field;
+pub mod target_identification_field;
 pub mod time_of_day_field;
+pub mod track_number_field;
+pub mod vehicle_fleet_field;
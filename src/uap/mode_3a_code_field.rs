@@ -0,0 +1,123 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, get_bits, set_bits, DataItem};
+
+// Mode-3/A Code in Octal Representation, also used for Mode-2 Code
+//
+// | 16| 15| 14| 13| 12 .. 1                  | bit
+// | V | G | L | 0 | A4 A2 A1 B4 .. D2 D1     |
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct Mode3ACode {
+    code: u16, // 2 bytes
+}
+
+/*
+* Implementation Mode3ACode
+*/
+impl Mode3ACode {
+    /*
+     * Set code as octal number
+     * 0o0000 <= code <= 0o7777
+     */
+    pub fn set_code(&mut self, code: u16) -> Result<(), CatError> {
+        if code > 0o7777 {
+            return Err(CatError::ValueInvalid);
+        }
+        self.code = set_bits(self.code as u32, 12, 12, code as u32) as u16;
+        Ok(())
+    }
+
+    /*
+     * Get code as octal number
+     */
+    pub fn get_code(&self) -> u16 {
+        get_bits(self.code as u32, 12, 12) as u16
+    }
+
+    /*
+     * Set code not validated
+     */
+    pub fn set_not_validated(&mut self, value: bool) {
+        self.code = set_bits(self.code as u32, 16, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get code not validated
+     */
+    pub fn get_not_validated(&self) -> bool {
+        get_bits(self.code as u32, 16, 1) == 1
+    }
+
+    /*
+     * Set garbled code
+     */
+    pub fn set_garbled(&mut self, value: bool) {
+        self.code = set_bits(self.code as u32, 15, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get garbled code
+     */
+    pub fn get_garbled(&self) -> bool {
+        get_bits(self.code as u32, 15, 1) == 1
+    }
+
+    /*
+     * Set code not extracted during the last scan
+     */
+    pub fn set_not_extracted(&mut self, value: bool) {
+        self.code = set_bits(self.code as u32, 14, 1, value as u32) as u16;
+    }
+
+    /*
+     * Get code not extracted during the last scan
+     */
+    pub fn get_not_extracted(&self) -> bool {
+        get_bits(self.code as u32, 14, 1) == 1
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for Mode3ACode {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let code = u16::from_be_bytes(fixed_array(array)?);
+        Ok((Mode3ACode { code }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.code.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mode_3a_code() {
+        // Create message
+        let mut mode_3a_code = Mode3ACode::default();
+        mode_3a_code.set_code(0o7700).unwrap();
+        mode_3a_code.set_garbled(true);
+
+        // Convert struct to byte stream
+        let array = mode_3a_code.encode();
+
+        assert_eq!(array, vec![0x4f, 0xc0]);
+
+        // New message
+        let (object, _length) = Mode3ACode::decode(&array).unwrap();
+
+        assert_eq!(object.get_code(), 0o7700);
+        assert!(object.get_garbled());
+        assert!(!object.get_not_validated());
+        assert!(!object.get_not_extracted());
+        assert_eq!(mode_3a_code.set_code(0o10000), Err(CatError::ValueInvalid));
+    }
+}
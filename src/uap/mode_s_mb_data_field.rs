@@ -0,0 +1,120 @@
+use crate::category::CatError;
+use crate::uap::data_item::DataItem;
+
+// Mode S MB Data
+//
+// Repetition factor followed by BDS registers
+// | 64 .. 9 | 8 .. 5 | 4 .. 1 | bit
+// | MB DATA |  BDS1  |  BDS2  |
+//
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ModeSMbData {
+    registers: Vec<u64>, // 8 bytes each
+}
+
+/*
+* Implementation ModeSMbData
+*/
+impl ModeSMbData {
+    /*
+     * Insert 56 bit MB data of BDS register
+     * 0 <= bds1, bds2 <= 15
+     */
+    pub fn insert_mb_data(&mut self, mb_data: u64, bds1: u8, bds2: u8) -> Result<(), CatError> {
+        if mb_data > Self::MB_DATA_MAX
+            || bds1 > 0x0f
+            || bds2 > 0x0f
+            || self.registers.len() >= u8::MAX as usize
+        {
+            return Err(CatError::ValueInvalid);
+        }
+        let register = (mb_data << 8) | ((bds1 as u64) << 4) | bds2 as u64;
+        self.registers.push(register);
+        Ok(())
+    }
+
+    /*
+     * Get all MB data with BDS1 and BDS2
+     */
+    pub fn get_mb_data(&self) -> Vec<(u64, u8, u8)> {
+        self.registers
+            .iter()
+            .map(|register| {
+                (
+                    register >> 8,
+                    ((register >> 4) & 0x0f) as u8,
+                    (register & 0x0f) as u8,
+                )
+            })
+            .collect()
+    }
+
+    /*
+     * Length of one BDS register.
+     */
+    pub const REGISTER_LENGTH: usize = 8;
+
+    /*
+     * Maximum MB data value in 56 bits.
+     */
+    const MB_DATA_MAX: u64 = 0xff_ffff_ffff_ffff;
+}
+
+impl DataItem for ModeSMbData {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let repetition = *array.first().ok_or(CatError::SizeInvalid(0))? as usize;
+        let length = 1 + repetition * Self::REGISTER_LENGTH;
+        let slice = array.get(1..length).ok_or(CatError::SizeInvalid(0))?;
+
+        let registers = slice
+            .chunks_exact(Self::REGISTER_LENGTH)
+            .map(|chunk| u64::from_be_bytes(chunk.try_into().expect("chunk of register length")))
+            .collect();
+        Ok((ModeSMbData { registers }, length))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.registers.len() as u8];
+
+        for register in self.registers.iter() {
+            vector.extend_from_slice(&register.to_be_bytes());
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mode_s_mb_data() {
+        // Create message
+        let mut mode_s_mb_data = ModeSMbData::default();
+        mode_s_mb_data
+            .insert_mb_data(0x00a0_0000_0000_0001, 4, 0)
+            .unwrap();
+
+        // Convert struct to byte stream
+        let array = mode_s_mb_data.encode();
+
+        assert_eq!(
+            array,
+            vec![0x01, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x40]
+        );
+
+        // New message
+        let (object, length) = ModeSMbData::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(object.get_mb_data(), vec![(0x00a0_0000_0000_0001, 4, 0)]);
+        assert_eq!(
+            mode_s_mb_data.insert_mb_data(0x0100_0000_0000_0000, 4, 0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            ModeSMbData::decode(&array[0..8]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
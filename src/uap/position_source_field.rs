@@ -1,7 +1,12 @@
 use std::mem;
 
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
 // 3D Position of Data Source
 //
+// | Height (2 bytes) | Latitude (3 bytes) | Longitude (3 bytes) |
+//
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
 pub struct PositionSource {
     position: [u8; 8], // 8 bytes
@@ -37,43 +42,42 @@ impl PositionSource {
      *  -90 <= latitude  <=  90
      * -180 <= longitude <= 180
      */
-    pub fn set_lat_lon(&mut self, lat: f64, lon: f64) {
-        let trunc = (lat * PositionSource::FACTOR).trunc();
-        let converted_lat = trunc as i32;
+    pub fn set_lat_lon(&mut self, lat: f64, lon: f64) -> Result<(), CatError> {
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return Err(CatError::ValueInvalid);
+        }
+        let converted_lat = (lat / PositionSource::FACTOR).round() as i32;
         let lat_bytes = converted_lat.to_be_bytes();
 
-        // Latitude
-        self.position[3] = lat_bytes[0];
-        self.position[4] = lat_bytes[1];
-        self.position[5] = lat_bytes[2];
+        // Latitude, 24 bit two's complement
+        self.position[2] = lat_bytes[1];
+        self.position[3] = lat_bytes[2];
+        self.position[4] = lat_bytes[3];
 
-        let trunc = (lon * PositionSource::FACTOR).trunc();
-        let converted_lon = trunc as i32;
+        // Longitude 180 degrees is the same meridian as -180 degrees
+        let converted_lon = (lon / PositionSource::FACTOR).round() as i32;
+        let converted_lon = if converted_lon > PositionSource::MAX_VALUE {
+            converted_lon - 2 * (PositionSource::MAX_VALUE + 1)
+        } else {
+            converted_lon
+        };
         let lon_bytes = converted_lon.to_be_bytes();
 
-        // Longitude
-        self.position[0] = lon_bytes[0];
-        self.position[1] = lon_bytes[1];
-        self.position[2] = lon_bytes[2];
+        // Longitude, 24 bit two's complement
+        self.position[5] = lon_bytes[1];
+        self.position[6] = lon_bytes[2];
+        self.position[7] = lon_bytes[3];
+        Ok(())
     }
 
     /*
      * Get latitude/longitude in degrees
      *  -90 <= latitude  <=  90
-     * -180 <= longitude <= 180
+     * -180 <= longitude <  180
      */
     pub fn get_lat_lon(&self) -> (f64, f64) {
-        let hi = self.position[0] as u32;
-        let mi = (self.position[1] as u32) << 8;
-        let lo = (self.position[2] as u32) << 16;
-
-        let converted_lat: f64 = (hi + mi + lo) as f64 * Self::FACTOR;
-
-        let hi = self.position[3] as u32;
-        let mi = (self.position[4] as u32) << 8;
-        let lo = (self.position[5] as u32) << 16;
-
-        let converted_lon: f64 = (hi + mi + lo) as f64 * Self::FACTOR;
+        let converted_lat: f64 = to_i24(&self.position[2..5]) as f64 * Self::FACTOR;
+        let converted_lon: f64 = to_i24(&self.position[5..8]) as f64 * Self::FACTOR;
 
         (converted_lat, converted_lon)
     }
@@ -83,19 +87,15 @@ impl PositionSource {
      */
     pub fn set_height(&mut self, height: i16) {
         let height_bytes = height.to_be_bytes();
-        self.position[6] = height_bytes[0];
-        self.position[7] = height_bytes[1];
+        self.position[0] = height_bytes[0];
+        self.position[1] = height_bytes[1];
     }
 
     /*
      * Get height in meters
      */
     pub fn get_height(&self) -> i16 {
-        let hi = self.position[6] as u16;
-        let lo = (self.position[7] as u16) << 8;
-        
-        let converted_time: i16 = (hi + lo) as i16;
-        converted_time
+        i16::from_be_bytes([self.position[0], self.position[1]])
     }
 
     /*
@@ -107,18 +107,44 @@ impl PositionSource {
      * Conversion factor.
      */
     const FACTOR: f64 = 180.0 / u32::pow(2, 23) as f64;
+
+    /*
+     * Largest value of 24 bit two's complement.
+     */
+    const MAX_VALUE: i32 = (1 << 23) - 1;
+}
+
+/*
+ * Convert 24 bit two's complement in network byte order to integer.
+ */
+fn to_i24(array: &[u8]) -> i32 {
+    // Shift sign bit into the highest bit, then shift back with sign extension
+    i32::from_be_bytes([array[0], array[1], array[2], 0]) >> 8
+}
+
+impl DataItem for PositionSource {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let mut position_source = PositionSource::default();
+        position_source.from_bytes(&fixed_array(array)?);
+        Ok((position_source, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn check_position() {
         // Create message
         let mut position_source = PositionSource::default();
         position_source.set_height(4242);
-        position_source.set_lat_lon(12.345, 34.567);
+        position_source.set_lat_lon(12.345, 34.567).unwrap();
 
         // Convert struct to byte stream
         let array = position_source.to_bytes();
@@ -132,4 +158,139 @@ mod tests {
         assert_eq!(position_source.get_height(), object.get_height());
         assert_eq!(position_source.get_lat_lon(), object.get_lat_lon());
     }
+
+    #[test]
+    fn check_position_captured() {
+        // WGS84 Height 555 m
+        // WGS84 Latitude 47.8034663200378 deg
+        // WGS84 Longitude 9.27816867828369 deg
+        let array = [0x02, 0x2b, 0x21, 0xfe, 0x5b, 0x06, 0x99, 0x0a];
+
+        let mut object = PositionSource::default();
+        object.from_bytes(&array);
+
+        let (lat, lon) = object.get_lat_lon();
+
+        assert_eq!(object.get_height(), 555);
+        assert!((lat - 47.8034663200378).abs() < 1e-9);
+        assert!((lon - 9.27816867828369).abs() < 1e-9);
+
+        // Encoding gives the same bytes
+        let mut position_source = PositionSource::default();
+        position_source.set_height(555);
+        position_source.set_lat_lon(lat, lon).unwrap();
+
+        assert_eq!(position_source.to_bytes(), array);
+    }
+
+    #[test]
+    fn check_position_western() {
+        // Negative height, southern latitude, western longitude
+        let mut position_source = PositionSource::default();
+        position_source.set_height(-12);
+        position_source.set_lat_lon(-33.9425, -118.4081).unwrap();
+
+        let (lat, lon) = position_source.get_lat_lon();
+
+        assert_eq!(position_source.get_height(), -12);
+        assert!((lat + 33.9425).abs() <= PositionSource::FACTOR / 2.0);
+        assert!((lon + 118.4081).abs() <= PositionSource::FACTOR / 2.0);
+        assert_eq!(position_source.to_bytes()[0..2], [0xff, 0xf4]);
+    }
+
+    #[test]
+    fn check_position_range() {
+        let mut position_source = PositionSource::default();
+
+        // Sweep over the full latitude and longitude range
+        let steps = 7919;
+        for step in 0..=steps {
+            let lat = -90.0 + 180.0 * step as f64 / steps as f64;
+            let lon = -180.0 + 360.0 * ((step * 104729) % steps) as f64 / steps as f64;
+
+            position_source.set_lat_lon(lat, lon).unwrap();
+
+            let mut object = PositionSource::default();
+            object.from_bytes(&position_source.to_bytes());
+            let (object_lat, object_lon) = object.get_lat_lon();
+
+            assert!((object_lat - lat).abs() <= PositionSource::FACTOR / 2.0);
+            assert!((object_lon - lon).abs() <= PositionSource::FACTOR / 2.0);
+        }
+
+        // Sweep over the full height range
+        for height in (i16::MIN..=i16::MAX).step_by(7) {
+            position_source.set_height(height);
+
+            assert_eq!(position_source.get_height(), height);
+        }
+    }
+
+    #[test]
+    fn check_position_limits() {
+        let mut position_source = PositionSource::default();
+
+        position_source.set_lat_lon(90.0, -180.0).unwrap();
+        assert_eq!(position_source.get_lat_lon(), (90.0, -180.0));
+
+        // Longitude 180 degrees wraps to -180 degrees
+        position_source.set_lat_lon(-90.0, 180.0).unwrap();
+        assert_eq!(position_source.get_lat_lon(), (-90.0, -180.0));
+
+        assert_eq!(
+            position_source.set_lat_lon(90.1, 0.0),
+            Err(CatError::ValueInvalid)
+        );
+        assert_eq!(
+            position_source.set_lat_lon(0.0, -180.1),
+            Err(CatError::ValueInvalid)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_position_round_trip(
+            lat in -90.0..=90.0,
+            lon in -180.0..180.0 - PositionSource::FACTOR,
+            height in any::<i16>(),
+        ) {
+            let mut position_source = PositionSource::default();
+            position_source.set_lat_lon(lat, lon).unwrap();
+            position_source.set_height(height);
+
+            let (object, length) = PositionSource::decode(&position_source.encode()).unwrap();
+            let (object_lat, object_lon) = object.get_lat_lon();
+
+            // Decoded position is within half a LSB of the input
+            prop_assert_eq!(length, PositionSource::MESSAGE_LENGTH);
+            prop_assert!((object_lat - lat).abs() <= PositionSource::FACTOR / 2.0);
+            prop_assert!((object_lon - lon).abs() <= PositionSource::FACTOR / 2.0);
+            prop_assert_eq!(object.get_height(), height);
+        }
+
+        #[test]
+        fn prop_position_negative(
+            lat in -90.0..-PositionSource::FACTOR,
+            lon in -180.0..-PositionSource::FACTOR,
+            height in i16::MIN..0,
+        ) {
+            let mut position_source = PositionSource::default();
+            position_source.set_lat_lon(lat, lon).unwrap();
+            position_source.set_height(height);
+
+            let array = position_source.encode();
+            let (object, _) = PositionSource::decode(&array).unwrap();
+            let (object_lat, object_lon) = object.get_lat_lon();
+
+            // Southern, western and below sea level keep their sign
+            prop_assert!(object_lat < 0.0);
+            prop_assert!(object_lon < 0.0);
+            prop_assert!(object.get_height() < 0);
+
+            // Sign bit is the highest bit of each subfield
+            prop_assert_eq!(array[0] & 0x80, 0x80);
+            prop_assert_eq!(array[2] & 0x80, 0x80);
+            prop_assert_eq!(array[5] & 0x80, 0x80);
+        }
+    }
 }
@@ -1,5 +1,8 @@
 use std::mem;
 
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
 // Sector Number
 //
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
@@ -61,6 +64,17 @@ impl SectorNumber {
     const FACTOR: f32 = 360.0 / 256.0;
 }
 
+impl DataItem for SectorNumber {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [sector] = fixed_array(array)?;
+        Ok((SectorNumber { sector }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
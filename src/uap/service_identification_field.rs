@@ -0,0 +1,67 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Service Identification
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct ServiceIdentification {
+    service: u8, // 1 byte
+}
+
+/*
+* Implementation ServiceIdentification
+*/
+impl ServiceIdentification {
+    /*
+     * Set service identification
+     */
+    pub fn set_service(&mut self, service: u8) {
+        self.service = service;
+    }
+
+    /*
+     * Get service identification
+     */
+    pub fn get_service(&self) -> u8 {
+        self.service
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for ServiceIdentification {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [service] = fixed_array(array)?;
+        Ok((ServiceIdentification { service }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.service]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_service_identification() {
+        // Create message
+        let mut service_identification = ServiceIdentification::default();
+        service_identification.set_service(3);
+
+        // Convert struct to byte stream
+        let array = service_identification.encode();
+
+        // New message
+        let (object, length) = ServiceIdentification::decode(&array).unwrap();
+
+        assert_eq!(length, ServiceIdentification::MESSAGE_LENGTH);
+        assert_eq!(object.get_service(), 3);
+    }
+}
@@ -1,4 +1,23 @@
-// System Configuration Status
+use crate::category::CatError;
+use crate::uap::data_item::{compound_length, get_bits, set_bits, DataItem};
+
+// System Configuration and Status
+//
+// Primary Subfield
+// | 8 | 7 | 6 | 5 | 4 | 3 | 2 | 1 | bit
+// |COM| 0 | 0 |PSR|SSR|MDS| 0 | FX| subfield
+//
+// COM Subfield
+// | 8  | 7  | 6  |   5   |   4   | 3 | 2 | 1 | bit
+// |NOGO|RDPC|RDPR|OVL RDP|OVL XMT|MSC|TSV| 0 |
+//
+// PSR and SSR Subfield
+// | 8 | 7  6 | 5 | 4 | 3 | 2 | 1 | bit
+// |ANT|CH-A/B|OVL|MSC| 0 | 0 | 0 |
+//
+// MDS Subfield
+// | 16| 15 14|   13  | 12| 11| 10|   9   |   8   | 7..1 | bit
+// |ANT|CH-A/B|OVL SUR|MSC|SCF|DLF|OVL SCF|OVL DLF|  0   |
 //
 
 /// Types of subfields with bit position from right to left
@@ -10,13 +29,72 @@ pub enum Subfield {
     Mds = 3,
 }
 
+/// Bits of COM subfield with bit position from right to left
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ComBit {
+    /// Operational use of system is inhibited
+    Nogo = 8,
+    /// RDPC-2 selected
+    Rdpc = 7,
+    /// Reset of RDPC
+    Rdpr = 6,
+    /// Overload in RDP
+    OvlRdp = 5,
+    /// Overload in transmission subsystem
+    OvlXmt = 4,
+    /// Monitoring system disconnected
+    Msc = 3,
+    /// Time source invalid
+    Tsv = 2,
+}
+
+/// Bits of PSR and SSR subfield with bit position from right to left
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum SensorBit {
+    /// Antenna 2 selected
+    Ant = 8,
+    /// Overload condition
+    Ovl = 5,
+    /// Monitoring system disconnected
+    Msc = 4,
+}
+
+/// Bits of MDS subfield with bit position from right to left
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum MdsBit {
+    /// Antenna 2 selected
+    Ant = 16,
+    /// Overload in surveillance
+    OvlSur = 13,
+    /// Monitoring system disconnected
+    Msc = 12,
+    /// Channel B in use for Surveillance Co-ordination Function
+    Scf = 11,
+    /// Channel B in use for Data Link Function
+    Dlf = 10,
+    /// Overload in Surveillance Co-ordination Function
+    OvlScf = 9,
+    /// Overload in Data Link Function
+    OvlDlf = 8,
+}
+
+/// Channel selection of a sensor
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ChannelSelection {
+    NoChannel,
+    ChannelA,
+    ChannelB,
+    /// Diversity mode for PSR/SSR, illegal combination for MDS
+    Both,
+}
+
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
 pub struct SystemConfigurationStatus {
     configuration_options: u8, // 1 byte
     com_subfield: u8,          // 1 byte
     psr_subfield: u8,          // 1 byte
     ssr_subfield: u8,          // 1 byte
-    mds_subfield: u8,          // 1 byte
+    mds_subfield: u16,         // 2 bytes
 }
 
 /*
@@ -24,72 +102,244 @@ pub struct SystemConfigurationStatus {
 */
 impl SystemConfigurationStatus {
     /*
-     * Convert byte stream to struct.
+     * Byte length of the data item defined by the primary subfield.
      */
-    pub fn from_bytes(&mut self, array: u8) {
-        self.configuration_options = array;
+    fn item_length(primary: u8) -> usize {
+        compound_length(
+            primary,
+            &[
+                (Subfield::Com as u8, 1),
+                (Subfield::Psr as u8, 1),
+                (Subfield::Ssr as u8, 1),
+                (Subfield::Mds as u8, 2),
+            ],
+        )
     }
 
     /*
-     * Convert struct to byte stream.
+     * Set subfield present in primary subfield
      */
-    pub fn to_bytes(&self) -> u8 {
-        self.configuration_options
+    pub fn set_configuration_option(&mut self, subfield: Subfield) {
+        let pattern = 0x1_u8 << (subfield as u8 - 1);
+        let field = u8::from_be(self.configuration_options);
+
+        let result = field | pattern;
+        self.configuration_options = result.to_be();
     }
 
     /*
-     * Convert byte stream to struct.
+     * Get subfield present in primary subfield
      */
-    pub fn from_bytes_subfield(&mut self, subfield: Subfield, array: u8) {
-        match subfield {
-            Subfield::Com => self.com_subfield = array,
-            Subfield::Psr => self.psr_subfield = array,
-            Subfield::Ssr => self.ssr_subfield = array,
-            Subfield::Mds => self.mds_subfield = array,
-        }
+    pub fn get_configuration_option(&self, subfield: Subfield) -> bool {
+        let pattern = 0x1_u8 << (subfield as u8 - 1);
+        let field = u8::from_be(self.configuration_options);
+
+        let result = field & pattern;
+        result == pattern
     }
 
     /*
-     * Convert struct to byte stream.
+     * Set COM bit, makes COM subfield present
      */
-    pub fn to_bytes_subfield(&self, subfield: Subfield) -> u8 {
-        match subfield {
-            Subfield::Com => self.com_subfield,
-            Subfield::Psr => self.psr_subfield,
-            Subfield::Ssr => self.ssr_subfield,
-            Subfield::Mds => self.mds_subfield,
-        }
+    pub fn set_com_bit(&mut self, bit: ComBit, value: bool) {
+        self.set_configuration_option(Subfield::Com);
+        self.com_subfield = set_bit(self.com_subfield as u16, bit as u8, value) as u8;
     }
 
-    fn set_configuration_option(&mut self, subfield: Subfield) {
-        let pattern = (0x1 as u8) << (subfield as u8 - 1);
-        let field = u8::from_be(self.configuration_options);
+    /*
+     * Get COM bit
+     */
+    pub fn get_com_bit(&self, bit: ComBit) -> bool {
+        get_bit(self.com_subfield as u16, bit as u8)
+    }
 
-        let result = field | pattern;
-        self.configuration_options = result.to_be();
+    /*
+     * Set PSR bit, makes PSR subfield present
+     */
+    pub fn set_psr_bit(&mut self, bit: SensorBit, value: bool) {
+        self.set_configuration_option(Subfield::Psr);
+        self.psr_subfield = set_bit(self.psr_subfield as u16, bit as u8, value) as u8;
     }
 
-    fn get_configuration_option(&self, subfield: Subfield) -> bool {
-        let pattern = (0x1 as u8) << (subfield as u8 - 1);
-        let field = u8::from_be(self.configuration_options);
+    /*
+     * Get PSR bit
+     */
+    pub fn get_psr_bit(&self, bit: SensorBit) -> bool {
+        get_bit(self.psr_subfield as u16, bit as u8)
+    }
 
-        let result = field & pattern;
-        return result == pattern;
+    /*
+     * Set PSR channel, makes PSR subfield present
+     */
+    pub fn set_psr_channel(&mut self, channel: ChannelSelection) {
+        self.set_configuration_option(Subfield::Psr);
+        self.psr_subfield = set_channel(self.psr_subfield as u16, 6, channel) as u8;
+    }
+
+    /*
+     * Get PSR channel
+     */
+    pub fn get_psr_channel(&self) -> ChannelSelection {
+        get_channel(self.psr_subfield as u16, 6)
+    }
+
+    /*
+     * Set SSR bit, makes SSR subfield present
+     */
+    pub fn set_ssr_bit(&mut self, bit: SensorBit, value: bool) {
+        self.set_configuration_option(Subfield::Ssr);
+        self.ssr_subfield = set_bit(self.ssr_subfield as u16, bit as u8, value) as u8;
+    }
+
+    /*
+     * Get SSR bit
+     */
+    pub fn get_ssr_bit(&self, bit: SensorBit) -> bool {
+        get_bit(self.ssr_subfield as u16, bit as u8)
+    }
+
+    /*
+     * Set SSR channel, makes SSR subfield present
+     */
+    pub fn set_ssr_channel(&mut self, channel: ChannelSelection) {
+        self.set_configuration_option(Subfield::Ssr);
+        self.ssr_subfield = set_channel(self.ssr_subfield as u16, 6, channel) as u8;
+    }
+
+    /*
+     * Get SSR channel
+     */
+    pub fn get_ssr_channel(&self) -> ChannelSelection {
+        get_channel(self.ssr_subfield as u16, 6)
     }
 
     /*
-     * Convert struct to byte stream.
+     * Set MDS bit, makes MDS subfield present
      */
-    // pub fn to_bytes(&self) -> Box<[u8]> {
-    //     // TODO: Count bits in options and assign array[1..3] to subfields
-    //     if self.processing_options == 1 {
-    //         let array = [0u8; 2];
-    //         Box::new(array)
-    //     } else {
-    //         let array = [0u8; 3];
-    //         Box::new(array)
-    //     }
-    // }
+    pub fn set_mds_bit(&mut self, bit: MdsBit, value: bool) {
+        self.set_configuration_option(Subfield::Mds);
+        self.mds_subfield = set_bit(self.mds_subfield, bit as u8, value);
+    }
+
+    /*
+     * Get MDS bit
+     */
+    pub fn get_mds_bit(&self, bit: MdsBit) -> bool {
+        get_bit(self.mds_subfield, bit as u8)
+    }
+
+    /*
+     * Set MDS channel, makes MDS subfield present
+     */
+    pub fn set_mds_channel(&mut self, channel: ChannelSelection) {
+        self.set_configuration_option(Subfield::Mds);
+        self.mds_subfield = set_channel(self.mds_subfield, 14, channel);
+    }
+
+    /*
+     * Get MDS channel
+     */
+    pub fn get_mds_channel(&self) -> ChannelSelection {
+        get_channel(self.mds_subfield, 14)
+    }
+}
+
+/*
+ * Set bit at position from right to left.
+ */
+fn set_bit(field: u16, bit: u8, value: bool) -> u16 {
+    set_bits(field as u32, bit, 1, value as u32) as u16
+}
+
+/*
+ * Get bit at position from right to left.
+ */
+fn get_bit(field: u16, bit: u8) -> bool {
+    get_bits(field as u32, bit, 1) == 1
+}
+
+/*
+ * Set two bit channel selection, lowest bit position from right to left.
+ */
+fn set_channel(field: u16, bit: u8, channel: ChannelSelection) -> u16 {
+    let value = match channel {
+        ChannelSelection::NoChannel => 0,
+        ChannelSelection::ChannelA => 1,
+        ChannelSelection::ChannelB => 2,
+        ChannelSelection::Both => 3,
+    };
+    set_bits(field as u32, bit + 1, 2, value) as u16
+}
+
+/*
+ * Get two bit channel selection, lowest bit position from right to left.
+ */
+fn get_channel(field: u16, bit: u8) -> ChannelSelection {
+    match get_bits(field as u32, bit + 1, 2) {
+        1 => ChannelSelection::ChannelA,
+        2 => ChannelSelection::ChannelB,
+        3 => ChannelSelection::Both,
+        _ => ChannelSelection::NoChannel,
+    }
+}
+
+impl DataItem for SystemConfigurationStatus {
+    /*
+     * Decode the subfields present in the primary subfield.
+     */
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let primary = *array.first().ok_or(CatError::SizeInvalid(0))?;
+        let length = Self::item_length(primary);
+
+        // Subfields run over the end of the byte stream
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let mut system_configuration = SystemConfigurationStatus {
+            configuration_options: primary,
+            ..Default::default()
+        };
+
+        let mut index = 1;
+        if system_configuration.get_configuration_option(Subfield::Com) {
+            system_configuration.com_subfield = array[index];
+            index += 1;
+        }
+        if system_configuration.get_configuration_option(Subfield::Psr) {
+            system_configuration.psr_subfield = array[index];
+            index += 1;
+        }
+        if system_configuration.get_configuration_option(Subfield::Ssr) {
+            system_configuration.ssr_subfield = array[index];
+            index += 1;
+        }
+        if system_configuration.get_configuration_option(Subfield::Mds) {
+            system_configuration.mds_subfield =
+                u16::from_be_bytes([array[index], array[index + 1]]);
+        }
+        Ok((system_configuration, length))
+    }
+
+    /*
+     * Encode only the subfields present in the primary subfield.
+     */
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.configuration_options];
+
+        if self.get_configuration_option(Subfield::Com) {
+            vector.push(self.com_subfield);
+        }
+        if self.get_configuration_option(Subfield::Psr) {
+            vector.push(self.psr_subfield);
+        }
+        if self.get_configuration_option(Subfield::Ssr) {
+            vector.push(self.ssr_subfield);
+        }
+        if self.get_configuration_option(Subfield::Mds) {
+            vector.extend_from_slice(&self.mds_subfield.to_be_bytes());
+        }
+        vector
+    }
 }
 
 #[cfg(test)]
@@ -103,14 +353,61 @@ mod tests {
         system_configuration.set_configuration_option(Subfield::Com);
 
         // Convert struct to byte stream
-        let array = system_configuration.to_bytes();
+        let array = system_configuration.encode();
+
+        // Convert byte stream to struct
+        let (object, length) = SystemConfigurationStatus::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(
+            system_configuration.get_configuration_option(Subfield::Com),
+            object.get_configuration_option(Subfield::Com)
+        );
+    }
 
-        // New message
-        let mut object = SystemConfigurationStatus::default();
+    #[test]
+    fn check_system_configuration_subfields() {
+        // Create message
+        let mut system_configuration = SystemConfigurationStatus::default();
+        system_configuration.set_com_bit(ComBit::Nogo, true);
+        system_configuration.set_com_bit(ComBit::Tsv, true);
+        system_configuration.set_ssr_channel(ChannelSelection::ChannelB);
+        system_configuration.set_ssr_bit(SensorBit::Ovl, true);
+        system_configuration.set_mds_channel(ChannelSelection::ChannelA);
+        system_configuration.set_mds_bit(MdsBit::OvlDlf, true);
+
+        // Convert struct to byte stream
+        let array = system_configuration.encode();
+
+        assert_eq!(array, vec![0x8c, 0x82, 0x50, 0x20, 0x80]);
 
         // Convert byte stream to struct
-        object.from_bytes(array);
+        let (object, length) = SystemConfigurationStatus::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(system_configuration, object);
+        assert!(object.get_com_bit(ComBit::Nogo));
+        assert!(!object.get_com_bit(ComBit::Rdpc));
+        assert!(!object.get_configuration_option(Subfield::Psr));
+        assert_eq!(object.get_ssr_channel(), ChannelSelection::ChannelB);
+        assert!(object.get_ssr_bit(SensorBit::Ovl));
+        assert_eq!(object.get_mds_channel(), ChannelSelection::ChannelA);
+        assert!(object.get_mds_bit(MdsBit::OvlDlf));
+        assert!(!object.get_mds_bit(MdsBit::Ant));
+    }
+
+    #[test]
+    fn check_system_configuration_length() {
+        // MDS subfield is two bytes long
+        let array = [0x04, 0x12];
 
-        assert_eq!(system_configuration.get_configuration_option(Subfield::Com), object.get_configuration_option(Subfield::Com));
+        assert_eq!(
+            SystemConfigurationStatus::decode(&array),
+            Err(CatError::SizeInvalid(0))
+        );
+        assert_eq!(
+            SystemConfigurationStatus::decode(&[]),
+            Err(CatError::SizeInvalid(0))
+        );
     }
 }
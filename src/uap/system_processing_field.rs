@@ -1,5 +1,28 @@
+use crate::category::CatError;
+use crate::uap::data_item::{compound_length, get_bits, set_bits, DataItem};
+
 // System Processing Mode
 //
+// Primary Subfield
+// | 8 | 7 | 6 | 5 | 4 | 3 | 2 | 1 | bit
+// |COM| 0 | 0 |PSR|SSR|MDS| 0 | FX| subfield
+//
+// COM Subfield
+// | 8 | 7  6  5 | 4  3  2 | 1 | bit
+// | 0 | REDRDP  | REDXMT  | 0 |
+//
+// PSR Subfield
+// | 8 | 7  6  5 | 4  3 | 2 | 1 | bit
+// |POL| REDRAD  | STC  | 0 | 0 |
+//
+// SSR Subfield
+// | 8  7  6 | 5 | 4 | 3 | 2 | 1 | bit
+// | REDRAD  | 0 | 0 | 0 | 0 | 0 |
+//
+// MDS Subfield
+// | 8  7  6 | 5 | 4 | 3 | 2 | 1 | bit
+// | REDRAD  |CLU| 0 | 0 | 0 | 0 |
+//
 
 /// Types of subfields with bit position from right to left
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -10,6 +33,29 @@ pub enum Subfield {
     Mds = 3,
 }
 
+/// PSR antenna polarization
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Polarization {
+    Linear,
+    Circular,
+}
+
+/// PSR Sensitivity Time Control map in use
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum StcMap {
+    Map1,
+    Map2,
+    Map3,
+    Map4,
+}
+
+/// MDS cluster state
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ClusterState {
+    Autonomous,
+    NotAutonomous,
+}
+
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
 pub struct SystemProcessingMode {
     processing_options: u8, // 1 byte
@@ -24,57 +70,269 @@ pub struct SystemProcessingMode {
 */
 impl SystemProcessingMode {
     /*
-     * Convert byte stream to struct.
+     * Byte length of the data item defined by the primary subfield.
+     * Each subfield is one byte long.
+     */
+    fn item_length(primary: u8) -> usize {
+        compound_length(
+            primary,
+            &[
+                (Subfield::Com as u8, 1),
+                (Subfield::Psr as u8, 1),
+                (Subfield::Ssr as u8, 1),
+                (Subfield::Mds as u8, 1),
+            ],
+        )
+    }
+
+    /*
+     * Set subfield present in primary subfield
+     */
+    pub fn set_processing_option(&mut self, subfield: Subfield) {
+        let pattern = 0x1_u8 << (subfield as u8 - 1);
+        let field = u8::from_be(self.processing_options);
+
+        let result = field | pattern;
+        self.processing_options = result.to_be();
+    }
+
+    /*
+     * Get subfield present in primary subfield
+     */
+    pub fn get_processing_option(&self, subfield: Subfield) -> bool {
+        let pattern = 0x1_u8 << (subfield as u8 - 1);
+        let field = u8::from_be(self.processing_options);
+
+        let result = field & pattern;
+        result == pattern
+    }
+
+    /*
+     * Set COM reduction steps in use for RDP overload
+     * 0 <= steps <= 7
+     */
+    pub fn set_com_redrdp(&mut self, steps: u8) -> Result<(), CatError> {
+        let steps = check_reduction_steps(steps)?;
+        self.set_processing_option(Subfield::Com);
+        self.com_subfield = set_bits(self.com_subfield as u32, 7, 3, steps as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get COM reduction steps in use for RDP overload
+     */
+    pub fn get_com_redrdp(&self) -> u8 {
+        get_bits(self.com_subfield as u32, 7, 3) as u8
+    }
+
+    /*
+     * Set COM reduction steps in use for transmission overload
+     * 0 <= steps <= 7
+     */
+    pub fn set_com_redxmt(&mut self, steps: u8) -> Result<(), CatError> {
+        let steps = check_reduction_steps(steps)?;
+        self.set_processing_option(Subfield::Com);
+        self.com_subfield = set_bits(self.com_subfield as u32, 4, 3, steps as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get COM reduction steps in use for transmission overload
      */
-    pub fn from_bytes(&mut self, array: u8) {
-        self.processing_options = array;
+    pub fn get_com_redxmt(&self) -> u8 {
+        get_bits(self.com_subfield as u32, 4, 3) as u8
     }
 
     /*
-     * Convert struct to byte stream.
+     * Set PSR polarization
      */
-    pub fn to_bytes(&self) -> u8 {
-        self.processing_options
+    pub fn set_psr_polarization(&mut self, polarization: Polarization) {
+        let value = match polarization {
+            Polarization::Linear => 0,
+            Polarization::Circular => 1,
+        };
+        self.set_processing_option(Subfield::Psr);
+        self.psr_subfield = set_bits(self.psr_subfield as u32, 8, 1, value as u32) as u8;
     }
 
     /*
-     * Convert byte stream to struct.
+     * Get PSR polarization
      */
-    pub fn from_bytes_subfield(&mut self, subfield: Subfield, array: u8) {
-        match subfield {
-            Subfield::Com => self.com_subfield = array,
-            Subfield::Psr => self.psr_subfield = array,
-            Subfield::Ssr => self.ssr_subfield = array,
-            Subfield::Mds => self.mds_subfield = array,
+    pub fn get_psr_polarization(&self) -> Polarization {
+        match get_bits(self.psr_subfield as u32, 8, 1) {
+            1 => Polarization::Circular,
+            _ => Polarization::Linear,
         }
     }
 
     /*
-     * Convert struct to byte stream.
+     * Set PSR reduction steps in use as result of an overload
+     * 0 <= steps <= 7
      */
-    pub fn to_bytes_subfield(&self, subfield: Subfield) -> u8 {
-        match subfield {
-            Subfield::Com => self.com_subfield,
-            Subfield::Psr => self.psr_subfield,
-            Subfield::Ssr => self.ssr_subfield,
-            Subfield::Mds => self.mds_subfield,
+    pub fn set_psr_redrad(&mut self, steps: u8) -> Result<(), CatError> {
+        let steps = check_reduction_steps(steps)?;
+        self.set_processing_option(Subfield::Psr);
+        self.psr_subfield = set_bits(self.psr_subfield as u32, 7, 3, steps as u32) as u8;
+        Ok(())
+    }
+
+    /*
+     * Get PSR reduction steps in use as result of an overload
+     */
+    pub fn get_psr_redrad(&self) -> u8 {
+        get_bits(self.psr_subfield as u32, 7, 3) as u8
+    }
+
+    /*
+     * Set PSR Sensitivity Time Control map
+     */
+    pub fn set_psr_stc(&mut self, stc: StcMap) {
+        let value = match stc {
+            StcMap::Map1 => 0,
+            StcMap::Map2 => 1,
+            StcMap::Map3 => 2,
+            StcMap::Map4 => 3,
+        };
+        self.set_processing_option(Subfield::Psr);
+        self.psr_subfield = set_bits(self.psr_subfield as u32, 4, 2, value as u32) as u8;
+    }
+
+    /*
+     * Get PSR Sensitivity Time Control map
+     */
+    pub fn get_psr_stc(&self) -> StcMap {
+        match get_bits(self.psr_subfield as u32, 4, 2) {
+            1 => StcMap::Map2,
+            2 => StcMap::Map3,
+            3 => StcMap::Map4,
+            _ => StcMap::Map1,
         }
     }
 
-    fn set_processing_option(&mut self, subfield: Subfield) {
-        let pattern = (0x1 as u8) << (subfield as u8 - 1);
-        let field = u8::from_be(self.processing_options);
+    /*
+     * Set SSR reduction steps in use as result of an overload
+     * 0 <= steps <= 7
+     */
+    pub fn set_ssr_redrad(&mut self, steps: u8) -> Result<(), CatError> {
+        let steps = check_reduction_steps(steps)?;
+        self.set_processing_option(Subfield::Ssr);
+        self.ssr_subfield = set_bits(self.ssr_subfield as u32, 8, 3, steps as u32) as u8;
+        Ok(())
+    }
 
-        let result = field | pattern;
-        self.processing_options = result.to_be();
+    /*
+     * Get SSR reduction steps in use as result of an overload
+     */
+    pub fn get_ssr_redrad(&self) -> u8 {
+        get_bits(self.ssr_subfield as u32, 8, 3) as u8
     }
 
-    fn get_processing_option(&self, subfield: Subfield) -> bool {
-        let pattern = (0x1 as u8) << (subfield as u8 - 1);
-        let field = u8::from_be(self.processing_options);
+    /*
+     * Set MDS reduction steps in use as result of an overload
+     * 0 <= steps <= 7
+     */
+    pub fn set_mds_redrad(&mut self, steps: u8) -> Result<(), CatError> {
+        let steps = check_reduction_steps(steps)?;
+        self.set_processing_option(Subfield::Mds);
+        self.mds_subfield = set_bits(self.mds_subfield as u32, 8, 3, steps as u32) as u8;
+        Ok(())
+    }
 
-        let result = field & pattern;
-        return result == pattern;
+    /*
+     * Get MDS reduction steps in use as result of an overload
+     */
+    pub fn get_mds_redrad(&self) -> u8 {
+        get_bits(self.mds_subfield as u32, 8, 3) as u8
+    }
+
+    /*
+     * Set MDS cluster state
+     */
+    pub fn set_mds_cluster(&mut self, cluster: ClusterState) {
+        let value = match cluster {
+            ClusterState::Autonomous => 0,
+            ClusterState::NotAutonomous => 1,
+        };
+        self.set_processing_option(Subfield::Mds);
+        self.mds_subfield = set_bits(self.mds_subfield as u32, 5, 1, value as u32) as u8;
+    }
+
+    /*
+     * Get MDS cluster state
+     */
+    pub fn get_mds_cluster(&self) -> ClusterState {
+        match get_bits(self.mds_subfield as u32, 5, 1) {
+            1 => ClusterState::NotAutonomous,
+            _ => ClusterState::Autonomous,
+        }
+    }
+}
+
+/*
+ * Check reduction steps fit into three bits.
+ */
+fn check_reduction_steps(steps: u8) -> Result<u8, CatError> {
+    if steps > 7 {
+        return Err(CatError::ValueInvalid);
+    }
+    Ok(steps)
+}
+
+impl DataItem for SystemProcessingMode {
+    /*
+     * Decode the subfields present in the primary subfield.
+     */
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let primary = *array.first().ok_or(CatError::SizeInvalid(0))?;
+        let length = Self::item_length(primary);
+
+        // Subfields run over the end of the byte stream
+        if array.len() < length {
+            return Err(CatError::SizeInvalid(0));
+        }
+        let mut system_processing = SystemProcessingMode {
+            processing_options: primary,
+            ..Default::default()
+        };
+
+        let mut index = 1;
+        if system_processing.get_processing_option(Subfield::Com) {
+            system_processing.com_subfield = array[index];
+            index += 1;
+        }
+        if system_processing.get_processing_option(Subfield::Psr) {
+            system_processing.psr_subfield = array[index];
+            index += 1;
+        }
+        if system_processing.get_processing_option(Subfield::Ssr) {
+            system_processing.ssr_subfield = array[index];
+            index += 1;
+        }
+        if system_processing.get_processing_option(Subfield::Mds) {
+            system_processing.mds_subfield = array[index];
+        }
+        Ok((system_processing, length))
+    }
+
+    /*
+     * Encode only the subfields present in the primary subfield.
+     */
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.processing_options];
+
+        if self.get_processing_option(Subfield::Com) {
+            vector.push(self.com_subfield);
+        }
+        if self.get_processing_option(Subfield::Psr) {
+            vector.push(self.psr_subfield);
+        }
+        if self.get_processing_option(Subfield::Ssr) {
+            vector.push(self.ssr_subfield);
+        }
+        if self.get_processing_option(Subfield::Mds) {
+            vector.push(self.mds_subfield);
+        }
+        vector
     }
 }
 
@@ -89,14 +347,65 @@ mod tests {
         system_processing.set_processing_option(Subfield::Com);
 
         // Convert struct to byte stream
-        let array = system_processing.to_bytes();
+        let array = system_processing.encode();
 
-        // New message
-        let mut object = SystemProcessingMode::default();
+        // Convert byte stream to struct
+        let (object, length) = SystemProcessingMode::decode(&array).unwrap();
+
+        assert_eq!(length, 2);
+        assert_eq!(
+            system_processing.get_processing_option(Subfield::Com),
+            object.get_processing_option(Subfield::Com)
+        );
+    }
+
+    #[test]
+    fn check_processing_subfields() {
+        // Create message
+        let mut system_processing = SystemProcessingMode::default();
+        system_processing.set_com_redrdp(5).unwrap();
+        system_processing.set_com_redxmt(2).unwrap();
+        system_processing.set_psr_polarization(Polarization::Circular);
+        system_processing.set_psr_redrad(3).unwrap();
+        system_processing.set_psr_stc(StcMap::Map3);
+        system_processing.set_mds_redrad(7).unwrap();
+        system_processing.set_mds_cluster(ClusterState::NotAutonomous);
+
+        // Convert struct to byte stream
+        let array = system_processing.encode();
+
+        assert_eq!(array, vec![0x94, 0x54, 0xb8, 0xf0]);
 
         // Convert byte stream to struct
-        object.from_bytes(array);
+        let (object, length) = SystemProcessingMode::decode(&array).unwrap();
+
+        assert_eq!(length, array.len());
+        assert_eq!(system_processing, object);
+        assert_eq!(object.get_com_redrdp(), 5);
+        assert_eq!(object.get_com_redxmt(), 2);
+        assert_eq!(object.get_psr_polarization(), Polarization::Circular);
+        assert_eq!(object.get_psr_redrad(), 3);
+        assert_eq!(object.get_psr_stc(), StcMap::Map3);
+        assert!(!object.get_processing_option(Subfield::Ssr));
+        assert_eq!(object.get_mds_redrad(), 7);
+        assert_eq!(object.get_mds_cluster(), ClusterState::NotAutonomous);
+    }
+
+    #[test]
+    fn check_processing_invalid() {
+        let mut system_processing = SystemProcessingMode::default();
+
+        assert_eq!(
+            system_processing.set_ssr_redrad(8),
+            Err(CatError::ValueInvalid)
+        );
+
+        // PSR subfield is missing
+        let array = [0x10];
 
-        assert_eq!(system_processing.get_processing_option(Subfield::Com), object.get_processing_option(Subfield::Com));
+        assert_eq!(
+            SystemProcessingMode::decode(&array),
+            Err(CatError::SizeInvalid(0))
+        );
     }
 }
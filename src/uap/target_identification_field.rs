@@ -0,0 +1,121 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::aircraft_identification_field::AircraftIdentification;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Target Identification
+//
+// | 8 7 | 6 .. 1 | bit
+// | STI | spare  |
+// followed by eight characters with 6 bit International Alphabet No. 5 coding
+//
+
+/// Source of the target identification
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum IdentificationSource {
+    Downlinked,
+    Callsign,
+    Registration,
+    Invalid,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TargetIdentification {
+    source: u8,                             // 1 byte
+    identification: AircraftIdentification, // 6 bytes
+}
+
+/*
+* Implementation TargetIdentification
+*/
+impl TargetIdentification {
+    /*
+     * Set source of the target identification
+     */
+    pub fn set_source(&mut self, source: IdentificationSource) {
+        self.source = (source as u8) << 6;
+    }
+
+    /*
+     * Get source of the target identification
+     */
+    pub fn get_source(&self) -> IdentificationSource {
+        match self.source >> 6 {
+            0 => IdentificationSource::Downlinked,
+            1 => IdentificationSource::Callsign,
+            2 => IdentificationSource::Registration,
+            _ => IdentificationSource::Invalid,
+        }
+    }
+
+    /*
+     * Set identification, padded with spaces to eight characters
+     * Characters A..Z, 0..9 and space
+     */
+    pub fn set_identification(&mut self, identification: &str) -> Result<(), CatError> {
+        self.identification.set_identification(identification)
+    }
+
+    /*
+     * Get identification without trailing spaces
+     */
+    pub fn get_identification(&self) -> String {
+        self.identification.get_identification()
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TargetIdentification {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [source] = fixed_array(array)?;
+        let (identification, length) =
+            AircraftIdentification::decode(&array[1..]).map_err(|_| CatError::SizeInvalid(0))?;
+        Ok((
+            TargetIdentification {
+                source,
+                identification,
+            },
+            1 + length,
+        ))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut vector = vec![self.source];
+        vector.extend(self.identification.encode());
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_target_identification() {
+        // Create message
+        let mut target_identification = TargetIdentification::default();
+        target_identification.set_source(IdentificationSource::Callsign);
+        target_identification.set_identification("DLH123").unwrap();
+
+        // Convert struct to byte stream
+        let array = target_identification.encode();
+
+        assert_eq!(array, vec![0x40, 0x10, 0xc2, 0x31, 0xcb, 0x38, 0x20]);
+
+        // New message
+        let (object, length) = TargetIdentification::decode(&array).unwrap();
+
+        assert_eq!(length, TargetIdentification::MESSAGE_LENGTH);
+        assert_eq!(object.get_source(), IdentificationSource::Callsign);
+        assert_eq!(object.get_identification(), "DLH123");
+        assert_eq!(
+            TargetIdentification::decode(&array[0..6]),
+            Err(CatError::SizeInvalid(0))
+        );
+    }
+}
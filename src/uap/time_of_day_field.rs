@@ -1,5 +1,8 @@
 use std::mem;
 
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
 // Time of Day
 //
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
@@ -67,6 +70,17 @@ impl TimeOfDay {
     const FACTOR: f32 = 1.0 / 128.0;
 }
 
+impl DataItem for TimeOfDay {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let time = fixed_array(array)?;
+        Ok((TimeOfDay { time }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.time.to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
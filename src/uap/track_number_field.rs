@@ -0,0 +1,67 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Track Number
+//
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct TrackNumber {
+    number: u16, // 2 bytes
+}
+
+/*
+* Implementation TrackNumber
+*/
+impl TrackNumber {
+    /*
+     * Set track number
+     */
+    pub fn set_track_number(&mut self, number: u16) {
+        self.number = number;
+    }
+
+    /*
+     * Get track number
+     */
+    pub fn get_track_number(&self) -> u16 {
+        self.number
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for TrackNumber {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let number = u16::from_be_bytes(fixed_array(array)?);
+        Ok((TrackNumber { number }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.number.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_track_number() {
+        // Create message
+        let mut track_number = TrackNumber::default();
+        track_number.set_track_number(4095);
+
+        // Convert struct to byte stream
+        let array = track_number.encode();
+
+        // New message
+        let (object, length) = TrackNumber::decode(&array).unwrap();
+
+        assert_eq!(length, TrackNumber::MESSAGE_LENGTH);
+        assert_eq!(track_number.get_track_number(), object.get_track_number());
+    }
+}
@@ -0,0 +1,116 @@
+use std::mem;
+
+use crate::category::CatError;
+use crate::uap::data_item::{fixed_array, DataItem};
+
+// Vehicle Fleet Identification
+//
+
+/// Type of vehicle
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum VehicleFleet {
+    Unknown,
+    AtcEquipmentMaintenance,
+    AirportMaintenance,
+    FireService,
+    BirdScarer,
+    SnowPlough,
+    RunwaySweeper,
+    Emergency,
+    Police,
+    Bus,
+    Tug,
+    GrassCutter,
+    Fuel,
+    Baggage,
+    Catering,
+    AircraftMaintenance,
+    Flyco,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct VehicleFleetIdentification {
+    fleet: u8, // 1 byte
+}
+
+/*
+* Implementation VehicleFleetIdentification
+*/
+impl VehicleFleetIdentification {
+    /*
+     * Set type of vehicle
+     */
+    pub fn set_fleet(&mut self, fleet: VehicleFleet) {
+        self.fleet = fleet as u8;
+    }
+
+    /*
+     * Get type of vehicle
+     */
+    pub fn get_fleet(&self) -> VehicleFleet {
+        match self.fleet {
+            1 => VehicleFleet::AtcEquipmentMaintenance,
+            2 => VehicleFleet::AirportMaintenance,
+            3 => VehicleFleet::FireService,
+            4 => VehicleFleet::BirdScarer,
+            5 => VehicleFleet::SnowPlough,
+            6 => VehicleFleet::RunwaySweeper,
+            7 => VehicleFleet::Emergency,
+            8 => VehicleFleet::Police,
+            9 => VehicleFleet::Bus,
+            10 => VehicleFleet::Tug,
+            11 => VehicleFleet::GrassCutter,
+            12 => VehicleFleet::Fuel,
+            13 => VehicleFleet::Baggage,
+            14 => VehicleFleet::Catering,
+            15 => VehicleFleet::AircraftMaintenance,
+            16 => VehicleFleet::Flyco,
+            _ => VehicleFleet::Unknown,
+        }
+    }
+
+    /*
+     * Message length in memory.
+     */
+    pub const MESSAGE_LENGTH: usize = mem::size_of::<Self>();
+}
+
+impl DataItem for VehicleFleetIdentification {
+    fn decode(array: &[u8]) -> Result<(Self, usize), CatError> {
+        let [fleet] = fixed_array(array)?;
+        Ok((VehicleFleetIdentification { fleet }, Self::MESSAGE_LENGTH))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        vec![self.fleet]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_vehicle_fleet() {
+        // Create message
+        let mut vehicle_fleet = VehicleFleetIdentification::default();
+        vehicle_fleet.set_fleet(VehicleFleet::FireService);
+
+        // Convert struct to byte stream
+        let array = vehicle_fleet.encode();
+
+        assert_eq!(array, vec![0x03]);
+
+        // New message
+        let (object, _length) = VehicleFleetIdentification::decode(&array).unwrap();
+
+        assert_eq!(object.get_fleet(), VehicleFleet::FireService);
+        assert_eq!(
+            VehicleFleetIdentification::decode(&[0x20])
+                .unwrap()
+                .0
+                .get_fleet(),
+            VehicleFleet::Unknown
+        );
+    }
+}
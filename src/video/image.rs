@@ -0,0 +1,125 @@
+use miniz_oxide::deflate::{compress_to_vec_zlib, CompressionLevel};
+
+use crate::category::CatError;
+
+// Images of 8 bit grey values, one row after the other
+//
+// PGM: | P5 | width height | 255 | pixels |
+// PNG: | signature | IHDR | IDAT | IEND |
+//
+
+/*
+ * Encode grey image as binary portable graymap (PGM).
+ */
+pub fn encode_pgm(width: usize, height: usize, pixels: &[u8]) -> Result<Vec<u8>, CatError> {
+    check_size(width, height, pixels)?;
+
+    let mut output = format!("P5\n{} {}\n255\n", width, height).into_bytes();
+    output.extend_from_slice(pixels);
+    Ok(output)
+}
+
+/*
+ * Encode grey image as portable network graphic (PNG) without filtering.
+ */
+pub fn encode_png(width: usize, height: usize, pixels: &[u8]) -> Result<Vec<u8>, CatError> {
+    check_size(width, height, pixels)?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // Bit depth 8, grey, deflate, no filter method, no interlace
+    header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+    // Each row starts with filter type none
+    let mut rows = Vec::with_capacity(height * (width + 1));
+
+    for row in pixels.chunks_exact(width) {
+        rows.push(0);
+        rows.extend_from_slice(row);
+    }
+    let data = compress_to_vec_zlib(&rows, CompressionLevel::DefaultLevel as u8);
+
+    let mut output = PNG_SIGNATURE.to_vec();
+    write_chunk(&mut output, b"IHDR", &header);
+    write_chunk(&mut output, b"IDAT", &data);
+    write_chunk(&mut output, b"IEND", &[]);
+    Ok(output)
+}
+
+/*
+ * Signature at start of each PNG file.
+ */
+const PNG_SIGNATURE: [u8; 8] = [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a];
+
+/*
+ * Check image size against number of pixels.
+ */
+fn check_size(width: usize, height: usize, pixels: &[u8]) -> Result<(), CatError> {
+    if width == 0
+        || height == 0
+        || width > u32::MAX as usize
+        || height > u32::MAX as usize
+        || width.checked_mul(height) != Some(pixels.len())
+    {
+        return Err(CatError::ValueInvalid);
+    }
+    Ok(())
+}
+
+/*
+ * Append chunk with length, type, data and CRC.
+ */
+fn write_chunk(output: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let start = output.len();
+    output.extend_from_slice(chunk_type);
+    output.extend_from_slice(data);
+
+    let crc = crc32fast::hash(&output[start..]);
+    output.extend_from_slice(&crc.to_be_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miniz_oxide::inflate::decompress_to_vec_zlib;
+
+    #[test]
+    fn check_encode_pgm() {
+        let pgm = encode_pgm(3, 2, &[0, 64, 128, 192, 255, 1]).unwrap();
+
+        assert_eq!(&pgm[..11], b"P5\n3 2\n255\n");
+        assert_eq!(&pgm[11..], &[0, 64, 128, 192, 255, 1]);
+        assert_eq!(encode_pgm(3, 3, &[0; 6]), Err(CatError::ValueInvalid));
+    }
+
+    #[test]
+    fn check_encode_png() {
+        let png = encode_png(2, 2, &[0x10, 0x20, 0x30, 0x40]).unwrap();
+
+        assert_eq!(&png[..8], &PNG_SIGNATURE);
+        assert_eq!(
+            &png[8..33],
+            &[
+                0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+                0x00, 0x02, 0x08, 0x00, 0x00, 0x00, 0x00, 0x57, 0xdd, 0x52, 0xf8
+            ]
+        );
+
+        // Image data holds the filtered rows
+        let length = u32::from_be_bytes([png[33], png[34], png[35], png[36]]) as usize;
+
+        assert_eq!(&png[37..41], b"IDAT");
+        assert_eq!(
+            decompress_to_vec_zlib(&png[41..41 + length]).unwrap(),
+            vec![0x00, 0x10, 0x20, 0x00, 0x30, 0x40]
+        );
+        assert_eq!(
+            &png[png.len() - 12..],
+            &[0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82]
+        );
+        assert_eq!(encode_png(0, 0, &[]), Err(CatError::ValueInvalid));
+    }
+}
@@ -0,0 +1,296 @@
+use std::io::Write;
+
+use crate::asterix::cat240::decode_video_cells;
+use crate::asterix::record240::Record240;
+use crate::category::CatError;
+use crate::video::image::{encode_pgm, encode_png};
+
+// Polar raster of radar video
+//
+// One row per azimuth step starting at north, one column per range cell
+// | row 0      | cell 0 .. cell n |
+// | ...        |                  |
+// | row m      | cell 0 .. cell n |
+//
+
+/// Grey values of radar video over one antenna rotation
+#[derive(Debug, PartialEq, Clone)]
+pub struct PolarRaster {
+    azimuths: usize,
+    cells: usize,
+    pixels: Vec<u8>,
+}
+
+/*
+* Implementation PolarRaster
+*/
+impl PolarRaster {
+    /*
+     * Create empty raster with number of azimuth rows and range cells
+     */
+    pub fn new(azimuths: usize, cells: usize) -> Result<Self, CatError> {
+        let size = azimuths.checked_mul(cells).ok_or(CatError::ValueInvalid)?;
+
+        if size == 0 {
+            return Err(CatError::ValueInvalid);
+        }
+        Ok(PolarRaster {
+            azimuths,
+            cells,
+            pixels: vec![0; size],
+        })
+    }
+
+    /*
+     * Get number of azimuth rows
+     */
+    pub fn get_azimuths(&self) -> usize {
+        self.azimuths
+    }
+
+    /*
+     * Get number of range cells
+     */
+    pub fn get_cells(&self) -> usize {
+        self.cells
+    }
+
+    /*
+     * Get grey value of one azimuth row and range cell
+     */
+    pub fn get_pixel(&self, azimuth: usize, cell: usize) -> Option<u8> {
+        if azimuth >= self.azimuths || cell >= self.cells {
+            return None;
+        }
+        Some(self.pixels[azimuth * self.cells + cell])
+    }
+
+    /*
+     * Get grey values row by row
+     */
+    pub fn get_pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /*
+     * Insert radial between start and end azimuth in degree.
+     * Cell values of the given bits are scaled to 8 bit grey values,
+     * overlapping radials keep the maximum. Cells beyond the raster are dropped.
+     */
+    pub fn insert_radial(
+        &mut self,
+        start_azimuth: f64,
+        end_azimuth: f64,
+        start_range: u32,
+        cells: &[u32],
+        bits: u32,
+    ) -> Result<(), CatError> {
+        if bits == 0 || bits > 32 || !start_azimuth.is_finite() || !end_azimuth.is_finite() {
+            return Err(CatError::ValueInvalid);
+        }
+        let rows = self.azimuths as f64 / 360.0;
+        let first = (start_azimuth.rem_euclid(360.0) * rows).floor() as usize % self.azimuths;
+
+        // Radial covers at least one row, end before start crosses north
+        let span = ((end_azimuth - start_azimuth).rem_euclid(360.0) * rows).round() as usize;
+        let span = span.clamp(1, self.azimuths);
+
+        for step in 0..span {
+            let row = (first + step) % self.azimuths;
+            let pixels = &mut self.pixels[row * self.cells..(row + 1) * self.cells];
+
+            for (index, cell) in cells.iter().enumerate() {
+                let column = start_range as usize + index;
+
+                if column >= self.cells {
+                    break;
+                }
+                let grey = grey_value(*cell, bits);
+                pixels[column] = pixels[column].max(grey);
+            }
+        }
+        Ok(())
+    }
+
+    /*
+     * Encode raster as PGM image.
+     */
+    pub fn to_pgm(&self) -> Vec<u8> {
+        encode_pgm(self.cells, self.azimuths, &self.pixels).expect("raster size is valid")
+    }
+
+    /*
+     * Encode raster as PNG image.
+     */
+    pub fn to_png(&self) -> Vec<u8> {
+        encode_png(self.cells, self.azimuths, &self.pixels).expect("raster size is valid")
+    }
+
+    /*
+     * Write raster as PGM image.
+     */
+    pub fn write_pgm<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_pgm())
+    }
+
+    /*
+     * Write raster as PNG image.
+     */
+    pub fn write_png<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_png())
+    }
+}
+
+/// Accumulation of CAT240 video records into polar rasters per antenna rotation
+#[derive(Debug, PartialEq, Clone)]
+pub struct Sweep {
+    raster: PolarRaster,
+    last_azimuth: Option<f64>,
+}
+
+/*
+* Implementation Sweep
+*/
+impl Sweep {
+    /*
+     * Create sweep with number of azimuth rows and range cells
+     */
+    pub fn new(azimuths: usize, cells: usize) -> Result<Self, CatError> {
+        Ok(Sweep {
+            raster: PolarRaster::new(azimuths, cells)?,
+            last_azimuth: None,
+        })
+    }
+
+    /*
+     * Insert video record into the current rotation.
+     * Returns the raster of the completed rotation when the start azimuth
+     * wraps around north. Records without video header are ignored.
+     */
+    pub fn insert_record(&mut self, record: &Record240) -> Result<Option<PolarRaster>, CatError> {
+        let Some(header) = record.video_header_nano.or(record.video_header_femto) else {
+            return Ok(None);
+        };
+        let cells = decode_video_cells(record)?;
+        let bits = record
+            .video_resolution
+            .ok_or(CatError::ValueInvalid)?
+            .get_cell_bits()?;
+        let start_azimuth = header.get_start_azimuth();
+
+        // Small steps backwards are jitter, not a new rotation
+        let completed = match self.last_azimuth {
+            Some(last_azimuth) if last_azimuth - start_azimuth > Self::WRAP_AZIMUTH => {
+                let raster = PolarRaster::new(self.raster.azimuths, self.raster.cells)?;
+                Some(std::mem::replace(&mut self.raster, raster))
+            }
+            _ => None,
+        };
+        self.last_azimuth = Some(start_azimuth);
+        self.raster.insert_radial(
+            start_azimuth,
+            header.get_end_azimuth(),
+            header.get_start_range(),
+            &cells,
+            bits,
+        )?;
+        Ok(completed)
+    }
+
+    /*
+     * Get raster of the current, possibly incomplete rotation
+     */
+    pub fn get_raster(&self) -> &PolarRaster {
+        &self.raster
+    }
+
+    /*
+     * Azimuth decrease in degree that starts a new rotation.
+     */
+    const WRAP_AZIMUTH: f64 = 180.0;
+}
+
+/*
+ * Scale cell value of given bits to 8 bit grey value.
+ */
+fn grey_value(cell: u32, bits: u32) -> u8 {
+    if bits >= 8 {
+        (cell >> (bits - 8)) as u8
+    } else {
+        let maximum = (1u32 << bits) - 1;
+        (cell.min(maximum) * 255 / maximum) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asterix::cat240::encode_video_cells;
+    use crate::uap::cat240::video_header_field::VideoHeader;
+    use crate::uap::cat240::video_resolution_field::CellResolution;
+
+    /*
+     * Create video record of one radial.
+     */
+    fn radial(start_azimuth: f64, end_azimuth: f64, cells: &[u32]) -> Record240 {
+        let mut video_header = VideoHeader::default();
+        video_header.set_start_azimuth(start_azimuth).unwrap();
+        video_header.set_end_azimuth(end_azimuth).unwrap();
+        video_header.set_cell_duration(416);
+
+        let mut record = Record240::default();
+        record.video_header_nano = Some(video_header);
+        encode_video_cells(&mut record, cells, CellResolution::Medium, true).unwrap();
+        record
+    }
+
+    #[test]
+    fn check_polar_raster() {
+        let mut raster = PolarRaster::new(4, 3).unwrap();
+
+        // Radial crosses north and covers the last and first row
+        raster
+            .insert_radial(270.0, 90.0, 1, &[0x0f, 0x05, 0x0f], 4)
+            .unwrap();
+        raster
+            .insert_radial(0.0, 90.0, 0, &[0x0a, 0x0a], 4)
+            .unwrap();
+
+        assert_eq!(
+            raster.get_pixels(),
+            &[170, 255, 85, 0, 0, 0, 0, 0, 0, 0, 255, 85]
+        );
+        assert_eq!(raster.get_pixel(3, 1), Some(255));
+        assert_eq!(raster.get_pixel(4, 0), None);
+        assert_eq!(&raster.to_pgm()[..11], b"P5\n3 4\n255\n");
+        assert_eq!(&raster.to_png()[1..4], b"PNG");
+        assert_eq!(PolarRaster::new(0, 3), Err(CatError::ValueInvalid));
+    }
+
+    #[test]
+    fn check_sweep() {
+        let mut sweep = Sweep::new(4, 2).unwrap();
+
+        // First rotation
+        assert_eq!(sweep.insert_record(&radial(0.0, 90.0, &[1, 2])), Ok(None));
+        assert_eq!(sweep.insert_record(&radial(270.0, 0.0, &[3])), Ok(None));
+        assert_eq!(sweep.insert_record(&Record240::default()), Ok(None));
+
+        // Start azimuth wraps around north into the second rotation
+        let raster = sweep
+            .insert_record(&radial(0.0, 90.0, &[15, 15]))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(raster.get_pixels(), &[17, 34, 0, 0, 0, 0, 51, 0]);
+        assert_eq!(
+            sweep.get_raster().get_pixels(),
+            &[255, 255, 0, 0, 0, 0, 0, 0]
+        );
+
+        let mut image = Vec::new();
+        raster.write_pgm(&mut image).unwrap();
+
+        assert_eq!(image.len(), 11 + 8);
+    }
+}